@@ -1,17 +1,37 @@
 use crate::filtering::evidence_parser::parse_evidence_list;
+use crate::utils::parse_difficulty;
 use enum_iterator::all;
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-use uncore::types::{evidence::Evidence, ghost::types::GhostType};
+use std::collections::HashMap;
+use uncore::difficulty::Difficulty;
+use uncore::types::{
+    evidence::{Evidence, EvidenceSet},
+    ghost::types::GhostType,
+};
 
 // This command will find ghosts that become indistinguishable if only a certain subset of evidence is considered,
 // or find evidence types that are "in conflict" (e.g., never appear together or always appear together).
 // The DESIGN.md mentions:
 // ghost_list conflicts --evidence "Freezing Temps,EMF Level 5"
 // ghost_list conflicts --show-all
-
-pub fn handle_conflicts_command(evidence_filter_str: Option<&str>, show_all: bool) {
-    if let Some(filter_str) = evidence_filter_str {
+// ghost_list conflicts --difficulty TutorialChapter1
+
+pub fn handle_conflicts_command(
+    evidence_filter_str: Option<&str>,
+    show_all: bool,
+    difficulty_str: Option<&str>,
+) {
+    if let Some(difficulty_str) = difficulty_str {
+        let Some(difficulty) = parse_difficulty(difficulty_str) else {
+            eprintln!("Error: Could not parse difficulty '{}'", difficulty_str);
+            return;
+        };
+        println!(
+            "Analysing conflicts within the ghost set of difficulty: {}",
+            difficulty.difficulty_name()
+        );
+        analyse_difficulty_conflicts(difficulty, evidence_filter_str);
+    } else if let Some(filter_str) = evidence_filter_str {
         println!("Analysing conflicts for evidence subset: {}", filter_str);
         analyse_subset_conflicts(filter_str);
     } else if show_all {
@@ -33,15 +53,12 @@ fn analyse_subset_conflicts(evidence_filter_str: &str) {
     }
 
     let all_ghosts: Vec<GhostType> = all::<GhostType>().collect();
+    let filter_set: EvidenceSet = filter_evidence.iter().collect();
 
     // Find ghosts that match this evidence subset exactly
     let matching_ghosts: Vec<&GhostType> = all_ghosts
         .iter()
-        .filter(|ghost| {
-            let ghost_evidence: HashSet<Evidence> = ghost.evidences().into_iter().collect();
-            let filter_set: HashSet<Evidence> = filter_evidence.iter().cloned().collect();
-            filter_set.is_subset(&ghost_evidence)
-        })
+        .filter(|ghost| ghost.evidence_set().is_superset_of(filter_set))
         .collect();
 
     if matching_ghosts.is_empty() {
@@ -58,16 +75,10 @@ fn analyse_subset_conflicts(evidence_filter_str: &str) {
     );
 
     // Group by identical evidence sets within this subset
-    let mut evidence_groups: HashMap<Vec<Evidence>, Vec<&GhostType>> = HashMap::new();
+    let mut evidence_groups: HashMap<EvidenceSet, Vec<&GhostType>> = HashMap::new();
 
     for ghost in &matching_ghosts {
-        let ghost_evidence: HashSet<Evidence> = ghost.evidences().into_iter().collect();
-        let filtered_evidence: Vec<Evidence> = filter_evidence
-            .iter()
-            .filter(|e| ghost_evidence.contains(e))
-            .cloned()
-            .collect();
-
+        let filtered_evidence = ghost.evidence_set().intersection(filter_set);
         evidence_groups
             .entry(filtered_evidence)
             .or_default()
@@ -95,6 +106,57 @@ fn analyse_subset_conflicts(evidence_filter_str: &str) {
     }
 }
 
+/// Finds ghosts in a difficulty's pool that cannot be told apart with the evidence
+/// obtainable through that difficulty's truck gear (optionally narrowed further by
+/// an evidence filter).
+fn analyse_difficulty_conflicts(difficulty: Difficulty, evidence_filter_str: Option<&str>) {
+    let difficulty_struct = difficulty.create_difficulty_struct();
+    let ghost_pool = difficulty_struct.ghost_set.as_vec();
+
+    let mut obtainable: EvidenceSet = difficulty_struct
+        .truck_gear
+        .iter()
+        .filter_map(|gear| Evidence::try_from(gear).ok())
+        .collect();
+    if let Some(filter_str) = evidence_filter_str {
+        let filter_set: EvidenceSet = parse_evidence_list(filter_str).iter().collect();
+        obtainable = obtainable.intersection(filter_set);
+    }
+
+    println!(
+        "Ghost pool: {} ghosts, obtainable evidence: [{}]",
+        ghost_pool.len(),
+        obtainable.iter().map(|e| e.name()).join(", ")
+    );
+
+    let mut evidence_groups: HashMap<EvidenceSet, Vec<GhostType>> = HashMap::new();
+    for ghost in &ghost_pool {
+        evidence_groups
+            .entry(ghost.evidence_set().intersection(obtainable))
+            .or_default()
+            .push(*ghost);
+    }
+
+    let mut groups: Vec<_> = evidence_groups
+        .into_iter()
+        .filter(|(_, ghosts)| ghosts.len() > 1)
+        .collect();
+    groups.sort_by_key(|(evidence_set, _)| *evidence_set);
+
+    for (evidence_set, ghosts) in &groups {
+        println!(
+            "⚠️  INDISTINGUISHABLE: {} ghosts share obtainable evidence [{}]: {}",
+            ghosts.len(),
+            evidence_set.iter().map(|e| e.name()).join(", "),
+            ghosts.iter().map(|g| g.name()).join(", ")
+        );
+    }
+
+    if groups.is_empty() {
+        println!("✅ All ghosts in this difficulty's pool are distinguishable");
+    }
+}
+
 fn analyse_all_conflicts() {
     let all_ghosts: Vec<GhostType> = all::<GhostType>().collect();
     let mut issues_found = false;
@@ -103,14 +165,14 @@ fn analyse_all_conflicts() {
     println!("Checking that all ghosts have exactly 5 evidences...");
 
     for ghost in &all_ghosts {
-        let evidence_count = ghost.evidences().len();
+        let evidence_count = ghost.evidence_set().len();
         if evidence_count != 5 {
             issues_found = true;
             println!(
                 "⚠️  {} has {} evidences (expected 5): [{}]",
                 ghost.name(),
                 evidence_count,
-                ghost.evidences().iter().map(|e| e.name()).join(", ")
+                ghost.evidence_set().iter().map(|e| e.name()).join(", ")
             );
         }
     }
@@ -118,12 +180,13 @@ fn analyse_all_conflicts() {
     println!("\n## 2. Duplicate Evidence Sets");
     println!("Checking for ghosts with identical evidence sets...");
 
-    let mut evidence_groups: HashMap<Vec<Evidence>, Vec<&GhostType>> = HashMap::new();
+    let mut evidence_groups: HashMap<EvidenceSet, Vec<&GhostType>> = HashMap::new();
 
     for ghost in &all_ghosts {
-        let mut evidence_set: Vec<Evidence> = ghost.evidences().into_iter().collect();
-        evidence_set.sort_by_key(|e| e.name()); // Sort for consistent comparison
-        evidence_groups.entry(evidence_set).or_default().push(ghost);
+        evidence_groups
+            .entry(ghost.evidence_set())
+            .or_default()
+            .push(ghost);
     }
 
     for (evidence_set, ghosts) in evidence_groups {
@@ -145,7 +208,7 @@ fn analyse_all_conflicts() {
 
     let mut evidence_counts: HashMap<Evidence, usize> = HashMap::new();
     for ghost in &all_ghosts {
-        for evidence in ghost.evidences() {
+        for evidence in ghost.evidence_set().iter() {
             *evidence_counts.entry(evidence).or_insert(0) += 1;
         }
     }
@@ -45,8 +45,9 @@ pub fn handle_correlation_command(evidence1_str: &str, evidence2_str: Option<&st
         let mut count_neither = 0;
 
         for ghost in &all_ghosts {
-            let has_e1 = ghost.evidences().contains(&evidence1);
-            let has_e2 = ghost.evidences().contains(&evidence2);
+            let ghost_evidence = ghost.evidence_set();
+            let has_e1 = ghost_evidence.contains(evidence1);
+            let has_e2 = ghost_evidence.contains(evidence2);
 
             if has_e1 {
                 count_e1 += 1;
@@ -138,13 +139,14 @@ pub fn handle_correlation_command(evidence1_str: &str, evidence2_str: Option<&st
         let mut correlations: HashMap<Evidence, (usize, usize)> = HashMap::new(); // (count_both, count_other_evidence)
         let mut count_e1_total = 0;
         for ghost in &all_ghosts {
-            if ghost.evidences().contains(&evidence1) {
+            let ghost_evidence = ghost.evidence_set();
+            if ghost_evidence.contains(evidence1) {
                 count_e1_total += 1;
                 for other_evidence_type in all::<Evidence>() {
                     if other_evidence_type == evidence1 {
                         continue;
                     }
-                    if ghost.evidences().contains(&other_evidence_type) {
+                    if ghost_evidence.contains(other_evidence_type) {
                         let entry = correlations.entry(other_evidence_type).or_insert((0, 0));
                         entry.0 += 1; // count_both with evidence1
                     }
@@ -155,7 +157,7 @@ pub fn handle_correlation_command(evidence1_str: &str, evidence2_str: Option<&st
                 if other_evidence_type == evidence1 {
                     continue;
                 }
-                if ghost.evidences().contains(&other_evidence_type) {
+                if ghost_evidence.contains(other_evidence_type) {
                     let entry = correlations.entry(other_evidence_type).or_insert((0, 0));
                     entry.1 += 1; // count_other_evidence
                 }
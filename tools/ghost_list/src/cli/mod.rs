@@ -121,6 +121,11 @@ pub enum Commands {
         evidence: Option<String>,
         #[arg(long, help = "Show all types of conflicts", default_value = "false")]
         show_all: bool,
+        #[arg(
+            long,
+            help = "Restrict analysis to the ghost pool and gear of a difficulty (e.g. \"TutorialChapter1\")"
+        )]
+        difficulty: Option<String>,
     },
     /// Show unique evidence combinations for identifying ghosts
     UniqueCombinations {
@@ -240,8 +245,12 @@ impl Cli {
             }) => {
                 handle_find_sets_command(target_evidence, *size, *max_results);
             }
-            Some(Commands::Conflicts { evidence, show_all }) => {
-                handle_conflicts_command(evidence.as_deref(), *show_all);
+            Some(Commands::Conflicts {
+                evidence,
+                show_all,
+                difficulty,
+            }) => {
+                handle_conflicts_command(evidence.as_deref(), *show_all, difficulty.as_deref());
             }
             Some(Commands::UniqueCombinations {
                 min_evidence,
@@ -0,0 +1,11 @@
+use uncore::difficulty::Difficulty;
+
+/// Finds a difficulty by its enum name (e.g. "TutorialChapter1") or display name
+/// (e.g. "Tutorial: Chapter 1"), case insensitive.
+pub fn parse_difficulty(difficulty_str: &str) -> Option<Difficulty> {
+    let wanted = difficulty_str.trim();
+    Difficulty::all().find(|difficulty| {
+        format!("{:?}", difficulty).eq_ignore_ascii_case(wanted)
+            || difficulty.difficulty_name().eq_ignore_ascii_case(wanted)
+    })
+}
@@ -1,4 +1,6 @@
+pub mod difficulty_parser;
 pub mod ghost_parser;
 
 // Re-export commonly used functions
+pub use difficulty_parser::parse_difficulty;
 pub use ghost_parser::parse_ghost_list;
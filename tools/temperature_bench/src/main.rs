@@ -0,0 +1,93 @@
+//! Runs the temperature diffusion on a synthetic board, without the game, and
+//! reports its throughput and stability.
+//!
+//! ```sh
+//! cargo run --release -p temperature_bench -- --width 128 --height 128 --frames 500
+//! ```
+
+use std::time::Instant;
+
+use clap::Parser;
+use rand::Rng;
+use uncore::celsius_to_kelvin;
+use uncore::random_seed;
+use uncore::resources::board_data::BoardData;
+use uncore::resources::roomdb::RoomDB;
+use ungearitems::components::thermometer::{self, temperature_diffusion_step};
+
+#[derive(Parser, Debug)]
+#[command(about = "Headless benchmark of the temperature diffusion")]
+struct Cli {
+    /// Board width in tiles.
+    #[arg(long, default_value_t = 64)]
+    width: usize,
+    /// Board height in tiles.
+    #[arg(long, default_value_t = 64)]
+    height: usize,
+    /// Number of floors.
+    #[arg(long, default_value_t = 1)]
+    floors: usize,
+    /// Diffusion frames to run.
+    #[arg(long, default_value_t = 300)]
+    frames: usize,
+    /// Seed for the initial temperatures and the tile selection.
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+}
+
+/// Builds the shared synthetic board with random temperatures around the
+/// ambient.
+fn synthetic_board(cli: &Cli, rng: &mut impl Rng) -> (BoardData, RoomDB) {
+    let (mut bf, roomdb) = thermometer::synthetic_board((cli.width, cli.height, cli.floors));
+    for t in bf.temperature_field.iter_mut() {
+        *t = celsius_to_kelvin(rng.random_range(5.0..25.0));
+    }
+    bf.temperature_field_prev = bf.temperature_field.clone();
+    // Every tile starts active, as right after a level load.
+    bf.temperature_activity.fill(50.0);
+    (bf, roomdb)
+}
+
+fn main() {
+    let cli = Cli::parse();
+    random_seed::set_seed(cli.seed);
+    let mut rng = random_seed::rng();
+    let (mut bf, roomdb) = synthetic_board(&cli, &mut rng);
+
+    let mut tiles_updated = 0;
+    let mut max_temp_change: f32 = 0.0;
+    let mut nan_rejections = 0;
+    let start = Instant::now();
+    for _ in 0..cli.frames {
+        let step = temperature_diffusion_step(&mut bf, &roomdb, &mut rng);
+        tiles_updated += step.tiles_updated;
+        max_temp_change = max_temp_change.max(step.max_temp_change);
+        nan_rejections += step.nan_rejections;
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    let nan_tiles = bf
+        .temperature_field
+        .iter()
+        .filter(|t| !t.is_finite())
+        .count();
+
+    let frames = cli.frames.max(1) as f64;
+    println!(
+        "Board {}x{}x{} ({} tiles), {} frames, seed {}",
+        cli.width,
+        cli.height,
+        cli.floors,
+        bf.temperature_field.len(),
+        cli.frames,
+        cli.seed
+    );
+    println!(
+        "Time: {:.3}s total, {:.3} ms/frame, {:.0} tile updates/s",
+        elapsed,
+        elapsed * 1000.0 / frames,
+        tiles_updated as f64 / elapsed.max(f64::EPSILON)
+    );
+    println!(
+        "Stability: max per-frame temperature change {max_temp_change:.3} K, {nan_rejections} NaN rejections, {nan_tiles} non-finite tiles"
+    );
+}
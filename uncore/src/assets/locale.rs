@@ -0,0 +1,48 @@
+use bevy::{asset::AssetLoader, prelude::*};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Key to translated text table for a single language.
+///
+/// Loaded from `locale/<language>.locale.json`, a flat JSON object mapping
+/// text keys (e.g. `walkie.gear_in_van.hint`) to the translated string.
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct LocaleTable {
+    pub entries: HashMap<String, String>,
+}
+
+#[derive(Error, Debug)]
+pub enum LocaleTableError {
+    #[error("Could not read locale table: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid locale table: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Default)]
+pub struct LocaleTableLoader;
+
+impl AssetLoader for LocaleTableLoader {
+    type Asset = LocaleTable;
+
+    type Settings = ();
+
+    type Error = LocaleTableError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn bevy::asset::io::Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut bevy::asset::LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(LocaleTable {
+            entries: serde_json::from_slice(&bytes)?,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["locale.json"]
+    }
+}
@@ -1,5 +1,6 @@
 //! This module adds tooling for addding new assets for Bevy.
 
 pub mod index;
+pub mod locale;
 pub mod tmxmap;
 pub mod tsxsheet;
@@ -32,7 +32,7 @@ use fastapprox::faster;
 use ordered_float::NotNan;
 use serde::{Deserialize, Serialize};
 
-use crate::types::board::light::LightData;
+use crate::types::board::light::{LightData, kelvin_to_rgb};
 use crate::types::tiledmap::map::MapLayer;
 
 /// The `Behaviour` component defines the behaviour of an object in the game world.
@@ -226,9 +226,52 @@ pub struct Light {
     pub can_emit_light: bool,
     pub emission_power: NotNan<f32>,
     pub heat_coef: i32,
-    pub flickering: bool,
+    /// Set for a moment by ghost events, dimming the light. Unrelated to the
+    /// map's `flicker` profile.
+    pub ghost_flicker: bool,
     /// Precomputed heat output to avoid expensive calculations every frame
     pub cached_heat_output: NotNan<f32>,
+    /// Colour temperature in Kelvin set by the map (`light:temperature`). Plain
+    /// white light when not set.
+    pub color_temperature: Option<u32>,
+    /// How the light output varies over time, set by the map (`light:flicker`).
+    pub flicker: LightFlicker,
+}
+
+/// Flicker profile of a light source.
+///
+/// Scales the baked contribution of the source while it is on; a light that is
+/// off stays off whatever its profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LightFlicker {
+    /// Steady light.
+    #[default]
+    Off,
+    /// Small, constant wavering, like an old bulb.
+    Subtle,
+    /// Large drops with the odd near blackout, like a failing fluorescent tube.
+    Faulty,
+}
+
+impl LightFlicker {
+    pub fn from_text(text: &str) -> Self {
+        match text.trim().to_ascii_lowercase().as_str() {
+            "subtle" => Self::Subtle,
+            "faulty" => Self::Faulty,
+            _ => Self::Off,
+        }
+    }
+
+    /// Output factor (0..=1) for a uniform random sample in 0..1.
+    pub fn factor(&self, sample: f32) -> f32 {
+        match self {
+            Self::Off => 1.0,
+            Self::Subtle => 1.0 - 0.15 * sample,
+            // One time in ten the tube nearly goes out.
+            Self::Faulty if sample > 0.9 => 0.05,
+            Self::Faulty => 1.0 - 0.4 * sample,
+        }
+    }
 }
 
 impl Default for Light {
@@ -240,15 +283,17 @@ impl Default for Light {
             can_emit_light: false,
             emission_power: NotNan::new(0.0).unwrap(),
             heat_coef: 0,
-            flickering: false,
+            ghost_flicker: false,
             cached_heat_output: NotNan::new(0.0).unwrap(),
+            color_temperature: None,
+            flicker: LightFlicker::Off,
         }
     }
 }
 
 impl Light {
     pub fn emmisivity_lumens(&self) -> f32 {
-        if self.flickering {
+        if self.ghost_flicker {
             // Reduced emission when flickering, with a slight glow even when off
             if self.light_emission_enabled {
                 self.emission_power.exp() * 0.4
@@ -279,8 +324,12 @@ impl Light {
         }
     }
 
+    /// RGB colour of the emitted light, used when baking the light field.
     pub fn color(&self) -> (f32, f32, f32) {
-        (1.0, 1.0, 1.0)
+        match self.color_temperature {
+            Some(kelvin) => kelvin_to_rgb(kelvin as f32),
+            None => (1.0, 1.0, 1.0),
+        }
     }
 
     /// This represents if a light on the map is emitting visible light or other types.
@@ -797,6 +846,13 @@ impl SpriteConfig {
         if p.object.name.is_empty() {
             p.object.name.clone_from(&self.variant.clone());
         }
+        if p.light.can_emit_light {
+            p.light.color_temperature = self
+                .properties
+                .get_string_opt("light:temperature")
+                .and_then(|t| parse_color_temperature(&t));
+            p.light.flicker = LightFlicker::from_text(&self.properties.get_string("light:flicker"));
+        }
     }
 
     /// A class requires a set of states. Not only these are the only valid ones for
@@ -914,10 +970,44 @@ impl BehaviourProperties {
     }
 }
 
+/// Parses a colour temperature such as "3000K", "3000" or "3000.0" into Kelvin.
+fn parse_color_temperature(text: &str) -> Option<u32> {
+    let number = text.trim().trim_end_matches(['K', 'k']).trim();
+    let kelvin = number.parse::<f32>().ok()?;
+    (kelvin > 0.0).then_some(kelvin.round() as u32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn light_flicker_factor_bounds() {
+        for profile in [
+            LightFlicker::Off,
+            LightFlicker::Subtle,
+            LightFlicker::Faulty,
+        ] {
+            for i in 0..=10 {
+                let f = profile.factor(i as f32 / 10.0);
+                assert!((0.0..=1.0).contains(&f), "{profile:?} gave {f}");
+            }
+        }
+        assert_eq!(LightFlicker::Off.factor(0.7), 1.0);
+        assert_eq!(LightFlicker::from_text("Faulty"), LightFlicker::Faulty);
+        assert_eq!(LightFlicker::from_text(""), LightFlicker::Off);
+    }
+
+    #[test]
+    fn color_temperature_parsing() {
+        assert_eq!(parse_color_temperature("3000K"), Some(3000));
+        assert_eq!(parse_color_temperature(" 4500 k"), Some(4500));
+        assert_eq!(parse_color_temperature("2700"), Some(2700));
+        assert_eq!(parse_color_temperature("6500.4"), Some(6500));
+        assert_eq!(parse_color_temperature("warm"), None);
+        assert_eq!(parse_color_temperature("0K"), None);
+    }
+
     #[test]
     fn class_serialization() {
         let c = Class::Breaker;
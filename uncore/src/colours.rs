@@ -1,7 +1,9 @@
 use bevy::{
     color::{Color, palettes::css},
+    prelude::Resource,
     ui::BorderColor,
 };
+use unsettings::video::ColourblindMode;
 
 pub const DEBUG_BCOLOR: BorderColor = BorderColor(Color::srgba(0.0, 1.0, 1.0, 0.0003));
 pub const INVENTORY_STATS_COLOR: Color = Color::srgba(0.7, 0.7, 0.7, 0.9);
@@ -25,3 +27,142 @@ pub const MENU_ITEM_COLOR_ON: Color = Color::Srgba(css::ORANGE_RED);
 pub const MENU_ITEM_COLOR_OFF: Color = Color::Srgba(css::GRAY);
 pub const MENU_DESC_TEXT_COLOR: Color = Color::srgba(0.8, 0.94, 0.98, 1.0);
 pub const JOURNAL_BUTTON_BLINK_BORDER_COLOR: Color = Color::srgba(0.9, 0.85, 0.7, 1.0);
+
+/// Key UI and evidence colours, swapped as a set for colour vision deficiencies.
+///
+/// The active palette is kept in sync with the `colourblind_mode` video setting
+/// and should be used instead of the constants above where colour carries meaning.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct UiPalette {
+    pub menu_item_on: Color,
+    pub menu_item_off: Color,
+    pub evidence_unknown: Color,
+    pub evidence_found: Color,
+    pub evidence_discarded: Color,
+    /// Fill of the progress bar on hold-to-confirm buttons.
+    pub hold_progress: Color,
+    /// Persistent warnings on the HUD, like the "cheats active" badge.
+    pub warning: Color,
+}
+
+impl UiPalette {
+    pub const DEFAULT: UiPalette = UiPalette {
+        menu_item_on: MENU_ITEM_COLOR_ON,
+        menu_item_off: MENU_ITEM_COLOR_OFF,
+        evidence_unknown: Color::srgba(0.7, 0.7, 0.7, 1.0),
+        evidence_found: Color::srgba(0.0, 0.5, 0.0, 1.0),
+        evidence_discarded: Color::srgba(1.0, 0.0, 0.0, 0.8),
+        hold_progress: Color::srgba(1.0, 1.0, 0.0, 0.2),
+        warning: Color::srgba(1.0, 0.45, 0.1, 1.0),
+    };
+
+    /// Red-weak: avoid red/green pairs, use blue against orange.
+    pub const PROTANOPIA: UiPalette = UiPalette {
+        menu_item_on: Color::srgba(0.35, 0.65, 1.0, 1.0),
+        menu_item_off: MENU_ITEM_COLOR_OFF,
+        evidence_unknown: Color::srgba(0.7, 0.7, 0.7, 1.0),
+        evidence_found: Color::srgba(0.34, 0.71, 0.91, 1.0),
+        evidence_discarded: Color::srgba(0.90, 0.62, 0.0, 1.0),
+        hold_progress: Color::srgba(0.34, 0.71, 0.91, 0.35),
+        warning: Color::srgba(0.90, 0.62, 0.0, 1.0),
+    };
+
+    /// Green-weak: same blue/orange split, slightly warmer highlight.
+    pub const DEUTERANOPIA: UiPalette = UiPalette {
+        menu_item_on: Color::srgba(0.95, 0.75, 0.2, 1.0),
+        menu_item_off: MENU_ITEM_COLOR_OFF,
+        evidence_unknown: Color::srgba(0.7, 0.7, 0.7, 1.0),
+        evidence_found: Color::srgba(0.34, 0.71, 0.91, 1.0),
+        evidence_discarded: Color::srgba(0.84, 0.37, 0.0, 1.0),
+        hold_progress: Color::srgba(0.34, 0.71, 0.91, 0.35),
+        warning: Color::srgba(0.84, 0.37, 0.0, 1.0),
+    };
+
+    /// Blue-weak: avoid blue/yellow pairs, use teal against vermillion.
+    pub const TRITANOPIA: UiPalette = UiPalette {
+        menu_item_on: Color::srgba(1.0, 0.35, 0.45, 1.0),
+        menu_item_off: MENU_ITEM_COLOR_OFF,
+        evidence_unknown: Color::srgba(0.7, 0.7, 0.7, 1.0),
+        evidence_found: Color::srgba(0.0, 0.75, 0.65, 1.0),
+        evidence_discarded: Color::srgba(0.84, 0.37, 0.0, 1.0),
+        hold_progress: Color::srgba(0.0, 0.75, 0.65, 0.35),
+        warning: Color::srgba(1.0, 0.35, 0.45, 1.0),
+    };
+
+    /// Bright colours for the high contrast theme. Yellow against white for
+    /// menus and blue against orange for evidence, so it works for every
+    /// colourblind mode too.
+    pub const HIGH_CONTRAST: UiPalette = UiPalette {
+        menu_item_on: Color::srgba(1.0, 0.85, 0.0, 1.0),
+        menu_item_off: Color::srgba(0.92, 0.92, 0.92, 1.0),
+        evidence_unknown: Color::srgba(0.92, 0.92, 0.92, 1.0),
+        evidence_found: Color::srgba(0.35, 0.75, 1.0, 1.0),
+        evidence_discarded: Color::srgba(1.0, 0.55, 0.0, 1.0),
+        hold_progress: Color::srgba(1.0, 0.85, 0.0, 0.4),
+        warning: Color::srgba(1.0, 0.45, 0.75, 1.0),
+    };
+
+    pub fn for_mode(mode: ColourblindMode) -> UiPalette {
+        match mode {
+            ColourblindMode::None => Self::DEFAULT,
+            ColourblindMode::Protanopia => Self::PROTANOPIA,
+            ColourblindMode::Deuteranopia => Self::DEUTERANOPIA,
+            ColourblindMode::Tritanopia => Self::TRITANOPIA,
+        }
+    }
+}
+
+impl Default for UiPalette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::color::Luminance;
+
+    /// WCAG contrast ratio between two colours
+    fn contrast(a: Color, b: Color) -> f32 {
+        let (la, lb) = (a.luminance(), b.luminance());
+        (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+    }
+
+    #[test]
+    fn test_palettes_readable_on_dark_background() {
+        // Menus and the `[current]` value markers are drawn over near-black panels;
+        // 3:1 is the WCAG minimum for UI components
+        let background = Color::BLACK;
+        let palettes = [
+            ("None", UiPalette::for_mode(ColourblindMode::None)),
+            (
+                "Protanopia",
+                UiPalette::for_mode(ColourblindMode::Protanopia),
+            ),
+            (
+                "Deuteranopia",
+                UiPalette::for_mode(ColourblindMode::Deuteranopia),
+            ),
+            (
+                "Tritanopia",
+                UiPalette::for_mode(ColourblindMode::Tritanopia),
+            ),
+            ("HighContrast", UiPalette::HIGH_CONTRAST),
+        ];
+        for (name, palette) in palettes {
+            for colour in [
+                palette.menu_item_on,
+                palette.menu_item_off,
+                palette.evidence_found,
+                palette.evidence_discarded,
+                palette.warning,
+            ] {
+                assert!(
+                    contrast(colour, background) >= 3.0,
+                    "{name}: {colour:?} is too dark"
+                );
+            }
+        }
+    }
+}
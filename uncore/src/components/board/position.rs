@@ -89,6 +89,29 @@ impl Position {
         Vec3::new(x, y, z + self.global_z)
     }
 
+    /// Inverse of `to_screen_coord` for a point on the floor at height `z`.
+    ///
+    /// Returns `None` if the isometric projection cannot be inverted.
+    pub fn from_screen_coord(screen: Vec2, z: f32) -> Option<Self> {
+        // Determinant of the 2x2 isometric projection matrix
+        let det = PERSPECTIVE_X[0] * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * PERSPECTIVE_X[1];
+        if det.abs() < 1e-6 {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+
+        // Remove the contribution of the floor height before inverting
+        let b_x = screen.x - z * PERSPECTIVE_Z[0];
+        let b_y = screen.y - z * PERSPECTIVE_Z[1];
+
+        Some(Self {
+            x: inv_det * (b_x * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * b_y),
+            y: inv_det * (PERSPECTIVE_X[0] * b_y - b_x * PERSPECTIVE_X[1]),
+            z,
+            global_z: 0.0,
+        })
+    }
+
     pub fn same_x(&self, other: &Self) -> bool {
         (self.x - other.x).abs() < EPSILON
     }
@@ -119,6 +119,34 @@ mod tests {
         );
     }
 
+    /// Test that screen coordinates map back to the same floor position
+    #[rstest]
+    #[case(0.0, 0.0, 0.0)]
+    #[case(12.5, -3.25, 0.0)]
+    #[case(7.0, 30.0, 2.0)]
+    fn test_screen_coord_roundtrip(#[case] x: f32, #[case] y: f32, #[case] z: f32) {
+        let pos = Position {
+            x,
+            y,
+            z,
+            global_z: 0.0,
+        };
+        let screen = pos.to_screen_coord().truncate();
+        let back = Position::from_screen_coord(screen, z).expect("projection is invertible");
+
+        assert!(
+            (back.x - x).abs() < 0.001,
+            "X: expected {x}, got {}",
+            back.x
+        );
+        assert!(
+            (back.y - y).abs() < 0.001,
+            "Y: expected {y}, got {}",
+            back.y
+        );
+        assert_eq!(back.z, z);
+    }
+
     /// Test direction normalization
     #[test]
     fn test_direction_normalization() {
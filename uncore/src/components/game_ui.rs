@@ -6,6 +6,10 @@ pub struct EvidenceUI;
 #[derive(Component, Debug)]
 pub struct GameUI;
 
+/// HUD badge reminding the player that Dev God Mode is on.
+#[derive(Component, Debug)]
+pub struct CheatsActiveBadge;
+
 #[derive(Component, Debug, PartialEq, Eq)]
 pub enum ElementObjectUI {
     Name,
@@ -33,3 +37,19 @@ pub struct WalkieTextUIRoot;
 
 #[derive(Component, Debug)]
 pub struct WalkieText;
+
+/// Root node of the minimap; toggled with the minimap key.
+#[derive(Component, Debug)]
+pub struct MinimapUI;
+
+/// Image of the floor shown in the minimap.
+#[derive(Component, Debug)]
+pub struct MinimapImage;
+
+/// Dot marking the player on the minimap.
+#[derive(Component, Debug)]
+pub struct MinimapPlayerMarker;
+
+/// Label with the floor shown in the minimap.
+#[derive(Component, Debug)]
+pub struct MinimapFloorLabel;
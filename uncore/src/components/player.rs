@@ -12,6 +12,13 @@ pub struct Hiding {
     pub hiding_spot: Entity,
 }
 
+/// Remembers the last interactive object the player used, so the interaction
+/// can be repeated with a single key press.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LastInteractive {
+    pub entity: Entity,
+}
+
 /// Component for managing player stamina and running ability
 #[derive(Component, Debug, Clone)]
 pub struct Stamina {
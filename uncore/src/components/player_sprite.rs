@@ -48,6 +48,11 @@ impl PlayerSprite {
         }
     }
 
+    /// Returns a modified version with the requested health
+    pub fn with_health(self, health: f32) -> Self {
+        Self { health, ..self }
+    }
+
     /// Returns a modified version with the requested controls
     pub fn with_controls(self, controls: ControlKeys) -> Self {
         Self { controls, ..self }
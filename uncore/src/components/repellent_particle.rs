@@ -1,10 +1,31 @@
 use super::board::direction::Direction;
+use super::board::position::Position;
 use crate::types::ghost::types::GhostType;
 use bevy::prelude::*;
 
-#[derive(Component, Debug, Clone, PartialEq)]
+/// How well a repellent made for `target` works on a ghost of type `actual`:
+/// 1.0 when it was made for that ghost, 0.0 otherwise. A wrong repellent
+/// doesn't weaken the ghost at all, it only counts as a miss and angers it.
+pub fn repellent_effectiveness(target: GhostType, actual: GhostType) -> f32 {
+    if target == actual { 1.0 } else { 0.0 }
+}
+
+/// How well repellent sprayed from `distance` tiles away from the ghost works:
+/// fully within `effective_range`, then falling off with the square of the
+/// distance.
+pub fn repellent_range_factor(distance: f32, effective_range: f32) -> f32 {
+    if distance <= effective_range {
+        1.0
+    } else {
+        (effective_range / distance).powi(2)
+    }
+}
+
+#[derive(Component, Debug, Clone)]
 pub struct RepellentParticle {
     pub class: GhostType,
+    /// Where the particle was sprayed from.
+    pub origin: Position,
     pub life: f32,
     pub dir: Direction,
     pub hit_correct: bool,
@@ -15,9 +36,10 @@ impl RepellentParticle {
     const MAX_LIFE: f32 = 30.0;
     pub const DEFAULT_COLOR: Color = Color::srgba(1.0, 1.0, 0.0, 0.15); // Approx. css::YELLOW.with_alpha(0.3).with_blue(0.02)
 
-    pub fn new(class: GhostType) -> Self {
+    pub fn new(class: GhostType, origin: Position) -> Self {
         Self {
             class,
+            origin,
             life: Self::MAX_LIFE,
             dir: Direction::zero(),
             hit_correct: false,
@@ -28,4 +50,39 @@ impl RepellentParticle {
     pub fn life_factor(&self) -> f32 {
         self.life / Self::MAX_LIFE
     }
+
+    /// Effectiveness of this particle against the given ghost.
+    pub fn effectiveness(&self, ghost: GhostType) -> f32 {
+        repellent_effectiveness(self.class, ghost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repellent_effectiveness() {
+        assert_eq!(
+            repellent_effectiveness(GhostType::Kappa, GhostType::Kappa),
+            1.0
+        );
+        assert_eq!(
+            repellent_effectiveness(GhostType::Kappa, GhostType::GreyMan),
+            0.0
+        );
+        let particle = RepellentParticle::new(GhostType::Widow, Position::new_i64(0, 0, 0));
+        assert_eq!(particle.effectiveness(GhostType::Widow), 1.0);
+        assert_eq!(particle.effectiveness(GhostType::BeanSidhe), 0.0);
+    }
+
+    #[test]
+    fn test_repellent_range_factor() {
+        // In range: full effect.
+        assert_eq!(repellent_range_factor(1.0, 3.0), 1.0);
+        assert_eq!(repellent_range_factor(3.0, 3.0), 1.0);
+        // Out of range: weaker the further away.
+        assert_eq!(repellent_range_factor(6.0, 3.0), 0.25);
+        assert!(repellent_range_factor(12.0, 3.0) < repellent_range_factor(6.0, 3.0));
+    }
 }
@@ -5,3 +5,11 @@ pub struct TruckUI;
 
 #[derive(Component, Debug)]
 pub struct TruckUIGhostGuess;
+
+/// Text listing the evidence recorded in the `PlayerJournal`.
+#[derive(Component, Debug)]
+pub struct TruckUIJournalSummary;
+
+/// Text naming the evidence worth checking next, from `GhostGuess`.
+#[derive(Component, Debug)]
+pub struct TruckUIEvidenceSuggestion;
@@ -50,6 +50,14 @@ impl TruckUIButton {
         }
     }
 
+    /// Drops any hold in progress. Returns true if the button was being held.
+    pub fn cancel_hold(&mut self) -> bool {
+        let was_holding = self.holding;
+        self.holding = false;
+        self.hold_timer = None;
+        was_holding
+    }
+
     /// Explicitly toggle the Discard state (Shift+Click)
     pub fn toggle_discard(&mut self) -> Option<TruckUIEvent> {
         match self.class {
@@ -33,6 +33,11 @@ impl WaypointQueue {
         self.0.first().copied()
     }
 
+    /// The last waypoint of the queue, where the player is heading to
+    pub fn destination(&self) -> Option<Entity> {
+        self.0.last().copied()
+    }
+
     /// Remove the first waypoint from the queue
     pub fn advance(&mut self) -> Option<Entity> {
         if !self.0.is_empty() {
@@ -370,6 +370,37 @@ impl Difficulty {
         }
     }
 
+    /// Returns the player's health at the start of a mission; health doesn't
+    /// recover above it.
+    pub fn max_health(&self) -> f32 {
+        match self {
+            Difficulty::TutorialChapter1 => 100.0,
+            Difficulty::TutorialChapter2 => 100.0,
+            Difficulty::TutorialChapter3 => 100.0,
+            Difficulty::TutorialChapter4 => 100.0,
+            Difficulty::TutorialChapter5 => 100.0,
+            Difficulty::StandardChallenge => 100.0,
+            Difficulty::HardChallenge => 100.0,
+            Difficulty::ExpertChallenge => 90.0,
+            Difficulty::MasterChallenge => 80.0,
+        }
+    }
+
+    /// Returns the health points recovered per second while in the truck.
+    pub fn truck_health_regen(&self) -> f32 {
+        match self {
+            Difficulty::TutorialChapter1 => 3.0,
+            Difficulty::TutorialChapter2 => 3.0,
+            Difficulty::TutorialChapter3 => 2.5,
+            Difficulty::TutorialChapter4 => 2.5,
+            Difficulty::TutorialChapter5 => 2.0,
+            Difficulty::StandardChallenge => 2.0,
+            Difficulty::HardChallenge => 2.0,
+            Difficulty::ExpertChallenge => 1.5,
+            Difficulty::MasterChallenge => 1.0,
+        }
+    }
+
     /// Returns the health recovery rate multiplier.
     ///
     /// A higher value means the player regains health more quickly.
@@ -462,6 +493,53 @@ impl Difficulty {
         }
     }
 
+    /// Returns the multiplier on the hunting energy a quartz stone absorbs
+    /// before it cracks. Lower values wear the stone out sooner.
+    pub fn quartz_durability(&self) -> f32 {
+        match self {
+            Difficulty::TutorialChapter1 => 1.0,
+            Difficulty::TutorialChapter2 => 1.0,
+            Difficulty::TutorialChapter3 => 1.0,
+            Difficulty::TutorialChapter4 => 1.0,
+            Difficulty::TutorialChapter5 => 1.0,
+            Difficulty::StandardChallenge => 0.8,
+            Difficulty::HardChallenge => 0.65,
+            Difficulty::ExpertChallenge => 0.5,
+            Difficulty::MasterChallenge => 0.4,
+        }
+    }
+
+    /// Returns how long a sage bundle burns, in seconds.
+    pub fn sage_burn_secs(&self) -> f32 {
+        match self {
+            Difficulty::TutorialChapter1 => 8.0,
+            Difficulty::TutorialChapter2 => 8.0,
+            Difficulty::TutorialChapter3 => 8.0,
+            Difficulty::TutorialChapter4 => 8.0,
+            Difficulty::TutorialChapter5 => 8.0,
+            Difficulty::StandardChallenge => 8.0,
+            Difficulty::HardChallenge => 7.0,
+            Difficulty::ExpertChallenge => 6.0,
+            Difficulty::MasterChallenge => 5.0,
+        }
+    }
+
+    /// Returns the distance to the ghost, in tiles, within which the repellent
+    /// works at full strength.
+    pub fn repellent_effective_range(&self) -> f32 {
+        match self {
+            Difficulty::TutorialChapter1 => 3.0,
+            Difficulty::TutorialChapter2 => 3.0,
+            Difficulty::TutorialChapter3 => 3.0,
+            Difficulty::TutorialChapter4 => 3.0,
+            Difficulty::TutorialChapter5 => 3.0,
+            Difficulty::StandardChallenge => 3.0,
+            Difficulty::HardChallenge => 3.0,
+            Difficulty::ExpertChallenge => 3.0,
+            Difficulty::MasterChallenge => 3.0,
+        }
+    }
+
     /// Returns the default tab selected in the van UI.
     pub fn default_van_tab(&self) -> TabContents {
         match self {
@@ -500,45 +578,71 @@ impl Difficulty {
         }
     }
 
-    pub fn truck_gear(&self) -> Vec<GearKind> {
+    /// Returns the gear that can be selected on this difficulty.
+    ///
+    /// Tutorial chapters restrict the player to the subset of gear they are
+    /// being taught, each chapter adding to the previous one. Challenges allow
+    /// everything. The truck loadout uses this to filter what can be equipped.
+    pub fn allowed_gear(&self) -> &'static [GearKind] {
         use crate::types::gear_kind::GearKind::*;
-        let mut gear = Vec::new();
-
+        const CHAPTER1: &[GearKind] = &[Flashlight, Thermometer, EMFMeter];
+        const CHAPTER2: &[GearKind] = &[Flashlight, Thermometer, EMFMeter, UVTorch, Videocam];
+        const CHAPTER3: &[GearKind] = &[
+            Flashlight,
+            Thermometer,
+            EMFMeter,
+            UVTorch,
+            Videocam,
+            Recorder,
+            GeigerCounter,
+        ];
+        const CHAPTER4: &[GearKind] = &[
+            Flashlight,
+            Thermometer,
+            EMFMeter,
+            UVTorch,
+            Videocam,
+            Recorder,
+            GeigerCounter,
+            SpiritBox,
+            RedTorch,
+        ];
+        const ALL: &[GearKind] = &[
+            Flashlight,
+            Thermometer,
+            EMFMeter,
+            UVTorch,
+            Videocam,
+            Recorder,
+            GeigerCounter,
+            SpiritBox,
+            RedTorch,
+            Salt,
+            QuartzStone,
+            SageBundle,
+        ];
         match self {
-            Difficulty::TutorialChapter1 => {
-                gear.push(Flashlight);
-                gear.push(Thermometer);
-                gear.push(EMFMeter);
-            }
-            Difficulty::TutorialChapter2 => {
-                gear.extend(Self::TutorialChapter1.truck_gear());
-                gear.push(UVTorch);
-                gear.push(Videocam);
-            }
-            Difficulty::TutorialChapter3 => {
-                gear.extend(Self::TutorialChapter2.truck_gear());
-                gear.push(Recorder);
-                gear.push(GeigerCounter);
-            }
-            Difficulty::TutorialChapter4 => {
-                gear.extend(Self::TutorialChapter3.truck_gear());
-                gear.push(SpiritBox);
-                gear.push(RedTorch);
-            }
-            Difficulty::TutorialChapter5 => {
-                gear.extend(Self::TutorialChapter4.truck_gear());
-                gear.push(Salt);
-                gear.push(QuartzStone);
-                gear.push(SageBundle);
-            }
-            // For StandardChallenge and above, they get all gear from TutorialChapter5
-            Difficulty::StandardChallenge
+            Difficulty::TutorialChapter1 => CHAPTER1,
+            Difficulty::TutorialChapter2 => CHAPTER2,
+            Difficulty::TutorialChapter3 => CHAPTER3,
+            Difficulty::TutorialChapter4 => CHAPTER4,
+            Difficulty::TutorialChapter5
+            | Difficulty::StandardChallenge
             | Difficulty::HardChallenge
             | Difficulty::ExpertChallenge
-            | Difficulty::MasterChallenge => {
-                gear = Self::TutorialChapter5.truck_gear();
-            }
+            | Difficulty::MasterChallenge => ALL,
         }
+    }
+
+    /// Returns true if the gear can be equipped on this difficulty. Empty slots
+    /// are always allowed.
+    pub fn is_gear_allowed(&self, kind: GearKind) -> bool {
+        kind == GearKind::None || self.allowed_gear().contains(&kind)
+    }
+
+    pub fn truck_gear(&self) -> Vec<GearKind> {
+        use crate::types::gear_kind::GearKind::*;
+        let mut gear = self.allowed_gear().to_vec();
 
         // This is for debugging purposes, to add gear that isn't functional yet.
         const ENABLE_INCOMPLETE: bool = false;
@@ -640,6 +744,10 @@ impl Difficulty {
     /// This method aggregates all the individual parameter settings defined by the
     /// other methods in this enum.
     pub fn create_difficulty_struct(&self) -> DifficultyStruct {
+        debug_assert!(
+            self.quartz_durability() > 0.0 && self.sage_burn_secs() > 0.0,
+            "Consumable durability must be positive for {self:?}"
+        );
         DifficultyStruct {
             ghost_speed: self.ghost_speed(),
             ghost_rage_likelihood: self.ghost_rage_likelihood(),
@@ -659,12 +767,17 @@ impl Difficulty {
             sanity_drain_rate: self.sanity_drain_rate(),
             health_drain_rate: self.health_drain_rate(),
             health_recovery_rate: self.health_recovery_rate(),
+            max_health: self.max_health(),
+            truck_health_regen: self.truck_health_regen(),
             player_speed: self.player_speed(),
             evidence_visibility: self.evidence_visibility(),
             equipment_sensitivity: self.equipment_sensitivity(),
             van_auto_open: self.van_auto_open(),
             default_van_tab: self.default_van_tab(),
             repellent_craft_limit: self.repellent_craft_limit(),
+            repellent_effective_range: self.repellent_effective_range(),
+            quartz_durability: self.quartz_durability(),
+            sage_burn_secs: self.sage_burn_secs(),
             player_gear: self.player_gear(),
             ghost_set: self.ghost_set(),
             difficulty: *self,
@@ -708,6 +821,10 @@ pub struct DifficultyStruct {
     pub sanity_drain_rate: f32,
     pub health_drain_rate: f32,
     pub health_recovery_rate: f32,
+    /// Starting health, and the most health can recover to.
+    pub max_health: f32,
+    /// Health points recovered per second in the truck.
+    pub truck_health_regen: f32,
     pub player_speed: f32,
     // --- Evidence Gathering ---
     pub evidence_visibility: f32,
@@ -716,6 +833,13 @@ pub struct DifficultyStruct {
     pub van_auto_open: bool,
     pub default_van_tab: TabContents,
     pub repellent_craft_limit: u32,
+    /// Distance to the ghost within which the repellent works at full strength.
+    pub repellent_effective_range: f32,
+    /// Multiplier on the hunting energy a quartz stone absorbs before each
+    /// crack.
+    pub quartz_durability: f32,
+    /// How long a sage bundle burns, in seconds.
+    pub sage_burn_secs: f32,
     pub player_gear: PlayerGearKind,
     pub ghost_set: GhostSet,
     // --- UI and Scoring ---
@@ -755,3 +879,17 @@ impl CurrentDifficulty {
 pub fn get_difficulty_struct(difficulty: Difficulty) -> DifficultyStruct {
     difficulty.create_difficulty_struct()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumable_durability_is_positive() {
+        for difficulty in all::<Difficulty>() {
+            let d = difficulty.create_difficulty_struct();
+            assert!(d.quartz_durability > 0.0, "{difficulty:?}");
+            assert!(d.sage_burn_secs > 0.0, "{difficulty:?}");
+        }
+    }
+}
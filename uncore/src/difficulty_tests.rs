@@ -6,7 +6,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::difficulty::Difficulty;
-    // Gear kinds removed as they're not used in current tests
+    use crate::types::gear_kind::GearKind;
     use crate::types::ghost::definitions::GhostSet;
     use enum_iterator::all;
     use proptest::prelude::*;
@@ -29,6 +29,38 @@ mod tests {
         Difficulty::MasterChallenge
     }
 
+    /// Master must allow every gear used anywhere, while the first tutorial
+    /// chapter only allows a teaching subset.
+    #[rstest]
+    fn test_allowed_gear_restrictions(
+        tutorial_difficulty: Difficulty,
+        master_difficulty: Difficulty,
+    ) {
+        for difficulty in all::<Difficulty>() {
+            for kind in difficulty.allowed_gear() {
+                assert!(
+                    master_difficulty.is_gear_allowed(*kind),
+                    "{kind:?} is allowed on {difficulty:?} but not on master"
+                );
+            }
+            let player_gear = difficulty.player_gear();
+            for kind in [player_gear.left_hand, player_gear.right_hand]
+                .into_iter()
+                .chain(player_gear.inventory)
+            {
+                assert!(
+                    difficulty.is_gear_allowed(kind),
+                    "{difficulty:?} starts with disallowed {kind:?}"
+                );
+            }
+        }
+
+        let tutorial = tutorial_difficulty.allowed_gear();
+        assert!(tutorial.len() < master_difficulty.allowed_gear().len());
+        assert!(!tutorial_difficulty.is_gear_allowed(GearKind::SpiritBox));
+        assert!(tutorial_difficulty.is_gear_allowed(GearKind::None));
+    }
+
     /// Test that all difficulty levels have valid configurations
     #[test]
     fn test_all_difficulties_have_valid_configs() {
@@ -304,6 +336,73 @@ mod tests {
     /// Integration tests with other systems
     mod integration {
         use super::*;
+        use crate::sanity::{self, compute_drain};
+
+        /// Every combination of (in_dark, near_ghost, in_hunt).
+        fn all_conditions() -> Vec<(bool, bool, bool)> {
+            (0..8)
+                .map(|i| (i & 1 != 0, i & 2 != 0, i & 4 != 0))
+                .collect()
+        }
+
+        #[test]
+        fn test_harder_difficulty_drains_sanity_faster() {
+            let difficulties: Vec<Difficulty> = all::<Difficulty>().collect();
+            for (dark, ghost, hunt) in all_conditions() {
+                for pair in difficulties.windows(2) {
+                    let easier = compute_drain(pair[0].sanity_drain_rate(), dark, ghost, hunt);
+                    let harder = compute_drain(pair[1].sanity_drain_rate(), dark, ghost, hunt);
+                    assert!(
+                        harder > easier,
+                        "{:?} should drain faster than {:?} (dark={dark}, ghost={ghost}, hunt={hunt})",
+                        pair[1],
+                        pair[0]
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_sanity_drain_conditions_stack() {
+            for difficulty in all::<Difficulty>() {
+                let rate = difficulty.sanity_drain_rate();
+                let base = compute_drain(rate, false, false, false);
+                assert_eq!(base, rate);
+
+                let dark = compute_drain(rate, true, false, false);
+                let ghost = compute_drain(rate, false, true, false);
+                let hunt = compute_drain(rate, false, false, true);
+                assert_eq!(dark, rate * sanity::DARKNESS_DRAIN_MULTIPLIER);
+                assert_eq!(ghost, rate * sanity::GHOST_PROXIMITY_DRAIN_MULTIPLIER);
+                assert_eq!(hunt, rate * sanity::HUNT_DRAIN_MULTIPLIER);
+
+                // Each condition makes things worse on its own and the factors
+                // multiply together.
+                for (d, g, h) in all_conditions() {
+                    let drain = compute_drain(rate, d, g, h);
+                    assert!(drain >= base);
+                    let expected =
+                        rate * if d {
+                            sanity::DARKNESS_DRAIN_MULTIPLIER
+                        } else {
+                            1.0
+                        } * if g {
+                            sanity::GHOST_PROXIMITY_DRAIN_MULTIPLIER
+                        } else {
+                            1.0
+                        } * if h {
+                            sanity::HUNT_DRAIN_MULTIPLIER
+                        } else {
+                            1.0
+                        };
+                    assert!((drain - expected).abs() <= expected * 1e-6);
+                }
+                assert!(
+                    compute_drain(rate, true, true, true) > compute_drain(rate, true, true, false)
+                );
+            }
+            assert_eq!(compute_drain(-1.0, true, true, true), 0.0);
+        }
 
         #[test]
         fn test_difficulty_with_ghost_system() {
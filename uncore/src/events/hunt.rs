@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Sent when a ghost locks on to the player and starts hunting.
+#[derive(Clone, Debug, Event)]
+pub struct HuntStarted {
+    pub ghost: Entity,
+}
+
+/// Sent when the hunt is over, or the hunting ghost is gone.
+#[derive(Clone, Debug, Event)]
+pub struct HuntEnded {
+    pub ghost: Entity,
+}
@@ -1,6 +1,7 @@
 pub mod ambient_sound_mute;
 pub mod board_data_rebuild;
 pub mod hint;
+pub mod hunt;
 pub mod loadlevel;
 pub mod map_selected;
 pub mod npc_help;
@@ -6,3 +6,8 @@ pub enum TruckUIEvent {
     ExitTruck,
     CraftRepellent,
 }
+
+/// Asks for a path from the player to the van entry, as if the player had
+/// clicked on it.
+#[derive(Clone, Debug, Event, PartialEq, Eq)]
+pub struct GoToTruckEvent;
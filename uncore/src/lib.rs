@@ -10,6 +10,7 @@ pub mod platform;
 pub mod plugin;
 pub mod random_seed;
 pub mod resources;
+pub mod sanity;
 pub mod states;
 pub mod systemparam;
 pub mod systems;
@@ -1,10 +1,15 @@
 use bevy::diagnostic::{DiagnosticMeasurement, DiagnosticPath};
 use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+use bevy_platform::collections::HashMap;
 use bevy_platform::time::Instant;
+use std::collections::VecDeque;
 use std::sync::{LazyLock, Mutex, mpsc};
 
 const CHANNEL_CAPACITY: usize = 2048;
 
+/// Samples kept per metric by the `MetricsDashboard`.
+pub const DASHBOARD_WINDOW: usize = 300;
+
 static DIAGNOSTIC_CHANNEL: LazyLock<StaticChannel> = LazyLock::new(StaticChannel::default);
 
 #[derive(Debug, Clone)]
@@ -29,13 +34,89 @@ impl Default for StaticChannel {
     }
 }
 
-fn receive_data(mut diag_store: ResMut<DiagnosticsStore>) {
+/// Summary of the recent samples of one metric.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricStats {
+    pub min: f64,
+    pub avg: f64,
+    pub max: f64,
+    pub p95: f64,
+    pub samples: usize,
+}
+
+impl MetricStats {
+    fn from_samples(samples: &VecDeque<f64>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = samples.iter().copied().collect();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len();
+        let p95_idx = ((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1;
+        Some(Self {
+            min: sorted[0],
+            avg: sorted.iter().sum::<f64>() / n as f64,
+            max: sorted[n - 1],
+            p95: sorted[p95_idx],
+            samples: n,
+        })
+    }
+}
+
+impl std::fmt::Display for MetricStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.2} avg {:.2} p95 {:.2} max {:.2} (n={})",
+            self.min, self.avg, self.p95, self.max, self.samples
+        )
+    }
+}
+
+/// Rolling window of the values sent through `SendMetric`, per metric.
+///
+/// Bevy's `DiagnosticsStore` only keeps a smoothed value; this keeps the last
+/// `DASHBOARD_WINDOW` samples so spikes show up in the max and p95.
+#[derive(Resource, Debug, Default)]
+pub struct MetricsDashboard {
+    windows: HashMap<DiagnosticPath, VecDeque<f64>>,
+}
+
+impl MetricsDashboard {
+    pub fn record(&mut self, path: &DiagnosticPath, value: f64) {
+        let window = self.windows.entry(path.clone()).or_default();
+        if window.len() == DASHBOARD_WINDOW {
+            window.pop_front();
+        }
+        window.push_back(value);
+    }
+
+    pub fn stats(&self, path: &DiagnosticPath) -> Option<MetricStats> {
+        self.windows.get(path).and_then(MetricStats::from_samples)
+    }
+
+    /// Current stats of every metric that has samples, sorted by path.
+    pub fn snapshot(&self) -> Vec<(DiagnosticPath, MetricStats)> {
+        let mut stats: Vec<_> = self
+            .windows
+            .iter()
+            .filter_map(|(path, samples)| {
+                MetricStats::from_samples(samples).map(|s| (path.clone(), s))
+            })
+            .collect();
+        stats.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+        stats
+    }
+}
+
+fn receive_data(mut diag_store: ResMut<DiagnosticsStore>, mut dashboard: ResMut<MetricsDashboard>) {
     let rx_guard = DIAGNOSTIC_CHANNEL
         .rx
         .try_lock()
         .expect("uncore::metrics::receive_data was unable to lock for reading messages");
 
     for data in rx_guard.try_iter() {
+        dashboard.record(&data.path, data.value);
         if let Some(diag) = diag_store.get_mut(&data.path) {
             diag.add_measurement(DiagnosticMeasurement {
                 time: data.time,
@@ -89,5 +170,36 @@ impl SendMetric for DiagnosticPath {
 }
 
 pub fn app_setup(app: &mut App) {
-    app.add_systems(PostUpdate, receive_data);
+    app.init_resource::<MetricsDashboard>()
+        .add_systems(PostUpdate, receive_data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashboard_stats_over_rolling_window() {
+        const PATH: DiagnosticPath = DiagnosticPath::const_new("test/metric");
+        let mut dashboard = MetricsDashboard::default();
+        assert_eq!(dashboard.stats(&PATH), None);
+
+        for v in 1..=100 {
+            dashboard.record(&PATH, v as f64);
+        }
+        let stats = dashboard.stats(&PATH).unwrap();
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 100.0);
+        assert_eq!(stats.avg, 50.5);
+        assert_eq!(stats.p95, 95.0);
+        assert_eq!(stats.samples, 100);
+
+        // Old samples fall out of the window.
+        for _ in 0..DASHBOARD_WINDOW {
+            dashboard.record(&PATH, 2.0);
+        }
+        let stats = dashboard.stats(&PATH).unwrap();
+        assert_eq!((stats.min, stats.max), (2.0, 2.0));
+        assert_eq!(dashboard.snapshot().len(), 1);
+    }
 }
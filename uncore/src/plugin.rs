@@ -13,12 +13,20 @@ impl Plugin for UnhaunterCorePlugin {
         crate::metric_recorder::app_setup(app);
         crate::systems::evidence_decay::app_setup(app);
         crate::systems::board::app_setup(app);
+        crate::systems::game_paused::app_setup(app);
         crate::systems::animation::app_setup(app);
+        crate::systems::ui_palette::app_setup(app);
+        crate::systems::localization::app_setup(app);
         app.init_resource::<CurrentEvidenceReadings>();
         app.init_resource::<CurrentMissionSelectMode>();
         app.init_resource::<HintUiState>();
+        app.init_resource::<crate::resources::free_camera::FreeCamera>();
         app.init_resource::<crate::noise::PerlinNoise>();
         app.init_resource::<crate::resources::player_input::PlayerInput>();
+        app.init_resource::<crate::resources::hunt_state::HuntState>();
         app.add_event::<OnScreenHintEvent>();
+        app.add_event::<crate::events::hunt::HuntStarted>();
+        app.add_event::<crate::events::hunt::HuntEnded>();
+        app.add_event::<crate::events::truck::GoToTruckEvent>();
     }
 }
@@ -92,3 +92,12 @@ pub fn rng() -> rand::rngs::SmallRng {
     let seed: u64 = RNG.with(|rng| rng.borrow_mut().rng().next_u64());
     rand::rngs::SmallRng::seed_from_u64(seed)
 }
+
+/// Reseeds the current thread's generator so later `rng()` calls are reproducible.
+///
+/// Useful for tests and for replaying a bug report with a known seed.
+pub fn set_seed(seed: u64) {
+    RNG.with(|rng| {
+        rng.borrow_mut().rng = Some(rand::rngs::SmallRng::seed_from_u64(seed));
+    });
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+/// Default limit for the summed volume of the effects playing at once.
+pub const DEFAULT_LIMITER_THRESHOLD: f32 = 2.5;
+
+/// Global limiter for sound effects, to avoid clipping when many of them
+/// overlap (e.g. during a hunt).
+///
+/// The volumes of the effects still playing are added up, and a new effect
+/// that would push the sum over `threshold` is scaled down so the total stays
+/// near it. Effects despawn when they finish, so the headroom comes back on its
+/// own.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AudioLimiter {
+    /// Maximum summed linear volume before new effects are attenuated.
+    pub threshold: f32,
+}
+
+impl Default for AudioLimiter {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_LIMITER_THRESHOLD,
+        }
+    }
+}
+
+impl AudioLimiter {
+    /// Gain multiplier for a new effect of `volume`, given the summed volume
+    /// of the effects already playing. Returns 1.0 while under the threshold.
+    pub fn gain_for(&self, playing_sum: f32, volume: f32) -> f32 {
+        let total = playing_sum + volume;
+        if total <= self.threshold || total <= 0.0 {
+            1.0
+        } else {
+            (self.threshold / total).clamp(0.0, 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limiter_scales_only_over_threshold() {
+        let limiter = AudioLimiter { threshold: 2.0 };
+        assert_eq!(limiter.gain_for(0.5, 1.0), 1.0);
+        assert_eq!(limiter.gain_for(1.0, 1.0), 1.0);
+
+        let gain = limiter.gain_for(3.0, 1.0);
+        assert!((gain - 0.5).abs() < 1e-6);
+        assert!(limiter.gain_for(10.0, 1.0) < gain);
+    }
+}
@@ -4,9 +4,12 @@ use crate::{
         board::{boardposition::BoardPosition, position::Position},
         ghost_behaviour_dynamics::GhostBehaviourDynamics,
     },
+    resources::roomdb::RoomDB,
     types::{
         board::{
             fielddata::{CollisionFieldData, LightFieldData},
+            light::{DEFAULT_PROPAGATION_STEP_BUDGET, LightLevel, LightLevelThresholds, LuxClamp},
+            light_cache::LightingCache,
             prebaked_lighting_data::{PrebakedLightingData, PrebakedMetadata, WaveEdgeData},
         },
         evidence::Evidence,
@@ -30,6 +33,22 @@ pub struct TemperatureDiffusionConfig {
     pub stair_score: u8,
     /// Score for closed doors (minimal processing)
     pub door_score: u8,
+    /// Conductivity between open tiles outside of any room
+    pub outside_conductivity: f32,
+    /// Conductivity between open tiles inside rooms
+    pub inside_conductivity: f32,
+    /// Conductivity of partially open tiles, like closed doors
+    pub other_conductivity: f32,
+    /// Conductivity of walls
+    pub wall_conductivity: f32,
+    /// Maximum energy exchanged per step, as a ratio of the smaller tile energy
+    pub max_energy_change_ratio: f32,
+    /// Let miasma flow carry temperature between tiles (cold miasma cools its path)
+    pub miasma_advection: bool,
+    /// Fraction of the energy difference moved per frame per unit of miasma speed
+    pub miasma_advection_rate: f32,
+    /// Dev toggle: pause the thermal simulation so the field can be inspected
+    pub frozen: bool,
 }
 
 impl Default for TemperatureDiffusionConfig {
@@ -40,6 +59,14 @@ impl Default for TemperatureDiffusionConfig {
             default_score: 16,
             stair_score: 1,
             door_score: 32,
+            outside_conductivity: 1000000.0,
+            inside_conductivity: 80000.0,
+            other_conductivity: 20000.0,
+            wall_conductivity: 0.00001,
+            max_energy_change_ratio: 0.9,
+            miasma_advection: false,
+            miasma_advection_rate: 0.05,
+            frozen: false,
         }
     }
 }
@@ -50,7 +77,16 @@ pub struct BoardData {
     pub origin: (i32, i32, i32),
 
     pub light_field: Array3<LightFieldData>,
+    /// Lux thresholds used by `light_level` and `is_lit`
+    pub light_level_thresholds: LightLevelThresholds,
+    pub lux_clamp: LuxClamp,
+    /// Maximum wave edge steps per light propagation pass. When exceeded the
+    /// pass stops early and the partially lit field is kept.
+    pub propagation_step_budget: usize,
     pub collision_field: Array3<CollisionFieldData>,
+    /// Bumped every time the collision field is rebuilt, so caches derived
+    /// from it (like pathfinding results) know when to drop their data.
+    pub collision_revision: u32,
     pub temperature_field: Array3<f32>,
     /// Previous frame's temperature for gradient calculation
     pub temperature_field_prev: Array3<f32>,
@@ -79,6 +115,25 @@ pub struct BoardData {
     pub prebaked_metadata: PrebakedMetadata,
     pub prebaked_wave_edges: Vec<WaveEdgeData>,
     pub prebaked_propagation: Vec<Array2<[bool; 4]>>,
+    /// Set when a light source toggled or a door changed since the light field
+    /// was last built. The light field is only propagated again while it's set.
+    pub lighting_dirty: bool,
+    /// Active light source ids the current light field was built with.
+    pub lighting_sources: HashSet<u32>,
+    /// Door open states the current light field was built with.
+    pub lighting_door_states: HashMap<(usize, usize, usize), bool>,
+    /// Set when the only change since the light field was last built is a
+    /// single light source toggling, so the rebuild can be incremental.
+    pub lighting_toggled_source: Option<u32>,
+    /// Intermediate light field of the last build, used by incremental
+    /// rebuilds. Cleared when it no longer matches the prebaked data.
+    pub lighting_cache: Option<LightingCache>,
+    /// Output factor of flickering light sources, by source id. Sources not
+    /// listed shine at full strength.
+    pub light_flicker_factors: HashMap<u32, f32>,
+    /// Set when `light_flicker_factors` changed since the light field was last
+    /// built. The rebuild then only rescales the cached field.
+    pub light_flicker_changed: bool,
 
     // Floor mapping (Tiled floor number to z-index)
     pub floor_z_map: HashMap<i32, usize>, // Maps Tiled floor numbers to contiguous z indices
@@ -98,13 +153,165 @@ pub struct BoardData {
 }
 
 impl BoardData {
-    /// Returns if the given position has light above a fixed threshold.
+    /// Raises the ghost warning at `position` to `intensity`, clamped to [0, 1].
+    ///
+    /// A weaker warning than the one currently active is ignored, so the strongest
+    /// source wins until it decays below the new one.
+    pub fn set_ghost_warning(&mut self, position: Position, intensity: f32) {
+        let intensity = intensity.clamp(0.0, 1.0);
+        if intensity >= self.ghost_warning_intensity {
+            self.ghost_warning_intensity = intensity;
+            self.ghost_warning_position = Some(position);
+        }
+    }
+
+    /// Exponentially decays the ghost warning towards zero.
+    ///
+    /// Once the intensity becomes negligible the warning is cleared entirely.
+    pub fn decay_ghost_warning(&mut self, delta_secs: f32) {
+        /// Fraction of the warning lost per second, as an exponential rate.
+        const GHOST_WARNING_DECAY_RATE: f32 = 3.0;
+        const GHOST_WARNING_MIN_INTENSITY: f32 = 0.001;
+
+        self.ghost_warning_intensity = (self.ghost_warning_intensity
+            * (-GHOST_WARNING_DECAY_RATE * delta_secs.max(0.0)).exp())
+        .clamp(0.0, 1.0);
+        if self.ghost_warning_intensity < GHOST_WARNING_MIN_INTENSITY {
+            self.ghost_warning_intensity = 0.0;
+            self.ghost_warning_position = None;
+        }
+    }
+
+    /// Clamps world coordinates to the board, so the position lies on a valid
+    /// tile. `global_z` is kept as is.
+    pub fn clamp_position(&self, pos: Position) -> Position {
+        let (width, height, depth) = self.map_size;
+        let max = |size: usize| size.saturating_sub(1) as f32;
+        Position {
+            x: pos.x.clamp(0.0, max(width)),
+            y: pos.y.clamp(0.0, max(height)),
+            z: pos.z.clamp(0.0, max(depth)),
+            global_z: pos.global_z,
+        }
+    }
+
+    /// Returns the contiguous z index used by the board for a Tiled floor number.
+    pub fn z_for_floor(&self, floor: i32) -> Option<usize> {
+        self.floor_z_map.get(&floor).copied()
+    }
+
+    /// Returns the Tiled floor number for a contiguous board z index.
+    pub fn floor_for_z(&self, z: usize) -> Option<i32> {
+        self.z_floor_map.get(&z).copied()
+    }
+
+    /// Finds the closest entity on the same floor as `pos`, within `radius` tiles.
+    ///
+    /// Candidates come from `map_entity_field`. `kind_filter` is called with each
+    /// entity on the nearby tiles and returns its position when it is an
+    /// interactive of the wanted kind, so callers can check components through
+    /// their own queries.
+    pub fn nearest_interactive(
+        &self,
+        pos: &Position,
+        radius: f32,
+        kind_filter: impl Fn(Entity) -> Option<Position>,
+    ) -> Option<(Entity, Position)> {
+        let bpos = pos.to_board_position();
+        if bpos.z < 0 || bpos.z >= self.map_size.2 as i64 {
+            return None;
+        }
+        let r = radius.ceil() as i64;
+        let x_range = (bpos.x - r).max(0)..=(bpos.x + r).min(self.map_size.0 as i64 - 1);
+        let y_range = (bpos.y - r).max(0)..=(bpos.y + r).min(self.map_size.1 as i64 - 1);
+
+        let mut nearest: Option<(Entity, Position, f32)> = None;
+        for x in x_range {
+            for y in y_range.clone() {
+                let idx = (x as usize, y as usize, bpos.z as usize);
+                for &entity in &self.map_entity_field[idx] {
+                    let Some(entity_pos) = kind_filter(entity) else {
+                        continue;
+                    };
+                    if entity_pos.z.round() != pos.z.round() {
+                        continue;
+                    }
+                    let distance = pos.distance(&entity_pos);
+                    if distance <= radius && nearest.is_none_or(|(_, _, d)| distance < d) {
+                        nearest = Some((entity, entity_pos, distance));
+                    }
+                }
+            }
+        }
+        nearest.map(|(entity, entity_pos, _)| (entity, entity_pos))
+    }
+
+    /// Returns the brightness category of the given position.
+    ///
+    /// Positions outside of the map are considered `Dark`.
+    pub fn light_level(&self, pos: BoardPosition) -> LightLevel {
+        match self.light_field.get(pos.ndidx()) {
+            Some(light_data) => self.light_level_thresholds.classify(light_data.lux),
+            None => LightLevel::Dark,
+        }
+    }
+
+    /// Average lux over the tiles of `room`, or 0.0 if the room has no tiles.
+    pub fn average_room_lux(&self, room: &str, roomdb: &RoomDB) -> f32 {
+        let (total, count) = roomdb
+            .room_tiles
+            .iter()
+            .filter(|(_, name)| name.as_str() == room)
+            .filter_map(|(bpos, _)| self.light_field.get(bpos.ndidx()))
+            .fold((0.0, 0), |(total, count), light| {
+                (total + light.lux, count + 1)
+            });
+        if count == 0 {
+            return 0.0;
+        }
+        total / count as f32
+    }
+
+    /// Returns if the given position is at least `LightLevel::Lit`.
     pub fn is_lit(&self, pos: BoardPosition) -> bool {
-        if let Some(light_data) = self.light_field.get(pos.ndidx()) {
-            light_data.lux > 0.5
-        } else {
-            false
+        self.light_level(pos) >= LightLevel::Lit
+    }
+
+    /// Renders the accumulated sound on floor `z` as an 8-bit grayscale heatmap.
+    ///
+    /// The image is `map_size.0` pixels wide and `map_size.1` pixels tall, stored
+    /// row-major (`y * width + x`). Each tile's value is the sum of the magnitudes of
+    /// all sound vectors currently stored for it in `sound_field`; vectors are not
+    /// summed first, so opposing sounds don't cancel out. Values are normalised so
+    /// the loudest tile on the floor is 255. The field itself is left untouched.
+    ///
+    /// Returns an empty vector if `z` is out of range.
+    pub fn export_sound_field_image(&self, z: usize) -> Vec<u8> {
+        let (width, height, depth) = self.map_size;
+        if z >= depth {
+            return Vec::new();
+        }
+        let mut magnitudes = vec![0.0_f32; width * height];
+        for (bpos, sounds) in self.sound_field.iter() {
+            if bpos.z != z as i64
+                || bpos.x < 0
+                || bpos.y < 0
+                || bpos.x >= width as i64
+                || bpos.y >= height as i64
+            {
+                continue;
+            }
+            magnitudes[bpos.y as usize * width + bpos.x as usize] +=
+                sounds.iter().map(|v| v.length()).sum::<f32>();
+        }
+        let max = magnitudes.iter().copied().fold(0.0, f32::max);
+        if max <= 0.0 {
+            return vec![0; width * height];
         }
+        magnitudes
+            .into_iter()
+            .map(|m| (m / max * 255.0).round() as u8)
+            .collect()
     }
 
     /// Calculate connectivity score for a tile at the given position
@@ -237,7 +444,11 @@ impl FromWorld for BoardData {
             map_size,
             origin: (0, 0, 0),
             collision_field: Array3::from_elem(map_size, CollisionFieldData::default()),
+            collision_revision: 0,
             light_field: Array3::from_elem(map_size, LightFieldData::default()),
+            light_level_thresholds: LightLevelThresholds::default(),
+            lux_clamp: LuxClamp::default(),
+            propagation_step_budget: DEFAULT_PROPAGATION_STEP_BUDGET,
             temperature_field: Array3::from_elem(map_size, 0.0),
             temperature_field_prev: Array3::from_elem(map_size, 0.0),
             temperature_activity: Array3::from_elem(map_size, 0.0),
@@ -256,6 +467,13 @@ impl FromWorld for BoardData {
             prebaked_metadata: PrebakedMetadata::default(),
             prebaked_wave_edges: Vec::new(),
             prebaked_propagation: Vec::new(),
+            lighting_dirty: true,
+            lighting_sources: HashSet::new(),
+            lighting_door_states: HashMap::new(),
+            lighting_toggled_source: None,
+            lighting_cache: None,
+            light_flicker_factors: HashMap::new(),
+            light_flicker_changed: false,
             ghost_warning_intensity: 0.0,
             ghost_warning_position: None,
             floor_z_map: HashMap::new(),
@@ -273,3 +491,135 @@ impl FromWorld for BoardData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ghost_warning_decays_and_clamps() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        let pos = Position::new_i64(1, 2, 0);
+
+        bf.set_ghost_warning(pos, 3.0);
+        assert_eq!(bf.ghost_warning_intensity, 1.0);
+        assert_eq!(bf.ghost_warning_position, Some(pos));
+
+        // A weaker warning elsewhere does not override the current one.
+        bf.set_ghost_warning(Position::new_i64(5, 5, 0), 0.5);
+        assert_eq!(bf.ghost_warning_position, Some(pos));
+
+        bf.decay_ghost_warning(0.1);
+        let after_one = bf.ghost_warning_intensity;
+        assert!(after_one < 1.0 && after_one > 0.0);
+        bf.decay_ghost_warning(0.1);
+        assert!(bf.ghost_warning_intensity < after_one);
+
+        bf.decay_ghost_warning(100.0);
+        assert_eq!(bf.ghost_warning_intensity, 0.0);
+        assert_eq!(bf.ghost_warning_position, None);
+    }
+
+    #[test]
+    fn test_clamp_position_to_board() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (10, 8, 2);
+
+        let inside = Position::new_i64(3, 4, 1);
+        assert_eq!(bf.clamp_position(inside), inside);
+
+        let edge = Position::new_i64(9, 7, 0);
+        assert_eq!(bf.clamp_position(edge), edge);
+
+        let outside = Position {
+            x: -2.5,
+            y: 12.0,
+            z: 3.0,
+            global_z: 0.25,
+        };
+        let clamped = bf.clamp_position(outside);
+        assert_eq!(
+            clamped,
+            Position {
+                x: 0.0,
+                y: 7.0,
+                z: 1.0,
+                global_z: 0.25,
+            }
+        );
+        assert_eq!(clamped.global_z, 0.25);
+        assert!(clamped.to_board_position().is_valid(bf.map_size));
+    }
+
+    #[test]
+    fn test_nearest_interactive_picks_closest_match() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (10, 10, 1);
+        bf.map_entity_field = Array3::from_elem(bf.map_size, Vec::new());
+
+        let near = Entity::from_raw(1);
+        let far = Entity::from_raw(2);
+        let near_pos = Position::new_i64(3, 2, 0);
+        let far_pos = Position::new_i64(5, 5, 0);
+        bf.map_entity_field[near_pos.to_board_position().ndidx()].push(near);
+        bf.map_entity_field[far_pos.to_board_position().ndidx()].push(far);
+        let position_of = |e: Entity| match e {
+            e if e == near => Some(near_pos),
+            e if e == far => Some(far_pos),
+            _ => None,
+        };
+
+        let player = Position::new_i64(2, 2, 0);
+        assert_eq!(
+            bf.nearest_interactive(&player, 5.0, position_of),
+            Some((near, near_pos))
+        );
+        // The filter decides which kinds count.
+        assert_eq!(
+            bf.nearest_interactive(&player, 5.0, |e| position_of(e).filter(|_| e == far)),
+            Some((far, far_pos))
+        );
+        assert_eq!(bf.nearest_interactive(&player, 0.5, position_of), None);
+    }
+
+    #[test]
+    fn test_average_room_lux_half_lit_room() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (4, 1, 1);
+        bf.light_field = Array3::from_elem(bf.map_size, LightFieldData::default());
+        let mut roomdb = RoomDB::default();
+        for x in 0..4 {
+            let bpos = BoardPosition { x, y: 0, z: 0 };
+            roomdb
+                .room_tiles
+                .insert(bpos.clone(), "Kitchen".to_string());
+            if x < 2 {
+                bf.light_field[bpos.ndidx()].lux = 2.0;
+            }
+        }
+
+        assert_eq!(bf.average_room_lux("Kitchen", &roomdb), 1.0);
+        assert_eq!(bf.average_room_lux("Basement", &roomdb), 0.0);
+    }
+
+    #[test]
+    fn test_floor_mapping_with_gap() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        // Tiled floors 0 and 2 exist, floor 1 is missing.
+        for (floor, z) in [(0, 0), (2, 1)] {
+            bf.floor_z_map.insert(floor, z);
+            bf.z_floor_map.insert(z, floor);
+        }
+
+        assert_eq!(bf.z_for_floor(0), Some(0));
+        assert_eq!(bf.z_for_floor(1), None);
+        assert_eq!(bf.z_for_floor(2), Some(1));
+        assert_eq!(bf.floor_for_z(0), Some(0));
+        assert_eq!(bf.floor_for_z(1), Some(2));
+        assert_eq!(bf.floor_for_z(2), None);
+        for (floor, z) in [(0, 0), (2, 1)] {
+            assert_eq!(bf.floor_for_z(bf.z_for_floor(floor).unwrap()), Some(floor));
+            assert_eq!(bf.z_for_floor(bf.floor_for_z(z).unwrap()), Some(z));
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use bevy::prelude::Resource;
 
+use crate::types::ghost::definitions::GhostSelection;
+
 #[derive(Resource, Debug, Default, Clone, Copy)]
 pub struct CliOptions {
     pub include_draft_maps: bool,
+    /// Record a `GhostInteractionLog` during missions.
+    pub ghost_log: bool,
+    /// How the ghost of each mission is picked; lets a specific ghost be tested.
+    pub ghost_selection: GhostSelection,
 }
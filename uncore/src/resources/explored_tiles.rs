@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use ndarray::Array3;
+
+use crate::components::board::position::Position;
+
+/// Tiles the player has been near during the current mission.
+///
+/// Reset when a mission starts and grown to the board size on first use. Each
+/// floor keeps a revision number that changes whenever new tiles on it are
+/// revealed, so consumers like the minimap can redraw only when needed.
+#[derive(Resource, Debug, Clone)]
+pub struct ExploredTiles {
+    explored: Array3<bool>,
+    revisions: Vec<u32>,
+}
+
+impl Default for ExploredTiles {
+    fn default() -> Self {
+        Self {
+            explored: Array3::from_elem((0, 0, 0), false),
+            revisions: Vec::new(),
+        }
+    }
+}
+
+impl ExploredTiles {
+    /// Radius, in tiles, revealed around the player.
+    pub const REVEAL_RADIUS: i64 = 3;
+
+    pub fn is_explored(&self, x: usize, y: usize, z: usize) -> bool {
+        self.explored.get((x, y, z)).copied().unwrap_or(false)
+    }
+
+    /// Revision of floor `z`; changes whenever tiles on it get revealed.
+    pub fn revision(&self, z: usize) -> u32 {
+        self.revisions.get(z).copied().unwrap_or_default()
+    }
+
+    pub fn explored_count(&self) -> usize {
+        self.explored.iter().filter(|e| **e).count()
+    }
+
+    /// True when `reveal_around` with the same arguments would change
+    /// anything. Lets callers skip taking the resource mutably.
+    pub fn needs_reveal(
+        &self,
+        pos: &Position,
+        radius: i64,
+        map_size: (usize, usize, usize),
+    ) -> bool {
+        if self.explored.dim() != map_size {
+            return true;
+        }
+        tiles_around(pos, radius, map_size).any(|idx| !self.explored[idx])
+    }
+
+    /// Marks the tiles within `radius` of `pos`, on its floor, as explored.
+    ///
+    /// The grid is resized (and cleared) when `map_size` differs from the one
+    /// it was built for. Returns true if any tile was newly revealed.
+    pub fn reveal_around(
+        &mut self,
+        pos: &Position,
+        radius: i64,
+        map_size: (usize, usize, usize),
+    ) -> bool {
+        if self.explored.dim() != map_size {
+            self.explored = Array3::from_elem(map_size, false);
+            self.revisions = vec![0; map_size.2];
+        }
+        let mut changed = false;
+        for idx in tiles_around(pos, radius, map_size) {
+            let tile = &mut self.explored[idx];
+            if !*tile {
+                *tile = true;
+                changed = true;
+            }
+        }
+        if changed {
+            let z = pos.to_board_position().z as usize;
+            self.revisions[z] = self.revisions[z].wrapping_add(1);
+        }
+        changed
+    }
+}
+
+/// Board indices within `radius` of `pos` on its floor. Empty if `pos` is
+/// off the board.
+fn tiles_around(
+    pos: &Position,
+    radius: i64,
+    map_size: (usize, usize, usize),
+) -> impl Iterator<Item = (usize, usize, usize)> {
+    let centre = pos.to_board_position();
+    let valid = centre.is_valid(map_size);
+    let x_range = (centre.x - radius).max(0)..=(centre.x + radius).min(map_size.0 as i64 - 1);
+    x_range
+        .filter(move |_| valid)
+        .flat_map(move |x| {
+            let y_range =
+                (centre.y - radius).max(0)..=(centre.y + radius).min(map_size.1 as i64 - 1);
+            y_range.map(move |y| (x, y))
+        })
+        .filter(move |(x, y)| {
+            let (dx, dy) = (x - centre.x, y - centre.y);
+            dx * dx + dy * dy <= radius * radius
+        })
+        .map(move |(x, y)| (x as usize, y as usize, centre.z as usize))
+}
@@ -0,0 +1,25 @@
+use crate::components::board::position::Position;
+use bevy::prelude::*;
+
+/// Dev cheat: camera detached from the player, for inspecting the map.
+///
+/// While active, the arena camera no longer follows the player and the player
+/// ignores movement keys. Visibility is computed from `focus` instead of the
+/// player, so lighting can be inspected on any floor.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FreeCamera {
+    pub active: bool,
+    /// Board floor (z) being inspected.
+    pub floor: usize,
+    /// Board position under the centre of the view, on `floor`.
+    pub focus: Option<Position>,
+    /// Camera transform to restore when leaving the free camera.
+    pub saved_transform: Option<Transform>,
+}
+
+impl FreeCamera {
+    /// Position to compute visibility from while the free camera is active.
+    pub fn view_position(&self) -> Option<Position> {
+        if self.active { self.focus } else { None }
+    }
+}
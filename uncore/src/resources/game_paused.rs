@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use crate::utils::time::clamped_delta_secs;
+
+/// Largest frame delta handed to simulation systems on the frame gameplay
+/// resumes, so the frame that closed the pause menu doesn't arrive as one big
+/// step.
+pub const MAX_RESUME_DELTA_SECS: f32 = 1.0 / 30.0;
+
+/// Whether gameplay is paused (the pause screen or the settings opened from
+/// it).
+///
+/// Kept in sync with `GameState` by the core plugin at the start of the frame.
+/// Simulation systems should use the `gameplay_running` run condition rather
+/// than checking the state themselves.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GamePaused {
+    pub paused: bool,
+    /// Gameplay resumed this frame.
+    pub just_resumed: bool,
+}
+
+impl GamePaused {
+    pub fn set(&mut self, paused: bool) {
+        self.just_resumed = self.paused && !paused;
+        self.paused = paused;
+    }
+
+    /// Frame delta for simulation systems: capped against frame hitches, and
+    /// further on the frame gameplay resumes.
+    pub fn delta_secs(&self, time: &Time) -> f32 {
+        let delta = clamped_delta_secs(time.delta_secs());
+        if self.just_resumed {
+            delta.min(MAX_RESUME_DELTA_SECS)
+        } else {
+            delta
+        }
+    }
+
+    /// `delta_secs` as a `Duration`, for ticking timers.
+    pub fn delta(&self, time: &Time) -> std::time::Duration {
+        std::time::Duration::from_secs_f32(self.delta_secs(time))
+    }
+}
+
+/// Run condition for simulation systems: true unless gameplay is paused.
+pub fn gameplay_running(paused: Option<Res<GamePaused>>) -> bool {
+    paused.is_none_or(|p| !p.paused)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::time::MAX_SIMULATION_DELTA_SECS;
+
+    #[derive(Resource, Default)]
+    struct Ticks(u32);
+
+    fn tick(mut ticks: ResMut<Ticks>) {
+        ticks.0 += 1;
+    }
+
+    #[test]
+    fn test_paused_systems_do_not_run() {
+        let mut app = App::new();
+        app.init_resource::<Ticks>()
+            .init_resource::<GamePaused>()
+            .add_systems(Update, tick.run_if(gameplay_running));
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        app.world_mut().resource_mut::<GamePaused>().set(true);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        app.world_mut().resource_mut::<GamePaused>().set(false);
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+    }
+
+    #[test]
+    fn test_resume_frame_delta_is_clamped() {
+        let mut time = Time::<()>::default();
+        time.advance_by(std::time::Duration::from_secs(2));
+        let mut paused = GamePaused::default();
+        assert_eq!(paused.delta_secs(&time), MAX_SIMULATION_DELTA_SECS);
+
+        paused.set(true);
+        paused.set(false);
+        assert!(paused.just_resumed);
+        assert_eq!(paused.delta_secs(&time), MAX_RESUME_DELTA_SECS);
+
+        paused.set(false);
+        assert_eq!(paused.delta_secs(&time), MAX_SIMULATION_DELTA_SECS);
+    }
+}
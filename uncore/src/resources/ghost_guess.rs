@@ -8,4 +8,6 @@ pub struct GhostGuess {
     pub ghost_type: Option<GhostType>,
     pub evidences_found: HashSet<Evidence>,
     pub evidences_missing: HashSet<Evidence>,
+    /// Evidence that would best narrow down the remaining candidates.
+    pub suggested_evidence: Option<Evidence>,
 }
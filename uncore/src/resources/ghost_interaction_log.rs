@@ -0,0 +1,111 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::components::board::position::Position;
+
+/// What happened in a `GhostLogEntry`.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GhostLogKind {
+    HuntStarted,
+    HuntEnded,
+    DoorSlam,
+    LightFlicker,
+    /// Periodic sample of where the ghost is.
+    Position,
+}
+
+/// A timestamped ghost event, with where the ghost was at the time.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct GhostLogEntry {
+    /// Elapsed game time, in seconds.
+    pub time: f32,
+    pub kind: GhostLogKind,
+    pub position: [f32; 3],
+}
+
+/// Record of what the ghost did during a mission, for reproducing reports like
+/// a ghost hunting through a wall.
+///
+/// Opt-in with the `--ghost-log` command line flag; while disabled nothing is
+/// recorded. The log is written as JSON when the mission ends. Only the last
+/// `MAX_ENTRIES` entries are kept.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct GhostInteractionLog {
+    pub enabled: bool,
+    entries: VecDeque<GhostLogEntry>,
+    /// Entries dropped to stay under `MAX_ENTRIES`.
+    dropped: usize,
+}
+
+#[derive(Serialize)]
+struct GhostLogExport<'a> {
+    dropped: usize,
+    entries: &'a VecDeque<GhostLogEntry>,
+}
+
+impl GhostInteractionLog {
+    /// About 8 hours of one position sample per second.
+    pub const MAX_ENTRIES: usize = 30_000;
+
+    pub fn record(&mut self, time: f32, kind: GhostLogKind, position: &Position) {
+        if !self.enabled {
+            return;
+        }
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries.push_back(GhostLogEntry {
+            time,
+            kind,
+            position: [position.x, position.y, position.z],
+        });
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &GhostLogEntry> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops every entry, keeping whether the log is enabled.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.dropped = 0;
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&GhostLogExport {
+            dropped: self.dropped,
+            entries: &self.entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_is_opt_in_and_capped() {
+        let pos = Position::new_i64(3, 4, 0);
+        let mut log = GhostInteractionLog::default();
+        log.record(1.0, GhostLogKind::HuntStarted, &pos);
+        assert!(log.is_empty());
+
+        log.enabled = true;
+        for i in 0..GhostInteractionLog::MAX_ENTRIES + 5 {
+            log.record(i as f32, GhostLogKind::Position, &pos);
+        }
+        assert_eq!(log.entries().count(), GhostInteractionLog::MAX_ENTRIES);
+        assert_eq!(log.entries().next().unwrap().time, 5.0);
+
+        let json = log.to_json().unwrap();
+        assert!(json.contains("\"dropped\": 5"));
+        assert!(json.contains("\"Position\""));
+    }
+}
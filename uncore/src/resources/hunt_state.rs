@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Whether a ghost is hunting the player right now.
+///
+/// Kept up to date by the ghost plugin, which also sends `HuntStarted` and
+/// `HuntEnded`. Systems that only need to know if a hunt is on should read this
+/// instead of looking at `GhostSprite::hunt_target` themselves.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct HuntState {
+    pub active: bool,
+    /// Elapsed game time when the current hunt started.
+    pub started_at: f32,
+    /// The hunting ghost.
+    pub ghost: Option<Entity>,
+}
+
+impl HuntState {
+    /// Seconds since the current hunt started, or `None` without a hunt.
+    pub fn elapsed(&self, now: f32) -> Option<f32> {
+        self.active.then(|| now - self.started_at)
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Language whose text is built into the game.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Translated text for the game, keyed by stable text keys.
+///
+/// English text lives in the code itself, so callers pass it as the fallback
+/// when resolving a key. Tables for other languages are loaded from
+/// `locale/<language>.locale.json`.
+#[derive(Resource, Debug, Clone)]
+pub struct Localization {
+    /// Language to show text in.
+    pub language: String,
+    tables: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self {
+            language: DEFAULT_LANGUAGE.to_string(),
+            tables: HashMap::new(),
+        }
+    }
+}
+
+impl Localization {
+    /// Asset path of the table for the given language.
+    pub fn table_path(language: &str) -> String {
+        format!("locale/{language}.locale.json")
+    }
+
+    pub fn insert_table(&mut self, language: impl Into<String>, entries: HashMap<String, String>) {
+        self.tables.insert(language.into(), entries);
+    }
+
+    /// Looks up a key in the current language, then in the English table.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        [self.language.as_str(), DEFAULT_LANGUAGE]
+            .into_iter()
+            .filter_map(|language| self.tables.get(language))
+            .find_map(|table| table.get(key))
+            .map(String::as_str)
+    }
+
+    /// Resolves a key, falling back to the built-in English text.
+    pub fn text<'a>(&'a self, key: &str, english: &'a str) -> &'a str {
+        self.get(key).unwrap_or(english)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_keys_fall_back_to_english() {
+        let mut localization = Localization {
+            language: "es".to_string(),
+            ..default()
+        };
+        localization.insert_table(
+            "es",
+            HashMap::from([(
+                "walkie.gear_in_van.hint".to_string(),
+                "Vuelve a la furgoneta.".to_string(),
+            )]),
+        );
+        localization.insert_table(
+            DEFAULT_LANGUAGE,
+            HashMap::from([(
+                "walkie.ghost_near_hunt.hint".to_string(),
+                "Hide!".to_string(),
+            )]),
+        );
+
+        assert_eq!(
+            localization.text("walkie.gear_in_van.hint", "Return to van."),
+            "Vuelve a la furgoneta."
+        );
+        assert_eq!(
+            localization.text("walkie.ghost_near_hunt.hint", "Run!"),
+            "Hide!"
+        );
+        assert_eq!(
+            localization.text("walkie.unknown.hint", "Built in"),
+            "Built in"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_persistent::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::difficulty::Difficulty;
+use crate::types::evidence::Evidence;
+use crate::types::gear_kind::PlayerGearKind;
+use crate::types::ghost::types::GhostType;
+
+/// State of a mission in progress, enough to resume it after a crash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissionSnapshot {
+    pub map_filepath: String,
+    pub difficulty: Difficulty,
+    pub ghost_type: GhostType,
+    /// Player position; `z` is the floor.
+    pub player_position: [f32; 3],
+    pub player_gear: PlayerGearKind,
+    /// Ghost marked in the journal.
+    pub ghost_guess: Option<GhostType>,
+    pub evidences_found: Vec<Evidence>,
+    pub evidences_missing: Vec<Evidence>,
+    pub repellents_crafted: u32,
+    pub time_taken_secs: f32,
+}
+
+/// The autosave file. Holds the last snapshot of the mission in progress, and
+/// nothing once the mission is over.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MissionAutosave {
+    pub mission: Option<MissionSnapshot>,
+}
+
+impl MissionAutosave {
+    /// Opens the autosave file at `path`. A corrupt or unreadable autosave is
+    /// replaced by an empty one instead of failing.
+    pub fn open(path: PathBuf) -> Result<Persistent<Self>, PersistenceError> {
+        Persistent::<Self>::builder()
+            .name("mission_autosave")
+            .format(StorageFormat::RonPrettyWithStructNames)
+            .path(path)
+            .default(Self::default())
+            .revert_to_default_on_deserialization_errors(true)
+            .build()
+    }
+}
+
+/// Mission being resumed from the autosave. Present from the "resume" choice
+/// in the main menu until the mission has started.
+#[derive(Resource, Debug, Clone)]
+pub struct PendingResume(pub MissionSnapshot);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unsettings::persist::testing::TempDir;
+
+    #[test]
+    fn test_corrupt_autosave_is_ignored() {
+        let dir = TempDir::new("autosave");
+        let path = dir.path().join("mission_autosave.ron");
+        std::fs::write(&path, "MissionAutosave(mission: Some(garbage").unwrap();
+        let autosave = MissionAutosave::open(path).unwrap();
+        assert_eq!(autosave.mission, None);
+    }
+}
@@ -1,18 +1,29 @@
 pub mod ambient_mute;
+pub mod audio_limiter;
 pub mod board_data;
 pub mod cli_options;
 pub mod current_evidence_readings;
 pub mod difficulty_state;
+pub mod explored_tiles;
+pub mod free_camera;
+pub mod game_paused;
 pub mod ghost_guess;
+pub mod ghost_interaction_log;
 pub mod hint_ui_state;
+pub mod hunt_state;
+pub mod localization;
 pub mod looking_gear;
 pub mod manual;
 pub mod maps;
+pub mod mission_autosave;
 pub mod mission_select_mode;
 pub mod mouse_visibility;
 pub mod object_interaction;
+pub mod path_cache;
 pub mod player_input;
+pub mod player_journal;
 pub mod potential_id_timer;
 pub mod roomdb;
 pub mod summary_data;
+pub mod ui_theme;
 pub mod visibility_data;
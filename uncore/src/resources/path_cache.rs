@@ -0,0 +1,139 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_platform::collections::HashMap;
+
+use crate::components::board::boardposition::BoardPosition;
+use crate::resources::board_data::BoardData;
+use crate::resources::visibility_data::VisibilityData;
+
+/// Identifies a pathfinding request by the tiles it starts and ends on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathKey {
+    pub start: BoardPosition,
+    pub goal: BoardPosition,
+    /// Paths to interactives treat the goal tile as walkable, so they are kept
+    /// apart from plain movement paths.
+    pub to_interactive: bool,
+    /// Whether diagonal steps were allowed.
+    pub diagonal: bool,
+}
+
+/// Small least-recently-used cache of pathfinding results.
+///
+/// Clicking repeatedly around the same spot asks for the same path over and
+/// over; this keeps the last few around. Every entry is dropped as soon as the
+/// collision field is rebuilt, a door opens or closes, or the visible tiles
+/// change, see [`Self::sync`].
+/// Only found paths are stored, as a failed search usually depends on what the
+/// player can see at the time.
+#[derive(Resource, Debug, Clone)]
+pub struct PathCache {
+    capacity: usize,
+    /// Most recently used entries first.
+    entries: VecDeque<(PathKey, Vec<BoardPosition>)>,
+    collision_revision: u32,
+    door_states: HashMap<(usize, usize, usize), bool>,
+    visible_hash: u64,
+}
+
+impl Default for PathCache {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl PathCache {
+    pub const DEFAULT_CAPACITY: usize = 32;
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+            collision_revision: 0,
+            door_states: HashMap::new(),
+            visible_hash: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Drops every entry if the collision field, the door states of the
+    /// board or the visible tiles changed since the last call.
+    ///
+    /// Door states are the ones gathered by the lighting rebuild
+    /// (`collect_door_states`), so no extra scan of the doors is needed here.
+    pub fn sync(&mut self, bf: &BoardData, vf: &VisibilityData) {
+        if self.collision_revision != bf.collision_revision
+            || self.door_states != bf.lighting_door_states
+            || self.visible_hash != vf.visible_hash
+        {
+            self.entries.clear();
+            self.collision_revision = bf.collision_revision;
+            self.door_states = bf.lighting_door_states.clone();
+            self.visible_hash = vf.visible_hash;
+        }
+    }
+
+    /// Returns the cached path for `key`, marking it as recently used.
+    pub fn get(&mut self, key: &PathKey) -> Option<Vec<BoardPosition>> {
+        let idx = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(idx)?;
+        let path = entry.1.clone();
+        self.entries.push_front(entry);
+        Some(path)
+    }
+
+    /// Stores a path, evicting the least recently used entry when full.
+    pub fn insert(&mut self, key: PathKey, path: Vec<BoardPosition>) {
+        if self.capacity == 0 || path.is_empty() {
+            return;
+        }
+        self.entries.retain(|(k, _)| *k != key);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_back();
+        }
+        self.entries.push_front((key, path));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(x: i64) -> PathKey {
+        PathKey {
+            start: BoardPosition { x: 0, y: 0, z: 0 },
+            goal: BoardPosition { x, y: 0, z: 0 },
+            to_interactive: false,
+            diagonal: false,
+        }
+    }
+
+    fn path(x: i64) -> Vec<BoardPosition> {
+        vec![key(x).start, key(x).goal]
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let mut cache = PathCache::new(2);
+        cache.insert(key(1), path(1));
+        cache.insert(key(2), path(2));
+        assert_eq!(cache.get(&key(1)), Some(path(1)));
+
+        cache.insert(key(3), path(3));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&key(2)), None);
+        assert_eq!(cache.get(&key(1)), Some(path(1)));
+    }
+}
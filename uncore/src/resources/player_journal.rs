@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+use crate::types::evidence::{Evidence, EvidenceSet};
+use crate::types::ghost::distinguisher;
+use crate::types::ghost::types::GhostType;
+
+/// What the player wrote down about a single evidence type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JournalMark {
+    #[default]
+    Unknown,
+    Confirmed,
+    Discarded,
+}
+
+/// The player's own record of evidence for the current mission.
+///
+/// Mirrors the journal evidence buttons, which are toggled both from the truck
+/// journal and from the "mark evidence" key on the gear. Confirmed evidence is
+/// kept in the order it was found so the truck summary reads like a log.
+#[derive(Resource, Debug, Default)]
+pub struct PlayerJournal {
+    confirmed: Vec<Evidence>,
+    discarded: EvidenceSet,
+}
+
+impl PlayerJournal {
+    pub fn mark(&self, evidence: Evidence) -> JournalMark {
+        if self.confirmed.contains(&evidence) {
+            JournalMark::Confirmed
+        } else if self.discarded.contains(evidence) {
+            JournalMark::Discarded
+        } else {
+            JournalMark::Unknown
+        }
+    }
+
+    /// Records a new mark for the evidence, replacing any previous one.
+    ///
+    /// Confirming an evidence that was ruled out (or the other way around)
+    /// simply moves it across; the latest decision of the player wins.
+    pub fn set_mark(&mut self, evidence: Evidence, mark: JournalMark) {
+        self.confirmed.retain(|e| *e != evidence);
+        self.discarded.remove(evidence);
+        match mark {
+            JournalMark::Unknown => {}
+            JournalMark::Confirmed => self.confirmed.push(evidence),
+            JournalMark::Discarded => self.discarded.insert(evidence),
+        }
+    }
+
+    /// Confirmed evidence, in the order it was confirmed.
+    pub fn confirmed(&self) -> &[Evidence] {
+        &self.confirmed
+    }
+
+    pub fn confirmed_set(&self) -> EvidenceSet {
+        self.confirmed.iter().collect()
+    }
+
+    pub fn discarded(&self) -> EvidenceSet {
+        self.discarded
+    }
+
+    /// Evidence that has been neither confirmed nor ruled out yet.
+    pub fn remaining(&self) -> EvidenceSet {
+        Evidence::all()
+            .filter(|e| self.mark(*e) == JournalMark::Unknown)
+            .collect()
+    }
+
+    /// Ghosts from `candidates` that agree with every mark in the journal.
+    pub fn matching_ghosts(&self, candidates: &[GhostType]) -> Vec<GhostType> {
+        distinguisher::matching_ghosts(candidates, self.confirmed_set(), self.discarded)
+    }
+
+    /// True when the marks rule out every candidate ghost, which means at
+    /// least one of them is wrong.
+    pub fn is_contradictory(&self, candidates: &[GhostType]) -> bool {
+        let has_marks = !self.confirmed.is_empty() || !self.discarded.is_empty();
+        has_marks && self.matching_ghosts(candidates).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changing_mark_moves_evidence() {
+        let mut journal = PlayerJournal::default();
+        journal.set_mark(Evidence::FreezingTemp, JournalMark::Discarded);
+        journal.set_mark(Evidence::FreezingTemp, JournalMark::Confirmed);
+
+        assert_eq!(journal.mark(Evidence::FreezingTemp), JournalMark::Confirmed);
+        assert!(journal.discarded().is_empty());
+        assert!(!journal.remaining().contains(Evidence::FreezingTemp));
+
+        journal.set_mark(Evidence::FreezingTemp, JournalMark::Unknown);
+        assert!(journal.confirmed().is_empty());
+        assert!(journal.remaining().contains(Evidence::FreezingTemp));
+    }
+
+    #[test]
+    fn test_contradictory_marks() {
+        let ghost = GhostType::LadyInWhite;
+        let candidates = [ghost];
+        let mut journal = PlayerJournal::default();
+        assert!(!journal.is_contradictory(&candidates));
+
+        let evidence = ghost.evidence_set().iter().next().unwrap();
+        journal.set_mark(evidence, JournalMark::Confirmed);
+        assert_eq!(journal.matching_ghosts(&candidates), vec![ghost]);
+
+        journal.set_mark(evidence, JournalMark::Discarded);
+        assert!(journal.is_contradictory(&candidates));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::difficulty::CurrentDifficulty;
 use crate::types::ghost::types::GhostType;
-use crate::types::grade::Grade;
+use crate::types::grade::{Grade, GradeThresholds};
 use bevy::prelude::*;
 
 #[derive(Debug, Clone, Resource, Default)]
@@ -46,6 +46,9 @@ pub struct SummaryData {
 
     /// Costs deducted from the deposit
     pub costs_deducted_from_deposit: i64,
+
+    /// Base-score grade thresholds of the mission played, see `grade`.
+    pub grade_thresholds: GradeThresholds,
 }
 
 impl SummaryData {
@@ -92,4 +95,19 @@ impl SummaryData {
         self.full_score = score.clamp(0.0, 1000000.0).round() as i64;
         self.full_score
     }
+
+    /// Returns the grade earned by `full_score`.
+    ///
+    /// Failed missions get N/A. Otherwise `grade_thresholds` are scaled by the
+    /// difficulty score multiplier so that the same play earns the same grade
+    /// on every difficulty. S needs twice the A threshold (see
+    /// `GradeThresholds::S_FACTOR`). Expects `calculate_score` to have run.
+    pub fn grade(&self) -> Grade {
+        if !self.mission_successful {
+            return Grade::NA;
+        }
+        self.grade_thresholds
+            .scaled(self.difficulty.0.difficulty_score_multiplier)
+            .grade_for(self.full_score)
+    }
 }
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use unsettings::video::{ColourblindMode, UiThemeKind};
+
+use crate::colours::UiPalette;
+use crate::types::root::font_assets::FontAssets;
+
+/// Fonts of a theme, by the role the text plays in the UI.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UiThemeFonts {
+    /// Menu entries and breadcrumbs in the left strip.
+    pub title: Handle<Font>,
+    /// Selectable items in content areas.
+    pub item: Handle<Font>,
+    /// Descriptions and help lines.
+    pub body: Handle<Font>,
+    /// Long texts, like manual pages.
+    pub text: Handle<Font>,
+}
+
+impl UiThemeFonts {
+    pub fn standard(fonts: &FontAssets) -> Self {
+        Self {
+            title: fonts.londrina.w300_light.clone(),
+            item: fonts.titillium.w400_regular.clone(),
+            body: fonts.titillium.w300_light.clone(),
+            text: fonts.chakra.w300_light.clone(),
+        }
+    }
+
+    /// Heavier weights of the same families; thin strokes wash out easily.
+    pub fn high_contrast(fonts: &FontAssets) -> Self {
+        Self {
+            title: fonts.londrina.w400_regular.clone(),
+            item: fonts.titillium.w600_semibold.clone(),
+            body: fonts.titillium.w400_regular.clone(),
+            text: fonts.chakra.w400_regular.clone(),
+        }
+    }
+}
+
+/// Role of a text in the UI, used to pick its font from the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiFontRole {
+    Title,
+    Item,
+    Body,
+    Text,
+}
+
+/// Role of a text in the UI, used to pick its colour from the theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiColourRole {
+    /// Highlighted or current entries.
+    ItemOn,
+    /// Regular entries and plain text.
+    ItemOff,
+    /// Entries that can't be selected.
+    Disabled,
+}
+
+/// Fonts and colours used by the menus.
+///
+/// Resolved from the `ui_theme` and `colourblind_mode` video settings, and
+/// kept in sync by `sync_ui_theme`. The high contrast theme has its own
+/// palette, readable under every colour vision deficiency, so it ignores the
+/// colourblind mode.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct UiTheme {
+    pub kind: UiThemeKind,
+    pub palette: UiPalette,
+    pub fonts: UiThemeFonts,
+}
+
+impl UiTheme {
+    /// Builds the theme. Without font assets (before they're loaded) the font
+    /// handles are left as default.
+    pub fn resolve(
+        kind: UiThemeKind,
+        colourblind_mode: ColourblindMode,
+        fonts: Option<&FontAssets>,
+    ) -> Self {
+        let (palette, fonts) = match kind {
+            UiThemeKind::Standard => (
+                UiPalette::for_mode(colourblind_mode),
+                fonts.map(UiThemeFonts::standard),
+            ),
+            UiThemeKind::HighContrast => (
+                UiPalette::HIGH_CONTRAST,
+                fonts.map(UiThemeFonts::high_contrast),
+            ),
+        };
+        Self {
+            kind,
+            palette,
+            fonts: fonts.unwrap_or_default(),
+        }
+    }
+
+    pub fn font(&self, role: UiFontRole) -> Handle<Font> {
+        match role {
+            UiFontRole::Title => self.fonts.title.clone(),
+            UiFontRole::Item => self.fonts.item.clone(),
+            UiFontRole::Body => self.fonts.body.clone(),
+            UiFontRole::Text => self.fonts.text.clone(),
+        }
+    }
+
+    pub fn colour(&self, role: UiColourRole) -> Color {
+        match role {
+            UiColourRole::ItemOn => self.palette.menu_item_on,
+            UiColourRole::ItemOff => self.palette.menu_item_off,
+            UiColourRole::Disabled => self.palette.menu_item_off.with_alpha(0.3),
+        }
+    }
+
+    /// Text colour of a menu entry.
+    pub fn highlight_colour(&self, selected: bool) -> Color {
+        if selected {
+            self.colour(UiColourRole::ItemOn)
+        } else {
+            self.colour(UiColourRole::ItemOff)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_switching_theme_changes_highlight() {
+        let standard = UiTheme::resolve(UiThemeKind::Standard, ColourblindMode::None, None);
+        let contrast = UiTheme::resolve(UiThemeKind::HighContrast, ColourblindMode::None, None);
+        assert_eq!(
+            standard.highlight_colour(true),
+            UiPalette::DEFAULT.menu_item_on
+        );
+        assert_eq!(
+            contrast.highlight_colour(true),
+            UiPalette::HIGH_CONTRAST.menu_item_on
+        );
+        assert_ne!(
+            standard.highlight_colour(true),
+            contrast.highlight_colour(true)
+        );
+
+        // The colourblind palettes only apply to the standard theme.
+        let protanopia =
+            UiTheme::resolve(UiThemeKind::HighContrast, ColourblindMode::Protanopia, None);
+        assert_eq!(protanopia.palette, contrast.palette);
+    }
+}
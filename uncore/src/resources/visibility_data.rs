@@ -1,7 +1,30 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use bevy::prelude::*;
 use ndarray::Array3;
 
 #[derive(Clone, Debug, Resource, Default)]
 pub struct VisibilityData {
     pub visibility_field: Array3<f32>,
+    /// Hash of which tiles are visible (`visibility_field > 0`). Results that
+    /// depend on visibility, like cached paths, are dropped when it changes.
+    pub visible_hash: u64,
+}
+
+impl VisibilityData {
+    /// Recomputes `visible_hash`. Call after every update of the field.
+    pub fn update_visible_hash(&mut self) {
+        let mut hasher = DefaultHasher::new();
+        self.visibility_field.dim().hash(&mut hasher);
+        let mut word = 0_u64;
+        for (i, v) in self.visibility_field.iter().enumerate() {
+            word |= u64::from(*v > 0.0) << (i % 64);
+            if i % 64 == 63 {
+                hasher.write_u64(word);
+                word = 0;
+            }
+        }
+        hasher.write_u64(word);
+        self.visible_hash = hasher.finish();
+    }
 }
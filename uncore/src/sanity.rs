@@ -0,0 +1,43 @@
+//! Situational model of how fast the player loses sanity.
+//!
+//! The difficulty sets a base `sanity_drain_rate`; being in the dark, close to
+//! the ghost or caught in a hunt each multiply it. The factors stack
+//! multiplicatively, so being in the dark next to a hunting ghost drains
+//! `DARK × GHOST × HUNT` times faster than a lit, quiet room.
+//!
+//! The thresholds that decide "in the dark" and "near the ghost" are shared
+//! with the walkie hints that warn about sanity dropping for those reasons.
+//! The in-game drain already grows as the light fades, so it leaves the dark
+//! multiplier out and only applies the ghost and hunt ones.
+
+/// Lux below which a player counts as being in the dark.
+pub const DARKNESS_LUX_THRESHOLD: f32 = 0.1;
+
+/// Distance, in tiles, under which a player counts as being near the ghost.
+pub const GHOST_PROXIMITY_DISTANCE: f32 = 3.0;
+
+/// Drain multiplier while in the dark.
+pub const DARKNESS_DRAIN_MULTIPLIER: f32 = 2.0;
+
+/// Drain multiplier while near the ghost.
+pub const GHOST_PROXIMITY_DRAIN_MULTIPLIER: f32 = 1.5;
+
+/// Drain multiplier while the ghost is hunting.
+pub const HUNT_DRAIN_MULTIPLIER: f32 = 3.0;
+
+/// Sanity drain for a difficulty `rate` under the given conditions.
+///
+/// A negative rate is treated as zero, so the result is never negative.
+pub fn compute_drain(rate: f32, in_dark: bool, near_ghost: bool, in_hunt: bool) -> f32 {
+    let mut drain = rate.max(0.0);
+    if in_dark {
+        drain *= DARKNESS_DRAIN_MULTIPLIER;
+    }
+    if near_ghost {
+        drain *= GHOST_PROXIMITY_DRAIN_MULTIPLIER;
+    }
+    if in_hunt {
+        drain *= HUNT_DRAIN_MULTIPLIER;
+    }
+    drain
+}
@@ -9,6 +9,7 @@ mod tests {
     use crate::difficulty::{CurrentDifficulty, Difficulty};
     use crate::resources::summary_data::SummaryData;
     use crate::types::ghost::types::GhostType;
+    use crate::types::grade::{Grade, GradeThresholds};
     // enum_iterator removed as it's not used in current tests
     use proptest::prelude::*;
     use quickcheck::{TestResult, quickcheck};
@@ -132,6 +133,53 @@ mod tests {
         );
     }
 
+    /// Pin the default grade thresholds at a multiplier of 1.0
+    #[rstest]
+    #[case(2000, Grade::S)]
+    #[case(1999, Grade::A)]
+    #[case(1000, Grade::A)]
+    #[case(999, Grade::B)]
+    #[case(500, Grade::B)]
+    #[case(250, Grade::C)]
+    #[case(125, Grade::D)]
+    #[case(124, Grade::F)]
+    #[case(0, Grade::F)]
+    fn test_grade_thresholds(#[case] score: i64, #[case] expected: Grade) {
+        assert_eq!(GradeThresholds::default().grade_for(score), expected);
+    }
+
+    /// A perfect, fast mission earns S; the same play earns the same grade on
+    /// every difficulty because thresholds scale with the multiplier.
+    #[test]
+    fn test_mission_grade() {
+        for difficulty in [
+            Difficulty::TutorialChapter1,
+            Difficulty::StandardChallenge,
+            Difficulty::MasterChallenge,
+        ] {
+            let mut summary = SummaryData::new(
+                vec![GhostType::BeanSidhe],
+                CurrentDifficulty(difficulty.create_difficulty_struct()),
+            );
+            summary.mission_successful = true;
+            summary.ghosts_unhaunted = 1;
+            summary.player_count = 1;
+            summary.alive_count = 1;
+            summary.average_sanity = 100.0;
+            summary.time_taken_secs = 60.0;
+            summary.calculate_score();
+            assert_eq!(summary.grade(), Grade::S, "{difficulty:?}");
+
+            summary.average_sanity = 80.0;
+            summary.time_taken_secs = 300.0;
+            summary.calculate_score();
+            assert_eq!(summary.grade(), Grade::A, "{difficulty:?}");
+
+            summary.mission_successful = false;
+            assert_eq!(summary.grade(), Grade::NA, "{difficulty:?}");
+        }
+    }
+
     /// Test sanity impact on scoring
     #[rstest]
     #[case(100, "perfect sanity")]
@@ -20,9 +20,19 @@ pub enum GameState {
     None,
     Truck,
     Pause,
+    /// Settings menu opened from the pause screen. The mission stays loaded
+    /// and paused underneath.
+    Settings,
     NpcHelp,
 }
 
+impl GameState {
+    /// Gameplay is frozen: the pause screen or the settings opened from it.
+    pub fn is_paused(&self) -> bool {
+        matches!(self, GameState::Pause | GameState::Settings)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, States, Default)]
 pub enum MapHubState {
     DifficultySelection,
@@ -0,0 +1,18 @@
+use crate::resources::game_paused::GamePaused;
+use crate::states::GameState;
+use bevy::prelude::*;
+
+/// Mirrors `GameState` into `GamePaused`. Runs before the state transitions of
+/// the frame, so a pause or resume takes effect on the next frame, and
+/// `just_resumed` is set on the first frame gameplay runs again.
+fn sync_game_paused(game_state: Option<Res<State<GameState>>>, mut paused: ResMut<GamePaused>) {
+    let now = game_state.is_some_and(|s| s.is_paused());
+    if paused.paused != now || paused.just_resumed {
+        paused.set(now);
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<GamePaused>()
+        .add_systems(PreUpdate, sync_game_paused);
+}
@@ -0,0 +1,55 @@
+use crate::assets::locale::{LocaleTable, LocaleTableLoader};
+use crate::resources::localization::{DEFAULT_LANGUAGE, Localization};
+use bevy::prelude::*;
+
+/// Handles of the locale tables requested so far, by language.
+#[derive(Resource, Default)]
+struct LocaleHandles(Vec<(String, Handle<LocaleTable>)>);
+
+/// Starts loading the table for the selected language when it changes.
+///
+/// English is built into the game, so it only needs a table to override text.
+fn request_locale_table(
+    asset_server: Res<AssetServer>,
+    localization: Res<Localization>,
+    mut handles: ResMut<LocaleHandles>,
+) {
+    if !localization.is_changed() || localization.language == DEFAULT_LANGUAGE {
+        return;
+    }
+    let language = &localization.language;
+    if handles.0.iter().any(|(l, _)| l == language) {
+        return;
+    }
+    info!("Loading locale table for '{language}'");
+    let handle = asset_server.load(Localization::table_path(language));
+    handles.0.push((language.clone(), handle));
+}
+
+/// Copies loaded (or hot-reloaded) tables into `Localization`.
+fn apply_locale_tables(
+    mut ev_asset: EventReader<AssetEvent<LocaleTable>>,
+    tables: Res<Assets<LocaleTable>>,
+    handles: Res<LocaleHandles>,
+    mut localization: ResMut<Localization>,
+) {
+    for ev in ev_asset.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = ev else {
+            continue;
+        };
+        let Some((language, _)) = handles.0.iter().find(|(_, h)| h.id() == *id) else {
+            continue;
+        };
+        if let Some(table) = tables.get(*id) {
+            localization.insert_table(language.clone(), table.entries.clone());
+        }
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_asset::<LocaleTable>()
+        .init_asset_loader::<LocaleTableLoader>()
+        .init_resource::<Localization>()
+        .init_resource::<LocaleHandles>()
+        .add_systems(Update, (request_locale_table, apply_locale_tables).chain());
+}
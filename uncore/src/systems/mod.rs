@@ -2,3 +2,6 @@ pub mod ambient_sound_mute;
 pub mod animation;
 pub mod board;
 pub mod evidence_decay;
+pub mod game_paused;
+pub mod localization;
+pub mod ui_palette;
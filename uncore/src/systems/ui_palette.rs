@@ -0,0 +1,33 @@
+use crate::colours::UiPalette;
+use crate::resources::ui_theme::UiTheme;
+use crate::types::root::game_assets::GameAssets;
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+use unsettings::video::VideoSettings;
+
+/// Keeps the active `UiTheme` in sync with the theme and colourblind mode
+/// settings. `UiPalette` follows the palette of the theme.
+fn sync_ui_theme(
+    video_settings: Res<Persistent<VideoSettings>>,
+    handles: Option<Res<GameAssets>>,
+    mut theme: ResMut<UiTheme>,
+    mut palette: ResMut<UiPalette>,
+) {
+    let target = UiTheme::resolve(
+        video_settings.ui_theme,
+        video_settings.colourblind_mode,
+        handles.as_deref().map(|h| &h.fonts),
+    );
+    if *theme != target {
+        *theme = target;
+    }
+    if *palette != theme.palette {
+        *palette = theme.palette;
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<UiPalette>()
+        .init_resource::<UiTheme>()
+        .add_systems(PreUpdate, sync_ui_theme);
+}
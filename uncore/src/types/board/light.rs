@@ -83,3 +83,193 @@ impl LightData {
         }
     }
 }
+
+/// Coarse brightness categories for a tile, derived from its lux.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LightLevel {
+    /// Too dark to see; ideal for UV, red light and night vision evidence.
+    Dark,
+    /// Some light reaches the tile but it isn't properly lit.
+    Dim,
+    /// Lit enough to see comfortably.
+    Lit,
+    /// Strongly lit, usually right under a light source.
+    Bright,
+}
+
+/// Lux thresholds separating the `LightLevel` bands.
+///
+/// Each threshold is exclusive: a tile needs strictly more lux than the threshold
+/// to be in that band or above.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightLevelThresholds {
+    /// Lux above which a tile is at least `Dim`.
+    pub dim: f32,
+    /// Lux above which a tile is at least `Lit`.
+    pub lit: f32,
+    /// Lux above which a tile is `Bright`.
+    pub bright: f32,
+}
+
+impl Default for LightLevelThresholds {
+    fn default() -> Self {
+        Self {
+            dim: 0.05,
+            lit: 0.5,
+            bright: 2.0,
+        }
+    }
+}
+
+impl LightLevelThresholds {
+    pub fn classify(&self, lux: f32) -> LightLevel {
+        if lux > self.bright {
+            LightLevel::Bright
+        } else if lux > self.lit {
+            LightLevel::Lit
+        } else if lux > self.dim {
+            LightLevel::Dim
+        } else {
+            LightLevel::Dark
+        }
+    }
+}
+
+/// Ceiling applied to the light field, so tiles lit by many overlapping
+/// sources don't blow out to white.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LuxClamp {
+    /// Lux that no tile can exceed.
+    pub max_lux: f32,
+    /// Fraction of `max_lux` below which lux is left untouched. Above it, lux is
+    /// compressed smoothly towards `max_lux`, so brighter tiles stay brighter.
+    pub knee: f32,
+}
+
+impl Default for LuxClamp {
+    fn default() -> Self {
+        Self {
+            max_lux: 30.0,
+            knee: 0.75,
+        }
+    }
+}
+
+impl LuxClamp {
+    pub fn apply(&self, lux: f32) -> f32 {
+        let knee = self.max_lux * self.knee;
+        if lux <= knee {
+            return lux;
+        }
+        let headroom = self.max_lux - knee;
+        let excess = lux - knee;
+        knee + headroom * excess / (excess + headroom)
+    }
+}
+
+/// Default cap on the wave edge steps a single lighting rebuild may process.
+///
+/// Regular maps stay well below this; it only kicks in on pathological layouts
+/// where the propagation would otherwise stall the frame.
+pub const DEFAULT_PROPAGATION_STEP_BUDGET: usize = 500_000;
+
+/// Scales an RGB colour down so no channel is above 1.0, keeping its hue.
+pub fn normalize_color(color: (f32, f32, f32)) -> (f32, f32, f32) {
+    let max = color.0.max(color.1).max(color.2);
+    if max <= 1.0 {
+        return color;
+    }
+    (color.0 / max, color.1 / max, color.2 / max)
+}
+
+/// Converts a colour temperature in Kelvin to an RGB colour in the 0..1 range.
+///
+/// Uses Tanner Helland's curve fit of the black body spectrum, which is accurate
+/// enough for lighting between 1000K (candle) and 40000K (clear blue sky).
+/// Around 6600K the result is plain white.
+pub fn kelvin_to_rgb(kelvin: f32) -> (f32, f32, f32) {
+    let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.69873 * (t - 60.0).powf(-0.13320476)
+    };
+    let green = if t <= 66.0 {
+        99.4708 * t.ln() - 161.11957
+    } else {
+        288.12217 * (t - 60.0).powf(-0.075514846)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.51773 * (t - 10.0).ln() - 305.0448
+    };
+    let channel = |v: f32| v.clamp(0.0, 255.0) / 255.0;
+    (channel(red), channel(green), channel(blue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_sources_stay_under_cap() {
+        let clamp = LuxClamp::default();
+        let single = 20.0;
+        let overlapped = single * 5.0;
+
+        assert!(clamp.apply(overlapped) < clamp.max_lux);
+        assert!(clamp.apply(overlapped) > clamp.apply(single));
+        assert!(clamp.apply(single) <= single);
+        // Normal lighting below the knee is not affected.
+        assert_eq!(clamp.apply(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_normalize_color_keeps_hue() {
+        assert_eq!(normalize_color((0.5, 0.2, 1.0)), (0.5, 0.2, 1.0));
+        assert_eq!(normalize_color((2.0, 1.0, 0.5)), (1.0, 0.5, 0.25));
+    }
+
+    #[test]
+    fn test_light_level_band_boundaries() {
+        let t = LightLevelThresholds::default();
+        assert_eq!(t.classify(0.0), LightLevel::Dark);
+        assert_eq!(t.classify(t.dim), LightLevel::Dark);
+        assert_eq!(t.classify(t.dim + 0.001), LightLevel::Dim);
+        assert_eq!(t.classify(t.lit), LightLevel::Dim);
+        assert_eq!(t.classify(t.lit + 0.001), LightLevel::Lit);
+        assert_eq!(t.classify(t.bright), LightLevel::Lit);
+        assert_eq!(t.classify(t.bright + 0.001), LightLevel::Bright);
+        assert_eq!(t.classify(1000.0), LightLevel::Bright);
+    }
+
+    #[test]
+    fn test_light_level_custom_thresholds() {
+        let t = LightLevelThresholds {
+            dim: 1.0,
+            lit: 2.0,
+            bright: 3.0,
+        };
+        assert_eq!(t.classify(0.9), LightLevel::Dark);
+        assert_eq!(t.classify(1.5), LightLevel::Dim);
+        assert_eq!(t.classify(2.5), LightLevel::Lit);
+        assert_eq!(t.classify(3.5), LightLevel::Bright);
+    }
+
+    #[test]
+    fn test_kelvin_to_rgb_warm_and_cool() {
+        let (r, g, b) = kelvin_to_rgb(6600.0);
+        assert!(r > 0.99 && g > 0.95 && b > 0.99);
+
+        let warm = kelvin_to_rgb(3000.0);
+        assert_eq!(warm.0, 1.0);
+        assert!(warm.2 < warm.1 && warm.1 < warm.0);
+
+        let cool = kelvin_to_rgb(10000.0);
+        assert_eq!(cool.2, 1.0);
+        assert!(cool.0 < cool.2);
+    }
+}
@@ -0,0 +1,64 @@
+//! Intermediate light field kept between rebuilds, so toggling a single light
+//! doesn't need to propagate every other light source again.
+
+use bevy_platform::collections::{HashMap, HashSet};
+use ndarray::Array3;
+
+use super::fielddata::LightFieldData;
+
+/// Which light source read or lit each tile during the wave edge propagation.
+#[derive(Clone, Debug)]
+pub struct LightOwners {
+    /// Source of each tile: `0` for none, `LightOwners::SHARED` for several.
+    pub tiles: Array3<u32>,
+    /// Sources that touched a tile another source touched too. Their light
+    /// depends on each other, so they can't be toggled on their own.
+    pub shared_sources: HashSet<u32>,
+    /// Propagation steps taken, to check against the step budget. An upper
+    /// bound once sources have been turned off.
+    pub steps: usize,
+    /// Cleared when the propagation ran out of steps; the partial result then
+    /// depends on the order all sources were processed in.
+    pub complete: bool,
+}
+
+impl LightOwners {
+    pub const SHARED: u32 = u32::MAX;
+
+    pub fn new(map_size: (usize, usize, usize)) -> Self {
+        Self {
+            tiles: Array3::from_elem(map_size, 0),
+            shared_sources: HashSet::new(),
+            steps: 0,
+            complete: true,
+        }
+    }
+
+    /// Records that `source_id` read or lit the tile.
+    pub fn touch(&mut self, idx: (usize, usize, usize), source_id: u32) {
+        let owner = &mut self.tiles[idx];
+        if *owner == 0 || *owner == source_id {
+            *owner = source_id;
+            return;
+        }
+        if *owner != Self::SHARED {
+            self.shared_sources.insert(*owner);
+            *owner = Self::SHARED;
+        }
+        self.shared_sources.insert(source_id);
+    }
+}
+
+/// Light field of the last rebuild after the prebaked contributions and the
+/// wave edge propagation, before the stair and ambient passes.
+#[derive(Clone, Debug)]
+pub struct LightingCache {
+    pub field: Array3<LightFieldData>,
+    pub owners: LightOwners,
+    /// `BoardData::collision_revision` the field was built with.
+    pub collision_revision: u32,
+    /// Lux of flickering shared sources built on their own, by source id. Lets
+    /// their flicker be applied where their light overlaps other sources.
+    /// Filled on demand.
+    pub source_lux: HashMap<u32, Array3<f32>>,
+}
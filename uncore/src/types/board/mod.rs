@@ -1,3 +1,5 @@
 pub mod fielddata;
 pub mod light;
+pub mod light_cache;
+pub mod prebaked_dump;
 pub mod prebaked_lighting_data;
@@ -0,0 +1,424 @@
+//! Dumping prebaked lighting data to disk, for inspection.
+//!
+//! Each floor is written to its own JSON file. Most tiles of a floor hold the
+//! same (empty) data, so the tile and propagation arrays are run-length
+//! encoded, which shrinks a typical map by two orders of magnitude. The
+//! `PrebakedMetadata` of the board goes into a separate file.
+
+use std::path::{Path, PathBuf};
+
+use ndarray::{Array2, Array3};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use bevy::prelude::Entity;
+
+use super::prebaked_lighting_data::{PrebakedLightingData, PrebakedMetadata, WaveEdgeData};
+use crate::resources::board_data::BoardData;
+
+/// Version written into every floor dump, for future migrations.
+pub const PREBAKED_DUMP_VERSION: u32 = 1;
+
+/// A value repeated `count` times in a run-length encoded array.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Run<T> {
+    pub count: u32,
+    pub value: T,
+}
+
+/// Run-length encodes the items, merging consecutive equal values.
+pub fn rle_encode<T: PartialEq>(items: impl IntoIterator<Item = T>) -> Vec<Run<T>> {
+    let mut runs: Vec<Run<T>> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some(run) if run.value == item => run.count += 1,
+            _ => runs.push(Run {
+                count: 1,
+                value: item,
+            }),
+        }
+    }
+    runs
+}
+
+/// Expands run-length encoded items back into a flat list.
+pub fn rle_decode<T: Clone>(runs: &[Run<T>]) -> Vec<T> {
+    runs.iter()
+        .flat_map(|run| std::iter::repeat_n(run.value.clone(), run.count as usize))
+        .collect()
+}
+
+/// Errors that can occur while writing or reading a prebaked lighting dump.
+#[derive(Error, Debug)]
+pub enum PrebakedDumpError {
+    /// The dump file could not be read or written.
+    #[error("Could not access prebaked lighting dump: {0}")]
+    Io(#[from] std::io::Error),
+    /// The dump is not valid JSON.
+    #[error("Invalid prebaked lighting dump: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The dump doesn't fit the board it's being loaded into.
+    #[error("Prebaked lighting dump does not match the board: {0}")]
+    Mismatch(String),
+}
+
+/// Prebaked lighting of a single floor.
+///
+/// Tiles are stored in `(x, y)` order. The light sources of the floor are in
+/// `PrebakedMetadataDump`, written next to the floor dumps.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PrebakedFloorDump {
+    #[serde(default)]
+    pub version: u32,
+    pub map_path: String,
+    pub z: usize,
+    pub map_size: (usize, usize, usize),
+    pub tiles: Vec<Run<PrebakedLightingData>>,
+    /// Wave edges on this floor.
+    pub wave_edges: Vec<WaveEdgeData>,
+    /// Length of the board's propagation list, so it can be rebuilt even when
+    /// some sources live on other floors.
+    pub propagation_len: usize,
+    /// Propagation directions of the light sources on this floor, by source id.
+    pub propagation: Vec<(u32, Vec<Run<[bool; 4]>>)>,
+}
+
+impl PrebakedFloorDump {
+    pub fn from_board(bf: &BoardData, z: usize) -> Self {
+        let (width, height, _) = bf.map_size;
+        let tiles = rle_encode(
+            (0..width)
+                .flat_map(|x| (0..height).map(move |y| (x, y)))
+                .map(|(x, y)| bf.prebaked_lighting[(x, y, z)].clone()),
+        );
+        let wave_edges = bf
+            .prebaked_wave_edges
+            .iter()
+            .filter(|edge| edge.position.z as usize == z)
+            .cloned()
+            .collect();
+        let mut propagation: Vec<(u32, Vec<Run<[bool; 4]>>)> = bf
+            .prebaked_metadata
+            .light_sources
+            .iter()
+            .filter(|(_, idx)| idx.2 == z)
+            .filter_map(|(entity, _)| bf.prebaked_metadata.light_source_ids.get(entity))
+            .filter_map(|&id| {
+                let array = bf.prebaked_propagation.get(id as usize)?;
+                Some((id, rle_encode(array.iter().copied())))
+            })
+            .collect();
+        propagation.sort_by_key(|(id, _)| *id);
+
+        Self {
+            version: PREBAKED_DUMP_VERSION,
+            map_path: bf.map_path.clone(),
+            z,
+            map_size: bf.map_size,
+            tiles,
+            wave_edges,
+            propagation_len: bf.prebaked_propagation.len(),
+            propagation,
+        }
+    }
+
+    /// Copies the floor back into the board's prebaked data.
+    ///
+    /// Wave edges already on the board for this floor are replaced, and the
+    /// propagation list is grown to the dumped length if needed.
+    pub fn restore_into(&self, bf: &mut BoardData) -> Result<(), PrebakedDumpError> {
+        if self.map_size != bf.map_size {
+            return Err(PrebakedDumpError::Mismatch(format!(
+                "map size {:?} differs from board {:?}",
+                self.map_size, bf.map_size
+            )));
+        }
+        let (width, height, depth) = self.map_size;
+        if self.z >= depth {
+            return Err(PrebakedDumpError::Mismatch(format!(
+                "floor {} out of range",
+                self.z
+            )));
+        }
+        let tiles = rle_decode(&self.tiles);
+        if tiles.len() != width * height {
+            return Err(PrebakedDumpError::Mismatch(format!(
+                "floor {} has {} tiles, expected {}",
+                self.z,
+                tiles.len(),
+                width * height
+            )));
+        }
+        if bf.prebaked_lighting.dim() != bf.map_size {
+            bf.prebaked_lighting = Array3::from_elem(bf.map_size, PrebakedLightingData::default());
+        }
+        for (i, tile) in tiles.into_iter().enumerate() {
+            bf.prebaked_lighting[(i / height, i % height, self.z)] = tile;
+        }
+        bf.prebaked_metadata
+            .index_source_tiles(&bf.prebaked_lighting);
+
+        bf.prebaked_wave_edges
+            .retain(|edge| edge.position.z as usize != self.z);
+        bf.prebaked_wave_edges
+            .extend(self.wave_edges.iter().cloned());
+
+        if bf.prebaked_propagation.len() < self.propagation_len {
+            bf.prebaked_propagation.resize(
+                self.propagation_len,
+                Array2::from_elem((width, height), [false; 4]),
+            );
+        }
+        for (id, runs) in &self.propagation {
+            let dirs = rle_decode(runs);
+            let array = Array2::from_shape_vec((width, height), dirs).map_err(|e| {
+                PrebakedDumpError::Mismatch(format!("propagation of source {id}: {e}"))
+            })?;
+            let Some(slot) = bf.prebaked_propagation.get_mut(*id as usize) else {
+                return Err(PrebakedDumpError::Mismatch(format!(
+                    "source id {id} out of range"
+                )));
+            };
+            *slot = array;
+        }
+        bf.lighting_cache = None;
+        bf.lighting_dirty = true;
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String, PrebakedDumpError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, PrebakedDumpError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Prebaked metadata of the whole board.
+///
+/// Entities are stored by their bits, so a dump only matches the session it
+/// was taken in; `source_tiles` is rebuilt from the lighting on load.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PrebakedMetadataDump {
+    #[serde(default)]
+    pub version: u32,
+    pub map_path: String,
+    pub light_sources: Vec<(u64, (usize, usize, usize))>,
+    pub doors: Vec<u64>,
+    /// Light source ids by entity, sorted by id.
+    pub light_source_ids: Vec<(u64, u32)>,
+}
+
+impl PrebakedMetadataDump {
+    pub fn from_board(bf: &BoardData) -> Self {
+        let metadata = &bf.prebaked_metadata;
+        let mut light_source_ids: Vec<(u64, u32)> = metadata
+            .light_source_ids
+            .iter()
+            .map(|(entity, &id)| (entity.to_bits(), id))
+            .collect();
+        light_source_ids.sort_by_key(|&(bits, id)| (id, bits));
+
+        Self {
+            version: PREBAKED_DUMP_VERSION,
+            map_path: bf.map_path.clone(),
+            light_sources: metadata
+                .light_sources
+                .iter()
+                .map(|(entity, idx)| (entity.to_bits(), *idx))
+                .collect(),
+            doors: metadata
+                .doors
+                .iter()
+                .map(|entity| entity.to_bits())
+                .collect(),
+            light_source_ids,
+        }
+    }
+
+    /// Replaces the board's `PrebakedMetadata` with the dumped one.
+    pub fn restore_into(&self, bf: &mut BoardData) -> Result<(), PrebakedDumpError> {
+        let entity = |bits: u64| {
+            Entity::try_from_bits(bits)
+                .map_err(|e| PrebakedDumpError::Mismatch(format!("entity {bits}: {e}")))
+        };
+        let mut metadata = PrebakedMetadata::default();
+        for &(bits, idx) in &self.light_sources {
+            metadata.light_sources.push((entity(bits)?, idx));
+        }
+        for &bits in &self.doors {
+            metadata.doors.push(entity(bits)?);
+        }
+        for &(bits, id) in &self.light_source_ids {
+            metadata.light_source_ids.insert(entity(bits)?, id);
+        }
+        metadata.index_source_tiles(&bf.prebaked_lighting);
+        bf.prebaked_metadata = metadata;
+        bf.lighting_cache = None;
+        bf.lighting_dirty = true;
+        Ok(())
+    }
+
+    pub fn to_json(&self) -> Result<String, PrebakedDumpError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, PrebakedDumpError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// File name of the dump for floor `z`.
+pub fn floor_dump_file(z: usize) -> String {
+    format!("prebaked_lighting_z{z}.json")
+}
+
+/// File name of the metadata dump.
+pub const METADATA_DUMP_FILE: &str = "prebaked_metadata.json";
+
+/// Default directory for the dumps of the current map.
+pub fn default_dump_dir(bf: &BoardData) -> PathBuf {
+    let map_name = Path::new(&bf.map_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown_map");
+    Path::new("local").join("prebaked_dumps").join(map_name)
+}
+
+/// Writes one dump file per floor and the metadata into `dir`, returning the
+/// written paths.
+pub fn dump_prebaked_lighting(
+    bf: &BoardData,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, PrebakedDumpError> {
+    std::fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for z in 0..bf.map_size.2 {
+        let path = dir.join(floor_dump_file(z));
+        std::fs::write(&path, PrebakedFloorDump::from_board(bf, z).to_json()?)?;
+        written.push(path);
+    }
+    let path = dir.join(METADATA_DUMP_FILE);
+    std::fs::write(&path, PrebakedMetadataDump::from_board(bf).to_json()?)?;
+    written.push(path);
+    Ok(written)
+}
+
+/// Reads the dump of every floor from `dir` back into the board.
+pub fn load_prebaked_lighting(bf: &mut BoardData, dir: &Path) -> Result<(), PrebakedDumpError> {
+    for z in 0..bf.map_size.2 {
+        let json = std::fs::read_to_string(dir.join(floor_dump_file(z)))?;
+        let dump = PrebakedFloorDump::from_json(&json)?;
+        if dump.z != z {
+            return Err(PrebakedDumpError::Mismatch(format!(
+                "file for floor {z} contains floor {}",
+                dump.z
+            )));
+        }
+        dump.restore_into(bf)?;
+    }
+    let json = std::fs::read_to_string(dir.join(METADATA_DUMP_FILE))?;
+    PrebakedMetadataDump::from_json(&json)?.restore_into(bf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::board::boardposition::BoardPosition;
+    use crate::types::board::prebaked_lighting_data::{LightInfo, WaveEdge};
+    use bevy::prelude::*;
+
+    fn lit_board() -> BoardData {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (5, 3, 2);
+        bf.map_path = "assets/maps/test_house.tmx".to_string();
+        bf.prebaked_lighting = Array3::from_elem(bf.map_size, PrebakedLightingData::default());
+        bf.prebaked_lighting[(1, 2, 1)] = PrebakedLightingData {
+            light_info: LightInfo {
+                source_id: Some(1),
+                lux: 3.5,
+                color: (1.0, 0.8, 0.6),
+            },
+            wave_edge: Some(WaveEdge {
+                src_light_lux: 4.0,
+                distance_travelled: 2.0,
+                ..default()
+            }),
+        };
+        let source = Entity::from_raw(7);
+        bf.prebaked_metadata.light_sources = vec![(source, (1, 2, 1))];
+        bf.prebaked_metadata.light_source_ids.insert(source, 1);
+        bf.prebaked_wave_edges = vec![WaveEdgeData {
+            position: BoardPosition { x: 1, y: 2, z: 1 },
+            source_id: 1,
+            lux: 3.5,
+            color: (1.0, 0.8, 0.6),
+            wave_edge: WaveEdge::default(),
+        }];
+        let mut propagation = Array2::from_elem((5, 3), [false; 4]);
+        propagation[(1, 2)] = [true, false, true, false];
+        bf.prebaked_propagation = vec![Array2::from_elem((5, 3), [false; 4]), propagation];
+        bf
+    }
+
+    #[test]
+    fn test_rle_roundtrip() {
+        let items = vec![0, 0, 0, 1, 1, 0, 2];
+        let runs = rle_encode(items.iter().copied());
+        assert_eq!(runs.len(), 4);
+        assert_eq!(rle_decode(&runs), items);
+    }
+
+    #[test]
+    fn test_floor_dump_roundtrip() {
+        let bf = lit_board();
+        let mut restored = BoardData::from_world(&mut World::new());
+        restored.map_size = bf.map_size;
+
+        for z in 0..bf.map_size.2 {
+            let dump = PrebakedFloorDump::from_board(&bf, z);
+            let parsed = PrebakedFloorDump::from_json(&dump.to_json().unwrap()).unwrap();
+            assert_eq!(parsed, dump);
+            parsed.restore_into(&mut restored).unwrap();
+        }
+
+        assert_eq!(restored.prebaked_lighting, bf.prebaked_lighting);
+        assert_eq!(restored.prebaked_wave_edges, bf.prebaked_wave_edges);
+        assert_eq!(restored.prebaked_propagation, bf.prebaked_propagation);
+    }
+
+    #[test]
+    fn test_metadata_dump_roundtrip() {
+        let mut bf = lit_board();
+        bf.prebaked_metadata.doors = vec![Entity::from_raw(9)];
+        bf.prebaked_metadata
+            .index_source_tiles(&bf.prebaked_lighting);
+        let mut restored = BoardData::from_world(&mut World::new());
+        restored.map_size = bf.map_size;
+        restored.prebaked_lighting = bf.prebaked_lighting.clone();
+
+        let dump = PrebakedMetadataDump::from_board(&bf);
+        let parsed = PrebakedMetadataDump::from_json(&dump.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, dump);
+        parsed.restore_into(&mut restored).unwrap();
+
+        let (expected, actual) = (&bf.prebaked_metadata, &restored.prebaked_metadata);
+        assert_eq!(actual.light_sources, expected.light_sources);
+        assert_eq!(actual.doors, expected.doors);
+        assert_eq!(actual.light_source_ids, expected.light_source_ids);
+        assert_eq!(actual.source_tiles, expected.source_tiles);
+    }
+
+    #[test]
+    fn test_dump_rejects_other_map_size() {
+        let bf = lit_board();
+        let dump = PrebakedFloorDump::from_board(&bf, 0);
+        let mut other = BoardData::from_world(&mut World::new());
+        other.map_size = (2, 2, 1);
+        assert!(matches!(
+            dump.restore_into(&mut other),
+            Err(PrebakedDumpError::Mismatch(_))
+        ));
+    }
+}
@@ -6,6 +6,8 @@
 
 use bevy::ecs::entity::Entity;
 use bevy_platform::collections::HashMap;
+use ndarray::Array3;
+use serde::{Deserialize, Serialize};
 
 use crate::components::board::boardposition::BoardPosition;
 
@@ -14,7 +16,7 @@ use crate::components::board::boardposition::BoardPosition;
 /// This structure stores minimal information about light sources and wave edges,
 /// supporting an efficient BFS-based light propagation algorithm that can be
 /// resumed at runtime when dynamic elements (like doors) change state.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PrebakedLightingData {
     /// Base light data for this tile
     pub light_info: LightInfo,
@@ -25,7 +27,7 @@ pub struct PrebakedLightingData {
     pub wave_edge: Option<WaveEdge>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct WaveEdge {
     /// The lux intensity for the corresponding light at the source
     pub src_light_lux: f32,
@@ -40,7 +42,7 @@ pub struct WaveEdge {
 }
 
 /// Represents a wave edge tile with its associated data for light propagation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaveEdgeData {
     /// The position of the wave edge on the board.
     pub position: BoardPosition,
@@ -55,7 +57,7 @@ pub struct WaveEdgeData {
 }
 
 /// Stores the base light information for a tile
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct LightInfo {
     /// The light source ID, or None if not a source
     pub source_id: Option<u32>,
@@ -73,4 +75,25 @@ pub struct PrebakedMetadata {
     pub light_sources: Vec<(Entity, (usize, usize, usize))>,
     pub doors: Vec<Entity>,
     pub light_source_ids: HashMap<Entity, u32>,
+    /// Tiles lit by each source in the prebaked lighting, indexed by source ID,
+    /// so a light rebuild only visits the tiles of the active sources.
+    pub source_tiles: Vec<Vec<(usize, usize, usize)>>,
+}
+
+impl PrebakedMetadata {
+    /// Rebuilds `source_tiles` from the prebaked lighting. Needs to be called
+    /// whenever `prebaked_lighting` is replaced.
+    pub fn index_source_tiles(&mut self, lighting: &Array3<PrebakedLightingData>) {
+        self.source_tiles.clear();
+        for (idx, data) in lighting.indexed_iter() {
+            let Some(source_id) = data.light_info.source_id else {
+                continue;
+            };
+            let source_id = source_id as usize;
+            if self.source_tiles.len() <= source_id {
+                self.source_tiles.resize(source_id + 1, Vec::new());
+            }
+            self.source_tiles[source_id].push(idx);
+        }
+    }
 }
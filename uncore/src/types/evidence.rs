@@ -65,15 +65,115 @@ impl Evidence {
     }
 
     pub fn from_bits(bits: u8) -> HashSet<Evidence> {
-        let mut evidences = HashSet::new();
-        let mut mask = 1;
-        for evidence in all::<Evidence>() {
-            if bits & mask != 0 {
-                evidences.insert(evidence);
-            }
-            mask <<= 1;
+        EvidenceSet::from_bits(bits).into()
+    }
+
+    /// Returns the single bit that represents this evidence inside an `EvidenceSet`.
+    ///
+    /// Bits follow the declaration order of the enum, starting with `FreezingTemp` as bit 0.
+    pub const fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// Compact bitflag representation of a set of evidences.
+///
+/// There are only 8 evidence types, so a whole set fits in a `u8`. This allows
+/// O(1) set operations when comparing ghosts, instead of building and iterating
+/// `HashSet`s.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EvidenceSet(u8);
+
+impl EvidenceSet {
+    pub const EMPTY: EvidenceSet = EvidenceSet(0);
+
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub fn insert(&mut self, evidence: Evidence) {
+        self.0 |= evidence.bit();
+    }
+
+    pub fn remove(&mut self, evidence: Evidence) {
+        self.0 &= !evidence.bit();
+    }
+
+    pub const fn contains(&self, evidence: Evidence) -> bool {
+        self.0 & evidence.bit() != 0
+    }
+
+    pub const fn intersection(&self, other: EvidenceSet) -> EvidenceSet {
+        EvidenceSet(self.0 & other.0)
+    }
+
+    pub const fn union(&self, other: EvidenceSet) -> EvidenceSet {
+        EvidenceSet(self.0 | other.0)
+    }
+
+    pub const fn difference(&self, other: EvidenceSet) -> EvidenceSet {
+        EvidenceSet(self.0 & !other.0)
+    }
+
+    /// True if every evidence in `other` is also in `self`.
+    pub const fn is_superset_of(&self, other: EvidenceSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_subset_of(&self, other: EvidenceSet) -> bool {
+        other.is_superset_of(*self)
+    }
+
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates the evidences in the set, in `Evidence` declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = Evidence> + use<> {
+        let set = *self;
+        all::<Evidence>().filter(move |e| set.contains(*e))
+    }
+}
+
+impl From<Evidence> for EvidenceSet {
+    fn from(evidence: Evidence) -> Self {
+        EvidenceSet(evidence.bit())
+    }
+}
+
+impl FromIterator<Evidence> for EvidenceSet {
+    fn from_iter<I: IntoIterator<Item = Evidence>>(iter: I) -> Self {
+        let mut set = EvidenceSet::EMPTY;
+        for evidence in iter {
+            set.insert(evidence);
         }
-        evidences
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Evidence> for EvidenceSet {
+    fn from_iter<I: IntoIterator<Item = &'a Evidence>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl From<&HashSet<Evidence>> for EvidenceSet {
+    fn from(evidences: &HashSet<Evidence>) -> Self {
+        evidences.iter().collect()
+    }
+}
+
+impl From<EvidenceSet> for HashSet<Evidence> {
+    fn from(set: EvidenceSet) -> Self {
+        set.iter().collect()
     }
 }
 
@@ -1,5 +1,5 @@
-use crate::colours;
-use bevy::{color::palettes::css, prelude::*};
+use crate::colours::UiPalette;
+use bevy::prelude::*;
 
 use super::{evidence::Evidence, truck_button::TruckButtonState};
 
@@ -13,7 +13,11 @@ pub struct EvidenceStatus {
 }
 
 impl EvidenceStatus {
-    pub fn from_gearkind(o_evidence: Option<Evidence>, ev_state: TruckButtonState) -> Self {
+    pub fn from_gearkind(
+        o_evidence: Option<Evidence>,
+        ev_state: TruckButtonState,
+        palette: &UiPalette,
+    ) -> Self {
         let Some(evidence) = o_evidence else {
             return Self {
                 title: "".into(),
@@ -38,9 +42,9 @@ impl EvidenceStatus {
         }
         .into();
         let status_color: Color = match ev_state {
-            TruckButtonState::Off => colours::INVENTORY_STATS_COLOR.with_alpha(1.0),
-            TruckButtonState::Pressed => css::GREEN.with_alpha(1.0).into(),
-            TruckButtonState::Discard => css::RED.with_alpha(0.8).into(),
+            TruckButtonState::Off => palette.evidence_unknown,
+            TruckButtonState::Pressed => palette.evidence_found,
+            TruckButtonState::Discard => palette.evidence_discarded,
         };
         Self {
             title,
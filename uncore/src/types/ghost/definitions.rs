@@ -1,6 +1,20 @@
 use crate::types::ghost::types::GhostType;
+use bevy::log::warn;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
+/// How the ghost of a mission is picked from the difficulty's `GhostSet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GhostSelection {
+    /// Any ghost of the set, at random.
+    #[default]
+    Random,
+    /// Any ghost of the set, but the same seed always picks the same ghost.
+    Seeded(u64),
+    /// Always this ghost, even if it isn't part of the set.
+    Forced(GhostType),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum GhostSet {
     TmpEMF,
@@ -47,6 +61,25 @@ impl GhostSet {
             Self::All => GhostType::all().collect(),
         }
     }
+
+    /// Picks the ghost of a mission. A forced ghost outside of the set is still
+    /// used, with a warning, since it's meant for testing.
+    pub fn select(&self, selection: GhostSelection, rng: &mut impl Rng) -> GhostType {
+        let ghost_types = self.as_vec();
+        match selection {
+            GhostSelection::Random => ghost_types[rng.random_range(0..ghost_types.len())],
+            GhostSelection::Seeded(seed) => {
+                let mut seeded = rand::rngs::SmallRng::seed_from_u64(seed);
+                ghost_types[seeded.random_range(0..ghost_types.len())]
+            }
+            GhostSelection::Forced(ghost) => {
+                if !ghost_types.contains(&ghost) {
+                    warn!("Forced ghost {ghost:?} is not part of the ghost set {self:?}");
+                }
+                ghost
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +90,22 @@ mod tests {
     use bevy_platform::collections::HashSet;
     use enum_iterator::all;
 
+    #[test]
+    fn test_forced_and_seeded_selection() {
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+        for set in [GhostSet::TmpEMF, GhostSet::All] {
+            let ghost = set.select(GhostSelection::Forced(GhostType::Kappa), &mut rng);
+            assert_eq!(ghost, GhostType::Kappa);
+        }
+
+        let seeded = GhostSet::Twenty.select(GhostSelection::Seeded(42), &mut rng);
+        assert!(GhostSet::Twenty.as_vec().contains(&seeded));
+        assert_eq!(
+            GhostSet::Twenty.select(GhostSelection::Seeded(42), &mut rng),
+            seeded
+        );
+    }
+
     #[test]
     fn test_generate_evidence_combinations() {
         for i in 0..256 {
@@ -0,0 +1,95 @@
+//! Helpers to narrow down a ghost from the evidence gathered so far.
+
+use crate::types::evidence::{Evidence, EvidenceSet};
+use crate::types::ghost::types::GhostType;
+
+/// Ghosts from `candidates` that have all the `found` evidence and none of the
+/// `missing` one.
+pub fn matching_ghosts(
+    candidates: &[GhostType],
+    found: EvidenceSet,
+    missing: EvidenceSet,
+) -> Vec<GhostType> {
+    candidates
+        .iter()
+        .copied()
+        .filter(|g| {
+            let set = g.evidence_set();
+            set.is_superset_of(found) && set.intersection(missing).is_empty()
+        })
+        .collect()
+}
+
+/// The evidence that best splits the remaining candidates.
+///
+/// Only evidence in `available` and not yet in `known` is considered. The pick
+/// is the one whose worst outcome (present or absent) leaves the fewest ghosts,
+/// so checking it next halves the list as closely as possible. Returns `None`
+/// when no evidence can tell the candidates apart.
+pub fn most_distinguishing_evidence(
+    candidates: &[GhostType],
+    known: EvidenceSet,
+    available: EvidenceSet,
+) -> Option<Evidence> {
+    if candidates.len() < 2 {
+        return None;
+    }
+    available
+        .difference(known)
+        .iter()
+        .filter_map(|ev| {
+            let with = candidates
+                .iter()
+                .filter(|g| g.evidence_set().contains(ev))
+                .count();
+            let without = candidates.len() - with;
+            (with > 0 && without > 0).then_some((with.max(without), ev))
+        })
+        .min_by_key(|(worst, _)| *worst)
+        .map(|(_, ev)| ev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_evidence() -> EvidenceSet {
+        Evidence::all().collect()
+    }
+
+    #[test]
+    fn test_matching_ghosts_filters_by_evidence() {
+        let ghosts: Vec<GhostType> = GhostType::all().collect();
+        let ghost = ghosts[0];
+        let found = ghost.evidence_set();
+        let missing = all_evidence().difference(found);
+
+        assert_eq!(matching_ghosts(&ghosts, found, missing), vec![ghost]);
+        assert_eq!(
+            matching_ghosts(&ghosts, EvidenceSet::EMPTY, EvidenceSet::EMPTY).len(),
+            ghosts.len()
+        );
+    }
+
+    #[test]
+    fn test_most_distinguishing_evidence_splits_candidates() {
+        let ghosts: Vec<GhostType> = GhostType::all().collect();
+        let ev = most_distinguishing_evidence(&ghosts, EvidenceSet::EMPTY, all_evidence())
+            .expect("the full ghost list can be split");
+        let with = ghosts
+            .iter()
+            .filter(|g| g.evidence_set().contains(ev))
+            .count();
+        assert!(with > 0 && with < ghosts.len());
+
+        // Nothing left to check, or nothing left to split.
+        assert_eq!(
+            most_distinguishing_evidence(&ghosts, all_evidence(), all_evidence()),
+            None
+        );
+        assert_eq!(
+            most_distinguishing_evidence(&ghosts[..1], EvidenceSet::EMPTY, all_evidence()),
+            None
+        );
+    }
+}
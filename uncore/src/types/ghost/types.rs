@@ -1,9 +1,10 @@
 use bevy_platform::collections::HashSet;
 use enum_iterator::{Sequence, all};
+use serde::{Deserialize, Serialize};
 
-use crate::types::evidence::Evidence;
+use crate::types::evidence::{Evidence, EvidenceSet};
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Sequence)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Sequence, Serialize, Deserialize)]
 pub enum GhostType {
     BeanSidhe,
     Dullahan,
@@ -56,6 +57,19 @@ impl GhostType {
         all::<GhostType>()
     }
 
+    /// Finds a ghost by its name, ignoring case, spaces and punctuation, so
+    /// "will o wisp" matches "Will O'Wisp".
+    pub fn from_name(name: &str) -> Option<GhostType> {
+        fn normalise(name: &str) -> String {
+            name.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        }
+        let name = normalise(name);
+        Self::all().find(|ghost| normalise(ghost.name()) == name)
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             GhostType::BeanSidhe => "Bean Sidhe",
@@ -105,8 +119,13 @@ impl GhostType {
         }
     }
 
-    #[rustfmt::skip]
     pub fn evidences(&self) -> HashSet<Evidence> {
+        self.evidence_set().into()
+    }
+
+    /// Evidences of this ghost as a bitflag set, for fast comparisons.
+    #[rustfmt::skip]
+    pub const fn evidence_set(&self) -> EvidenceSet {
         use GhostType::*;
 
         // Order of evidence: (From right to left)
@@ -128,50 +147,50 @@ impl GhostType {
         // * CPM500,       8   1000 0000
         match self {
             // -------------------------------   87654321
-            BeanSidhe => Evidence::from_bits(0b00011111),
-            Dullahan => Evidence::from_bits(0b01101101),
-            Leprechaun => Evidence::from_bits(0b00110111),
-            Barghest => Evidence::from_bits(0b00111011),
-            WillOWisp => Evidence::from_bits(0b00111101),
-            Widow => Evidence::from_bits(0b00111110),
-            HobsTally => Evidence::from_bits(0b01001111),
-            Ghoul => Evidence::from_bits(0b01010111),
-            Afrit => Evidence::from_bits(0b01011011),
-            BaobhanSith => Evidence::from_bits(0b01011101),
-            Ghostlight => Evidence::from_bits(0b01011110),
-            Kappa => Evidence::from_bits(0b11100101),
-            Tengu => Evidence::from_bits(0b01101011),
-            LaLlorona => Evidence::from_bits(0b10111100),
-            Curupira => Evidence::from_bits(0b01101110),
-            Dybbuk => Evidence::from_bits(0b01110011),
-            Phooka => Evidence::from_bits(0b01110101),
-            Aswang => Evidence::from_bits(0b01110110),
-            GreyMan => Evidence::from_bits(0b01111001),
-            LadyInWhite => Evidence::from_bits(0b11110001),
-            Maresca => Evidence::from_bits(0b10001111),
-            Gashadokuro => Evidence::from_bits(0b10010111),
-            Jorogumo => Evidence::from_bits(0b10011011),
-            Namahage => Evidence::from_bits(0b10011101),
-            Tsuchinoko => Evidence::from_bits(0b10011110),
-            Obayifo => Evidence::from_bits(0b10100111),
-            Brume => Evidence::from_bits(0b10101110),
-            Bugbear => Evidence::from_bits(0b10101101),
-            Boggart => Evidence::from_bits(0b10110011),
-            GreyLady => Evidence::from_bits(0b10110101),
-            OldNan => Evidence::from_bits(0b10110110),
-            BrownLady => Evidence::from_bits(0b11111000),
-            Morag => Evidence::from_bits(0b10111010),
-            Fionnuala => Evidence::from_bits(0b11000111),
-            Ailill => Evidence::from_bits(0b11001101),
-            Cairbre => Evidence::from_bits(0b11010011),
-            Oonagh => Evidence::from_bits(0b11010110),
-            Mider => Evidence::from_bits(0b11011010),
-            Orla => Evidence::from_bits(0b11100011),
-            Finvarra => Evidence::from_bits(0b11100110),
-            Caoilte => Evidence::from_bits(0b11101010),
-            Ceara => Evidence::from_bits(0b11101100),
-            Muirgheas => Evidence::from_bits(0b11110010),
-            Domovoy => Evidence::from_bits(0b11110100),
+            BeanSidhe => EvidenceSet::from_bits(0b00011111),
+            Dullahan => EvidenceSet::from_bits(0b01101101),
+            Leprechaun => EvidenceSet::from_bits(0b00110111),
+            Barghest => EvidenceSet::from_bits(0b00111011),
+            WillOWisp => EvidenceSet::from_bits(0b00111101),
+            Widow => EvidenceSet::from_bits(0b00111110),
+            HobsTally => EvidenceSet::from_bits(0b01001111),
+            Ghoul => EvidenceSet::from_bits(0b01010111),
+            Afrit => EvidenceSet::from_bits(0b01011011),
+            BaobhanSith => EvidenceSet::from_bits(0b01011101),
+            Ghostlight => EvidenceSet::from_bits(0b01011110),
+            Kappa => EvidenceSet::from_bits(0b11100101),
+            Tengu => EvidenceSet::from_bits(0b01101011),
+            LaLlorona => EvidenceSet::from_bits(0b10111100),
+            Curupira => EvidenceSet::from_bits(0b01101110),
+            Dybbuk => EvidenceSet::from_bits(0b01110011),
+            Phooka => EvidenceSet::from_bits(0b01110101),
+            Aswang => EvidenceSet::from_bits(0b01110110),
+            GreyMan => EvidenceSet::from_bits(0b01111001),
+            LadyInWhite => EvidenceSet::from_bits(0b11110001),
+            Maresca => EvidenceSet::from_bits(0b10001111),
+            Gashadokuro => EvidenceSet::from_bits(0b10010111),
+            Jorogumo => EvidenceSet::from_bits(0b10011011),
+            Namahage => EvidenceSet::from_bits(0b10011101),
+            Tsuchinoko => EvidenceSet::from_bits(0b10011110),
+            Obayifo => EvidenceSet::from_bits(0b10100111),
+            Brume => EvidenceSet::from_bits(0b10101110),
+            Bugbear => EvidenceSet::from_bits(0b10101101),
+            Boggart => EvidenceSet::from_bits(0b10110011),
+            GreyLady => EvidenceSet::from_bits(0b10110101),
+            OldNan => EvidenceSet::from_bits(0b10110110),
+            BrownLady => EvidenceSet::from_bits(0b11111000),
+            Morag => EvidenceSet::from_bits(0b10111010),
+            Fionnuala => EvidenceSet::from_bits(0b11000111),
+            Ailill => EvidenceSet::from_bits(0b11001101),
+            Cairbre => EvidenceSet::from_bits(0b11010011),
+            Oonagh => EvidenceSet::from_bits(0b11010110),
+            Mider => EvidenceSet::from_bits(0b11011010),
+            Orla => EvidenceSet::from_bits(0b11100011),
+            Finvarra => EvidenceSet::from_bits(0b11100110),
+            Caoilte => EvidenceSet::from_bits(0b11101010),
+            Ceara => EvidenceSet::from_bits(0b11101100),
+            Muirgheas => EvidenceSet::from_bits(0b11110010),
+            Domovoy => EvidenceSet::from_bits(0b11110100),
         }
     }
 }
@@ -181,3 +200,58 @@ impl std::fmt::Display for GhostType {
         write!(f, "{}", self.name())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evidence_set_matches_evidences() {
+        use Evidence::*;
+        let expected = [
+            (
+                GhostType::BeanSidhe,
+                vec![
+                    FreezingTemp,
+                    FloatingOrbs,
+                    UVEctoplasm,
+                    EMFLevel5,
+                    EVPRecording,
+                ],
+            ),
+            (
+                GhostType::Dullahan,
+                vec![FreezingTemp, UVEctoplasm, EMFLevel5, SpiritBox, RLPresence],
+            ),
+            (
+                GhostType::Kappa,
+                vec![FreezingTemp, UVEctoplasm, SpiritBox, RLPresence, CPM500],
+            ),
+            (
+                GhostType::LaLlorona,
+                vec![UVEctoplasm, EMFLevel5, EVPRecording, SpiritBox, CPM500],
+            ),
+        ];
+        for (ghost, evidences) in expected {
+            let evidences: HashSet<Evidence> = evidences.into_iter().collect();
+            assert_eq!(ghost.evidences(), evidences, "Mismatch for {:?}", ghost);
+            assert_eq!(ghost.evidence_set(), EvidenceSet::from(&evidences));
+        }
+        for ghost in GhostType::all() {
+            assert_eq!(ghost.evidence_set().len(), 5, "Wrong count for {:?}", ghost);
+        }
+    }
+
+    #[test]
+    fn test_evidence_set_operations() {
+        let a: EvidenceSet = [Evidence::FreezingTemp, Evidence::EMFLevel5]
+            .iter()
+            .collect();
+        let b: EvidenceSet = [Evidence::EMFLevel5, Evidence::CPM500].iter().collect();
+        assert_eq!(a.intersection(b), EvidenceSet::from(Evidence::EMFLevel5));
+        assert_eq!(a.union(b).len(), 3);
+        assert!(a.union(b).is_superset_of(a));
+        assert!(!a.is_superset_of(b));
+        assert!(a.is_superset_of(EvidenceSet::EMPTY));
+    }
+}
@@ -2,9 +2,11 @@ use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::fmt;
 
-/// Represents mission performance grades, ordered from highest (A) to lowest (N/A)
+/// Represents mission performance grades, ordered from highest (S) to lowest (N/A)
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Grade {
+    /// Outstanding performance, well above the A threshold
+    S,
     /// Excellent performance
     A,
     /// Very good performance
@@ -24,6 +26,7 @@ impl Grade {
     /// Returns the multiplier associated with each grade
     pub fn multiplier(&self) -> f64 {
         match self {
+            Grade::S => 8.0,
             Grade::A => 5.0,
             Grade::B => 3.0,
             Grade::C => 2.0,
@@ -34,8 +37,11 @@ impl Grade {
     }
 
     /// Returns the index in the badge spritesheet for this grade
+    ///
+    /// S shares the A badge until the spritesheet has a dedicated one.
     pub fn badge_index(&self) -> usize {
         match self {
+            Grade::S => 0,
             Grade::A => 0,
             Grade::B => 1,
             Grade::C => 2,
@@ -50,6 +56,7 @@ impl Grade {
         use bevy::prelude::Color;
 
         match self {
+            Grade::S => Color::srgb(1.0, 0.85, 0.3), // Gold
             Grade::A => Color::srgb(0.0, 0.8, 0.0),  // Green
             Grade::B => Color::srgb(0.5, 0.8, 0.0),  // Light green
             Grade::C => Color::srgb(1.0, 0.8, 0.0),  // Yellow
@@ -62,6 +69,7 @@ impl Grade {
     /// Returns a descriptive text for this grade
     pub fn description(&self) -> &'static str {
         match self {
+            Grade::S => "Outstanding",
             Grade::A => "Excellent",
             Grade::B => "Very Good",
             Grade::C => "Good",
@@ -93,6 +101,59 @@ impl Grade {
     }
 }
 
+/// Base score needed for each grade on a mission.
+///
+/// These are expressed without the difficulty multiplier; `scaled` applies it so
+/// they can be compared against a full score. The S threshold is not configured
+/// per map, it is always `S_FACTOR` times the A threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GradeThresholds {
+    pub a: i64,
+    pub b: i64,
+    pub c: i64,
+    pub d: i64,
+}
+
+impl Default for GradeThresholds {
+    fn default() -> Self {
+        Self {
+            a: 1000,
+            b: 500,
+            c: 250,
+            d: 125,
+        }
+    }
+}
+
+impl GradeThresholds {
+    /// How many times the A threshold must be reached to earn an S.
+    pub const S_FACTOR: f64 = 2.0;
+
+    pub fn s(&self) -> i64 {
+        (self.a as f64 * Self::S_FACTOR).round() as i64
+    }
+
+    /// Returns the thresholds multiplied by the difficulty score multiplier.
+    pub fn scaled(&self, multiplier: f64) -> Self {
+        let scale = |t: i64| (t as f64 * multiplier).round() as i64;
+        Self {
+            a: scale(self.a),
+            b: scale(self.b),
+            c: scale(self.c),
+            d: scale(self.d),
+        }
+    }
+
+    /// Maps a score to a grade. Scores below D get an F.
+    pub fn grade_for(&self, score: i64) -> Grade {
+        if score >= self.s() {
+            Grade::S
+        } else {
+            Grade::from_score(score, self.a, self.b, self.c, self.d)
+        }
+    }
+}
+
 impl PartialOrd for Grade {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -114,6 +175,7 @@ impl Ord for Grade {
 impl fmt::Display for Grade {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Grade::S => write!(f, "S"),
             Grade::A => write!(f, "A"),
             Grade::B => write!(f, "B"),
             Grade::C => write!(f, "C"),
@@ -127,6 +189,7 @@ impl fmt::Display for Grade {
 impl From<&str> for Grade {
     fn from(s: &str) -> Self {
         match s {
+            "S" => Grade::S,
             "A" => Grade::A,
             "B" => Grade::B,
             "C" => Grade::C,
@@ -3,6 +3,16 @@ use bevy::{
     time::{Timer, TimerMode},
 };
 
+/// Largest frame delta simulation systems step by. A longer frame (alt-tab,
+/// a load stall) is simulated as this much time, so timers, glitches and
+/// integrated readings advance smoothly instead of jumping.
+pub const MAX_SIMULATION_DELTA_SECS: f32 = 0.1;
+
+/// Frame delta capped to `MAX_SIMULATION_DELTA_SECS`.
+pub fn clamped_delta_secs(delta_secs: f32) -> f32 {
+    delta_secs.clamp(0.0, MAX_SIMULATION_DELTA_SECS)
+}
+
 pub fn format_time(time_in_seconds: f32) -> String {
     let minutes = (time_in_seconds / 60.0).floor() as u32;
     let seconds = (time_in_seconds % 60.0).floor() as u32;
@@ -20,3 +30,15 @@ impl Default for PrintingTimer {
         Self(Timer::from_seconds(60.0, TimerMode::Repeating))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamped_delta_caps_hitches() {
+        assert_eq!(clamped_delta_secs(1.0 / 60.0), 1.0 / 60.0);
+        assert_eq!(clamped_delta_secs(2.0), MAX_SIMULATION_DELTA_SECS);
+        assert_eq!(clamped_delta_secs(-1.0), 0.0);
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use uncore::resources::ui_theme::{UiColourRole, UiFontRole};
 
 /// A component that marks an entity as a menu item that can be interacted with
 #[derive(Component, Debug)]
@@ -45,3 +46,26 @@ pub struct MenuStrip;
 /// A component that marks an entity as the help text for a menu
 #[derive(Component, Debug)]
 pub struct MenuHelpText;
+
+/// Text whose font (and optionally colour) comes from the active `UiTheme`.
+///
+/// Added by the menu templates; `apply_ui_theme` fills in the values when the
+/// text is spawned and again whenever the theme changes.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ThemedText {
+    pub font: UiFontRole,
+    pub colour: Option<UiColourRole>,
+}
+
+impl ThemedText {
+    pub fn new(font: UiFontRole) -> Self {
+        Self { font, colour: None }
+    }
+
+    pub fn with_colour(font: UiFontRole, colour: UiColourRole) -> Self {
+        Self {
+            font,
+            colour: Some(colour),
+        }
+    }
+}
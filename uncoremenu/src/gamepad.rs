@@ -0,0 +1,182 @@
+use crate::components::MenuRoot;
+use crate::systems::{GRACE_PERIOD_SECS, menu_keyboard_system};
+use bevy::prelude::*;
+
+/// Stick deflection below which the stick counts as centred, so a drifting
+/// stick doesn't move the selection on its own.
+pub const STICK_DEADZONE: f32 = 0.5;
+
+/// How long the stick has to be held before the selection starts repeating.
+const STICK_REPEAT_DELAY_SECS: f32 = 0.4;
+
+/// Time between repeated steps while the stick is held.
+const STICK_REPEAT_SECS: f32 = 0.12;
+
+/// Menu input read from a gamepad, handled by the menu keyboard system as if
+/// the matching key had been pressed.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamepadMenuInput {
+    /// Moves the selection like the given navigation key.
+    Navigate(KeyCode),
+    /// Activates the selected item, like Enter.
+    Activate,
+    /// Goes back, like Escape.
+    Back,
+    /// The activate button was let go, for buttons that act while held.
+    Release,
+}
+
+/// Marks a UI root that isn't a `MenuRoot` but takes `GamepadMenuInput` while
+/// it is visible, like the truck.
+#[derive(Component, Debug, Default)]
+pub struct GamepadMenuRoot;
+
+/// Turns a held stick into selection steps: one step when it leaves the
+/// deadzone, then repeated steps while it stays there.
+#[derive(Debug, Default)]
+struct StickRepeat {
+    /// -1 down, 1 up, 0 centred.
+    direction: i8,
+    held_for: f32,
+    next_repeat: f32,
+}
+
+impl StickRepeat {
+    /// Returns the direction to step in this frame, if any.
+    fn update(&mut self, stick_y: f32, delta_secs: f32) -> Option<i8> {
+        let direction = if stick_y > STICK_DEADZONE {
+            1
+        } else if stick_y < -STICK_DEADZONE {
+            -1
+        } else {
+            0
+        };
+        if direction != self.direction {
+            self.direction = direction;
+            self.held_for = 0.0;
+            self.next_repeat = STICK_REPEAT_DELAY_SECS;
+            return (direction != 0).then_some(direction);
+        }
+        if direction == 0 {
+            return None;
+        }
+        self.held_for += delta_secs;
+        if self.held_for < self.next_repeat {
+            return None;
+        }
+        self.next_repeat += STICK_REPEAT_SECS;
+        Some(direction)
+    }
+}
+
+/// Reads every connected gamepad and sends the matching `GamepadMenuInput`
+/// while a menu or a `GamepadMenuRoot` is shown.
+///
+/// D-pad and left stick move the selection, the bumpers page through it, South
+/// (A) activates and East (B) goes back.
+fn gamepad_menu_system(
+    time: Res<Time>,
+    gamepads: Query<&Gamepad>,
+    menu_query: Query<
+        (Entity, Option<&InheritedVisibility>),
+        Or<(With<MenuRoot>, With<GamepadMenuRoot>)>,
+    >,
+    mut menu_inputs: EventWriter<GamepadMenuInput>,
+    mut stick: Local<StickRepeat>,
+    mut opened: Local<Option<(Entity, f32)>>,
+) {
+    let Some(menu) = menu_query
+        .iter()
+        .find(|(_, visibility)| visibility.is_none_or(|v| v.get()))
+        .map(|(entity, _)| entity)
+    else {
+        *opened = None;
+        return;
+    };
+    let now = time.elapsed_secs();
+    let opened_at = match *opened {
+        Some((entity, at)) if entity == menu => at,
+        _ => {
+            *opened = Some((menu, now));
+            now
+        }
+    };
+
+    let stick_y = gamepads
+        .iter()
+        .map(|gamepad| gamepad.left_stick().y)
+        .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+        .unwrap_or_default();
+    let stick_step = stick.update(stick_y, time.delta_secs());
+
+    // Ignore input that happened too soon after the menu opened
+    if now - opened_at < GRACE_PERIOD_SECS {
+        return;
+    }
+
+    const BUTTONS: [(GamepadButton, GamepadMenuInput); 6] = [
+        (
+            GamepadButton::DPadUp,
+            GamepadMenuInput::Navigate(KeyCode::ArrowUp),
+        ),
+        (
+            GamepadButton::DPadDown,
+            GamepadMenuInput::Navigate(KeyCode::ArrowDown),
+        ),
+        (
+            GamepadButton::LeftTrigger,
+            GamepadMenuInput::Navigate(KeyCode::PageUp),
+        ),
+        (
+            GamepadButton::RightTrigger,
+            GamepadMenuInput::Navigate(KeyCode::PageDown),
+        ),
+        (GamepadButton::South, GamepadMenuInput::Activate),
+        (GamepadButton::East, GamepadMenuInput::Back),
+    ];
+    for gamepad in &gamepads {
+        for (button, input) in BUTTONS {
+            if gamepad.just_pressed(button) {
+                menu_inputs.write(input);
+            }
+        }
+        if gamepad.just_released(GamepadButton::South) {
+            menu_inputs.write(GamepadMenuInput::Release);
+        }
+    }
+    match stick_step {
+        Some(1) => {
+            menu_inputs.write(GamepadMenuInput::Navigate(KeyCode::ArrowUp));
+        }
+        Some(-1) => {
+            menu_inputs.write(GamepadMenuInput::Navigate(KeyCode::ArrowDown));
+        }
+        _ => {}
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.add_event::<GamepadMenuInput>()
+        .add_systems(Update, gamepad_menu_system.before(menu_keyboard_system));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stick_drift_does_not_step() {
+        let mut stick = StickRepeat::default();
+        for _ in 0..100 {
+            assert_eq!(stick.update(0.3, 0.016), None);
+        }
+
+        // Pushing it steps once, then repeats after the delay.
+        assert_eq!(stick.update(-0.9, 0.016), Some(-1));
+        assert_eq!(stick.update(-0.9, 0.2), None);
+        assert_eq!(stick.update(-0.9, 0.2), Some(-1));
+        assert_eq!(stick.update(-0.9, 0.15), Some(-1));
+        assert_eq!(stick.update(0.1, 0.016), None);
+        assert_eq!(stick.update(0.9, 0.016), Some(1));
+    }
+}
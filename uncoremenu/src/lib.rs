@@ -1,5 +1,6 @@
 pub mod components;
 pub mod events;
+pub mod gamepad;
 pub mod plugin;
 pub mod scrollbar;
 pub mod systems;
@@ -15,5 +15,6 @@ impl Plugin for UnhaunterCoreMenuPlugin {
 
         crate::systems::app_setup(app);
         crate::scrollbar::app_setup(app);
+        crate::gamepad::app_setup(app);
     }
 }
@@ -3,6 +3,7 @@ use bevy::picking::hover::HoverMap;
 use bevy::prelude::*;
 use bevy::ui::ComputedNode;
 use bevy::ui::ScrollPosition;
+use uncore::resources::ui_theme::{UiColourRole, UiTheme};
 use uncore::types::root::game_assets::GameAssets;
 
 use crate::components::MenuItemInteractive;
@@ -134,8 +135,34 @@ fn update_scroll_position(
     }
 }
 
-/// Updates the scrollbar thumb position and visual state based on the current scroll position
+/// Where the scrollbar thumb goes, as fractions of the track: `(top, height)`.
+/// Returns `None` when the content fits in the view.
+pub fn scroll_thumb(view_height: f32, content_height: f32, offset: f32) -> Option<(f32, f32)> {
+    if view_height <= 0.0 || content_height <= view_height + 0.5 {
+        return None;
+    }
+    let height = view_height / content_height;
+    let max_offset = content_height - view_height;
+    let top = (offset / max_offset).clamp(0.0, 1.0) * (1.0 - height);
+    Some((top, height))
+}
+
+/// Colour of a scrollbar arrow for its state.
+fn arrow_colour(theme: &UiTheme, disabled: bool, interaction: Interaction) -> Color {
+    if disabled {
+        return theme.colour(UiColourRole::Disabled).with_alpha(0.15);
+    }
+    match interaction {
+        Interaction::Pressed => theme.colour(UiColourRole::ItemOn),
+        Interaction::Hovered => theme.colour(UiColourRole::ItemOn).with_alpha(0.8),
+        Interaction::None => theme.colour(UiColourRole::ItemOff),
+    }
+}
+
+/// Updates the scrollbar thumb position and visual state based on the current
+/// scroll position, hiding the scrollbar when there is nothing to scroll.
 fn update_scrollbar(
+    theme: Res<UiTheme>,
     // Query container node, scroll position, and its children
     scroll_container_query: Query<
         (&ScrollPosition, &ComputedNode, &Children),
@@ -143,7 +170,7 @@ fn update_scrollbar(
     >,
     // Query the computed nodes of the items within the container
     item_node_query: Query<&ComputedNode>,
-    track_query: Query<&ComputedNode, With<ScrollbarTrack>>,
+    mut scrollbar_query: Query<&mut Visibility, With<ScrollbarContainer>>,
     mut thumb_query: Query<(&mut Node, &Children), With<ScrollbarThumb>>,
     mut thumb_image_query: Query<&mut ImageNode>,
     arrow_up_query: Query<
@@ -156,112 +183,64 @@ fn update_scrollbar(
     >,
 ) {
     // Get container info and children list
-    if let Ok((scroll_position, container_node, children)) = scroll_container_query.single() {
-        let scroll_y = scroll_position.offset_y;
-        let container_height = container_node.size().y;
-
-        // Calculate actual content height by summing children heights
-        let mut content_height = 0.0;
-        for child_entity in children.iter() {
-            // Get the ComputedNode for each child item
-            if let Ok(child_node) = item_node_query.get(child_entity) {
-                // Add the child's height to the total content height
-                content_height += child_node.size().y + 2.0;
-                // Note: This assumes no vertical margin/padding between items.
-                // If items have margins, this calculation might need adjustment.
-            }
+    let Ok((scroll_position, container_node, children)) = scroll_container_query.single() else {
+        return;
+    };
+    // Sizes are in physical pixels, the scroll offset in logical ones.
+    let scale = container_node.inverse_scale_factor();
+    let scroll_y = scroll_position.offset_y;
+    let container_height = container_node.size().y * scale;
+
+    // Calculate actual content height by summing children heights
+    let mut content_height = 0.0;
+    for child_entity in children.iter() {
+        if let Ok(child_node) = item_node_query.get(child_entity) {
+            content_height += child_node.size().y * scale + 2.0;
         }
+    }
 
-        // Determine if scrolling is possible
-        let has_scrollable_content = content_height > container_height;
-
-        // Update up/down arrow colours based on scroll position
-        if let Ok((children, interaction)) = arrow_up_query.single()
-            && let Some(child) = children.first()
-            && let Ok(mut image) = thumb_image_query.get_mut(*child)
-        {
-            // Set transparency/colour for up arrow
-            let at_top = scroll_y <= 0.1;
-            let is_disabled = at_top || !has_scrollable_content;
-
-            // If disabled, use a very transparent grey colour regardless of interaction
-            image.color = if is_disabled {
-                Color::srgba(0.5, 0.5, 0.5, 0.15) // Much more transparent and grey when disabled
-            } else {
-                // Normal colouring based on interaction state
-                match *interaction {
-                    Interaction::Pressed => uncore::colours::MENU_ITEM_COLOR_ON,
-                    Interaction::Hovered => uncore::colours::MENU_ITEM_COLOR_ON.with_alpha(0.8),
-                    Interaction::None => Color::WHITE,
-                }
-            };
+    let thumb = scroll_thumb(container_height, content_height, scroll_y);
+    let has_scrollable_content = thumb.is_some();
+
+    let target_visibility = if has_scrollable_content {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut visibility in &mut scrollbar_query {
+        if *visibility != target_visibility {
+            *visibility = target_visibility;
         }
+    }
+    let Some((thumb_top, thumb_height)) = thumb else {
+        return;
+    };
 
-        if let Ok((children, interaction)) = arrow_down_query.single()
-            && let Some(child) = children.first()
+    // Update up/down arrow colours based on scroll position
+    if let Ok((children, interaction)) = arrow_up_query.single()
+        && let Some(child) = children.first()
+        && let Ok(mut image) = thumb_image_query.get_mut(*child)
+    {
+        let at_top = scroll_y <= 0.1;
+        image.color = arrow_colour(&theme, at_top, *interaction);
+    }
+
+    if let Ok((children, interaction)) = arrow_down_query.single()
+        && let Some(child) = children.first()
+        && let Ok(mut image) = thumb_image_query.get_mut(*child)
+    {
+        let at_bottom = scroll_y >= (content_height - container_height - 0.1).max(0.0);
+        image.color = arrow_colour(&theme, at_bottom, *interaction);
+    }
+
+    // The thumb covers the part of the content in view.
+    if let Ok((mut thumb_node, children)) = thumb_query.single_mut() {
+        thumb_node.top = Val::Percent(thumb_top * 100.0);
+        thumb_node.height = Val::Percent(thumb_height * 100.0);
+        if let Some(child) = children.first()
             && let Ok(mut image) = thumb_image_query.get_mut(*child)
         {
-            // Set transparency/colour for down arrow
-            // Use the calculated content_height to determine if at the bottom
-            let at_bottom = scroll_y >= (content_height - container_height - 0.1).max(0.0);
-            let is_disabled = at_bottom || !has_scrollable_content;
-
-            // If disabled, use a very transparent grey colour regardless of interaction
-            image.color = if is_disabled {
-                Color::srgba(0.5, 0.5, 0.5, 0.15) // Much more transparent and grey when disabled
-            } else {
-                // Normal colouring based on interaction state
-                match *interaction {
-                    Interaction::Pressed => uncore::colours::MENU_ITEM_COLOR_ON,
-                    Interaction::Hovered => uncore::colours::MENU_ITEM_COLOR_ON.with_alpha(0.8),
-                    Interaction::None => Color::WHITE,
-                }
-            };
-        }
-
-        // Update thumb position and visibility
-        if let Ok((mut thumb_node, children)) = thumb_query.single_mut() {
-            // If we have a track, calculate the thumb position
-            if let Ok(track_node) = track_query.single() {
-                let track_height = track_node.size().y;
-
-                if has_scrollable_content {
-                    // Fixed thumb height - don't dynamically resize the thumb
-                    // Instead, adjust the visible track area that the thumb can move in
-
-                    // Get the actual thumb height from the node
-                    let thumb_height = 150.0;
-
-                    // Calculate scroll percentage using the actual content_height
-                    let max_scroll = content_height - container_height; // Use actual content height
-                    let scroll_percent = if max_scroll > 0.0 {
-                        (scroll_y / max_scroll).clamp(0.0, 1.0)
-                    } else {
-                        0.0
-                    };
-
-                    // Calculate thumb position - keep the thumb within the visible track area
-                    let available_track = track_height - thumb_height;
-                    let thumb_position = scroll_percent * available_track;
-
-                    // Update thumb position
-                    thumb_node.top = Val::Px(thumb_position);
-
-                    // Make thumb visible
-                    if let Some(child) = children.first()
-                        && let Ok(mut image) = thumb_image_query.get_mut(*child)
-                    {
-                        image.color = Color::WHITE.with_alpha(0.2);
-                    }
-                } else {
-                    // If there's no scrollable content, hide the thumb
-                    if let Some(child) = children.first()
-                        && let Ok(mut image) = thumb_image_query.get_mut(*child)
-                    {
-                        image.color = Color::srgba(1.0, 1.0, 1.0, 0.0);
-                    }
-                }
-            }
+            image.color = theme.colour(UiColourRole::ItemOn).with_alpha(0.6);
         }
     }
 }
@@ -349,6 +328,7 @@ pub fn build_scrollbar_ui(scrollbar: &mut ChildSpawnerCommands, handles: &GameAs
             ..default()
         })
         .insert(ScrollbarContainer)
+        .insert(Visibility::Hidden)
         .with_children(|scrollbar| {
             // Up arrow
             scrollbar
@@ -418,11 +398,17 @@ pub fn build_scrollbar_ui(scrollbar: &mut ChildSpawnerCommands, handles: &GameAs
                         })
                         .insert(Interaction::default())
                         .with_children(|thumb| {
-                            thumb.spawn(ImageNode {
-                                image: handles.images.scroll_thumb.clone(),
-                                color: Color::srgba(0.6, 0.6, 0.6, 0.4),
-                                ..default()
-                            });
+                            thumb
+                                .spawn(ImageNode {
+                                    image: handles.images.scroll_thumb.clone(),
+                                    color: Color::srgba(0.6, 0.6, 0.6, 0.4),
+                                    ..default()
+                                })
+                                .insert(Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                });
                         });
                 });
 
@@ -448,6 +434,42 @@ pub fn build_scrollbar_ui(scrollbar: &mut ChildSpawnerCommands, handles: &GameAs
         });
 }
 
+/// Spawns a vertical list that scrolls, with the scrollbar next to it.
+///
+/// `build_list` adds the items of the list. The list is marked with
+/// `ScrollableListContainer`, so the scrollbar follows it.
+pub fn build_scrollable_list(
+    parent: &mut ChildSpawnerCommands,
+    handles: &GameAssets,
+    build_list: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Stretch,
+            ..default()
+        })
+        .with_children(|list_and_scrollbar| {
+            list_and_scrollbar
+                .spawn(Node {
+                    flex_grow: 1.0,
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    justify_content: JustifyContent::FlexStart,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .insert(ScrollableListContainer)
+                .insert(ScrollPosition::default())
+                .with_children(build_list);
+
+            build_scrollbar_ui(list_and_scrollbar, handles);
+        });
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(
         Update,
@@ -459,3 +481,21 @@ pub(crate) fn app_setup(app: &mut App) {
         ),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_thumb_follows_offset() {
+        assert_eq!(scroll_thumb(300.0, 300.0, 0.0), None);
+        assert_eq!(scroll_thumb(300.0, 200.0, 0.0), None);
+
+        let (top, height) = scroll_thumb(300.0, 1200.0, 0.0).unwrap();
+        assert_eq!((top, height), (0.0, 0.25));
+        let (top, _) = scroll_thumb(300.0, 1200.0, 450.0).unwrap();
+        assert!((top - 0.375).abs() < 1e-6);
+        let (top, height) = scroll_thumb(300.0, 1200.0, 2000.0).unwrap();
+        assert!((top + height - 1.0).abs() < 1e-6);
+    }
+}
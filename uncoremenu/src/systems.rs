@@ -1,9 +1,21 @@
-use crate::components::{MenuItemInteractive, MenuMouseTracker, MenuRoot, PrincipalMenuText};
+use crate::components::{
+    MenuItemInteractive, MenuMouseTracker, MenuRoot, PrincipalMenuText, ThemedText,
+};
 use crate::events::KeyboardNavigate;
-use bevy::{input::mouse::MouseMotion, prelude::*};
-use uncore::colours;
+use crate::gamepad::GamepadMenuInput;
+use crate::scrollbar::ScrollableListContainer;
+use bevy::{
+    input::mouse::MouseMotion,
+    prelude::*,
+    ui::{ComputedNode, UiSystem},
+};
+use uncore::resources::ui_theme::UiTheme;
 use uncore::states::AppState;
 
+/// Menu input is ignored for this long after a menu opens, so the key or
+/// button press that opened it isn't taken as a press inside it.
+pub const GRACE_PERIOD_SECS: f32 = 0.1;
+
 /// Event sent when a menu item is clicked
 #[derive(Event, Debug, Clone, Copy)]
 pub struct MenuItemClicked {
@@ -73,18 +85,53 @@ fn menu_interaction_system(
     }
 }
 
+/// Items moved by PageUp/PageDown when the list can't be measured yet.
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// Selection after pressing a navigation key, or `None` if the key doesn't
+/// move it. Up/Down wrap around; PageUp/PageDown and Home/End stop at the
+/// ends of the list, the last item being "Go Back" where there is one.
+fn navigate(key: KeyCode, current: usize, item_count: usize, page_size: usize) -> Option<usize> {
+    let last = item_count.checked_sub(1)?;
+    let page_size = page_size.max(1);
+    match key {
+        KeyCode::ArrowUp => Some(if current == 0 { last } else { current - 1 }),
+        KeyCode::ArrowDown => Some((current + 1) % item_count),
+        KeyCode::PageUp => Some(current.saturating_sub(page_size).min(last)),
+        KeyCode::PageDown => Some((current + page_size).min(last)),
+        KeyCode::Home => Some(0),
+        KeyCode::End => Some(last),
+        _ => None,
+    }
+}
+
+/// How many items fit in the scrollable list, used as the page size.
+fn visible_item_count(list_height: Option<f32>, item_heights: impl Iterator<Item = f32>) -> usize {
+    let (total, count) = item_heights
+        .filter(|h| *h > 0.0)
+        .fold((0.0, 0), |(total, count), h| (total + h, count + 1));
+    match list_height {
+        Some(list_height) if count > 0 => ((list_height * count as f32 / total) as usize).max(1),
+        _ => DEFAULT_PAGE_SIZE,
+    }
+}
+
 /// Handles keyboard navigation for menu items, including up/down arrows,
-/// enter for selection, and escape key events.
-fn menu_keyboard_system(
+/// page up/down, home/end, enter for selection, and escape key events.
+/// Gamepad input arrives as `GamepadMenuInput` and is handled the same way.
+pub(crate) fn menu_keyboard_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut gamepad_inputs: EventReader<GamepadMenuInput>,
     mut menu_query: Query<&mut MenuRoot>,
-    menu_items: Query<&MenuItemInteractive>,
+    menu_items: Query<Option<&ComputedNode>, With<MenuItemInteractive>>,
+    lists: Query<&ComputedNode, With<ScrollableListContainer>>,
     mut selection_events: EventWriter<MenuItemSelected>,
     mut keyboard_nav_events: EventWriter<KeyboardNavigate>,
     mut click_events: EventWriter<MenuItemClicked>,
     mut escape_events: EventWriter<MenuEscapeEvent>,
     app_state: Res<State<AppState>>,
 ) {
+    let gamepad_inputs: Vec<_> = gamepad_inputs.read().copied().collect();
     let Ok(mut menu) = menu_query.single_mut() else {
         return;
     };
@@ -96,17 +143,28 @@ fn menu_keyboard_system(
         return;
     };
 
-    // Handle up/down navigation
-    let mut new_selection = None;
-    if keyboard_input.just_pressed(KeyCode::ArrowUp) {
-        new_selection = Some(if menu.selected_item == 0 {
-            item_count - 1
-        } else {
-            menu.selected_item - 1
-        });
-    } else if keyboard_input.just_pressed(KeyCode::ArrowDown) {
-        new_selection = Some((menu.selected_item + 1) % item_count);
-    }
+    // Handle navigation keys
+    let page_size = visible_item_count(
+        lists.single().ok().map(|list| list.size().y),
+        menu_items.iter().flatten().map(|item| item.size().y),
+    );
+    let new_selection = [
+        KeyCode::ArrowUp,
+        KeyCode::ArrowDown,
+        KeyCode::PageUp,
+        KeyCode::PageDown,
+        KeyCode::Home,
+        KeyCode::End,
+    ]
+    .into_iter()
+    .find(|key| keyboard_input.just_pressed(*key))
+    .or_else(|| {
+        gamepad_inputs.iter().find_map(|input| match input {
+            GamepadMenuInput::Navigate(key) => Some(*key),
+            _ => None,
+        })
+    })
+    .and_then(|key| navigate(key, menu.selected_item, item_count, page_size));
 
     // Update selection if changed
     if let Some(new_idx) = new_selection {
@@ -116,7 +174,9 @@ fn menu_keyboard_system(
     }
 
     // Handle enter key for selection
-    if keyboard_input.just_pressed(KeyCode::Enter) {
+    if keyboard_input.just_pressed(KeyCode::Enter)
+        || gamepad_inputs.contains(&GamepadMenuInput::Activate)
+    {
         click_events.write(MenuItemClicked {
             state: **app_state,
             pos: menu.selected_item,
@@ -124,7 +184,9 @@ fn menu_keyboard_system(
     }
 
     // Handle escape key
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if keyboard_input.just_pressed(KeyCode::Escape)
+        || gamepad_inputs.contains(&GamepadMenuInput::Back)
+    {
         escape_events.write(MenuEscapeEvent);
     }
 }
@@ -143,6 +205,7 @@ fn update_menu_item_visuals(
         Query<&mut TextColor>,
         Query<(&mut TextColor, &ChildOf), With<PrincipalMenuText>>,
     )>,
+    theme: Res<UiTheme>,
 ) {
     // Skip if there are no menus
     let Ok(menu) = menu_query.single() else {
@@ -169,10 +232,10 @@ fn update_menu_item_visuals(
 
         // Calculate the target text colour based on selection and hover state
         let target_text_color = match (is_selected, is_hovered) {
-            (true, true) => colours::MENU_ITEM_COLOR_ON.with_alpha(1.0), // Selected and hovered
-            (true, false) => colours::MENU_ITEM_COLOR_ON,                // Selected
-            (false, true) => colours::MENU_ITEM_COLOR_OFF.with_alpha(0.8), // Just hovered
-            (false, false) => colours::MENU_ITEM_COLOR_OFF,              // Neither
+            (true, true) => theme.highlight_colour(true).with_alpha(1.0), // Selected and hovered
+            (true, false) => theme.highlight_colour(true),                // Selected
+            (false, true) => theme.highlight_colour(false).with_alpha(0.8), // Just hovered
+            (false, false) => theme.highlight_colour(false),              // Neither
         };
 
         // First, try to find a child with the PrincipalMenuText marker
@@ -201,6 +264,30 @@ fn update_menu_item_visuals(
     }
 }
 
+/// Sets the font and colour of themed texts, for new texts and for all of
+/// them when the theme changes. Runs before the UI measures text so a new menu
+/// never shows a frame with the wrong font.
+fn apply_ui_theme(
+    theme: Res<UiTheme>,
+    mut texts: Query<(Ref<ThemedText>, &mut TextFont, &mut TextColor)>,
+) {
+    for (themed, mut font, mut colour) in &mut texts {
+        if !theme.is_changed() && !themed.is_added() {
+            continue;
+        }
+        let target_font = theme.font(themed.font);
+        if font.font != target_font {
+            font.font = target_font;
+        }
+        if let Some(role) = themed.colour {
+            let target_colour = theme.colour(role);
+            if colour.0 != target_colour {
+                colour.0 = target_colour;
+            }
+        }
+    }
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(
         Update,
@@ -210,5 +297,34 @@ pub(crate) fn app_setup(app: &mut App) {
             menu_keyboard_system,
             update_menu_item_visuals,
         ),
-    );
+    )
+    .add_systems(PostUpdate, apply_ui_theme.before(UiSystem::Prepare));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paging_clamps_at_list_ends() {
+        assert_eq!(navigate(KeyCode::PageDown, 2, 12, 5), Some(7));
+        assert_eq!(navigate(KeyCode::PageDown, 9, 12, 5), Some(11));
+        assert_eq!(navigate(KeyCode::PageUp, 3, 12, 5), Some(0));
+        assert_eq!(navigate(KeyCode::Home, 6, 12, 5), Some(0));
+        assert_eq!(navigate(KeyCode::End, 6, 12, 5), Some(11));
+        // Single steps still wrap around.
+        assert_eq!(navigate(KeyCode::ArrowDown, 11, 12, 5), Some(0));
+        assert_eq!(navigate(KeyCode::Enter, 6, 12, 5), None);
+        assert_eq!(navigate(KeyCode::End, 0, 0, 5), None);
+    }
+
+    #[test]
+    fn test_page_size_from_visible_items() {
+        assert_eq!(visible_item_count(Some(300.0), [40.0; 12].into_iter()), 7);
+        assert_eq!(visible_item_count(Some(10.0), [40.0; 3].into_iter()), 1);
+        assert_eq!(
+            visible_item_count(None, [40.0; 3].into_iter()),
+            DEFAULT_PAGE_SIZE
+        );
+    }
 }
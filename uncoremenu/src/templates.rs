@@ -2,6 +2,7 @@ use crate::components::*;
 use bevy::prelude::*;
 use uncore::colours;
 use uncore::platform::plt::{FONT_SCALE, UI_SCALE, VERSION};
+use uncore::resources::ui_theme::{UiColourRole, UiFontRole};
 use uncore::types::root::game_assets::GameAssets;
 
 /// Creates a standard menu background with the background image
@@ -122,6 +123,7 @@ pub fn create_menu_item<'a>(
                 } else {
                     unselected_color
                 }))
+                .insert(ThemedText::new(UiFontRole::Title))
                 .insert(PrincipalMenuText);
         });
 
@@ -158,6 +160,10 @@ pub fn create_help_text(
                     ..default()
                 })
                 .insert(TextColor(colours::MENU_ITEM_COLOR_OFF))
+                .insert(ThemedText::with_colour(
+                    UiFontRole::Body,
+                    UiColourRole::ItemOff,
+                ))
                 .insert(TextLayout {
                     // centre align text within its container
                     justify: JustifyText::Center,
@@ -270,6 +276,10 @@ pub fn create_description_content_area<'a>(
                 ..default()
             })
             .insert(TextColor(colours::MENU_ITEM_COLOR_OFF))
+            .insert(ThemedText::with_colour(
+                UiFontRole::Body,
+                UiColourRole::ItemOff,
+            ))
             .insert(Node {
                 margin: UiRect::all(Val::Px(20.0 * UI_SCALE)),
                 ..default()
@@ -318,6 +328,10 @@ pub fn create_scrollable_content_area<'a>(
                             ..default()
                         })
                         .insert(TextColor(colours::MENU_ITEM_COLOR_OFF))
+                        .insert(ThemedText::with_colour(
+                            UiFontRole::Text,
+                            UiColourRole::ItemOff,
+                        ))
                         .insert(Node {
                             margin: UiRect::all(Val::Px(20.0 * UI_SCALE)),
                             ..default()
@@ -361,7 +375,8 @@ pub fn create_navigation_bar(parent: &mut ChildSpawnerCommands, handles: &GameAs
                             font_size: 24.0 * FONT_SCALE,
                             ..default()
                         })
-                        .insert(TextColor(Color::WHITE));
+                        .insert(TextColor(Color::WHITE))
+                        .insert(ThemedText::new(UiFontRole::Title));
                 });
 
             // Next button
@@ -381,7 +396,8 @@ pub fn create_navigation_bar(parent: &mut ChildSpawnerCommands, handles: &GameAs
                             font_size: 24.0 * FONT_SCALE,
                             ..default()
                         })
-                        .insert(TextColor(Color::WHITE));
+                        .insert(TextColor(Color::WHITE))
+                        .insert(ThemedText::new(UiFontRole::Title));
                 });
         })
         .id()
@@ -439,7 +455,8 @@ pub fn create_breadcrumb_navigation<'a>(
                             font_size: 38.0 * FONT_SCALE,
                             ..default()
                         })
-                        .insert(TextColor(Color::WHITE));
+                        .insert(TextColor(Color::WHITE))
+                        .insert(ThemedText::new(UiFontRole::Title));
                 });
 
             // Sub-breadcrumb item (e.g., "Select Map")
@@ -460,7 +477,11 @@ pub fn create_breadcrumb_navigation<'a>(
                             font_size: 30.0 * FONT_SCALE,
                             ..default()
                         })
-                        .insert(TextColor(colours::MENU_ITEM_COLOR_ON)); // Orange colour for current item
+                        .insert(TextColor(colours::MENU_ITEM_COLOR_ON)) // Orange colour for current item
+                        .insert(ThemedText::with_colour(
+                            UiFontRole::Title,
+                            UiColourRole::ItemOn,
+                        ));
                 });
         });
 
@@ -585,6 +606,11 @@ pub fn create_content_item_enabled<'a>(
                 } else {
                     unselected_color
                 }))
+                .insert(if is_enabled {
+                    ThemedText::new(UiFontRole::Item)
+                } else {
+                    ThemedText::with_colour(UiFontRole::Item, UiColourRole::Disabled)
+                })
                 .insert(PrincipalMenuText);
         });
 
@@ -625,6 +651,10 @@ pub fn create_player_status_bar(
                     ..default()
                 })
                 .insert(TextColor(colours::MENU_ITEM_COLOR_ON))
+                .insert(ThemedText::with_colour(
+                    UiFontRole::Title,
+                    UiColourRole::ItemOn,
+                ))
                 .insert(Node {
                     // Using Node for styling the text element's layout
                     margin: UiRect {
@@ -676,6 +706,10 @@ pub fn create_player_status_bar(
                     font_size: 20.0 * FONT_SCALE,
                     ..default()
                 })
-                .insert(TextColor(colours::MENU_ITEM_COLOR_ON));
+                .insert(TextColor(colours::MENU_ITEM_COLOR_ON))
+                .insert(ThemedText::with_colour(
+                    UiFontRole::Title,
+                    UiColourRole::ItemOn,
+                ));
         });
 }
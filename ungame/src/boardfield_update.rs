@@ -3,7 +3,7 @@ use uncore::behaviour::Behaviour;
 use uncore::components::board::position::Position;
 use uncore::events::board_data_rebuild::BoardDataToRebuild;
 use uncore::resources::board_data::BoardData;
-use unlight::lighting::rebuild_lighting_field;
+use unlight::lighting::{rebuild_lighting_field, update_lighting_dirty};
 use unstd::plugins::board::rebuild_collision_data;
 
 /// Updates the board field based on incoming events and rebuilds collision and lighting data if needed.
@@ -41,7 +41,11 @@ fn boardfield_update(
 
     if bdr.lighting {
         let mut lens = qt.transmute_lens::<(&Position, &Behaviour)>();
-        rebuild_lighting_field(&mut bf, &lens.query(), &mut avg_time);
+        let query = lens.query();
+        update_lighting_dirty(&mut bf, &query);
+        if bf.lighting_dirty || bf.light_flicker_changed {
+            rebuild_lighting_field(&mut bf, &query, &mut avg_time);
+        }
     }
 }
 
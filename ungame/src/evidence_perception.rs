@@ -35,6 +35,20 @@ fn update_current_evidence_readings_from_player_perception_system(
             return;
         };
 
+        // Gear that names the evidence it's indicating is trusted as soon as
+        // its readout can be seen.
+        if is_status_text_prominently_visible
+            && let Some(evidence_type) = gear_data.current_evidence()
+        {
+            evidence_readings.report_clarity(
+                evidence_type,
+                1.0,
+                current_game_time_secs,
+                delta_time_secs,
+            );
+            return;
+        }
+
         if let Ok(evidence_type) = Evidence::try_from(&gear.kind) {
             let mut clarity = 0.0f32;
 
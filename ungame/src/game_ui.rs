@@ -3,9 +3,10 @@ use bevy::{color::palettes::css, prelude::*};
 use bevy_persistent::Persistent;
 use uncore::behaviour::Behaviour;
 use uncore::colours;
+use uncore::colours::UiPalette;
 use uncore::components::game_ui::{
-    DamageBackground, ElementObjectUI, EvidenceUI, GameUI, RightSideGearUI, WalkieText,
-    WalkieTextUIRoot,
+    CheatsActiveBadge, DamageBackground, ElementObjectUI, EvidenceUI, GameUI, RightSideGearUI,
+    WalkieText, WalkieTextUIRoot,
 };
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::platform::plt::{FONT_SCALE, UI_SCALE};
@@ -44,6 +45,7 @@ fn setup_ui(
     mut commands: Commands,
     handles: Res<GameAssets>,
     game_settings: Res<Persistent<GameplaySettings>>,
+    palette: Res<UiPalette>,
 ) {
     // Spawn independent WalkieText UI
     commands
@@ -79,6 +81,32 @@ fn setup_ui(
                 .insert(WalkieText);
         });
 
+    // "Cheats active" badge; shown or hidden by `update_cheats_badge`
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0 * UI_SCALE),
+            left: Val::Px(10.0 * UI_SCALE),
+            padding: UiRect::axes(Val::Px(8.0 * UI_SCALE), Val::Px(2.0 * UI_SCALE)),
+            display: cheats_badge_display(&game_settings),
+            ..default()
+        })
+        .insert(BackgroundColor(css::BLACK.with_alpha(0.6).into()))
+        .insert(Pickable::IGNORE)
+        .insert(ZIndex(100))
+        .insert(GameUI)
+        .insert(CheatsActiveBadge)
+        .with_children(|badge| {
+            badge
+                .spawn(Text::new("CHEATS ACTIVE"))
+                .insert(TextFont {
+                    font: handles.fonts.chakra.w300_light.clone(),
+                    font_size: 14.0 * FONT_SCALE,
+                    ..default()
+                })
+                .insert(TextColor(palette.warning));
+        });
+
     // Spawn vignette for the damage background
     commands
         .spawn(Node {
@@ -484,6 +512,37 @@ fn toggle_held_object_ui(
     }
 }
 
+fn cheats_badge_display(game_settings: &GameplaySettings) -> Display {
+    if game_settings.dev_cheat_mode.is_enabled() {
+        Display::Flex
+    } else {
+        Display::None
+    }
+}
+
+/// Shows the "cheats active" badge while Dev God Mode is enabled.
+///
+/// Uses `Display` rather than `Visibility` so pausing the game, which hides
+/// every `GameUI` node, doesn't fight with it.
+fn update_cheats_badge(
+    game_settings: Res<Persistent<GameplaySettings>>,
+    palette: Res<UiPalette>,
+    mut badges: Query<(&mut Node, &Children), With<CheatsActiveBadge>>,
+    mut texts: Query<&mut TextColor>,
+) {
+    if !game_settings.is_changed() && !palette.is_changed() {
+        return;
+    }
+    for (mut node, children) in badges.iter_mut() {
+        node.display = cheats_badge_display(&game_settings);
+        for child in children.iter() {
+            if let Ok(mut colour) = texts.get_mut(child) {
+                colour.0 = palette.warning;
+            }
+        }
+    }
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(OnEnter(AppState::InGame), setup_ui)
         .add_systems(OnExit(AppState::InGame), cleanup)
@@ -492,5 +551,9 @@ pub(crate) fn app_setup(app: &mut App) {
         .add_systems(
             Update,
             toggle_held_object_ui.run_if(in_state(GameState::None)),
+        )
+        .add_systems(
+            Update,
+            update_cheats_badge.run_if(in_state(AppState::InGame)),
         );
 }
@@ -4,6 +4,8 @@ pub mod game_ui;
 pub mod gear_ui;
 pub mod hide_mouse;
 pub mod looking_gear;
+pub mod metrics_overlay;
+pub mod minimap;
 pub mod object_charge;
 pub mod pause_ui;
 pub mod plugin;
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+use uncore::metric_recorder::MetricsDashboard;
+use uncore::states::AppState;
+use unsettings::game::GameplaySettings;
+
+/// Seconds between refreshes of the overlay text.
+const REFRESH_SECS: f32 = 0.5;
+
+/// Marker for the metrics overlay text.
+#[derive(Component)]
+pub struct MetricsOverlayText;
+
+/// Dev cheat: F7 shows the timings recorded through `SendMetric` (min, avg,
+/// p95 and max over the recent frames, in ms), and logs them when shown.
+///
+/// Disabling the cheat mode hides it.
+fn toggle_metrics_overlay(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+    dashboard: Res<MetricsDashboard>,
+    qo: Query<Entity, With<MetricsOverlayText>>,
+) {
+    let cheats = game_settings.dev_cheat_mode.is_enabled();
+    let spawned = !qo.is_empty();
+    let toggle = cheats && keyboard_input.just_pressed(KeyCode::F7);
+    if spawned && (toggle || !cheats) {
+        for e in qo.iter() {
+            commands.entity(e).despawn();
+        }
+        return;
+    }
+    if !toggle {
+        return;
+    }
+    for (path, stats) in dashboard.snapshot() {
+        info!("Metric {path}: {stats}");
+    }
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(4.0),
+            left: Val::Px(6.0),
+            ..default()
+        })
+        .insert(Text::new(""))
+        .insert(TextFont {
+            font_size: 12.0,
+            ..default()
+        })
+        .insert(TextColor(Color::srgba(0.9, 0.9, 0.9, 0.8)))
+        .insert(BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)))
+        .insert(GlobalZIndex(i32::MAX))
+        .insert(MetricsOverlayText);
+}
+
+fn update_metrics_overlay(
+    time: Res<Time>,
+    dashboard: Res<MetricsDashboard>,
+    mut qo: Query<&mut Text, With<MetricsOverlayText>>,
+    mut since_refresh: Local<f32>,
+) {
+    let Ok(mut text) = qo.single_mut() else {
+        return;
+    };
+    *since_refresh += time.delta_secs();
+    if *since_refresh < REFRESH_SECS && !text.0.is_empty() {
+        return;
+    }
+    *since_refresh = 0.0;
+    let lines: Vec<String> = dashboard
+        .snapshot()
+        .into_iter()
+        .map(|(path, stats)| format!("{path}: {stats}"))
+        .collect();
+    text.0 = if lines.is_empty() {
+        "No metrics recorded yet".to_string()
+    } else {
+        lines.join("\n")
+    };
+}
+
+fn cleanup(mut commands: Commands, qo: Query<Entity, With<MetricsOverlayText>>) {
+    for e in qo.iter() {
+        commands.entity(e).despawn();
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.add_systems(
+        Update,
+        (toggle_metrics_overlay, update_metrics_overlay)
+            .chain()
+            .run_if(in_state(AppState::InGame)),
+    )
+    .add_systems(OnExit(AppState::InGame), cleanup);
+}
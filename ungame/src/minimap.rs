@@ -0,0 +1,457 @@
+//! Minimap of the current floor, drawn from `BoardData`.
+//!
+//! Each floor is rendered once into a tiny image (one pixel per tile) and
+//! cached, so the minimap only touches the board when the shown floor changes.
+//! The player is a UI dot moved over the image every frame.
+//!
+//! Tiles the player hasn't explored yet are drawn dimmed, and the shown floor
+//! is redrawn whenever `ExploredTiles` reveals new tiles on it.
+//!
+//! The image is rotated to match the isometric view: board +x points down and
+//! board +y points right, so walking "up-right" on screen goes up-right on the
+//! minimap too.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageSampler;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_persistent::Persistent;
+use std::collections::HashMap;
+use uncore::behaviour::component::Interactive;
+use uncore::components::board::position::Position;
+use uncore::components::game_config::GameConfig;
+use uncore::components::game_ui::{
+    GameUI, MinimapFloorLabel, MinimapImage, MinimapPlayerMarker, MinimapUI,
+};
+use uncore::components::player_sprite::PlayerSprite;
+use uncore::platform::plt::{FONT_SCALE, UI_SCALE};
+use uncore::resources::board_data::BoardData;
+use uncore::resources::explored_tiles::ExploredTiles;
+use uncore::resources::localization::Localization;
+use uncore::states::{AppState, GameState};
+use uncore::types::root::game_assets::GameAssets;
+use unsettings::controls::ControlKeys;
+
+const MINIMAP_WIDTH: f32 = 160.0;
+
+const WALL_COLOUR: [u8; 4] = [20, 20, 24, 200];
+const FLOOR_COLOUR: [u8; 4] = [150, 150, 140, 220];
+const STAIRS_COLOUR: [u8; 4] = [90, 140, 220, 240];
+const INTERACTIVE_COLOUR: [u8; 4] = [240, 200, 60, 255];
+
+/// Which floor the minimap shows and the images rendered so far.
+#[derive(Resource, Debug, Default)]
+pub struct MinimapState {
+    pub visible: bool,
+    /// Floor picked with the floor keys; `None` follows the player.
+    pub viewed_floor: Option<usize>,
+    /// Floor currently displayed.
+    pub shown_floor: Option<usize>,
+    /// Map the cached images belong to.
+    map_path: String,
+    /// Rendered image of each floor, with the `ExploredTiles` revision it was
+    /// drawn with.
+    cache: HashMap<usize, (Handle<Image>, u32)>,
+}
+
+/// Pixel of the minimap image for board tile `(x, y)`.
+///
+/// Returns the index of the first byte in an RGBA8 image of a board with
+/// `map_size`, following the rotation described in the module docs.
+fn pixel_offset(map_size: (usize, usize, usize), x: usize, y: usize) -> usize {
+    (x * map_size.1 + y) * 4
+}
+
+/// Darkens a colour for tiles that haven't been explored yet.
+fn dim(colour: [u8; 4]) -> [u8; 4] {
+    [colour[0] / 4, colour[1] / 4, colour[2] / 4, colour[3] / 2]
+}
+
+/// Renders floor `z` of the board into a minimap image.
+///
+/// Walkable tiles, walls and stairs come from the collision field, and every
+/// position in `interactives` on this floor gets a marker. With `explored`,
+/// unexplored tiles are dimmed and their interactives left out.
+pub fn render_minimap_floor(
+    bf: &BoardData,
+    z: usize,
+    interactives: impl IntoIterator<Item = Position>,
+    explored: Option<&ExploredTiles>,
+) -> Image {
+    let is_explored = |x: usize, y: usize| explored.is_none_or(|e| e.is_explored(x, y, z));
+    let (width, height, _) = bf.map_size;
+    let mut data = vec![0u8; width * height * 4];
+    for x in 0..width {
+        for y in 0..height {
+            let collision = &bf.collision_field[(x, y, z)];
+            let colour = if collision.stair_offset != 0 {
+                STAIRS_COLOUR
+            } else if collision.player_free {
+                FLOOR_COLOUR
+            } else {
+                WALL_COLOUR
+            };
+            let colour = if is_explored(x, y) {
+                colour
+            } else {
+                dim(colour)
+            };
+            let i = pixel_offset(bf.map_size, x, y);
+            data[i..i + 4].copy_from_slice(&colour);
+        }
+    }
+    for pos in interactives {
+        let bpos = pos.to_board_position();
+        if bpos.z != z as i64
+            || !bpos.is_valid(bf.map_size)
+            || !is_explored(bpos.x as usize, bpos.y as usize)
+        {
+            continue;
+        }
+        let i = pixel_offset(bf.map_size, bpos.x as usize, bpos.y as usize);
+        data[i..i + 4].copy_from_slice(&INTERACTIVE_COLOUR);
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: height.max(1) as u32,
+            height: width.max(1) as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        if data.is_empty() { vec![0; 4] } else { data },
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD | RenderAssetUsages::MAIN_WORLD,
+    );
+    // Keep tiles crisp when scaled up
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+fn setup_minimap(mut commands: Commands, handles: Res<GameAssets>, state: Res<MinimapState>) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(40.0 * UI_SCALE),
+            right: Val::Px(10.0 * UI_SCALE),
+            padding: UiRect::all(Val::Px(4.0 * UI_SCALE)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            display: if state.visible {
+                Display::Flex
+            } else {
+                Display::None
+            },
+            ..default()
+        })
+        .insert(BackgroundColor(Color::BLACK.with_alpha(0.5)))
+        .insert(Pickable::IGNORE)
+        .insert(ZIndex(90))
+        .insert(GameUI)
+        .insert(MinimapUI)
+        .with_children(|root| {
+            root.spawn(ImageNode::default())
+                .insert(Node {
+                    width: Val::Px(MINIMAP_WIDTH * UI_SCALE),
+                    ..default()
+                })
+                .insert(MinimapImage)
+                .with_children(|image| {
+                    image
+                        .spawn(Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(5.0 * UI_SCALE),
+                            height: Val::Px(5.0 * UI_SCALE),
+                            margin: UiRect::all(Val::Px(-2.5 * UI_SCALE)),
+                            ..default()
+                        })
+                        .insert(BorderRadius::MAX)
+                        .insert(BackgroundColor(Color::srgb(1.0, 0.2, 0.2)))
+                        .insert(MinimapPlayerMarker);
+                });
+            root.spawn(Text::new(""))
+                .insert(TextFont {
+                    font: handles.fonts.chakra.w300_light.clone(),
+                    font_size: 14.0 * FONT_SCALE,
+                    ..default()
+                })
+                .insert(TextColor(Color::WHITE))
+                .insert(MinimapFloorLabel);
+        });
+}
+
+/// Exploration is kept per mission only.
+fn reset_explored_tiles(mut explored: ResMut<ExploredTiles>) {
+    *explored = ExploredTiles::default();
+}
+
+/// Reveals the tiles around the player as they move.
+fn mark_explored_tiles(
+    board_data: Res<BoardData>,
+    gc: Res<GameConfig>,
+    qp: Query<(&PlayerSprite, &Position)>,
+    mut explored: ResMut<ExploredTiles>,
+) {
+    for (player, pos) in qp.iter() {
+        if player.id != gc.player_id {
+            continue;
+        }
+        // Only take the resource mutably when something changes, so
+        // `is_changed` stays meaningful for readers.
+        if explored.needs_reveal(pos, ExploredTiles::REVEAL_RADIUS, board_data.map_size) {
+            explored.reveal_around(pos, ExploredTiles::REVEAL_RADIUS, board_data.map_size);
+        }
+    }
+}
+
+fn cleanup_minimap(mut state: ResMut<MinimapState>) {
+    state.shown_floor = None;
+    state.viewed_floor = None;
+}
+
+/// The minimap toggle key (`M` by default) shows or hides the minimap; the
+/// floor keys (`,` and `.`) browse the floors.
+fn keyboard_minimap(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    control_settings: Res<Persistent<ControlKeys>>,
+    board_data: Res<BoardData>,
+    gc: Res<GameConfig>,
+    qp: Query<(&PlayerSprite, &Position)>,
+    mut state: ResMut<MinimapState>,
+    mut qm: Query<&mut Node, With<MinimapUI>>,
+) {
+    if keyboard_input.just_pressed(control_settings.minimap_toggle) {
+        state.visible = !state.visible;
+        state.viewed_floor = None;
+        for mut node in qm.iter_mut() {
+            node.display = if state.visible {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+    }
+    if !state.visible {
+        return;
+    }
+    let down = keyboard_input.just_pressed(control_settings.minimap_floor_down);
+    let up = keyboard_input.just_pressed(control_settings.minimap_floor_up);
+    if !down && !up {
+        return;
+    }
+    let player_floor = qp
+        .iter()
+        .find(|(p, _)| p.id == gc.player_id)
+        .map(|(_, pos)| pos.z.round().max(0.0) as usize)
+        .unwrap_or_default();
+    let max_floor = board_data.map_size.2.saturating_sub(1);
+    let floor = state.viewed_floor.unwrap_or(player_floor);
+    let floor = if up {
+        (floor + 1).min(max_floor)
+    } else {
+        floor.saturating_sub(1)
+    };
+    state.viewed_floor = Some(floor);
+}
+
+/// Swaps the minimap image when the shown floor changes, rendering the floor
+/// the first time it's needed and again only after new tiles on it have been
+/// explored.
+fn update_minimap_image(
+    board_data: Res<BoardData>,
+    explored: Res<ExploredTiles>,
+    gc: Res<GameConfig>,
+    qp: Query<(&PlayerSprite, &Position)>,
+    q_interactives: Query<&Position, With<Interactive>>,
+    mut state: ResMut<MinimapState>,
+    mut images: ResMut<Assets<Image>>,
+    mut q_image: Query<&mut ImageNode, With<MinimapImage>>,
+    mut q_label: Query<&mut Text, With<MinimapFloorLabel>>,
+    localization: Res<Localization>,
+) {
+    if !state.visible || board_data.map_size.2 == 0 {
+        return;
+    }
+    if state.map_path != board_data.map_path {
+        state.map_path.clone_from(&board_data.map_path);
+        state.cache.clear();
+        state.shown_floor = None;
+    }
+    let player_floor = qp
+        .iter()
+        .find(|(p, _)| p.id == gc.player_id)
+        .map(|(_, pos)| pos.z.round().max(0.0) as usize);
+    let Some(floor) = state.viewed_floor.or(player_floor) else {
+        return;
+    };
+    let floor = floor.min(board_data.map_size.2 - 1);
+    let revision = explored.revision(floor);
+    let cached = state.cache.get(&floor).cloned();
+    if state.shown_floor == Some(floor) && cached.as_ref().is_some_and(|(_, r)| *r == revision) {
+        return;
+    }
+    let render = || {
+        render_minimap_floor(
+            &board_data,
+            floor,
+            q_interactives.iter().copied(),
+            Some(&explored),
+        )
+    };
+    let handle = match cached {
+        Some((handle, r)) if r == revision => handle,
+        Some((handle, _)) => {
+            images.insert(&handle, render());
+            handle
+        }
+        None => images.add(render()),
+    };
+    state.cache.insert(floor, (handle.clone(), revision));
+    for mut image_node in q_image.iter_mut() {
+        image_node.image = handle.clone();
+    }
+    let floor_name = board_data.floor_for_z(floor).unwrap_or(floor as i32);
+    for mut text in q_label.iter_mut() {
+        text.0 = format!(
+            "{} {floor_name}",
+            localization.text("minimap.floor", "Floor")
+        );
+    }
+    state.shown_floor = Some(floor);
+}
+
+/// Moves the player dot, hiding it when the player is on another floor.
+fn update_minimap_marker(
+    board_data: Res<BoardData>,
+    gc: Res<GameConfig>,
+    qp: Query<(&PlayerSprite, &Position)>,
+    state: Res<MinimapState>,
+    mut q_marker: Query<&mut Node, With<MinimapPlayerMarker>>,
+) {
+    if !state.visible {
+        return;
+    }
+    let Some((_, pos)) = qp.iter().find(|(p, _)| p.id == gc.player_id) else {
+        return;
+    };
+    let (width, height, _) = board_data.map_size;
+    if width == 0 || height == 0 {
+        return;
+    }
+    let on_floor = state.shown_floor == Some(pos.z.round().max(0.0) as usize);
+    for mut node in q_marker.iter_mut() {
+        node.display = if on_floor {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        node.left = Val::Percent((pos.y + 0.5) / height as f32 * 100.0);
+        node.top = Val::Percent((pos.x + 0.5) / width as f32 * 100.0);
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<MinimapState>()
+        .init_resource::<ExploredTiles>()
+        .add_systems(
+            OnEnter(AppState::InGame),
+            (setup_minimap, reset_explored_tiles),
+        )
+        .add_systems(OnExit(AppState::InGame), cleanup_minimap)
+        .add_systems(
+            Update,
+            (
+                keyboard_minimap.run_if(in_state(GameState::None)),
+                mark_explored_tiles,
+                update_minimap_image,
+                update_minimap_marker,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+    use uncore::types::board::fielddata::CollisionFieldData;
+
+    fn pixel(image: &Image, bf: &BoardData, x: usize, y: usize) -> [u8; 4] {
+        let i = pixel_offset(bf.map_size, x, y);
+        let data = image.data.as_ref().unwrap();
+        [data[i], data[i + 1], data[i + 2], data[i + 3]]
+    }
+
+    #[test]
+    fn test_render_minimap_floor() {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (4, 3, 2);
+        bf.collision_field = Array3::from_elem(bf.map_size, CollisionFieldData::default());
+        bf.collision_field[(1, 1, 0)].player_free = true;
+        bf.collision_field[(2, 1, 0)].player_free = true;
+        bf.collision_field[(3, 2, 0)].stair_offset = 1;
+
+        let interactives = [Position::new_i64(2, 1, 0), Position::new_i64(0, 0, 1)];
+        let image = render_minimap_floor(&bf, 0, interactives, None);
+
+        // Board x runs down the image, board y across it.
+        assert_eq!(image.width(), 3);
+        assert_eq!(image.height(), 4);
+        assert_eq!(pixel(&image, &bf, 0, 0), WALL_COLOUR);
+        assert_eq!(pixel(&image, &bf, 1, 1), FLOOR_COLOUR);
+        assert_eq!(pixel(&image, &bf, 3, 2), STAIRS_COLOUR);
+        assert_eq!(pixel(&image, &bf, 2, 1), INTERACTIVE_COLOUR);
+
+        // The interactive on the upper floor doesn't show here.
+        assert_eq!(pixel(&image, &bf, 0, 0), WALL_COLOUR);
+        let upper = render_minimap_floor(&bf, 1, interactives, None);
+        assert_eq!(pixel(&upper, &bf, 0, 0), INTERACTIVE_COLOUR);
+
+        // Unexplored tiles are dimmed and hide their interactives.
+        let mut explored = ExploredTiles::default();
+        explored.reveal_around(&Position::new_i64(1, 1, 0), 0, bf.map_size);
+        let image = render_minimap_floor(&bf, 0, interactives, Some(&explored));
+        assert_eq!(pixel(&image, &bf, 1, 1), FLOOR_COLOUR);
+        assert_eq!(pixel(&image, &bf, 2, 1), dim(FLOOR_COLOUR));
+    }
+
+    #[test]
+    fn test_moving_player_marks_explored_tiles() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<GameConfig>()
+            .init_resource::<ExploredTiles>()
+            .add_systems(Update, mark_explored_tiles);
+        let mut bf = BoardData::from_world(app.world_mut());
+        bf.map_size = (20, 20, 2);
+        app.insert_resource(bf);
+        let player_id = app.world().resource::<GameConfig>().player_id;
+        let start = Position::new_i64(2, 2, 0);
+        let player = app
+            .world_mut()
+            .spawn((PlayerSprite::new(player_id, start), start))
+            .id();
+
+        app.update();
+        let explored = app.world().resource::<ExploredTiles>();
+        let r = ExploredTiles::REVEAL_RADIUS as usize;
+        assert!(explored.is_explored(2, 2, 0));
+        assert!(explored.is_explored(2 + r, 2, 0));
+        assert!(!explored.is_explored(2 + r + 1, 2, 0));
+        assert!(!explored.is_explored(2, 2, 1));
+        let revision = explored.revision(0);
+
+        // Walk across the room and upstairs.
+        *app.world_mut().get_mut::<Position>(player).unwrap() = Position::new_i64(12, 2, 0);
+        app.update();
+        *app.world_mut().get_mut::<Position>(player).unwrap() = Position::new_i64(12, 2, 1);
+        app.update();
+        let explored = app.world().resource::<ExploredTiles>();
+        assert!(explored.is_explored(2, 2, 0));
+        assert!(explored.is_explored(12, 2, 0));
+        assert!(explored.is_explored(12, 2, 1));
+        assert!(!explored.is_explored(7, 2, 0));
+        assert!(explored.revision(0) != revision);
+    }
+}
@@ -24,6 +24,9 @@ fn keyboard(
     if keyboard_input.just_pressed(KeyCode::Escape) {
         game_next_state.set(GameState::None);
     }
+    if keyboard_input.just_pressed(KeyCode::KeyS) {
+        game_next_state.set(GameState::Settings);
+    }
     if keyboard_input.just_pressed(KeyCode::KeyQ) {
         game_next_state.set(GameState::None);
         next_state.set(AppState::MissionSelect);
@@ -98,7 +101,7 @@ fn setup_ui(
                     });
                     mid_blk
                         .spawn(Text::new(
-                            "The game is paused. Hit [ESC] again to resume or [Q] to Quit.",
+                            "The game is paused. Hit [ESC] again to resume, [S] for Settings or [Q] to Quit.",
                         ))
                         .insert(TextFont {
                             font: handles.fonts.chakra.w300_light.clone(),
@@ -1,5 +1,5 @@
 use crate::evidence_perception;
-use crate::{boardfield_update, hide_mouse, looking_gear, systems};
+use crate::{boardfield_update, hide_mouse, looking_gear, metrics_overlay, minimap, systems};
 
 use super::{game_ui, object_charge, pause_ui, roomchanged};
 use bevy::prelude::*;
@@ -20,5 +20,7 @@ impl Plugin for UnhaunterGamePlugin {
         object_charge::app_setup(app);
         evidence_perception::app_setup(app);
         looking_gear::app_setup(app);
+        minimap::app_setup(app);
+        metrics_overlay::app_setup(app);
     }
 }
@@ -1,4 +1,9 @@
-use bevy::{prelude::*, render::camera::ScalingMode};
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    render::camera::ScalingMode,
+    window::PrimaryWindow,
+};
 use bevy_persistent::Persistent;
 use uncore::{
     components::{
@@ -7,8 +12,12 @@ use uncore::{
         game_config::GameConfig,
         player_sprite::PlayerSprite,
     },
-    resources::board_data::BoardData,
+    events::board_data_rebuild::BoardDataToRebuild,
+    resources::{
+        board_data::BoardData, free_camera::FreeCamera, mouse_visibility::MouseVisibility,
+    },
     states::{AppState, GameState},
+    types::board::prebaked_dump,
 };
 use unsettings::controls::ControlKeys;
 use unsettings::game::GameplaySettings;
@@ -38,11 +47,13 @@ fn cleanup(
     qc: Query<Entity, With<GCameraArena>>,
     qgs: Query<Entity, With<GameSprite>>,
     qs: Query<Entity, With<GameSound>>,
+    mut free_camera: ResMut<FreeCamera>,
 ) {
     // Despawn old camera if exists
     for cam in qc.iter() {
         commands.entity(cam).despawn();
     }
+    *free_camera = FreeCamera::default();
 
     // Despawn game sprites if not used
     for gs in qgs.iter() {
@@ -55,6 +66,36 @@ fn cleanup(
     }
 }
 
+/// Width of the band along the window edges that pans the camera in edge
+/// scroll mode, as a fraction of the smaller window side.
+const EDGE_SCROLL_MARGIN: f32 = 0.05;
+
+/// Direction to pan the camera for a cursor near the window edges.
+///
+/// Each axis goes from 0.0 at the inner side of the edge band to 1.0 at the
+/// window border, so the pan speeds up the closer the cursor gets. The result
+/// is in world orientation (y up), while `cursor` is in window coordinates
+/// (y down).
+pub fn edge_pan_vector(cursor: Vec2, window_size: Vec2, margin: f32) -> Vec2 {
+    if margin <= 0.0 || window_size.min_element() <= 0.0 {
+        return Vec2::ZERO;
+    }
+    let axis = |pos: f32, size: f32| -> f32 {
+        let pos = pos.clamp(0.0, size);
+        if pos < margin {
+            -(1.0 - pos / margin)
+        } else if pos > size - margin {
+            1.0 - (size - pos) / margin
+        } else {
+            0.0
+        }
+    };
+    Vec2::new(
+        axis(cursor.x, window_size.x),
+        -axis(cursor.y, window_size.y),
+    )
+}
+
 fn keyboard(
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
@@ -62,24 +103,44 @@ fn keyboard(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut camera: Query<(&mut Transform, &mut Direction), With<GCameraArena>>,
     gc: Res<GameConfig>,
-    pc: Query<(&PlayerSprite, &Transform, &Direction), Without<GCameraArena>>,
+    pc: Query<(&PlayerSprite, &Transform, &Position), Without<GCameraArena>>,
     time: Res<Time>,
     game_settings: Res<Persistent<GameplaySettings>>,
     control_settings: Res<Persistent<ControlKeys>>,
+    free_camera: Res<FreeCamera>,
+    mut scroll_events: EventReader<MouseWheel>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    mouse_visibility: Res<MouseVisibility>,
+    board_data: Res<BoardData>,
+    mut edge_offset: Local<Vec2>,
 ) {
+    // The camera zoom key + wheel zooms; the plain wheel is left for cycling gear.
+    let scroll: f32 = scroll_events
+        .read()
+        .map(|ev| match ev.unit {
+            MouseScrollUnit::Line => ev.y,
+            MouseScrollUnit::Pixel => ev.y / 20.0,
+        })
+        .sum();
+    let zoom_held = keyboard_input.pressed(control_settings.camera_zoom);
     if *app_state.get() != AppState::InGame {
+        *edge_offset = Vec2::ZERO;
         return;
     }
     let in_game = *game_state.get() == GameState::None;
-    if *game_state.get() == GameState::Pause {
+    if game_state.is_paused() {
         return;
     }
     let dt = time.delta_secs() * 60.0;
     if keyboard_input.just_pressed(KeyCode::Escape) && in_game {
         game_next_state.set(GameState::Pause);
     }
+    if free_camera.active {
+        // Camera is driven by `free_camera_controls`
+        return;
+    }
     for (mut transform, mut cam_dir) in camera.iter_mut() {
-        for (player, p_transform, _p_dir) in pc.iter() {
+        for (player, p_transform, p_pos) in pc.iter() {
             if player.id != gc.player_id {
                 continue;
             }
@@ -92,21 +153,52 @@ fn keyboard(
             const CAMERA_AHEAD_FACTOR: f32 = 0.11 / 1.8;
             ref_point.y += 20.0 + sc_dir.y * CAMERA_AHEAD_FACTOR;
             ref_point.x += sc_dir.x * CAMERA_AHEAD_FACTOR;
-            let dist = (transform.translation.distance(ref_point) - 1.0).max(0.00001);
-            let mut delta = ref_point - transform.translation;
-            delta.z = 0.0;
-            const RED: f32 = 120.0 * 2.0;
-            const MEAN_DIST: f32 = 120.0 / 15.0;
-            const MAX_DIST: f32 = 1000.0;
-            let strength = ((dist.min(MAX_DIST) / MEAN_DIST).powf(1.4) * MEAN_DIST) / RED
-                * (delta.dot(cam_dir.to_vec3()).clamp(0.2, 1.0));
-            let vector = delta.normalize() * strength;
-            let f_strength: f32 = 0.05;
-            cam_dir.dx = (cam_dir.dx + vector.x * f_strength * dt) / (1.0 + f_strength * dt);
-            cam_dir.dy = (cam_dir.dy + vector.y * f_strength * dt) / (1.0 + f_strength * dt);
-            cam_dir.dz = (cam_dir.dz + vector.z * f_strength * dt) / (1.0 + f_strength * dt);
-
-            transform.translation += cam_dir.to_vec3() * dt;
+
+            // Edge scrolling moves the view away from the player, within the map.
+            if !game_settings.camera_controls.edge_scroll() {
+                *edge_offset = Vec2::ZERO;
+            } else if in_game && mouse_visibility.is_visible {
+                let cursor = q_window
+                    .single()
+                    .ok()
+                    .and_then(|w| w.cursor_position().map(|c| (c, w.size())));
+                if let Some((cursor, size)) = cursor {
+                    let margin = size.min_element() * EDGE_SCROLL_MARGIN;
+                    let pan = edge_pan_vector(cursor, size, margin);
+                    *edge_offset += pan * 4.0 * dt * transform.scale.x;
+                }
+            }
+            if *edge_offset != Vec2::ZERO {
+                let target = ref_point.truncate() + *edge_offset;
+                if let Some(pos) = Position::from_screen_coord(target, p_pos.z) {
+                    let pos = board_data.clamp_position(pos);
+                    *edge_offset = pos.to_screen_coord().truncate() - ref_point.truncate();
+                }
+                ref_point += edge_offset.extend(0.0);
+            }
+            if let Some(alpha) = game_settings.camera_follow.lerp_alpha(dt) {
+                // Ease straight toward the player; pathing reads the camera's
+                // GlobalTransform each frame, so picking stays correct mid-ease.
+                let target = ref_point.with_z(transform.translation.z);
+                transform.translation = transform.translation.lerp(target, alpha);
+                *cam_dir = Direction::zero();
+            } else {
+                let dist = (transform.translation.distance(ref_point) - 1.0).max(0.00001);
+                let mut delta = ref_point - transform.translation;
+                delta.z = 0.0;
+                const RED: f32 = 120.0 * 2.0;
+                const MEAN_DIST: f32 = 120.0 / 15.0;
+                const MAX_DIST: f32 = 1000.0;
+                let strength = ((dist.min(MAX_DIST) / MEAN_DIST).powf(1.4) * MEAN_DIST) / RED
+                    * (delta.dot(cam_dir.to_vec3()).clamp(0.2, 1.0));
+                let vector = delta.normalize() * strength;
+                let f_strength: f32 = 0.05;
+                cam_dir.dx = (cam_dir.dx + vector.x * f_strength * dt) / (1.0 + f_strength * dt);
+                cam_dir.dy = (cam_dir.dy + vector.y * f_strength * dt) / (1.0 + f_strength * dt);
+                cam_dir.dz = (cam_dir.dz + vector.z * f_strength * dt) / (1.0 + f_strength * dt);
+
+                transform.translation += cam_dir.to_vec3() * dt;
+            }
         }
         if in_game && game_settings.camera_controls.on() {
             if keyboard_input.pressed(control_settings.camera_right) {
@@ -129,6 +221,17 @@ fn keyboard(
                 transform.scale.x *= 1.02_f32.powf(dt);
                 transform.scale.y *= 1.02_f32.powf(dt);
             }
+            if zoom_held && scroll != 0.0 {
+                transform.scale.x /= 1.1_f32.powf(scroll);
+                transform.scale.y /= 1.1_f32.powf(scroll);
+            }
+        }
+        // Keep the zoom in the configured range, also when the range changes.
+        let (min_scale, max_scale) = game_settings.camera_zoom_range();
+        let scale = transform.scale.x.clamp(min_scale, max_scale);
+        if transform.scale.x != scale {
+            transform.scale.x = scale;
+            transform.scale.y = scale;
         }
     }
 }
@@ -210,7 +313,7 @@ fn keyboard_floor_switch(
         player_pos.z = target_z as f32;
 
         // Log the floor change for debugging
-        if let Some(tiled_floor) = board_data.z_floor_map.get(&target_z) {
+        if let Some(tiled_floor) = board_data.floor_for_z(target_z) {
             warn!(
                 "Floor switch: Moving to z={} (Tiled floor number: {})",
                 target_z, tiled_floor
@@ -224,11 +327,227 @@ fn keyboard_floor_switch(
     }
 }
 
+/// Dev cheat: F8 freezes/unfreezes the temperature simulation.
+///
+/// Only available while `DevCheatMode` is enabled; disabling the cheat mode
+/// releases any active freeze so normal play is never left paused.
+fn keyboard_freeze_temperature(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+    mut board_data: ResMut<BoardData>,
+) {
+    if !game_settings.dev_cheat_mode.is_enabled() {
+        if board_data.temp_diffusion_config.frozen {
+            board_data.temp_diffusion_config.frozen = false;
+        }
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::F8) {
+        let config = &mut board_data.temp_diffusion_config;
+        config.frozen = !config.frozen;
+        warn!("Dev cheat: temperature freeze set to {}", config.frozen);
+    }
+}
+
+/// Dev cheat: F10 dumps the prebaked lighting of every floor to disk, and
+/// Shift+F10 loads it back from the same place.
+fn keyboard_dump_prebaked_lighting(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+    mut board_data: ResMut<BoardData>,
+    mut ev_bdr: EventWriter<BoardDataToRebuild>,
+) {
+    if !game_settings.dev_cheat_mode.is_enabled() || !keyboard_input.just_pressed(KeyCode::F10) {
+        return;
+    }
+    let dir = prebaked_dump::default_dump_dir(&board_data);
+    if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        match prebaked_dump::load_prebaked_lighting(&mut board_data, &dir) {
+            Ok(()) => {
+                warn!("Dev cheat: prebaked lighting loaded from {}", dir.display());
+                ev_bdr.write(BoardDataToRebuild {
+                    lighting: true,
+                    collision: false,
+                });
+            }
+            Err(e) => error!(
+                "Error loading prebaked lighting from {}: {e}",
+                dir.display()
+            ),
+        }
+        return;
+    }
+    match prebaked_dump::dump_prebaked_lighting(&board_data, &dir) {
+        Ok(files) => warn!(
+            "Dev cheat: prebaked lighting dumped to {} ({} floors)",
+            dir.display(),
+            files.len()
+        ),
+        Err(e) => error!("Error dumping prebaked lighting to {}: {e}", dir.display()),
+    }
+}
+
+/// Dev cheat: F9 detaches the camera from the player.
+///
+/// Leaving the free camera (or disabling cheat mode) restores the camera as it
+/// was when the free camera was entered.
+fn keyboard_free_camera(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+    mut free_camera: ResMut<FreeCamera>,
+    mut camera: Query<&mut Transform, With<GCameraArena>>,
+    pc: Query<(&PlayerSprite, &Position)>,
+    gc: Res<GameConfig>,
+) {
+    let cheats = game_settings.dev_cheat_mode.is_enabled();
+    let toggle = cheats && keyboard_input.just_pressed(KeyCode::F9);
+    let force_exit = !cheats && free_camera.active;
+    if !toggle && !force_exit {
+        return;
+    }
+    if free_camera.active {
+        if let Some(saved) = free_camera.saved_transform.take() {
+            for mut transform in camera.iter_mut() {
+                *transform = saved;
+            }
+        }
+        free_camera.active = false;
+        free_camera.focus = None;
+        warn!("Dev cheat: free camera disabled");
+        return;
+    }
+    let player_floor = pc
+        .iter()
+        .find(|(player, _)| player.id == gc.player_id)
+        .map(|(_, pos)| pos.z.round().max(0.0) as usize)
+        .unwrap_or_default();
+    free_camera.floor = player_floor;
+    free_camera.saved_transform = camera.iter().next().copied();
+    free_camera.active = true;
+    warn!("Dev cheat: free camera enabled on floor {player_floor}");
+}
+
+/// Pans, zooms and switches floors while the free camera is active.
+///
+/// Movement and camera keys pan, numpad +/- zoom and PageUp/PageDown change
+/// the inspected floor. The board position under the centre of the view is
+/// stored as the free camera focus.
+fn free_camera_controls(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    control_settings: Res<Persistent<ControlKeys>>,
+    board_data: Res<BoardData>,
+    mut free_camera: ResMut<FreeCamera>,
+    mut camera: Query<&mut Transform, With<GCameraArena>>,
+    time: Res<Time>,
+) {
+    if !free_camera.active {
+        return;
+    }
+    let dt = time.delta_secs() * 60.0;
+    let max_floor = board_data.map_size.2.saturating_sub(1);
+    if keyboard_input.just_pressed(KeyCode::PageUp) && free_camera.floor < max_floor {
+        free_camera.floor += 1;
+        warn!("Free camera: floor {}", free_camera.floor);
+    }
+    if keyboard_input.just_pressed(KeyCode::PageDown) && free_camera.floor > 0 {
+        free_camera.floor -= 1;
+        warn!("Free camera: floor {}", free_camera.floor);
+    }
+    let keys = &control_settings;
+    let pressed = |a: KeyCode, b: KeyCode| keyboard_input.pressed(a) || keyboard_input.pressed(b);
+    for mut transform in camera.iter_mut() {
+        // Pan faster when zoomed out
+        let speed = 4.0 * dt * transform.scale.x;
+        if pressed(keys.right, keys.camera_right) {
+            transform.translation.x += speed;
+        }
+        if pressed(keys.left, keys.camera_left) {
+            transform.translation.x -= speed;
+        }
+        if pressed(keys.up, keys.camera_up) {
+            transform.translation.y += speed;
+        }
+        if pressed(keys.down, keys.camera_down) {
+            transform.translation.y -= speed;
+        }
+        if keyboard_input.pressed(KeyCode::NumpadAdd) {
+            transform.scale.x /= 1.02_f32.powf(dt);
+            transform.scale.y /= 1.02_f32.powf(dt);
+        }
+        if keyboard_input.pressed(KeyCode::NumpadSubtract) {
+            transform.scale.x *= 1.02_f32.powf(dt);
+            transform.scale.y *= 1.02_f32.powf(dt);
+        }
+
+        let floor = free_camera.floor as f32;
+        // Visibility is flood-filled from the focus, so keep it on the board
+        free_camera.focus = Position::from_screen_coord(transform.translation.truncate(), floor)
+            .map(|pos| board_data.clamp_position(pos));
+    }
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(OnEnter(AppState::InGame), setup);
     app.add_systems(OnExit(AppState::InGame), cleanup);
     app.add_systems(
         Update,
-        (keyboard, keyboard_floor_switch).run_if(in_state(AppState::InGame)),
+        (
+            keyboard,
+            keyboard_floor_switch,
+            keyboard_freeze_temperature,
+            keyboard_dump_prebaked_lighting,
+            (keyboard_free_camera, free_camera_controls).chain(),
+        )
+            .run_if(in_state(AppState::InGame)),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_pan_vector() {
+        let size = Vec2::new(800.0, 600.0);
+        let margin = 40.0;
+
+        assert_eq!(edge_pan_vector(size / 2.0, size, margin), Vec2::ZERO);
+        assert_eq!(
+            edge_pan_vector(Vec2::new(40.0, 300.0), size, margin),
+            Vec2::ZERO
+        );
+
+        // Window y grows downwards; world y grows upwards.
+        assert_eq!(
+            edge_pan_vector(Vec2::new(0.0, 300.0), size, margin),
+            Vec2::NEG_X
+        );
+        assert_eq!(
+            edge_pan_vector(Vec2::new(800.0, 300.0), size, margin),
+            Vec2::X
+        );
+        assert_eq!(
+            edge_pan_vector(Vec2::new(400.0, 0.0), size, margin),
+            Vec2::Y
+        );
+        assert_eq!(
+            edge_pan_vector(Vec2::new(400.0, 600.0), size, margin),
+            Vec2::NEG_Y
+        );
+
+        // Halfway into the band pans at half speed; corners pan diagonally.
+        let half = edge_pan_vector(Vec2::new(780.0, 300.0), size, margin);
+        assert!((half.x - 0.5).abs() < 1e-6);
+        assert_eq!(
+            edge_pan_vector(Vec2::ZERO, size, margin),
+            Vec2::new(-1.0, 1.0)
+        );
+
+        // Out-of-window cursors are treated as on the border.
+        assert_eq!(
+            edge_pan_vector(Vec2::new(-50.0, 300.0), size, margin),
+            Vec2::NEG_X
+        );
+        assert_eq!(edge_pan_vector(size / 2.0, Vec2::ZERO, margin), Vec2::ZERO);
+    }
+}
@@ -1,13 +1,17 @@
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
 use uncore::states::AppState;
-use uncore::{
-    components::player_sprite::PlayerSprite, resources::looking_gear::LookingGear,
-    types::evidence::Evidence,
-};
+use uncore::{components::player_sprite::PlayerSprite, resources::looking_gear::LookingGear};
 use ungear::components::playergear::PlayerGear;
+use ungear::gear_usable::GearUsable;
 use unprofile::data::PlayerProfileData;
 
+/// Counts a gear acknowledgement when the player presses the evidence key
+/// while the gear in the looked-at hand is indicating evidence.
+///
+/// Together with the confirm evidence key (`untruck::evidence::confirm_evidence`)
+/// this feeds `times_evidence_acknowledged_on_gear`, which every gear uses to
+/// stop blinking once the player knows the evidence.
 fn acknowledge_blinking_gear_hint_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     player_query: Query<(&PlayerSprite, &PlayerGear)>,
@@ -23,24 +27,14 @@ fn acknowledge_blinking_gear_hint_system(
                 uncore::types::gear::equipmentposition::Hand::Right => &player_gear.right_hand,
             };
 
-            if let Some(gear_data) = &active_gear.data
-                && gear_data.is_blinking_hint_active()
-                && let Ok(evidence_type) = Evidence::try_from(&active_gear.kind)
+            if let Some(evidence_type) = active_gear.current_evidence()
+                && profile_data.acknowledge_evidence_on_gear(evidence_type)
             {
-                const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-                let count = profile_data
-                    .times_evidence_acknowledged_on_gear
-                    .entry(evidence_type)
-                    .or_insert(0);
-
-                if *count < HINT_ACKNOWLEDGE_THRESHOLD {
-                    *count += 1;
-                    info!(
-                        "Acknowledged gear hint for {:?}. New count: {}",
-                        evidence_type, *count
-                    );
-                    profile_data.set_changed();
-                }
+                info!(
+                    "Acknowledged gear hint for {:?}. New count: {}",
+                    evidence_type, profile_data.times_evidence_acknowledged_on_gear[&evidence_type]
+                );
+                profile_data.set_changed();
             }
         }
     }
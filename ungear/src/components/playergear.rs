@@ -5,6 +5,12 @@ use uncore::types::gear::equipmentposition::{EquipmentPosition, Hand};
 use uncore::types::gear_kind::GearKind;
 use uncore::types::ghost::types::GhostType;
 
+/// Returned by [`PlayerGear::try_add`] when every slot is already taken. Carries
+/// the rejected gear so the caller can put it back where it came from.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("no free slot for {:?}", .0.kind)]
+pub struct GearFull(pub Gear);
+
 #[derive(Clone, Debug, Component, Default)]
 pub struct PlayerGear {
     pub left_hand: Gear,
@@ -36,13 +42,39 @@ impl PlayerGear {
         ret
     }
 
-    pub fn append(&mut self, ngear: Gear) {
+    /// Total number of slots: both hands plus the stowed inventory.
+    pub fn capacity(&self) -> usize {
+        2 + self.inventory.len()
+    }
+
+    /// Number of slots that currently hold no gear.
+    pub fn free_slots(&self) -> usize {
+        self.as_vec()
+            .iter()
+            .filter(|(g, _)| matches!(g.kind, GearKind::None))
+            .count()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free_slots() == 0
+    }
+
+    /// Places the gear in the first empty slot (hands first, then inventory).
+    pub fn try_add(&mut self, ngear: Gear) -> Result<(), GearFull> {
         for (pgear, _hand) in self.as_vec_mut() {
             if matches!(pgear.kind, GearKind::None) {
                 *pgear = ngear;
-                return;
+                return Ok(());
             }
         }
+        Err(GearFull(ngear))
+    }
+
+    /// Like `try_add`, but the gear is dropped with a warning if there's no room.
+    pub fn append(&mut self, ngear: Gear) {
+        if let Err(e) = self.try_add(ngear) {
+            warn!("Discarding gear: {e}");
+        }
     }
     pub fn empty_right_handed(&self) -> bool {
         matches!(self.right_hand.kind, GearKind::None)
@@ -163,3 +195,47 @@ impl PlayerGear {
         flask.0.data.as_ref().unwrap().can_fill_liquid(ghost_type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gear(kind: GearKind) -> Gear {
+        Gear { kind, data: None }
+    }
+
+    fn full_gear() -> PlayerGear {
+        PlayerGear {
+            left_hand: gear(GearKind::Flashlight),
+            right_hand: gear(GearKind::Thermometer),
+            inventory: vec![gear(GearKind::EMFMeter), gear(GearKind::Videocam)],
+            held_item: None,
+        }
+    }
+
+    #[test]
+    fn test_try_add_into_full_inventory() {
+        let mut pg = full_gear();
+        assert_eq!(pg.capacity(), 4);
+        assert!(pg.is_full());
+
+        let Err(GearFull(rejected)) = pg.try_add(gear(GearKind::UVTorch)) else {
+            panic!("Adding to a full inventory should fail");
+        };
+        assert_eq!(rejected.kind, GearKind::UVTorch);
+        assert_eq!(pg.inventory[0].kind, GearKind::EMFMeter);
+        assert_eq!(pg.inventory[1].kind, GearKind::Videocam);
+    }
+
+    #[test]
+    fn test_try_add_fills_first_empty_slot() {
+        let mut pg = full_gear();
+        pg.inventory[1] = Gear::none();
+        assert_eq!(pg.free_slots(), 1);
+
+        pg.try_add(gear(GearKind::UVTorch)).unwrap();
+        assert_eq!(pg.inventory[1].kind, GearKind::UVTorch);
+        assert!(pg.is_full());
+        assert_eq!(pg.get_next_non_empty().unwrap().kind, GearKind::EMFMeter);
+    }
+}
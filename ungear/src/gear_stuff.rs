@@ -22,7 +22,8 @@ pub struct GearStuff<'w, 's> {
     pub commands: Commands<'w, 's>,
     /// Provides access to the asset server for loading sound effects.
     pub asset_server: Res<'w, AssetServer>,
-    /// Access to the current game time.
+    /// Access to the current game time. Gear updates run in `FixedUpdate`, so
+    /// the delta here is the fixed step and doesn't spike on a frame hitch.
     pub time: Res<'w, Time>,
     /// Event writer for sending sound events.
     pub sound_events: EventWriter<'w, SoundEvent>,
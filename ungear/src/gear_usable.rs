@@ -3,6 +3,7 @@ use bevy::prelude::Color;
 use std::any::Any;
 use uncore::components::board::position::Position;
 use uncore::components::ghost_sprite::GhostSprite;
+use uncore::types::evidence::Evidence;
 use uncore::types::gear::{equipmentposition::EquipmentPosition, spriteid::GearSpriteID};
 use uncore::types::ghost::types::GhostType; // Added
 
@@ -107,6 +108,26 @@ pub trait GearUsable: std::fmt::Debug + Sync + Send + Any {
         false
     }
 
+    /// Returns the evidence the gear is clearly indicating right now, if any.
+    fn current_evidence(&self) -> Option<Evidence> {
+        None
+    }
+
+    /// Called when the gear leaves the player's hands and is placed on the floor
+    /// as `DeployedGear`.
+    ///
+    /// Together with `on_pickup`, this marks a change of context for the gear.
+    /// Implementations should drop any state that only makes sense for the
+    /// previous context (e.g. smoothed readings) so the next `update` starts
+    /// fresh. Both hooks are called exactly once per transition, before the gear
+    /// is updated in its new context.
+    fn on_deploy(&mut self) {}
+
+    /// Called when deployed gear is picked up again from the floor.
+    ///
+    /// See `on_deploy` for the contract.
+    fn on_pickup(&mut self) {}
+
     // ATTENTION: The "as_any" methods are **NOT NEEDED** for downcasting.
     // .. **DO NOT ENABLE THEM**.
     // fn as_any(&self) -> &dyn Any;
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use uncore::components::game_config::GameConfig;
 use uncore::events::sound::SoundEvent;
+use uncore::resources::audio_limiter::AudioLimiter;
 
 use super::systems;
 
@@ -8,7 +9,9 @@ pub struct UnhaunterGearPlugin;
 
 impl Plugin for UnhaunterGearPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<GameConfig>().add_event::<SoundEvent>();
+        app.init_resource::<GameConfig>()
+            .init_resource::<AudioLimiter>()
+            .add_event::<SoundEvent>();
 
         systems::app_setup(app);
     }
@@ -10,10 +10,13 @@ use uncore::components::game_config::GameConfig;
 use uncore::components::player_inventory::{Inventory, InventoryNext, InventoryStats};
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::events::sound::SoundEvent;
+use uncore::resources::audio_limiter::AudioLimiter;
+use uncore::resources::game_paused::gameplay_running;
 use uncore::resources::looking_gear::LookingGear;
 use uncore::states::GameState;
 use uncore::types::gear::equipmentposition::{EquipmentPosition, Hand};
 use unsettings::audio::{AudioPositioning, AudioSettings, SoundOutput};
+use unsettings::controls::{ControlAction, ControlInput};
 
 /// System for updating the internal state of all gear carried by the player.
 ///
@@ -53,6 +56,11 @@ fn update_deployed_gear_sprites(mut q_gear: Query<(&mut Sprite, &DeployedGearDat
     }
 }
 
+/// Linear volume an effect spawned by `sound_playback_system` is playing at,
+/// so the `AudioLimiter` can add up what is currently audible.
+#[derive(Component, Debug, Clone, Copy)]
+struct PlayingEffect(f32);
+
 /// System to handle the SoundEvent, playing the sound with volume adjusted by
 /// distance and stereo positioning based on audio positioning mode.
 fn sound_playback_system(
@@ -62,7 +70,11 @@ fn sound_playback_system(
     qp: Query<(Entity, &Position, &PlayerSprite)>,
     mut commands: Commands,
     audio_settings: Res<Persistent<AudioSettings>>,
+    limiter: Res<AudioLimiter>,
+    q_playing: Query<&PlayingEffect>,
 ) {
+    // Finished effects are despawned, so this only counts what still plays.
+    let mut playing_sum: f32 = q_playing.iter().map(|p| p.0).sum();
     for sound_event in sound_events.read() {
         // Get player position
         let Some((_player_entity, player_position, _)) =
@@ -84,9 +96,14 @@ fn sound_playback_system(
             adjusted_volume /= 1.0 + dist * 0.4;
         }
 
+        let volume = adjusted_volume * audio_settings.effects_volume();
+        let volume = volume * limiter.gain_for(playing_sum, volume);
+        playing_sum += volume;
+
         // Spawn an AudioBundle with the adjusted volume
-        let mut sound = commands.spawn(AudioPlayer::<AudioSource>(
-            asset_server.load(sound_event.sound_file.clone()),
+        let mut sound = commands.spawn((
+            AudioPlayer::<AudioSource>(asset_server.load(sound_event.sound_file.clone())),
+            PlayingEffect(volume),
         ));
 
         // Apply stereo positioning based on audio positioning mode
@@ -155,11 +172,7 @@ fn sound_playback_system(
 
         sound.insert(PlaybackSettings {
             mode: bevy::audio::PlaybackMode::Despawn,
-            volume: bevy::audio::Volume::Linear(
-                adjusted_volume
-                    * audio_settings.volume_effects.as_f32()
-                    * audio_settings.volume_master.as_f32(),
-            ),
+            volume: bevy::audio::Volume::Linear(volume),
             speed: 1.0,
             paused: false,
             spatial: spatial_enabled,
@@ -173,23 +186,25 @@ fn sound_playback_system(
     }
 }
 
+/// Gear controls from the keyboard and from gamepads, through the bindings in
+/// the player's `ControlKeys`.
 fn keyboard_gear(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     mut q_gear: Query<(&PlayerSprite, &mut PlayerGear)>,
     looking_gear: Res<LookingGear>,
     mut gs: GearStuff,
 ) {
     for (ps, mut playergear) in q_gear.iter_mut() {
-        if keyboard_input.just_pressed(ps.controls.cycle) {
+        if controls.just_pressed(&ps.controls, ControlAction::Cycle) {
             playergear.cycle(&looking_gear.hand());
         }
-        if keyboard_input.just_pressed(ps.controls.swap) {
+        if controls.just_pressed(&ps.controls, ControlAction::Swap) {
             playergear.swap();
         }
-        if keyboard_input.just_released(ps.controls.trigger) {
+        if controls.just_released(&ps.controls, ControlAction::Trigger) {
             playergear.right_hand.set_trigger(&mut gs);
         }
-        if keyboard_input.just_released(ps.controls.torch) {
+        if controls.just_released(&ps.controls, ControlAction::Torch) {
             playergear.left_hand.set_trigger(&mut gs);
         }
     }
@@ -246,10 +261,16 @@ fn update_gear_ui(
 }
 
 pub(crate) fn app_setup(app: &mut App) {
-    app.add_systems(FixedUpdate, update_playerheld_gear_data)
-        .add_systems(FixedUpdate, update_deployed_gear_data)
-        .add_systems(FixedUpdate, update_deployed_gear_sprites)
-        .add_systems(FixedUpdate, update_gear_ui)
-        .add_systems(Update, keyboard_gear.run_if(in_state(GameState::None)))
-        .add_systems(Update, sound_playback_system);
+    app.add_systems(
+        FixedUpdate,
+        update_playerheld_gear_data.run_if(gameplay_running),
+    )
+    .add_systems(
+        FixedUpdate,
+        update_deployed_gear_data.run_if(gameplay_running),
+    )
+    .add_systems(FixedUpdate, update_deployed_gear_sprites)
+    .add_systems(FixedUpdate, update_gear_ui)
+    .add_systems(Update, keyboard_gear.run_if(in_state(GameState::None)))
+    .add_systems(Update, sound_playback_system);
 }
@@ -5,6 +5,7 @@ use uncore::types::gear_kind::GearKind;
 use uncore::types::ghost::types::GhostType;
 use uncore::{
     components::board::position::Position,
+    types::evidence::Evidence,
     types::gear::{equipmentposition::EquipmentPosition, spriteid::GearSpriteID},
 };
 
@@ -180,4 +181,20 @@ impl GearUsable for Gear {
             None => false,
         }
     }
+
+    fn current_evidence(&self) -> Option<Evidence> {
+        self.data.as_ref().and_then(|x| x.current_evidence())
+    }
+
+    fn on_deploy(&mut self) {
+        if let Some(x) = &mut self.data {
+            x.on_deploy()
+        }
+    }
+
+    fn on_pickup(&mut self) {
+        if let Some(x) = &mut self.data {
+            x.on_pickup()
+        }
+    }
 }
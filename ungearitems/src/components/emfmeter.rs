@@ -229,16 +229,9 @@ impl GearUsable for EMFMeter {
             self.emf_level = EMFLevel::from_milligauss(self.emf);
 
             // Update blinking_hint_active
-            const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-            if self.emf_level == EMFLevel::EMF5 {
-                let count = gs
-                    .player_profile
-                    .times_evidence_acknowledged_on_gear
-                    .get(&Evidence::EMFLevel5)
-                    .copied()
-                    .unwrap_or(0);
-                self.blinking_hint_active = count < HINT_ACKNOWLEDGE_THRESHOLD;
-            }
+            self.blinking_hint_active = self
+                .current_evidence()
+                .is_some_and(|ev| gs.player_profile.gear_hint_needed(ev));
         }
         if self.enabled {
             let delta = 10.0 / (self.emf + 0.5).powf(1.5);
@@ -327,6 +320,10 @@ impl GearUsable for EMFMeter {
     fn is_blinking_hint_active(&self) -> bool {
         self.blinking_hint_active
     }
+
+    fn current_evidence(&self) -> Option<Evidence> {
+        (self.is_status_text_showing_evidence() > 0.0).then_some(Evidence::EMFLevel5)
+    }
 }
 
 impl From<EMFMeter> for Gear {
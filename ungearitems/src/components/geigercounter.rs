@@ -192,29 +192,13 @@ impl GearUsable for GeigerCounter {
         if self.display_secs_since_last_update > 0.5 {
             self.display_secs_since_last_update = 0.0; // Reset the timer
             self.sound_display = self.output_sound; // Update the display value
-
-            // Update blinking_hint_active
-            const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-            // Consider evidence showing if cpm is >= 500 and not glitching
-            if self.sound_display >= 499.9 && self.display_glitch_timer <= 0.0 {
-                let count = gs
-                    .player_profile
-                    .times_evidence_acknowledged_on_gear
-                    .get(&Evidence::CPM500)
-                    .copied()
-                    .unwrap_or(0);
-                self.blinking_hint_active = count < HINT_ACKNOWLEDGE_THRESHOLD;
-            } else {
-                self.blinking_hint_active = false;
-            }
-        } else {
-            // Ensure blinking_hint_active is false if not updating display this frame,
-            // or if we want it to strictly follow the evidence condition.
-            if !(self.sound_display >= 499.9 && self.display_glitch_timer <= 0.0) {
-                self.blinking_hint_active = false;
-            }
         }
 
+        // Update blinking_hint_active
+        self.blinking_hint_active = self
+            .current_evidence()
+            .is_some_and(|ev| gs.player_profile.gear_hint_needed(ev));
+
         // Decrement glitch timer if active
         if self.display_glitch_timer > 0.0 {
             self.display_glitch_timer -= gs.time.delta_secs();
@@ -260,6 +244,12 @@ impl GearUsable for GeigerCounter {
     fn is_blinking_hint_active(&self) -> bool {
         self.blinking_hint_active
     }
+
+    /// Evidence shows when the displayed cpm reaches 500 and the display isn't glitching.
+    fn current_evidence(&self) -> Option<Evidence> {
+        (self.is_enabled() && self.sound_display >= 499.9 && self.display_glitch_timer <= 0.0)
+            .then_some(Evidence::CPM500)
+    }
 }
 
 impl From<GeigerCounter> for Gear {
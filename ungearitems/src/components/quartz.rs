@@ -6,6 +6,7 @@ use crate::metrics;
 use bevy::prelude::*;
 use uncore::{
     components::{board::position::Position, ghost_sprite::GhostSprite},
+    difficulty::DifficultyStruct,
     metric_recorder::SendMetric,
     types::gear::equipmentposition::EquipmentPosition,
 };
@@ -13,6 +14,17 @@ use ungear::components::{deployedgear::DeployedGearData, playergear::PlayerGear}
 
 const MAX_CRACKS: u8 = 4;
 
+/// Condition of a quartz stone, from the number of cracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuartzState {
+    /// Never cracked.
+    Intact,
+    /// Cracked this many times, it still absorbs hunts.
+    Cracked(u8),
+    /// Broken, it no longer absorbs anything.
+    Shattered,
+}
+
 /// Data structure for the Quartz Stone consumable.
 #[derive(Component, Debug, Clone, Default, PartialEq)]
 pub struct QuartzStoneData {
@@ -24,6 +36,34 @@ pub struct QuartzStoneData {
     pub energy_absorbed: f32,
 }
 
+impl QuartzStoneData {
+    pub fn state(&self) -> QuartzState {
+        match self.cracks {
+            0 => QuartzState::Intact,
+            n if n < MAX_CRACKS => QuartzState::Cracked(n),
+            _ => QuartzState::Shattered,
+        }
+    }
+
+    /// Cracks the stone once it has absorbed more than `threshold` energy.
+    /// Returns the new state if it cracked.
+    pub fn crack_if_full(&mut self, threshold: f32) -> Option<QuartzState> {
+        if self.state() == QuartzState::Shattered || self.energy_absorbed <= threshold {
+            return None;
+        }
+        self.energy_absorbed = 0.0;
+        self.cracked_time = 5.0;
+        self.cracks += 1;
+        Some(self.state())
+    }
+}
+
+/// Hunting energy a quartz stone absorbs before each crack. Longer hunts carry
+/// more energy, so the stone holds more of it on difficulties with longer hunts.
+pub fn quartz_crack_threshold(difficulty: &DifficultyStruct) -> f32 {
+    10.0 * difficulty.ghost_hunt_duration.sqrt() * difficulty.quartz_durability
+}
+
 impl GearUsable for QuartzStoneData {
     fn get_display_name(&self) -> &'static str {
         "Quartz Stone"
@@ -53,14 +93,10 @@ impl GearUsable for QuartzStoneData {
     }
 
     fn update(&mut self, gs: &mut GearStuff, pos: &Position, _ep: &EquipmentPosition) {
-        if self.energy_absorbed > 10.0 * gs.difficulty.0.ghost_hunt_duration.sqrt()
-            && self.cracks <= MAX_CRACKS
+        if self
+            .crack_if_full(quartz_crack_threshold(&gs.difficulty.0))
+            .is_some()
         {
-            self.energy_absorbed = 0.0;
-            self.cracked_time = 5.0;
-            // Increment cracks
-            self.cracks += 1;
-
             // Play cracking sound
             gs.play_audio("sounds/quartz_crack.ogg".into(), 1.0, pos);
         }
@@ -165,3 +201,57 @@ fn update_quartz_and_ghost(
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(Update, update_quartz_and_ghost);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uncore::difficulty::Difficulty;
+
+    #[test]
+    fn test_quartz_cracks_then_shatters() {
+        let mut quartz = QuartzStoneData::default();
+        assert_eq!(quartz.state(), QuartzState::Intact);
+        // Not enough energy yet
+        quartz.energy_absorbed = 5.0;
+        assert_eq!(quartz.crack_if_full(10.0), None);
+
+        quartz.energy_absorbed = 11.0;
+        assert_eq!(quartz.crack_if_full(10.0), Some(QuartzState::Cracked(1)));
+        assert_eq!(quartz.energy_absorbed, 0.0);
+        for cracks in 2..MAX_CRACKS {
+            quartz.energy_absorbed = 11.0;
+            assert_eq!(
+                quartz.crack_if_full(10.0),
+                Some(QuartzState::Cracked(cracks))
+            );
+        }
+        quartz.energy_absorbed = 11.0;
+        assert_eq!(quartz.crack_if_full(10.0), Some(QuartzState::Shattered));
+
+        // A shattered stone stays shattered.
+        quartz.energy_absorbed = 11.0;
+        assert_eq!(quartz.crack_if_full(10.0), None);
+        assert_eq!(quartz.cracks, MAX_CRACKS);
+    }
+
+    #[test]
+    fn test_master_cracks_quartz_sooner_than_tutorial() {
+        let tutorial = Difficulty::TutorialChapter1.create_difficulty_struct();
+        let master = Difficulty::MasterChallenge.create_difficulty_struct();
+        // Absorb the same energy every second until the stone shatters.
+        let seconds_to_shatter = |threshold: f32| {
+            let mut quartz = QuartzStoneData::default();
+            let mut seconds = 0;
+            while quartz.state() != QuartzState::Shattered {
+                quartz.energy_absorbed += 0.5;
+                quartz.crack_if_full(threshold);
+                seconds += 1;
+            }
+            seconds
+        };
+        assert!(
+            seconds_to_shatter(quartz_crack_threshold(&master))
+                < seconds_to_shatter(quartz_crack_threshold(&tutorial))
+        );
+    }
+}
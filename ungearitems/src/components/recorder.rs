@@ -214,21 +214,9 @@ impl GearUsable for Recorder {
         }
 
         // Update blinking_hint_active
-        const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-        // We consider "showing evidence strongly" when an EVP has been recorded at least once.
-        // The `evp_recorded_display` flag is for the temporary "- EVP RECORDED -" message,
-        // while `evp_recorded_count > 0` indicates that evidence has been found.
-        if self.evp_recorded_count > 0 && self.display_glitch_timer <= 0.0 {
-            let count = gs
-                .player_profile
-                .times_evidence_acknowledged_on_gear
-                .get(&Evidence::EVPRecording)
-                .copied()
-                .unwrap_or(0);
-            self.blinking_hint_active = count < HINT_ACKNOWLEDGE_THRESHOLD;
-        } else {
-            self.blinking_hint_active = false;
-        }
+        self.blinking_hint_active = self
+            .current_evidence()
+            .is_some_and(|ev| gs.player_profile.gear_hint_needed(ev));
     }
 
     fn is_status_text_showing_evidence(&self) -> f32 {
@@ -247,6 +235,13 @@ impl GearUsable for Recorder {
         self.blinking_hint_active
     }
 
+    /// We consider "showing evidence strongly" when an EVP has been recorded at least once.
+    /// The `evp_recorded_display` flag is for the temporary "- EVP RECORDED -" message,
+    /// while `evp_recorded_count > 0` indicates that evidence has been found.
+    fn current_evidence(&self) -> Option<Evidence> {
+        (self.is_status_text_showing_evidence() > 0.0).then_some(Evidence::EVPRecording)
+    }
+
     fn is_electronic(&self) -> bool {
         true
     }
@@ -4,7 +4,7 @@ use ndarray::Array3;
 use uncore::components::board::boardposition::BoardPosition;
 use uncore::components::board::mapcolour::MapColour;
 use uncore::components::board::{direction::Direction, position::Position};
-use uncore::components::repellent_particle::RepellentParticle;
+use uncore::components::repellent_particle::{RepellentParticle, repellent_range_factor};
 use uncore::components::sprite_type::SpriteType;
 use uncore::metric_recorder::SendMetric;
 use uncore::random_seed;
@@ -128,6 +128,7 @@ impl GearUsable for RepellentFlask {
             self.active = false;
             return;
         };
+        let origin = *pos;
         let mut pos = *pos;
         pos.z += 0.2;
         let spread: f32 = if matches!(ep, EquipmentPosition::Deployed) {
@@ -147,7 +148,7 @@ impl GearUsable for RepellentFlask {
             .insert(MapColour {
                 color: css::YELLOW.with_alpha(0.3).with_blue(0.02).into(),
             })
-            .insert(RepellentParticle::new(liquid_content))
+            .insert(RepellentParticle::new(liquid_content, origin))
             .insert(SpriteType::Other);
     }
 
@@ -345,8 +346,15 @@ fn repellent_update(
             let dist2 = g_pos.distance2(&r_pos);
             if dist2 < 4.5 {
                 let dist2b = (dist2 + 1.0) * 2.0;
-                if ghost.class == rep.class {
-                    ghost.repellent_hits_frame += dt * 120.2 / dist2b;
+                let effectiveness = rep.effectiveness(ghost.class);
+                if effectiveness > 0.0 {
+                    // Repellent sprayed from too far away barely reaches the ghost
+                    let range_factor = repellent_range_factor(
+                        rep.origin.distance(g_pos),
+                        difficulty.0.repellent_effective_range,
+                    );
+                    ghost.repellent_hits_frame +=
+                        dt * 120.2 * effectiveness * range_factor / dist2b;
                     // Correct repellent - turn electric blue
                     rep.hit_correct = true;
                 } else {
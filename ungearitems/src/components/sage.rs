@@ -5,6 +5,7 @@ use crate::metrics;
 use super::{Gear, GearKind, GearSpriteID, GearStuff, GearUsable};
 use bevy::prelude::*;
 use rand::Rng;
+use std::time::Duration;
 use uncore::components::board::mapcolour::MapColour;
 use uncore::metric_recorder::SendMetric;
 use uncore::random_seed;
@@ -32,6 +33,52 @@ pub struct SageBundleData {
     pub smoke_produced: usize,
 }
 
+/// Stage of a sage bundle's life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SageState {
+    Unlit,
+    Burning,
+    /// Burned out, it can't be lit again.
+    Spent,
+}
+
+impl SageBundleData {
+    pub fn state(&self) -> SageState {
+        if self.consumed {
+            SageState::Spent
+        } else if self.is_active {
+            SageState::Burning
+        } else {
+            SageState::Unlit
+        }
+    }
+
+    /// Lights an unlit bundle to burn for `burn_secs`. Returns the new state if
+    /// it was lit.
+    pub fn light(&mut self, burn_secs: f32) -> Option<SageState> {
+        if self.state() != SageState::Unlit {
+            return None;
+        }
+        self.is_active = true;
+        self.burn_timer = Timer::from_seconds(burn_secs, TimerMode::Once);
+        Some(self.state())
+    }
+
+    /// Burns a lit bundle for `delta`. Returns the new state if it burned out.
+    pub fn burn(&mut self, delta: Duration) -> Option<SageState> {
+        if self.state() != SageState::Burning {
+            return None;
+        }
+        self.burn_timer.tick(delta);
+        if !self.burn_timer.just_finished() {
+            return None;
+        }
+        self.is_active = false;
+        self.consumed = true;
+        Some(self.state())
+    }
+}
+
 impl Default for SageBundleData {
     fn default() -> Self {
         Self {
@@ -63,53 +110,46 @@ impl GearUsable for SageBundleData {
     }
 
     fn set_trigger(&mut self, gs: &mut GearStuff) {
-        if !self.is_active && !self.consumed {
-            self.is_active = true;
-            self.burn_timer.reset();
-
+        if self.light(gs.difficulty.0.sage_burn_secs).is_some() {
             // Play activation sound
             gs.play_audio_nopos("sounds/sage_activation.ogg".into(), 0.8);
         }
     }
 
     fn update(&mut self, gs: &mut GearStuff, pos: &Position, _ep: &EquipmentPosition) {
-        if self.is_active && !self.consumed {
-            self.burn_timer.tick(gs.time.delta());
-
-            // Spawn smoke particles
-            if self.burn_timer.just_finished() {
-                self.is_active = false;
-                self.consumed = true;
-            } else if (self.smoke_produced as f32) < self.burn_timer.elapsed_secs() * 3.0 {
-                let mut pos = *pos;
-                let mut rng = random_seed::rng();
-                pos.z += 0.2;
-                pos.x += rng.random_range(-0.2..0.2);
-                pos.y += rng.random_range(-0.2..0.2);
-
-                // Spawn smoke particle
-                gs.commands
-                    .spawn(Sprite {
-                        image: gs.asset_server.load("img/smoke.png"),
-                        ..default()
-                    })
-                    .insert(
-                        Transform::from_translation(pos.to_screen_coord())
-                            .with_scale(Vec3::new(0.2, 0.2, 0.2)),
-                    )
-                    .insert(SageSmokeParticle)
-                    .insert(GameSprite)
-                    .insert(pos)
-                    .insert(MapColour {
-                        color: Color::WHITE.with_alpha(0.00),
-                    })
-                    .insert(SmokeParticleTimer(Timer::from_seconds(
-                        5.0,
-                        TimerMode::Once,
-                    )))
-                    .insert(SpriteType::Other);
-                self.smoke_produced += 1;
-            }
+        // Spawn smoke particles while it burns
+        if self.state() == SageState::Burning
+            && self.burn(gs.time.delta()).is_none()
+            && (self.smoke_produced as f32) < self.burn_timer.elapsed_secs() * 3.0
+        {
+            let mut pos = *pos;
+            let mut rng = random_seed::rng();
+            pos.z += 0.2;
+            pos.x += rng.random_range(-0.2..0.2);
+            pos.y += rng.random_range(-0.2..0.2);
+
+            // Spawn smoke particle
+            gs.commands
+                .spawn(Sprite {
+                    image: gs.asset_server.load("img/smoke.png"),
+                    ..default()
+                })
+                .insert(
+                    Transform::from_translation(pos.to_screen_coord())
+                        .with_scale(Vec3::new(0.2, 0.2, 0.2)),
+                )
+                .insert(SageSmokeParticle)
+                .insert(GameSprite)
+                .insert(pos)
+                .insert(MapColour {
+                    color: Color::WHITE.with_alpha(0.00),
+                })
+                .insert(SmokeParticleTimer(Timer::from_seconds(
+                    5.0,
+                    TimerMode::Once,
+                )))
+                .insert(SpriteType::Other);
+            self.smoke_produced += 1;
         }
     }
 
@@ -121,14 +161,14 @@ impl GearUsable for SageBundleData {
         if !self.is_active {
             return GearSpriteID::SageBundle0;
         }
-        let remaining_time = self.burn_timer.remaining_secs();
-        if remaining_time > 5.0 {
+        let remaining = self.burn_timer.fraction_remaining();
+        if remaining > 0.625 {
             return GearSpriteID::SageBundle1;
         }
-        if remaining_time > 3.0 {
+        if remaining > 0.375 {
             return GearSpriteID::SageBundle2;
         }
-        if remaining_time > 0.0 {
+        if remaining > 0.0 {
             return GearSpriteID::SageBundle3;
         }
 
@@ -225,3 +265,42 @@ fn sage_smoke_system(
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(Update, sage_smoke_system);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uncore::difficulty::Difficulty;
+
+    #[test]
+    fn test_sage_lights_burns_and_is_spent() {
+        let mut sage = SageBundleData::default();
+        assert_eq!(sage.state(), SageState::Unlit);
+        // It doesn't burn until lit
+        assert_eq!(sage.burn(Duration::from_secs(10)), None);
+        assert_eq!(sage.state(), SageState::Unlit);
+
+        assert_eq!(sage.light(8.0), Some(SageState::Burning));
+        assert_eq!(sage.light(8.0), None);
+        assert_eq!(sage.burn(Duration::from_secs(5)), None);
+        assert_eq!(sage.state(), SageState::Burning);
+        assert_eq!(sage.burn(Duration::from_secs(5)), Some(SageState::Spent));
+
+        // A spent bundle can't be lit again.
+        assert_eq!(sage.light(8.0), None);
+        assert_eq!(sage.state(), SageState::Spent);
+    }
+
+    #[test]
+    fn test_master_burns_sage_faster_than_tutorial() {
+        let tutorial = Difficulty::TutorialChapter1.create_difficulty_struct();
+        let master = Difficulty::MasterChallenge.create_difficulty_struct();
+        let mut tutorial_sage = SageBundleData::default();
+        let mut master_sage = SageBundleData::default();
+        tutorial_sage.light(tutorial.sage_burn_secs);
+        master_sage.light(master.sage_burn_secs);
+
+        let elapsed = Duration::from_secs_f32(master.sage_burn_secs);
+        assert_eq!(master_sage.burn(elapsed), Some(SageState::Spent));
+        assert_eq!(tutorial_sage.burn(elapsed), None);
+    }
+}
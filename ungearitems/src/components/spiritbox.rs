@@ -241,20 +241,9 @@ impl GearUsable for SpiritBox {
         }
 
         // Update blinking_hint_active
-        const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-        // Spirit Box shows evidence when ghost_answer is true and it's not glitching/interfered.
-        if self.ghost_answer && self.display_glitch_timer <= 0.0 && self.interference2_timer <= 0.0
-        {
-            let count = gs
-                .player_profile
-                .times_evidence_acknowledged_on_gear
-                .get(&Evidence::SpiritBox)
-                .copied()
-                .unwrap_or(0);
-            self.blinking_hint_active = count < HINT_ACKNOWLEDGE_THRESHOLD;
-        } else {
-            self.blinking_hint_active = false;
-        }
+        self.blinking_hint_active = self
+            .current_evidence()
+            .is_some_and(|ev| gs.player_profile.gear_hint_needed(ev));
     }
     fn is_electronic(&self) -> bool {
         true
@@ -294,6 +283,12 @@ impl GearUsable for SpiritBox {
     fn is_blinking_hint_active(&self) -> bool {
         self.blinking_hint_active
     }
+
+    /// Spirit Box shows evidence when the ghost answers and it's not glitching/interfered.
+    fn current_evidence(&self) -> Option<Evidence> {
+        (self.is_sound_showing_evidence() > 0.0 && self.interference2_timer <= 0.0)
+            .then_some(Evidence::SpiritBox)
+    }
 }
 
 impl From<SpiritBox> for Gear {
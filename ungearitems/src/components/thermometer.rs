@@ -2,6 +2,7 @@ use crate::metrics;
 
 use super::{Gear, GearKind, GearSpriteID, GearUsable, on_off};
 use bevy::prelude::*;
+use ndarray::Array3;
 use rand::Rng;
 use uncore::behaviour::Behaviour;
 use uncore::components::board::boardposition::BoardPosition;
@@ -10,8 +11,11 @@ use uncore::components::ghost_sprite::GhostSprite;
 use uncore::difficulty::CurrentDifficulty;
 use uncore::metric_recorder::SendMetric;
 use uncore::random_seed;
-use uncore::resources::board_data::BoardData;
+use uncore::resources::board_data::{BoardData, TemperatureDiffusionConfig};
+use uncore::resources::game_paused::{GamePaused, gameplay_running};
 use uncore::resources::roomdb::RoomDB;
+use uncore::states::AppState;
+use uncore::types::board::fielddata::CollisionFieldData;
 use uncore::types::evidence::Evidence;
 use uncore::types::gear::equipmentposition::EquipmentPosition;
 use uncore::{celsius_to_kelvin, kelvin_to_celsius};
@@ -25,6 +29,10 @@ pub struct Thermometer {
     pub frame_counter: u16,
     pub display_glitch_timer: f32,
     pub blinking_hint_active: bool,
+    /// Set when the thermometer changes context (deployed or picked up), so the
+    /// next reading seeds the smoothing filters instead of blending with stale
+    /// values from the previous location.
+    pub needs_calibration: bool,
 }
 
 impl Default for Thermometer {
@@ -37,10 +45,21 @@ impl Default for Thermometer {
             frame_counter: Default::default(),
             display_glitch_timer: Default::default(),
             blinking_hint_active: false,
+            needs_calibration: false,
         }
     }
 }
 
+impl Thermometer {
+    /// Seeds both smoothing stages and the displayed value with `reading`.
+    pub fn calibrate(&mut self, reading: f32) {
+        self.temp_l1 = reading;
+        self.temp_l2 = [reading; 5];
+        self.temp = (reading * 5.0).round() / 5.0;
+        self.needs_calibration = false;
+    }
+}
+
 impl GearUsable for Thermometer {
     fn get_sprite_idx(&self) -> GearSpriteID {
         match self.enabled {
@@ -109,6 +128,9 @@ impl GearUsable for Thermometer {
         let temperature = gs.bf.temperature_field[bpos.ndidx()];
         let temp_reading = temperature;
         let air_mass: f32 = 5.0 / gs.difficulty.0.equipment_sensitivity;
+        if self.needs_calibration {
+            self.calibrate(temp_reading);
+        }
 
         // Double noise reduction to remove any noise from measurement.
         let n = self.frame_counter as usize % self.temp_l2.len();
@@ -118,29 +140,13 @@ impl GearUsable for Thermometer {
             let sum_temp: f32 = self.temp_l2.iter().sum();
             let avg_temp: f32 = sum_temp / self.temp_l2.len() as f32;
             self.temp = (avg_temp * 5.0).round() / 5.0;
-
-            // Update blinking_hint_active
-            const HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
-            if kelvin_to_celsius(self.temp) < 0.0 && self.display_glitch_timer <= 0.0 {
-                let count = gs
-                    .player_profile
-                    .times_evidence_acknowledged_on_gear
-                    .get(&Evidence::FreezingTemp)
-                    .copied()
-                    .unwrap_or(0);
-                self.blinking_hint_active = count < HINT_ACKNOWLEDGE_THRESHOLD;
-            } else {
-                self.blinking_hint_active = false;
-            }
-        } else {
-            // Ensure blinking_hint_active is false if not updating temp this frame,
-            // or if we want it to strictly follow the evidence condition.
-            // For now, let's ensure it's false if the condition isn't met.
-            if !(kelvin_to_celsius(self.temp) < 0.0 && self.display_glitch_timer <= 0.0) {
-                self.blinking_hint_active = false;
-            }
         }
 
+        // Update blinking_hint_active
+        self.blinking_hint_active = self
+            .current_evidence()
+            .is_some_and(|ev| gs.player_profile.gear_hint_needed(ev));
+
         // Decrement glitch timer if active
         if self.display_glitch_timer > 0.0 {
             self.display_glitch_timer -= gs.time.delta_secs();
@@ -166,6 +172,14 @@ impl GearUsable for Thermometer {
         Box::new(self.clone())
     }
 
+    fn on_deploy(&mut self) {
+        self.needs_calibration = true;
+    }
+
+    fn on_pickup(&mut self) {
+        self.needs_calibration = true;
+    }
+
     fn is_electronic(&self) -> bool {
         true
     }
@@ -217,6 +231,10 @@ impl GearUsable for Thermometer {
     fn is_blinking_hint_active(&self) -> bool {
         self.blinking_hint_active
     }
+
+    fn current_evidence(&self) -> Option<Evidence> {
+        (self.is_status_text_showing_evidence() > 0.0).then_some(Evidence::FreezingTemp)
+    }
 }
 
 impl From<Thermometer> for Gear {
@@ -225,16 +243,98 @@ impl From<Thermometer> for Gear {
     }
 }
 
+/// Aggregated statistics of the temperature diffusion for the current mission.
+///
+/// Reset when a mission starts and dumped to the log when it ends, so performance
+/// on big maps can be checked without a profiler.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TemperatureMissionStats {
+    /// Number of frames where the diffusion ran.
+    pub frames: u64,
+    /// Sum of tiles updated over all frames.
+    pub tiles_updated: u64,
+    /// Largest single energy exchange between two tiles, after stability limits.
+    pub peak_energy_change: f32,
+    /// Number of tile updates discarded because the averaged energy was not finite
+    /// or not positive.
+    pub nan_rejections: u64,
+}
+
+impl TemperatureMissionStats {
+    pub fn record_frame(
+        &mut self,
+        tiles_updated: usize,
+        peak_energy_change: f32,
+        nan_rejections: u64,
+    ) {
+        self.frames += 1;
+        self.tiles_updated += tiles_updated as u64;
+        self.peak_energy_change = self.peak_energy_change.max(peak_energy_change);
+        self.nan_rejections += nan_rejections;
+    }
+
+    pub fn avg_tiles_per_frame(&self) -> f32 {
+        if self.frames == 0 {
+            0.0
+        } else {
+            self.tiles_updated as f32 / self.frames as f32
+        }
+    }
+
+    /// Writes the aggregated statistics to the log.
+    pub fn log_summary(&self) {
+        info!(
+            "Temperature diffusion stats: {} frames, avg {:.1} tiles updated/frame, peak energy change {:.1}, {} NaN rejections",
+            self.frames,
+            self.avg_tiles_per_frame(),
+            self.peak_energy_change,
+            self.nan_rejections
+        );
+        if self.nan_rejections > 0 {
+            warn!(
+                "Temperature diffusion rejected {} non-finite updates during this mission",
+                self.nan_rejections
+            );
+        }
+    }
+}
+
+fn reset_temperature_mission_stats(mut stats: ResMut<TemperatureMissionStats>) {
+    *stats = TemperatureMissionStats::default();
+}
+
 fn temperature_update(
     mut bf: ResMut<BoardData>,
+    mut stats: ResMut<TemperatureMissionStats>,
     roomdb: Res<RoomDB>,
     qt: Query<(&Position, &Behaviour)>,
     qg: Query<(&GhostSprite, &Position)>,
     // Access the difficulty settings
     difficulty: Res<CurrentDifficulty>,
+    time: Res<Time>,
+    paused: Res<GamePaused>,
+    mut was_frozen: Local<bool>,
 ) {
+    let frozen = bf.temp_diffusion_config.frozen;
+    if frozen != *was_frozen {
+        *was_frozen = frozen;
+        if frozen {
+            info!("Temperature simulation frozen (dev toggle)");
+        } else {
+            // Resync the previous-frame snapshot so the gradient doesn't jump on resume
+            let current = bf.temperature_field.clone();
+            bf.temperature_field_prev = current;
+            info!("Temperature simulation resumed");
+        }
+    }
+    if frozen {
+        return;
+    }
     let measure = metrics::TEMPERATURE_UPDATE.time_measure();
     let freezing = bf.ghost_dynamics.freezing_temp_clarity;
+    // Heat and cold were tuned per frame at 60 FPS. The clamped delta keeps a
+    // frame hitch from dumping seconds' worth of heat into a tile at once.
+    let dt = paused.delta_secs(&time) * 60.0;
 
     for (pos, bh) in qt.iter() {
         let h_out = bh.temp_heat_output();
@@ -244,7 +344,7 @@ fn temperature_update(
         let bpos = pos.to_board_position();
         let prev_temp = bf.temperature_field[bpos.ndidx()];
         let k = (f32::tanh((19.0 - prev_temp) / 5.0) + 1.0) / 2.0;
-        let t_out = h_out * k * 0.2 * difficulty.0.light_heat;
+        let t_out = h_out * k * 0.2 * difficulty.0.light_heat * dt;
         bf.temperature_field[bpos.ndidx()] += t_out;
     }
     for (gs, pos) in qg.iter() {
@@ -271,7 +371,7 @@ fn temperature_update(
                 // Calculate distance-based power decay
                 let distance2 = npos.distance2(&bpos) + 1.0; // Add small constant to avoid division by zero
                 let distance_decay = 1.0 / distance2;
-                let effective_power = GHOST_MAX_POWER * power * distance_decay;
+                let effective_power = GHOST_MAX_POWER * power * distance_decay * dt;
 
                 let t = &mut bf.temperature_field[npos.ndidx()];
                 *t = (*t + ghost_target_temp * effective_power) / (1.0 + effective_power);
@@ -288,14 +388,51 @@ fn temperature_update(
             // Calculate distance-based power decay
             let distance2 = npos.distance2(&gs.spawn_point) + 1.0; // Add small constant to avoid division by zero
             let distance_decay = 1.0 / distance2;
-            let effective_power = BREACH_MAX_POWER * power * distance_decay;
+            let effective_power = BREACH_MAX_POWER * power * distance_decay * dt;
 
             let t = &mut bf.temperature_field[npos.ndidx()];
             *t = (*t + ghost_target_temp * effective_power) / (1.0 + effective_power);
         }
     }
 
-    let mut rng = random_seed::rng();
+    if bf.temp_diffusion_config.miasma_advection {
+        miasma_advection_update(&mut bf);
+    }
+
+    let step = temperature_diffusion_step(&mut bf, &roomdb, &mut random_seed::rng());
+    stats.record_frame(
+        step.tiles_updated,
+        step.peak_energy_change,
+        step.nan_rejections,
+    );
+
+    measure.end_ms();
+}
+
+/// Outcome of one `temperature_diffusion_step`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DiffusionStep {
+    pub tiles_updated: usize,
+    /// Largest single energy exchange between two tiles, after stability limits.
+    pub peak_energy_change: f32,
+    /// Largest change of a tile's temperature in this step, in kelvin.
+    pub max_temp_change: f32,
+    /// Tile updates discarded because the averaged energy was not finite or not
+    /// positive.
+    pub nan_rejections: u64,
+}
+
+/// Runs one frame of heat diffusion between neighbouring tiles.
+///
+/// Tiles are picked at random, weighted by how much they changed lately, and
+/// exchange energy (E = T³) with their neighbours. All the exchanges of the
+/// frame are averaged in energy space before being applied. Heat sources and
+/// the ghost's cold are applied by `temperature_update` before this.
+pub fn temperature_diffusion_step(
+    bf: &mut BoardData,
+    roomdb: &RoomDB,
+    rng: &mut impl Rng,
+) -> DiffusionStep {
     let old_temps: Vec<(_, _)> = bf
         .temperature_field
         .indexed_iter()
@@ -314,31 +451,122 @@ fn temperature_update(
             }
         })
         .collect();
-    const OUTSIDE_CONDUCTIVITY: f32 = 1000000.0;
-    const INSIDE_CONDUCTIVITY: f32 = 80000.0;
+    #[cfg(not(feature = "parallel-diffusion"))]
+    let (energy_changes, frame_peak_energy_change) = collect_energy_changes(bf, roomdb, &old_temps);
+    #[cfg(feature = "parallel-diffusion")]
+    let (energy_changes, frame_peak_energy_change) =
+        collect_energy_changes_parallel(bf, roomdb, &old_temps);
+
+    // Apply all accumulated energy changes by averaging them in energy space
+    // and track activity for adaptive selection
+    let mut updated_positions = std::collections::HashSet::new();
+    let mut debug_total_activity = 0.0;
+    let mut debug_activity_count = 0;
+    let mut nan_rejections = 0;
+    let mut max_temp_change: f32 = 0.0;
+    for (pos_idx, energy_list) in energy_changes {
+        if !energy_list.is_empty() {
+            let old_temp = bf.temperature_field[pos_idx];
+
+            // Average all energies in energy space
+            let avg_energy = energy_list.iter().sum::<f32>() / energy_list.len() as f32;
+
+            // Check for NaN and clamp energy to reasonable bounds
+            if avg_energy.is_finite() && avg_energy > 0.0 {
+                // Convert back to temperature using T = ∛E
+                let new_temp = avg_energy
+                    .cbrt()
+                    .clamp(celsius_to_kelvin(-50.0), celsius_to_kelvin(100.0));
+                bf.temperature_field[pos_idx] = new_temp;
+                max_temp_change = max_temp_change.max((new_temp - old_temp).abs());
+
+                // Update activity: track the sum of absolute temperature changes for this tile
+                // Convert energy changes back to temperature space for reasonable activity values
+                let mut total_temp_change = 0.0;
+                for energy in &energy_list {
+                    let temp_from_energy = energy.cbrt();
+                    total_temp_change += (temp_from_energy - old_temp).abs();
+                }
+
+                // Debug tracking
+                debug_total_activity += total_temp_change;
+                debug_activity_count += 1;
+
+                let current_activity = bf.temperature_activity.get(pos_idx).copied().unwrap_or(0.0);
+                // Exponential moving average with activity addition
+                let new_activity = (current_activity / 1.05) + total_temp_change;
+                bf.temperature_activity[pos_idx] = new_activity;
+
+                updated_positions.insert(pos_idx);
+            } else {
+                nan_rejections += 1;
+            }
+        }
+    }
+    // Debug output for activity tracking
+    if debug_activity_count > 0 {
+        let avg_activity = debug_total_activity / debug_activity_count as f32;
+
+        // Calculate average activity across all tiles
+        let total_tiles = bf.temperature_activity.len();
+        let sum_activity: f32 = bf.temperature_activity.iter().sum();
+        let avg_tile_activity = if total_tiles > 0 {
+            sum_activity / total_tiles as f32
+        } else {
+            0.0
+        };
 
+        debug!(
+            "Frame activity debug: {} tiles updated, avg total_temp_change: {:.6}, avg_tile_activity: {:.6}",
+            debug_activity_count, avg_activity, avg_tile_activity
+        );
+    }
+
+    DiffusionStep {
+        tiles_updated: debug_activity_count,
+        peak_energy_change: frame_peak_energy_change,
+        max_temp_change,
+        nan_rejections,
+    }
+}
+
+/// Energies a tile would end up with after each of its exchanges in a frame,
+/// by tile index. They are averaged before being applied.
+type EnergyChanges = std::collections::HashMap<(usize, usize, usize), Vec<f32>>;
+
+/// Computes the energy exchanges of the given tiles with their neighbours,
+/// without applying them. Also returns the largest single exchange.
+fn collect_energy_changes(
+    bf: &BoardData,
+    roomdb: &RoomDB,
+    tiles: &[((usize, usize, usize), f32)],
+) -> (EnergyChanges, f32) {
+    let config = &bf.temp_diffusion_config;
+    let outside_conductivity = config.outside_conductivity;
+    let inside_conductivity = config.inside_conductivity;
     // Closed Doors
-    const OTHER_CONDUCTIVITY: f32 = 20000.0;
-    const WALL_CONDUCTIVITY: f32 = 0.00001;
+    let other_conductivity = config.other_conductivity;
+    let wall_conductivity = config.wall_conductivity;
+    let max_energy_change_ratio = config.max_energy_change_ratio;
     let smooth: f32 = 1.0; // / difficulty.0.temperature_spread_speed;
 
     // Collect all energy changes before applying them
-    let mut energy_changes: std::collections::HashMap<(usize, usize, usize), Vec<f32>> =
-        std::collections::HashMap::new();
+    let mut energy_changes = EnergyChanges::new();
+    let mut frame_peak_energy_change: f32 = 0.0;
 
-    for (p, temp) in old_temps.into_iter() {
+    for &(p, temp) in tiles {
         let cp = &bf.collision_field[p];
         let free = (cp.see_through, cp.see_through || cp.is_dynamic);
 
         let mut self_k = match free {
-            (true, true) => INSIDE_CONDUCTIVITY,
-            (false, false) => WALL_CONDUCTIVITY,
-            _ => OTHER_CONDUCTIVITY,
+            (true, true) => inside_conductivity,
+            (false, false) => wall_conductivity,
+            _ => other_conductivity,
         };
         let bpos = BoardPosition::from_ndidx(p);
         let is_outside = roomdb.room_tiles.get(&bpos).is_none();
         if is_outside && cp.see_through {
-            self_k = OUTSIDE_CONDUCTIVITY;
+            self_k = outside_conductivity;
         }
 
         // Check if this is a stair tile - if so, add vertical neighbour
@@ -378,14 +606,14 @@ fn temperature_update(
             let is_stair_connection = neigh.z != bpos.z;
 
             let mut neigh_k = match neigh_free {
-                (true, true) => INSIDE_CONDUCTIVITY,
-                (false, false) => WALL_CONDUCTIVITY,
-                _ => OTHER_CONDUCTIVITY,
+                (true, true) => inside_conductivity,
+                (false, false) => wall_conductivity,
+                _ => other_conductivity,
             };
 
             let nis_outside = roomdb.room_tiles.get(neigh).is_none();
             if nis_outside && neigh_free.0 && !is_stair_connection {
-                neigh_k = OUTSIDE_CONDUCTIVITY;
+                neigh_k = outside_conductivity;
             }
             let neigh_temp = bf
                 .temperature_field
@@ -414,29 +642,16 @@ fn temperature_update(
                 _ => 1.0,
             };
 
-            // Calculate weighted average energy, accounting for thermal mass
-            // Use diffusion-limited energies for the energy transfer calculation
-            let total_mass = self_thermal_mass + neigh_thermal_mass;
-            let mid_energy = (temp_energy_for_diffusion * self_thermal_mass
-                + neigh_energy_for_diffusion * neigh_thermal_mass)
-                / total_mass;
-
             let conductivity = (self_k.recip() + neigh_k.recip()).recip() / smooth;
-            let energy_diff = (temp_energy_for_diffusion + mid_energy * conductivity)
-                / (conductivity + 1.0)
-                - temp_energy_for_diffusion;
-
-            // Apply stability limit: cap energy changes to 10% of each component's energy
-            const MAX_ENERGY_CHANGE_RATIO: f32 = 0.9;
-            let max_self_energy_change = temp_energy_for_diffusion * MAX_ENERGY_CHANGE_RATIO;
-            let max_neigh_energy_change = neigh_energy_for_diffusion * MAX_ENERGY_CHANGE_RATIO;
-
-            // Limit the base energy difference to prevent instability
-            let limited_energy_diff = energy_diff.clamp(
-                -max_self_energy_change.min(max_neigh_energy_change),
-                max_self_energy_change.min(max_neigh_energy_change),
+            let limited_energy_diff = limited_energy_exchange(
+                (temp_energy_for_diffusion, self_thermal_mass),
+                (neigh_energy_for_diffusion, neigh_thermal_mass),
+                conductivity,
+                max_energy_change_ratio,
             );
 
+            frame_peak_energy_change = frame_peak_energy_change.max(limited_energy_diff.abs());
+
             // Apply energy diffusion with thermal mass consideration
             // Walls (low thermal mass) change temperature more easily
             let self_energy_change = limited_energy_diff / self_thermal_mass;
@@ -472,71 +687,305 @@ fn temperature_update(
                 .push(adjusted_energy2);
         }
     }
+    (energy_changes, frame_peak_energy_change)
+}
 
-    // Apply all accumulated energy changes by averaging them in energy space
-    // and track activity for adaptive selection
-    let mut updated_positions = std::collections::HashSet::new();
-    let mut debug_total_activity = 0.0;
-    let mut debug_activity_count = 0;
-    for (pos_idx, energy_list) in energy_changes {
-        if !energy_list.is_empty() {
-            let old_temp = bf.temperature_field[pos_idx];
+/// Parallel version of `collect_energy_changes`: tiles are split in chunks,
+/// each chunk collects into its own map and the maps are merged in chunk order,
+/// so every tile gets its energies in the same order as the serial version.
+#[cfg(feature = "parallel-diffusion")]
+fn collect_energy_changes_parallel(
+    bf: &BoardData,
+    roomdb: &RoomDB,
+    tiles: &[((usize, usize, usize), f32)],
+) -> (EnergyChanges, f32) {
+    use rayon::prelude::*;
+    const CHUNK_SIZE: usize = 256;
+
+    tiles
+        .par_chunks(CHUNK_SIZE)
+        .map(|chunk| collect_energy_changes(bf, roomdb, chunk))
+        .reduce(
+            || (EnergyChanges::new(), 0.0),
+            |(mut merged, peak), (changes, chunk_peak)| {
+                for (p, energies) in changes {
+                    merged.entry(p).or_default().extend(energies);
+                }
+                (merged, peak.max(chunk_peak))
+            },
+        )
+}
 
-            // Average all energies in energy space
-            let avg_energy = energy_list.iter().sum::<f32>() / energy_list.len() as f32;
+/// Computes the energy exchanged between a tile and its neighbour in one step.
+///
+/// Each side is given as `(energy, thermal_mass)`. The tile's energy moves towards
+/// the mass-weighted average of both, scaled by `conductivity`. The result is
+/// capped to `max_ratio` of the smaller energy to keep the diffusion stable.
+fn limited_energy_exchange(
+    (energy, thermal_mass): (f32, f32),
+    (neigh_energy, neigh_thermal_mass): (f32, f32),
+    conductivity: f32,
+    max_ratio: f32,
+) -> f32 {
+    // Calculate weighted average energy, accounting for thermal mass
+    let total_mass = thermal_mass + neigh_thermal_mass;
+    let mid_energy = (energy * thermal_mass + neigh_energy * neigh_thermal_mass) / total_mass;
+
+    let energy_diff = (energy + mid_energy * conductivity) / (conductivity + 1.0) - energy;
+
+    // Apply stability limit: cap energy changes to a ratio of each component's energy
+    let max_energy_change = (energy * max_ratio).min(neigh_energy * max_ratio);
+    energy_diff.clamp(-max_energy_change, max_energy_change)
+}
 
-            // Check for NaN and clamp energy to reasonable bounds
-            if avg_energy.is_finite() && avg_energy > 0.0 {
-                // Convert back to temperature using T = ∛E
-                let new_temp = avg_energy
-                    .cbrt()
-                    .clamp(celsius_to_kelvin(-50.0), celsius_to_kelvin(100.0));
-                bf.temperature_field[pos_idx] = new_temp;
+/// Moves a small amount of thermal energy along the miasma flow.
+///
+/// Each air tile with a moving miasma exchanges energy with the downstream tile in
+/// the dominant direction of the velocity, pulling the downstream temperature
+/// towards its own. The same amount is removed from the source so total energy is
+/// preserved, and the transfer is capped like the diffusion step to stay stable.
+fn miasma_advection_update(bf: &mut BoardData) {
+    const MIN_SPEED: f32 = 0.001;
+    const MAX_ADVECTION_ENERGY_RATIO: f32 = 0.1;
+    let rate = bf.temp_diffusion_config.miasma_advection_rate;
+
+    let mut transfers: Vec<((usize, usize, usize), (usize, usize, usize), f32)> = Vec::new();
+    for (p, velocity) in bf.miasma.velocity_field.indexed_iter() {
+        let speed = velocity.length();
+        if speed < MIN_SPEED || !bf.collision_field[p].see_through {
+            continue;
+        }
+        let bpos = BoardPosition::from_ndidx(p);
+        let downstream = if velocity.x.abs() >= velocity.y.abs() {
+            if velocity.x > 0.0 {
+                bpos.right()
+            } else {
+                bpos.left()
+            }
+        } else if velocity.y > 0.0 {
+            bpos.bottom()
+        } else {
+            bpos.top()
+        };
+        let np = downstream.ndidx();
+        if np == p
+            || !bf
+                .collision_field
+                .get(np)
+                .map(|c| c.see_through)
+                .unwrap_or(false)
+        {
+            continue;
+        }
+        let (Some(temp), Some(neigh_temp)) =
+            (bf.temperature_field.get(p), bf.temperature_field.get(np))
+        else {
+            continue;
+        };
+        let energy = temp.powi(3);
+        let neigh_energy = neigh_temp.powi(3);
+        let max_change = energy.min(neigh_energy) * MAX_ADVECTION_ENERGY_RATIO;
+        let transfer =
+            ((energy - neigh_energy) * rate * speed.min(1.0)).clamp(-max_change, max_change);
+        if transfer.is_finite() {
+            transfers.push((p, np, transfer));
+        }
+    }
 
-                // Update activity: track the sum of absolute temperature changes for this tile
-                // Convert energy changes back to temperature space for reasonable activity values
-                let mut total_temp_change = 0.0;
-                for energy in &energy_list {
-                    let temp_from_energy = energy.cbrt();
-                    total_temp_change += (temp_from_energy - old_temp).abs();
-                }
+    for (p, np, transfer) in transfers {
+        let new_energy = bf.temperature_field[p].powi(3) - transfer;
+        let new_neigh_energy = bf.temperature_field[np].powi(3) + transfer;
+        if new_energy > 0.0 && new_neigh_energy > 0.0 {
+            bf.temperature_field[p] = new_energy.cbrt();
+            bf.temperature_field[np] = new_neigh_energy.cbrt();
+        }
+    }
+}
 
-                // Debug tracking
-                debug_total_activity += total_temp_change;
-                debug_activity_count += 1;
+/// Rooms of [`synthetic_board`] are squares of this many tiles, walls included.
+pub const SYNTHETIC_ROOM_SIZE: usize = 8;
+
+/// Builds a board of square rooms with doorways, surrounded by a strip of
+/// outside tiles, at 20ºC and with no temperature activity.
+///
+/// Used by the diffusion tests and `tools/temperature_bench`, so they run on
+/// the same layout.
+pub fn synthetic_board(map_size: (usize, usize, usize)) -> (BoardData, RoomDB) {
+    let (width, height, _) = map_size;
+    let mut bf = BoardData::from_world(&mut World::new());
+    let mut roomdb = RoomDB::default();
+    bf.map_size = map_size;
+    bf.collision_field = Array3::from_elem(map_size, CollisionFieldData::default());
+    for ((x, y, z), cp) in bf.collision_field.indexed_iter_mut() {
+        let inside = x > 0 && y > 0 && x < width - 1 && y < height - 1;
+        let wall = inside && (x % SYNTHETIC_ROOM_SIZE == 0 || y % SYNTHETIC_ROOM_SIZE == 0);
+        let doorway = x % SYNTHETIC_ROOM_SIZE == SYNTHETIC_ROOM_SIZE / 2
+            || y % SYNTHETIC_ROOM_SIZE == SYNTHETIC_ROOM_SIZE / 2;
+        let free = !wall || doorway;
+        cp.see_through = free;
+        cp.player_free = free;
+        cp.ghost_free = free;
+        if inside && free {
+            let pos = BoardPosition {
+                x: x as i64,
+                y: y as i64,
+                z: z as i64,
+            };
+            let room = format!(
+                "room {}-{}-{z}",
+                x / SYNTHETIC_ROOM_SIZE,
+                y / SYNTHETIC_ROOM_SIZE
+            );
+            roomdb.room_tiles.insert(pos, room);
+        }
+    }
+    bf.temperature_field = Array3::from_elem(map_size, celsius_to_kelvin(20.0));
+    bf.temperature_field_prev = bf.temperature_field.clone();
+    bf.temperature_activity = Array3::from_elem(map_size, 0.0);
+    (bf, roomdb)
+}
 
-                let current_activity = bf.temperature_activity.get(pos_idx).copied().unwrap_or(0.0);
-                // Exponential moving average with activity addition
-                let new_activity = (current_activity / 1.05) + total_temp_change;
-                bf.temperature_activity[pos_idx] = new_activity;
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<TemperatureMissionStats>();
+    app.add_systems(OnEnter(AppState::InGame), reset_temperature_mission_stats);
+    app.add_systems(Update, temperature_update.run_if(gameplay_running));
+}
 
-                updated_positions.insert(pos_idx);
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+    use uncore::types::ghost::types::GhostType;
+    use uncore::utils::time::MAX_SIMULATION_DELTA_SECS;
+
+    #[test]
+    fn test_lower_energy_ratio_limits_change() {
+        let cold = celsius_to_kelvin(5.0).powi(3);
+        let warm = celsius_to_kelvin(25.0).powi(3);
+        let conductivity = 80000.0;
+
+        let default_ratio = TemperatureDiffusionConfig::default().max_energy_change_ratio;
+        let loose = limited_energy_exchange((cold, 0.9), (warm, 0.9), conductivity, default_ratio);
+        let tight = limited_energy_exchange((cold, 0.9), (warm, 0.9), conductivity, 0.01);
+
+        assert!(loose > 0.0, "Cold tile should gain energy");
+        assert!(tight > 0.0, "Cold tile should gain energy");
+        assert!(tight < loose);
+        assert!(tight <= cold * 0.01 + f32::EPSILON);
+
+        let t_loose = (cold + loose).cbrt() - cold.cbrt();
+        let t_tight = (cold + tight).cbrt() - cold.cbrt();
+        assert!(t_tight < t_loose);
     }
 
-    // Debug output for activity tracking
-    if debug_activity_count > 0 {
-        let avg_activity = debug_total_activity / debug_activity_count as f32;
+    #[test]
+    fn test_pickup_resets_smoothing() {
+        let mut thermometer = Thermometer {
+            temp: celsius_to_kelvin(20.0),
+            temp_l2: [celsius_to_kelvin(20.0); 5],
+            temp_l1: celsius_to_kelvin(20.0),
+            ..default()
+        };
+        thermometer.on_deploy();
+        assert!(thermometer.needs_calibration);
+        thermometer.calibrate(celsius_to_kelvin(-2.0));
+        thermometer.on_pickup();
+        assert!(thermometer.needs_calibration);
+
+        let reading = celsius_to_kelvin(4.0);
+        thermometer.calibrate(reading);
+        assert!(!thermometer.needs_calibration);
+        assert_eq!(thermometer.temp_l1, reading);
+        assert!(thermometer.temp_l2.iter().all(|t| *t == reading));
+        assert!((kelvin_to_celsius(thermometer.temp) - 4.0).abs() < 0.2);
+    }
 
-        // Calculate average activity across all tiles
-        let total_tiles = bf.temperature_activity.len();
-        let sum_activity: f32 = bf.temperature_activity.iter().sum();
-        let avg_tile_activity = if total_tiles > 0 {
-            sum_activity / total_tiles as f32
-        } else {
-            0.0
+    #[test]
+    fn test_reports_freezing_temps_below_zero() {
+        let mut thermometer = Thermometer {
+            enabled: true,
+            temp: celsius_to_kelvin(0.0),
+            ..default()
         };
+        assert_eq!(thermometer.current_evidence(), None);
 
-        debug!(
-            "Frame activity debug: {} tiles updated, avg total_temp_change: {:.6}, avg_tile_activity: {:.6}",
-            debug_activity_count, avg_activity, avg_tile_activity
-        );
+        thermometer.temp = celsius_to_kelvin(-0.2);
+        assert_eq!(thermometer.current_evidence(), Some(Evidence::FreezingTemp));
+
+        // Glitching or switched off readings aren't evidence.
+        thermometer.display_glitch_timer = 0.3;
+        assert_eq!(thermometer.current_evidence(), None);
+        thermometer.display_glitch_timer = 0.0;
+        thermometer.enabled = false;
+        assert_eq!(thermometer.current_evidence(), None);
     }
 
-    measure.end_ms();
-}
+    /// Runs one `temperature_update` frame of the given length with a ghost
+    /// whose breach is in the room, and returns the resulting temperatures.
+    fn temperature_frame(delta: Duration) -> Array3<f32> {
+        random_seed::set_seed(7);
+        let (bf, roomdb) = synthetic_board((20, 20, 1));
+        let mut time = Time::<()>::default();
+        time.advance_by(delta);
+
+        let mut world = World::new();
+        world.insert_resource(bf);
+        world.insert_resource(roomdb);
+        world.insert_resource(time);
+        world.init_resource::<TemperatureMissionStats>();
+        world.init_resource::<CurrentDifficulty>();
+        world.init_resource::<GamePaused>();
+        let breach = BoardPosition { x: 14, y: 12, z: 0 };
+        world.spawn((
+            GhostSprite::new(breach, &[GhostType::BeanSidhe]),
+            breach.to_position(),
+        ));
+        world.run_system_once(temperature_update).unwrap();
+        world.resource::<BoardData>().temperature_field.clone()
+    }
 
-pub(crate) fn app_setup(app: &mut App) {
-    app.add_systems(Update, temperature_update);
+    #[test]
+    fn test_hitch_does_not_destabilize_temperature() {
+        let normal = temperature_frame(Duration::from_secs_f32(1.0 / 60.0));
+        let capped = temperature_frame(Duration::from_secs_f32(MAX_SIMULATION_DELTA_SECS));
+        let hitch = temperature_frame(Duration::from_secs(2));
+
+        // A 2s frame is simulated as the capped delta, not as 2s of cold.
+        assert_eq!(hitch, capped);
+        assert!(hitch.iter().all(|t| t.is_finite()));
+        let lowest = hitch.iter().copied().fold(f32::INFINITY, f32::min);
+        assert!(lowest >= celsius_to_kelvin(1.0 - 4.0) - 0.01);
+        let cooled = |field: &Array3<f32>| celsius_to_kelvin(20.0) - field[(14, 12, 0)];
+        assert!(cooled(&hitch) > cooled(&normal));
+    }
+
+    #[cfg(feature = "parallel-diffusion")]
+    #[test]
+    fn test_parallel_diffusion_matches_serial() {
+        let map_size = (40, 40, 2);
+        let (mut bf, roomdb) = synthetic_board(map_size);
+        random_seed::set_seed(42);
+        let mut rng = random_seed::rng();
+        bf.temperature_field = Array3::from_shape_fn(map_size, |_| {
+            celsius_to_kelvin(rng.random_range(-5.0..30.0))
+        });
+        let tiles: Vec<_> = bf
+            .temperature_field
+            .indexed_iter()
+            .map(|(p, t)| (p, *t))
+            .collect();
+
+        let (serial, serial_peak) = collect_energy_changes(&bf, &roomdb, &tiles);
+        let (parallel, parallel_peak) = collect_energy_changes_parallel(&bf, &roomdb, &tiles);
+        assert_eq!(serial.len(), parallel.len());
+        assert_eq!(serial_peak, parallel_peak);
+        for (p, energies) in &serial {
+            let avg = |e: &Vec<f32>| e.iter().sum::<f32>() / e.len() as f32;
+            let other = &parallel[p];
+            assert_eq!(energies.len(), other.len());
+            let (a, b) = (avg(energies).cbrt(), avg(other).cbrt());
+            assert!((a - b).abs() < 1e-3, "{p:?}: serial {a} vs parallel {b}");
+        }
+    }
 }
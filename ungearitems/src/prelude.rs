@@ -6,11 +6,11 @@ pub use super::components::geigercounter::GeigerCounter;
 pub use super::components::ionmeter::IonMeter;
 pub use super::components::motionsensor::MotionSensor;
 pub use super::components::photocam::Photocam;
-pub use super::components::quartz::QuartzStoneData;
+pub use super::components::quartz::{QuartzState, QuartzStoneData};
 pub use super::components::recorder::Recorder;
 pub use super::components::redtorch::RedTorch;
 pub use super::components::repellentflask::RepellentFlask;
-pub use super::components::sage::SageBundleData;
+pub use super::components::sage::{SageBundleData, SageState};
 pub use super::components::salt::SaltData;
 pub use super::components::spiritbox::SpiritBox;
 pub use super::components::thermalimager::ThermalImager;
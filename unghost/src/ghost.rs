@@ -19,6 +19,7 @@ use uncore::difficulty::CurrentDifficulty;
 use uncore::metric_recorder::SendMetric;
 use uncore::random_seed;
 use uncore::resources::board_data::BoardData;
+use uncore::resources::game_paused::{GamePaused, gameplay_running};
 use uncore::resources::object_interaction::ObjectInteractionConfig;
 use uncore::resources::roomdb::RoomDB;
 use uncore::resources::summary_data::SummaryData;
@@ -58,7 +59,7 @@ impl FadeOut {
 ///
 /// This system handles the ghost's movement logic, ensuring it navigates the game
 /// world according to its current state and objectives.
-fn ghost_movement(
+pub(crate) fn ghost_movement(
     mut q: Query<
         (&mut GhostSprite, &mut Position, Entity),
         (
@@ -73,6 +74,7 @@ fn ghost_movement(
     bf: Res<BoardData>,
     mut commands: Commands,
     time: Res<Time>,
+    paused: Res<GamePaused>,
     config: Res<ObjectInteractionConfig>,
     object_query: Query<(&Position, &GhostInfluence)>,
     difficulty: Res<CurrentDifficulty>,
@@ -80,7 +82,7 @@ fn ghost_movement(
     let measure = GHOST_MOVEMENT.time_measure();
 
     let mut rng = random_seed::rng();
-    let dt = time.delta_secs() * 60.0;
+    let dt = paused.delta_secs(&time) * 60.0;
     for (mut ghost, mut pos, entity) in q.iter_mut() {
         if let Some(target_point) = ghost.target_point {
             let mut delta = target_point.delta(*pos);
@@ -365,7 +367,7 @@ impl RoarType {
 /// This system updates the ghost's rage based on player proximity, sanity, and
 /// sound levels. It triggers hunts when rage exceeds a threshold and handles
 /// player damage during hunts.
-fn ghost_enrage(
+pub(crate) fn ghost_enrage(
     time: Res<Time>,
     mut timer: Local<PrintingTimer>,
     mut avg_angry: Local<MeanValue>,
@@ -377,18 +379,20 @@ fn ghost_enrage(
     roomdb: Res<RoomDB>,
     mut ev_ambient_mute: EventWriter<AmbientSoundMuteEvent>,
     gameplay_settings: Res<Persistent<GameplaySettings>>,
+    paused: Res<GamePaused>,
 ) {
     let measure = GHOST_ENRAGE.time_measure();
 
-    timer.tick(time.delta());
-    let dt = time.delta_secs();
+    let delta = paused.delta(&time);
+    timer.tick(delta);
+    let dt = delta.as_secs_f32();
 
     for (mut ghost, ghost_position, _dynamics) in qg.iter_mut() {
         // --- Salty Trace Spawning Logic ---
         if !ghost.salty_effect_timer.finished() && ghost.hunting <= 0.1 {
             // Only spawn traces when NOT hunting and salty effect is active
-            ghost.salty_effect_timer.tick(time.delta());
-            ghost.salty_trace_spawn_timer.tick(time.delta());
+            ghost.salty_effect_timer.tick(delta);
+            ghost.salty_trace_spawn_timer.tick(delta);
             if ghost.salty_trace_spawn_timer.just_finished() {
                 if random_seed::rng().random_bool(0.5) {
                     // 50% chance to spawn --- Find Valid Floor Tile ---
@@ -525,7 +529,7 @@ fn ghost_enrage(
 
             let angry2 = dist2.recip() * 1000000.0 / sanity
                 * player.mean_sound
-                * (player.health / 100.0).clamp(0.0, 1.0);
+                * (player.health / difficulty.0.max_health).clamp(0.0, 1.0);
 
             total_angry2 +=
                 angry2 * inv_sanity + player.mean_sound.sqrt() * inv_sanity * dt * 3000.1;
@@ -703,6 +707,7 @@ fn spawn_salty_trace(
 fn ghost_fade_out_system(
     mut commands: Commands,
     time: Res<Time>,
+    paused: Res<GamePaused>,
     asset_server: Res<AssetServer>,
     mut query: Query<(
         Entity,
@@ -715,7 +720,7 @@ fn ghost_fade_out_system(
 ) {
     let mut rng = random_seed::rng();
     for (entity, mut fade_out, mut map_color, position, ghost_sprite) in query.iter_mut() {
-        fade_out.timer.tick(time.delta());
+        fade_out.timer.tick(paused.delta(&time));
         let rem_f = fade_out.timer.remaining_secs() / fade_out.timer.duration().as_secs_f32();
 
         // Fade out the sprite
@@ -776,33 +781,35 @@ fn ghost_fade_out_system(
     }
 }
 
+/// Decays the ghost warning so EMI effects taper off after a ghost event instead
+/// of cutting out abruptly.
+fn decay_ghost_warning_field(
+    mut board_data: ResMut<BoardData>,
+    time: Res<Time>,
+    paused: Res<GamePaused>,
+) {
+    board_data.decay_ghost_warning(paused.delta_secs(&time));
+}
+
 /// Updates the ghost warning field based on the intensity of nearby ghosts.
 ///
-/// This system calculates the ghost warning field based on the highest intensity
-/// warning from any ghost. The warning field is used to display a visual warning
-/// to the player when a ghost is nearby.
+/// This system raises the ghost warning field to the highest intensity warning
+/// from any ghost. The warning field is used to display a visual warning to the
+/// player when a ghost is nearby.
 fn update_ghost_warning_field(
     mut board_data: ResMut<BoardData>,
     q_ghost: Query<(&GhostSprite, &Position)>,
     time: Res<Time>,
 ) {
-    // Reset warning field
-    board_data.ghost_warning_intensity = 0.0;
-    board_data.ghost_warning_position = None;
-
-    let mut max_intensity = 0.0;
+    let cur_t = time.elapsed_secs_f64();
+    let wave = f64::sin(PI * cur_t * 2.0).powi(2) as f32;
 
     // Find the highest intensity warning from any ghost
     for (ghost, position) in q_ghost.iter() {
-        if ghost.hunt_warning_intensity > max_intensity {
-            max_intensity = ghost.hunt_warning_intensity;
-            board_data.ghost_warning_position = Some(*position);
+        if ghost.hunt_warning_intensity > 0.0 {
+            board_data.set_ghost_warning(*position, ghost.hunt_warning_intensity * wave);
         }
     }
-
-    let cur_t = time.elapsed_secs_f64();
-    let wave = f64::sin(PI * cur_t * 2.0).powi(2);
-    board_data.ghost_warning_intensity = max_intensity * wave as f32;
 }
 
 /// Calculate distance with Z component multiplied by 10 if on different floors
@@ -848,10 +855,11 @@ fn calculate_weighted_distance_squared(ghost_pos: &Position, player_pos: &Positi
 /// This creates a visual indication of ghost instability without affecting position or movement
 fn ghost_scale_glitch_system(
     time: Res<Time>,
+    paused: Res<GamePaused>,
     mut q_ghost: Query<(&GhostSprite, &mut Transform), (With<GhostSprite>, Without<FadeOut>)>,
 ) {
     let mut rng = random_seed::rng();
-    let dt = time.delta_secs();
+    let dt = paused.delta_secs(&time);
 
     for (ghost, mut transform) in q_ghost.iter_mut() {
         if ghost.repellent_hits_delta > 0.0 {
@@ -901,9 +909,10 @@ pub(crate) fn app_setup(app: &mut App) {
             ghost_movement,
             ghost_enrage,
             ghost_fade_out_system,
-            update_ghost_warning_field,
+            (decay_ghost_warning_field, update_ghost_warning_field).chain(),
             ghost_scale_glitch_system,
             crate::systems::dynamic_behaviour_update::update_ghost_behaviour_dynamics_system,
-        ),
+        )
+            .run_if(gameplay_running),
     );
 }
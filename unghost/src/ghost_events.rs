@@ -8,6 +8,7 @@ use uncore::difficulty::CurrentDifficulty;
 use uncore::events::board_data_rebuild::BoardDataToRebuild;
 use uncore::events::sound::SoundEvent;
 use uncore::random_seed;
+use uncore::resources::ghost_interaction_log::{GhostInteractionLog, GhostLogKind};
 use unstd::board::spritedb::SpriteDB;
 use unstd::systemparam::interactivestuff::InteractiveStuff;
 
@@ -37,6 +38,8 @@ pub fn trigger_ghost_events(
     mut interactive_stuff: InteractiveStuff,
     mut ev_bdr: EventWriter<BoardDataToRebuild>,
     difficulty: Res<CurrentDifficulty>,
+    time: Res<Time>,
+    mut ghost_log: ResMut<GhostInteractionLog>,
 ) {
     let mut rng = random_seed::rng();
     let roomdb = interactive_stuff.roomdb.clone();
@@ -64,6 +67,11 @@ pub fn trigger_ghost_events(
                 _ => GhostEvent::LightFlicker,
             };
             // warn!("Event: {:?}", event);
+            let log_kind = match event {
+                GhostEvent::DoorSlam => GhostLogKind::DoorSlam,
+                GhostEvent::LightFlicker => GhostLogKind::LightFlicker,
+            };
+            ghost_log.record(time.elapsed_secs(), log_kind, ghost_pos);
             match event {
                 GhostEvent::DoorSlam => {
                     // Find doors in the player's room
@@ -154,7 +162,7 @@ pub fn trigger_ghost_events(
                                     == Some(&player_room)
                             {
                                 // Toggle the light's state using the public method
-                                behaviour.p.light.flickering = true;
+                                behaviour.p.light.ghost_flicker = true;
 
                                 // Add a timer to reset the light after a short duration
                                 commands
@@ -190,7 +198,7 @@ fn update_flicker_timers(
         flicker_timer.0.tick(time.delta());
         if flicker_timer.0.finished() {
             // Reset the light to its original state using the public method
-            behaviour.p.light.flickering = false;
+            behaviour.p.light.ghost_flicker = false;
             commands.entity(entity).remove::<FlickerTimer>();
             ev_bdr.write(BoardDataToRebuild {
                 lighting: true,
@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use bevy::prelude::*;
+use uncore::components::board::position::Position;
+use uncore::components::ghost_sprite::GhostSprite;
+use uncore::events::hunt::{HuntEnded, HuntStarted};
+use uncore::resources::cli_options::CliOptions;
+use uncore::resources::ghost_interaction_log::{GhostInteractionLog, GhostLogKind};
+use uncore::states::AppState;
+
+/// Seconds between two samples of the ghost position.
+const POSITION_SAMPLE_SECS: f32 = 1.0;
+
+/// Directory the logs are written to, relative to the working directory.
+const GHOST_LOG_DIR: &str = "ghost_logs";
+
+/// Starts a fresh log for the mission, enabled by the `--ghost-log` flag.
+fn start_ghost_log(
+    cli_options: Option<Res<CliOptions>>,
+    mut ghost_log: ResMut<GhostInteractionLog>,
+) {
+    ghost_log.clear();
+    ghost_log.enabled = cli_options.is_some_and(|o| o.ghost_log);
+}
+
+fn record_ghost_activity(
+    time: Res<Time>,
+    mut ghost_log: ResMut<GhostInteractionLog>,
+    q_ghost: Query<&Position, With<GhostSprite>>,
+    mut ev_started: EventReader<HuntStarted>,
+    mut ev_ended: EventReader<HuntEnded>,
+    mut since_sample: Local<f32>,
+) {
+    if !ghost_log.enabled {
+        ev_started.clear();
+        ev_ended.clear();
+        return;
+    }
+    let now = time.elapsed_secs();
+    let hunts = ev_started
+        .read()
+        .map(|ev| (ev.ghost, GhostLogKind::HuntStarted))
+        .chain(
+            ev_ended
+                .read()
+                .map(|ev| (ev.ghost, GhostLogKind::HuntEnded)),
+        );
+    for (ghost, kind) in hunts {
+        // A hunt may end because the ghost is gone; there's no position then.
+        let pos = q_ghost
+            .get(ghost)
+            .copied()
+            .unwrap_or(Position::new_i64(0, 0, 0));
+        ghost_log.record(now, kind, &pos);
+    }
+
+    *since_sample += time.delta_secs();
+    if *since_sample >= POSITION_SAMPLE_SECS {
+        *since_sample = 0.0;
+        for pos in &q_ghost {
+            ghost_log.record(now, GhostLogKind::Position, pos);
+        }
+    }
+}
+
+/// Writes the mission's log as JSON when leaving the game.
+fn export_ghost_log(mut ghost_log: ResMut<GhostInteractionLog>) {
+    if !ghost_log.enabled || ghost_log.is_empty() {
+        return;
+    }
+    if cfg!(target_arch = "wasm32") {
+        ghost_log.clear();
+        return;
+    }
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let path = Path::new(GHOST_LOG_DIR).join(format!("ghost-log-{stamp}.json"));
+    let result = ghost_log
+        .to_json()
+        .map_err(std::io::Error::other)
+        .and_then(|json| {
+            std::fs::create_dir_all(GHOST_LOG_DIR)?;
+            std::fs::write(&path, json)
+        });
+    match result {
+        Ok(()) => info!("Ghost interaction log written to {}", path.display()),
+        Err(e) => error!("Error writing ghost interaction log: {e:?}"),
+    }
+    ghost_log.clear();
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<GhostInteractionLog>()
+        .add_systems(OnEnter(AppState::InGame), start_ghost_log)
+        .add_systems(OnExit(AppState::InGame), export_ghost_log)
+        .add_systems(
+            Update,
+            record_ghost_activity.run_if(in_state(AppState::InGame)),
+        );
+}
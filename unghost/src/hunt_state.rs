@@ -0,0 +1,98 @@
+use crate::ghost;
+use bevy::prelude::*;
+use uncore::components::ghost_sprite::GhostSprite;
+use uncore::events::hunt::{HuntEnded, HuntStarted};
+use uncore::resources::hunt_state::HuntState;
+
+/// Mirrors the hunting state of the ghosts into `HuntState`, sending
+/// `HuntStarted` and `HuntEnded` when it flips. A hunt also ends when the
+/// hunting ghost is despawned, like when the mission ends mid-hunt.
+fn update_hunt_state(
+    time: Res<Time>,
+    q_ghost: Query<(Entity, &GhostSprite)>,
+    mut hunt_state: ResMut<HuntState>,
+    mut ev_started: EventWriter<HuntStarted>,
+    mut ev_ended: EventWriter<HuntEnded>,
+) {
+    let hunting_ghost = q_ghost
+        .iter()
+        .find(|(_, ghost)| ghost.hunt_target)
+        .map(|(entity, _)| entity);
+    if hunting_ghost == hunt_state.ghost {
+        return;
+    }
+    if let Some(ghost) = hunt_state.ghost {
+        ev_ended.write(HuntEnded { ghost });
+    }
+    *hunt_state = match hunting_ghost {
+        Some(ghost) => {
+            ev_started.write(HuntStarted { ghost });
+            HuntState {
+                active: true,
+                started_at: time.elapsed_secs(),
+                ghost: Some(ghost),
+            }
+        }
+        None => HuntState::default(),
+    };
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_hunt_state
+            .after(ghost::ghost_movement)
+            .after(ghost::ghost_enrage),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uncore::components::board::boardposition::BoardPosition;
+    use uncore::types::ghost::types::GhostType;
+
+    fn set_hunting(app: &mut App, ghost: Entity, hunting: bool) {
+        app.world_mut()
+            .get_mut::<GhostSprite>(ghost)
+            .unwrap()
+            .hunt_target = hunting;
+        app.update();
+    }
+
+    fn event_count<E: Event>(app: &App) -> usize {
+        app.world()
+            .resource::<Events<E>>()
+            .iter_current_update_events()
+            .count()
+    }
+
+    #[test]
+    fn test_hunt_start_and_end_flip_state() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<HuntState>()
+            .add_event::<HuntStarted>()
+            .add_event::<HuntEnded>()
+            .add_systems(Update, update_hunt_state);
+        let ghost = app
+            .world_mut()
+            .spawn(GhostSprite::new(
+                BoardPosition { x: 0, y: 0, z: 0 },
+                &[GhostType::BeanSidhe],
+            ))
+            .id();
+        app.update();
+        assert!(!app.world().resource::<HuntState>().active);
+
+        set_hunting(&mut app, ghost, true);
+        let state = app.world().resource::<HuntState>();
+        assert!(state.active);
+        assert_eq!(state.ghost, Some(ghost));
+        assert_eq!(event_count::<HuntStarted>(&app), 1);
+
+        set_hunting(&mut app, ghost, false);
+        assert!(!app.world().resource::<HuntState>().active);
+        assert_eq!(event_count::<HuntEnded>(&app), 1);
+    }
+}
@@ -1,6 +1,8 @@
 pub mod ghost;
 pub mod ghost_events;
+pub mod ghost_log;
 pub mod ghost_orb;
+pub mod hunt_state;
 pub mod metrics;
 pub mod plugin;
 pub mod systems;
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{ghost, ghost_events, ghost_orb, metrics};
+use crate::{ghost, ghost_events, ghost_log, ghost_orb, hunt_state, metrics};
 
 pub struct UnhaunterGhostPlugin;
 
@@ -8,7 +8,9 @@ impl Plugin for UnhaunterGhostPlugin {
     fn build(&self, app: &mut App) {
         ghost::app_setup(app);
         ghost_events::app_setup(app);
+        ghost_log::app_setup(app);
         ghost_orb::app_setup(app);
+        hunt_state::app_setup(app);
         metrics::register_all(app);
     }
 }
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use uncore::{
     components::{ghost_behaviour_dynamics::GhostBehaviourDynamics, ghost_sprite::GhostSprite},
     difficulty::CurrentDifficulty,
-    resources::board_data::BoardData,
+    resources::{board_data::BoardData, game_paused::GamePaused},
     types::evidence::Evidence,
 };
 
@@ -35,6 +35,7 @@ fn calculate_noise_multiplier(
 
 pub(crate) fn update_ghost_behaviour_dynamics_system(
     time: Res<Time>,
+    paused: Res<GamePaused>,
     difficulty: Res<CurrentDifficulty>,
     noise_table: Res<uncore::noise::PerlinNoise>,
     mut query: Query<(&GhostSprite, &mut GhostBehaviourDynamics)>,
@@ -43,7 +44,7 @@ pub(crate) fn update_ghost_behaviour_dynamics_system(
 ) {
     let elapsed_seconds = time.elapsed_secs();
     let evidence_visibility_recip = difficulty.0.evidence_visibility.recip();
-    *report_time += time.delta_secs();
+    *report_time += paused.delta_secs(&time);
     for (ghost_sprite, mut dynamics) in query.iter_mut() {
         // Iterate through all 8 actual Evidence enum variants
         for evidence_type in Evidence::all() {
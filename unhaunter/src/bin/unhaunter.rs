@@ -1,15 +1,38 @@
 use clap::Parser;
 use uncore::resources::cli_options::CliOptions;
+use uncore::types::ghost::definitions::GhostSelection;
+use uncore::types::ghost::types::GhostType;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
     #[clap(long, action)]
     draft_maps: bool,
+    /// Write a log of what the ghost did to ghost_logs/ after each mission.
+    #[clap(long, action)]
+    ghost_log: bool,
+    /// Always pick this ghost (e.g. "bean sidhe"), for testing.
+    #[clap(long)]
+    ghost: Option<String>,
+    /// Pick the ghost from the difficulty's set with this seed.
+    #[clap(long, conflicts_with = "ghost")]
+    ghost_seed: Option<u64>,
 }
 
 fn main() {
     let args = Args::parse();
+    let ghost_selection = match (&args.ghost, args.ghost_seed) {
+        (Some(name), _) => match GhostType::from_name(name) {
+            Some(ghost) => GhostSelection::Forced(ghost),
+            None => {
+                let names: Vec<_> = GhostType::all().map(|g| g.name()).collect();
+                eprintln!("Unknown ghost {name:?}. Known ghosts: {}", names.join(", "));
+                std::process::exit(2);
+            }
+        },
+        (None, Some(seed)) => GhostSelection::Seeded(seed),
+        (None, None) => GhostSelection::Random,
+    };
 
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -19,5 +42,7 @@ fn main() {
     }
     unhaunter::app_run(CliOptions {
         include_draft_maps: args.draft_maps,
+        ghost_log: args.ghost_log,
+        ghost_selection,
     });
 }
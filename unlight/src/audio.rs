@@ -3,6 +3,7 @@ use bevy_persistent::Persistent;
 use ndarray::s;
 use uncore::components::board::boardposition::BoardPosition;
 use uncore::components::board::position::Position;
+use uncore::difficulty::CurrentDifficulty;
 use uncore::resources::ambient_mute::AmbientMuteController;
 use uncore::{
     components::{game::GameSound, player_sprite::PlayerSprite},
@@ -100,6 +101,7 @@ fn update_ambient_sound_volumes(
     roomdb: Res<RoomDB>,
     audio_settings: Res<Persistent<AudioSettings>>,
     ambient_mute_controller: Res<AmbientMuteController>,
+    difficulty: Res<CurrentDifficulty>,
 ) {
     // Get player position and sprite data
     let Some((player_pos, player_sprite)) = player_query.iter().next() else {
@@ -113,7 +115,7 @@ fn update_ambient_sound_volumes(
 
     // Calculate HeartBeat volume based on player health (analog/fuzzy logic)
     // HeartBeat should get louder as health gets lower
-    let health_ratio = (player_sprite.health / 100.0).clamp(0.0, 1.0);
+    let health_ratio = (player_sprite.health / difficulty.0.max_health).clamp(0.0, 1.0);
     let heartbeat_volume = if health_ratio < 0.5 {
         // Health is below 50%, calculate heartbeat intensity
         let health_deficit = 1.0 - health_ratio; // 0.5 to 1.0
@@ -136,15 +138,14 @@ fn update_ambient_sound_volumes(
     };
 
     // Apply audio settings
-    let ambient_volume_setting = audio_settings.volume_ambient.as_f32();
-    let master_volume_setting = audio_settings.volume_master.as_f32();
+    let ambient_volume_setting = audio_settings.ambient_volume();
 
     // Apply mute effects (multiplicative)
     let mute_multiplier = ambient_mute_controller.current_multiplier();
 
     // Original IIR smoothing constant (simple and robust)
     const SMOOTH: f32 = 60.0;
-    let volume_factor = 2.0 * master_volume_setting * ambient_volume_setting;
+    let volume_factor = 2.0 * ambient_volume_setting;
 
     // Update each ambient sound entity
     for (game_sound, mut audio_sink) in &mut game_sound_query {
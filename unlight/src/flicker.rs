@@ -0,0 +1,72 @@
+//! Flickering light sources
+//!
+//! Light sources with a flicker profile get a new output factor every
+//! `FLICKER_STEP_SECS`, which scales their light in the cached light field on
+//! the next rebuild; the other sources aren't propagated again. Factors are
+//! drawn from `random_seed`, so flickering is reproducible after
+//! `random_seed::set_seed`.
+
+use bevy::prelude::*;
+use bevy_platform::collections::HashMap;
+use rand::Rng;
+use rand::rngs::SmallRng;
+use uncore::{
+    behaviour::{Behaviour, LightFlicker},
+    events::board_data_rebuild::BoardDataToRebuild,
+    random_seed,
+    resources::board_data::BoardData,
+    states::AppState,
+};
+
+/// Seconds between flicker updates. The light field is rescaled at this rate
+/// while any flickering light is on.
+const FLICKER_STEP_SECS: f32 = 0.08;
+
+fn light_flicker_system(
+    mut bf: ResMut<BoardData>,
+    qt: Query<&Behaviour>,
+    time: Res<Time>,
+    mut elapsed: Local<f32>,
+    mut rng: Local<Option<SmallRng>>,
+    mut ev_bdr: EventWriter<BoardDataToRebuild>,
+) {
+    *elapsed += time.delta_secs();
+    if *elapsed < FLICKER_STEP_SECS {
+        return;
+    }
+    *elapsed = 0.0;
+    let rng = rng.get_or_insert_with(random_seed::rng);
+
+    let mut factors = HashMap::new();
+    for (entity, ndidx) in &bf.prebaked_metadata.light_sources {
+        let Ok(behaviour) = qt.get(*entity) else {
+            continue;
+        };
+        let light = &behaviour.p.light;
+        if light.flicker == LightFlicker::Off || !light.light_emission_enabled {
+            continue;
+        }
+        let Some(source_id) = bf.prebaked_lighting[*ndidx].light_info.source_id else {
+            continue;
+        };
+        factors.insert(source_id, light.flicker.factor(rng.random()));
+    }
+
+    // Nothing flickers now, nor did on the last rebuild.
+    if factors.is_empty() && bf.light_flicker_factors.is_empty() {
+        return;
+    }
+    bf.light_flicker_factors = factors;
+    bf.light_flicker_changed = true;
+    ev_bdr.write(BoardDataToRebuild {
+        lighting: true,
+        collision: false,
+    });
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.add_systems(
+        Update,
+        light_flicker_system.run_if(in_state(AppState::InGame)),
+    );
+}
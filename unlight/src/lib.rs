@@ -1,5 +1,6 @@
 pub mod audio;
 pub mod cached_board_pos;
+pub mod flicker;
 pub mod lighting;
 pub mod maplight;
 pub mod metrics;
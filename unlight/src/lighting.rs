@@ -1,18 +1,170 @@
 use crate::utils::{
-    apply_prebaked_contributions, create_stair_wave_edges, identify_active_light_sources,
-    is_in_bounds, propagate_from_wave_edges, update_exposure_and_stats,
+    apply_prebaked_contributions, collect_door_states, create_stair_wave_edges,
+    identify_active_light_sources, is_in_bounds, propagate_from_wave_edges,
+    update_exposure_and_stats,
 };
 use bevy::prelude::*;
-use bevy_platform::collections::HashSet;
+use bevy_platform::collections::{HashMap, HashSet};
 use bevy_platform::time::Instant;
 use ndarray::Array3;
 use uncore::{
     behaviour::{Behaviour, Orientation},
     components::board::position::Position,
     resources::board_data::BoardData,
-    types::board::fielddata::LightFieldData,
+    types::board::{
+        fielddata::LightFieldData,
+        light_cache::{LightOwners, LightingCache},
+    },
 };
 
+/// Marks the light field as dirty if a light source toggled or a door opened or
+/// closed since it was last built.
+///
+/// Many rebuild requests (e.g. light flickers) don't change anything the light
+/// field depends on; this lets the caller skip the full rebuild for those.
+pub fn update_lighting_dirty(bf: &mut BoardData, qt: &Query<(&Position, &Behaviour)>) {
+    let sources = identify_active_light_sources(bf, qt);
+    let door_states = collect_door_states(bf, qt);
+    if sources != bf.lighting_sources || door_states != bf.lighting_door_states {
+        // A single light toggled and nothing else pending: rebuild incrementally.
+        bf.lighting_toggled_source = if !bf.lighting_dirty && door_states == bf.lighting_door_states
+        {
+            let mut toggled = sources.symmetric_difference(&bf.lighting_sources);
+            match (toggled.next(), toggled.next()) {
+                (Some(source_id), None) => Some(*source_id),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        bf.lighting_sources = sources;
+        bf.lighting_door_states = door_states;
+        bf.lighting_dirty = true;
+    }
+}
+
+/// Builds the light field of the active sources up to the wave edge
+/// propagation, recording which source touched each tile.
+pub fn build_lighting_cache(bf: &BoardData, active_source_ids: &HashSet<u32>) -> LightingCache {
+    let mut field = Array3::from_elem(bf.map_size, LightFieldData::default());
+    let mut owners = LightOwners::new(bf.map_size);
+    apply_prebaked_contributions(active_source_ids, bf, &mut field);
+    for (idx, prebaked) in bf.prebaked_lighting.indexed_iter() {
+        if let Some(source_id) = prebaked.light_info.source_id
+            && active_source_ids.contains(&source_id)
+        {
+            owners.touch(idx, source_id);
+        }
+    }
+    propagate_from_wave_edges(bf, &mut field, active_source_ids, Some(&mut owners));
+    LightingCache {
+        field,
+        owners,
+        collision_revision: bf.collision_revision,
+        source_lux: HashMap::new(),
+    }
+}
+
+/// Returns true if the cache was built for the board as it is now.
+fn cache_matches_board(bf: &BoardData, cache: &LightingCache) -> bool {
+    cache.collision_revision == bf.collision_revision && cache.field.dim() == bf.map_size
+}
+
+/// Scales the light of flickering sources in a field built at full strength.
+///
+/// Tiles lit by a single source are scaled directly. Where a flickering
+/// source overlaps others, its own light from `LightingCache::source_lux` is
+/// taken off in proportion, leaving the other sources steady.
+pub fn apply_light_flicker(
+    cache: &LightingCache,
+    factors: &HashMap<u32, f32>,
+    lfs: &mut Array3<LightFieldData>,
+) {
+    if factors.is_empty() {
+        return;
+    }
+    for (idx, owner) in cache.owners.tiles.indexed_iter() {
+        if *owner == LightOwners::SHARED {
+            for (source_id, lux) in &cache.source_lux {
+                if let Some(factor) = factors.get(source_id) {
+                    lfs[idx].lux = (lfs[idx].lux - lux[idx] * (1.0 - factor)).max(0.0);
+                }
+            }
+        } else if let Some(factor) = factors.get(owner) {
+            lfs[idx].lux *= factor;
+        }
+    }
+}
+
+/// Builds the light of each flickering source that shares tiles with another
+/// source on its own, if the cache doesn't have it yet.
+fn cache_shared_flicker_sources(bf: &BoardData, cache: &mut LightingCache) {
+    for source_id in bf.light_flicker_factors.keys() {
+        if cache.owners.shared_sources.contains(source_id)
+            && !cache.source_lux.contains_key(source_id)
+        {
+            let single = build_lighting_cache(bf, &HashSet::from_iter([*source_id]));
+            cache
+                .source_lux
+                .insert(*source_id, single.field.mapv(|l| l.lux));
+        }
+    }
+}
+
+/// Turns a single light source on or off in the cached light field, without
+/// propagating the other sources again.
+///
+/// Returns `None` when the result could differ from a full build: the source's
+/// light reaches tiles another source touched, the propagation would run out
+/// of steps, or the board changed since the cache was built.
+pub fn toggle_cached_source(
+    bf: &BoardData,
+    mut cache: LightingCache,
+    source_id: u32,
+    on: bool,
+) -> Option<LightingCache> {
+    if !cache.owners.complete || !cache_matches_board(bf, &cache) {
+        return None;
+    }
+
+    if !on {
+        if cache.owners.shared_sources.contains(&source_id) {
+            return None;
+        }
+        for (idx, owner) in cache.owners.tiles.indexed_iter_mut() {
+            if *owner == source_id {
+                *owner = 0;
+                cache.field[idx] = LightFieldData::default();
+            }
+        }
+        return Some(cache);
+    }
+
+    let source = build_lighting_cache(bf, &HashSet::from_iter([source_id]));
+    if !source.owners.complete
+        || cache.owners.steps + source.owners.steps > bf.propagation_step_budget
+    {
+        return None;
+    }
+    let overlaps = source
+        .owners
+        .tiles
+        .iter()
+        .zip(cache.owners.tiles.iter())
+        .any(|(new, old)| *new != 0 && *old != 0);
+    if overlaps {
+        return None;
+    }
+    for (idx, owner) in source.owners.tiles.indexed_iter() {
+        if *owner != 0 {
+            cache.owners.tiles[idx] = *owner;
+            cache.field[idx] = source.field[idx].clone();
+        }
+    }
+    cache.owners.steps += source.owners.steps;
+    Some(cache)
+}
+
 /// Rebuilds the lighting field based on the current state of the board and behaviours
 /// by switching between legacy and new implementations.
 ///
@@ -32,20 +184,34 @@ pub fn rebuild_lighting_field(
     // info!("Starting rebuild_lighting_field using prebaked data");
     let build_start_time = Instant::now();
 
-    // Create a new light field with default values
-    let mut lfs = Array3::from_elem(bf.map_size, LightFieldData::default());
-
     // Identify active light sources
     let active_source_ids = identify_active_light_sources(bf, qt);
 
-    // Apply prebaked contributions from active sources
-    let _initial_tiles_lit = apply_prebaked_contributions(&active_source_ids, bf, &mut lfs);
-    let _prebake_time = build_start_time.elapsed();
-
-    // First pass of light propagation from wave edges
-    let time_main_propagation = Instant::now();
-    let _dynamic_propagation_count = propagate_from_wave_edges(bf, &mut lfs, &active_source_ids);
-    let _main_propagation_time = time_main_propagation.elapsed();
+    // Prebaked contributions and propagation from wave edges. When a single
+    // light toggled, only that light is updated in the cached field, and when
+    // only the flicker changed the cached field is used as is.
+    let toggled = bf.lighting_toggled_source.take();
+    let mut cache = match (toggled, bf.lighting_cache.take()) {
+        (Some(source_id), Some(cache)) => {
+            toggle_cached_source(bf, cache, source_id, active_source_ids.contains(&source_id))
+        }
+        (None, Some(cache)) if !bf.lighting_dirty && cache_matches_board(bf, &cache) => Some(cache),
+        _ => None,
+    }
+    .unwrap_or_else(|| build_lighting_cache(bf, &active_source_ids));
+    // The cache is only kept, at the cost of a copy of the field, when a later
+    // rebuild can reuse it: to toggle a single light or to rescale flickering
+    // lights. Otherwise the field is handed over as is.
+    let mut lfs = if cache.owners.complete || !bf.light_flicker_factors.is_empty() {
+        cache_shared_flicker_sources(bf, &mut cache);
+        let mut lfs = cache.field.clone();
+        apply_light_flicker(&cache, &bf.light_flicker_factors, &mut lfs);
+        bf.lighting_cache = Some(cache);
+        lfs
+    } else {
+        cache.field
+    };
+    let _main_propagation_time = build_start_time.elapsed();
 
     // Log light statistics before stair propagation
     // if bf.map_size.2 > 1 {
@@ -92,7 +258,7 @@ pub fn rebuild_lighting_field(
         //     "Starting stair light propagation with {} wave edges",
         //     stair_wave_edges.len()
         // );
-        _stair_propagation_count = propagate_from_wave_edges(bf, &mut lfs, &all_sources);
+        _stair_propagation_count = propagate_from_wave_edges(bf, &mut lfs, &all_sources, None);
 
         // Restore original wave edges
         bf.prebaked_wave_edges = original_wave_edges;
@@ -129,6 +295,8 @@ pub fn rebuild_lighting_field(
 
     // Calculate exposure and update board data
     update_exposure_and_stats(bf, &lfs);
+    bf.lighting_dirty = false;
+    bf.light_flicker_changed = false;
 
     let total_time = build_start_time.elapsed().as_secs_f32();
     let tot_cnt = 4.0;
@@ -262,3 +430,129 @@ fn apply_ambient_light_to_walls(bf: &BoardData, lfs: &mut Array3<LightFieldData>
     //     wall_light_start.elapsed()
     // );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+    use uncore::components::board::boardposition::BoardPosition;
+    use uncore::types::board::fielddata::CollisionFieldData;
+    use uncore::types::board::prebaked_lighting_data::{
+        LightInfo, PrebakedLightingData, WaveEdge, WaveEdgeData,
+    };
+
+    /// Two rooms split by a thick wall, with lights at the given tiles.
+    fn two_rooms(lights: &[(usize, usize)]) -> BoardData {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (60, 9, 1);
+        bf.collision_field = Array3::from_elem(bf.map_size, CollisionFieldData::default());
+        for ((i, _, _), collision) in bf.collision_field.indexed_iter_mut() {
+            let wall = (18..36).contains(&i);
+            collision.player_free = !wall;
+            collision.see_through = !wall;
+        }
+        bf.prebaked_lighting = Array3::from_elem(bf.map_size, PrebakedLightingData::default());
+        bf.prebaked_propagation = vec![Array2::from_elem((60, 9), [false; 4])];
+        for (n, &(x, y)) in lights.iter().enumerate() {
+            let source_id = n as u32 + 1;
+            bf.prebaked_lighting[(x, y, 0)].light_info = LightInfo {
+                source_id: Some(source_id),
+                lux: 10.0,
+                color: (1.0, 0.9, 0.8),
+            };
+            bf.prebaked_propagation
+                .push(Array2::from_elem((60, 9), [true; 4]));
+            bf.prebaked_wave_edges.push(WaveEdgeData {
+                position: BoardPosition {
+                    x: x as i64,
+                    y: y as i64,
+                    z: 0,
+                },
+                source_id,
+                lux: 10.0,
+                color: (1.0, 0.9, 0.8),
+                wave_edge: WaveEdge {
+                    src_light_lux: 10.0,
+                    distance_travelled: 1.0,
+                    ..default()
+                },
+            });
+        }
+        bf.prebaked_metadata
+            .index_source_tiles(&bf.prebaked_lighting);
+        bf
+    }
+
+    fn assert_same_field(a: &LightingCache, b: &LightingCache) {
+        for (x, y) in a.field.iter().zip(b.field.iter()) {
+            assert_eq!((x.lux, x.color), (y.lux, y.color));
+        }
+    }
+
+    #[test]
+    fn test_single_toggle_matches_full_rebuild() {
+        let bf = two_rooms(&[(4, 4), (50, 4)]);
+        let both = HashSet::from_iter([1, 2]);
+        let first = HashSet::from_iter([1]);
+
+        let cache = build_lighting_cache(&bf, &both);
+        let off = toggle_cached_source(&bf, cache, 2, false).expect("rooms don't share light");
+        assert_same_field(&off, &build_lighting_cache(&bf, &first));
+
+        let on = toggle_cached_source(&bf, off, 2, true).expect("rooms don't share light");
+        assert_same_field(&on, &build_lighting_cache(&bf, &both));
+    }
+
+    #[test]
+    fn test_flicker_scales_only_its_source() {
+        let bf = two_rooms(&[(4, 4), (50, 4)]);
+        let cache = build_lighting_cache(&bf, &HashSet::from_iter([1, 2]));
+        let mut lfs = cache.field.clone();
+        apply_light_flicker(&cache, &HashMap::from_iter([(2, 0.5)]), &mut lfs);
+
+        for (idx, owner) in cache.owners.tiles.indexed_iter() {
+            let expected = match owner {
+                2 => cache.field[idx].lux * 0.5,
+                _ => cache.field[idx].lux,
+            };
+            assert_eq!(lfs[idx].lux, expected);
+        }
+        assert!(lfs[(50, 4, 0)].lux > 0.0);
+        assert!(lfs[(50, 4, 0)].lux < cache.field[(50, 4, 0)].lux);
+    }
+
+    #[test]
+    fn test_flicker_dims_overlapping_light() {
+        let mut bf = two_rooms(&[(4, 4), (8, 4)]);
+        bf.light_flicker_factors = HashMap::from_iter([(2, 0.5)]);
+        let mut cache = build_lighting_cache(&bf, &HashSet::from_iter([1, 2]));
+        cache_shared_flicker_sources(&bf, &mut cache);
+        assert!(cache.source_lux.contains_key(&2));
+        assert!(!cache.source_lux.contains_key(&1));
+
+        let mut lfs = cache.field.clone();
+        apply_light_flicker(&cache, &bf.light_flicker_factors, &mut lfs);
+        for (l, full) in lfs.iter().zip(cache.field.iter()) {
+            assert!(l.lux >= 0.0 && l.lux <= full.lux);
+        }
+        let (shared, dimmed): (Vec<_>, Vec<_>) = cache
+            .owners
+            .tiles
+            .indexed_iter()
+            .filter(|(_, owner)| **owner == LightOwners::SHARED)
+            .map(|(idx, _)| (idx, lfs[idx].lux < cache.field[idx].lux))
+            .unzip();
+        assert!(!shared.is_empty());
+        assert!(dimmed.into_iter().any(|d| d));
+    }
+
+    #[test]
+    fn test_overlapping_lights_need_full_rebuild() {
+        let bf = two_rooms(&[(4, 4), (8, 4)]);
+        let cache = build_lighting_cache(&bf, &HashSet::from_iter([1, 2]));
+        assert!(toggle_cached_source(&bf, cache.clone(), 2, false).is_none());
+
+        let cache = build_lighting_cache(&bf, &HashSet::from_iter([1]));
+        assert!(toggle_cached_source(&bf, cache, 2, true).is_none());
+    }
+}
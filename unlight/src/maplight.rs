@@ -13,6 +13,7 @@
 //! * Systems for dynamically updating lighting and visibility as the player moves and
 //!   interacts with the environment.
 use bevy::{color::palettes::css, prelude::*};
+use bevy_persistent::Persistent;
 use bevy_platform::collections::HashMap;
 use bevy_platform::collections::HashSet;
 use core::f32;
@@ -31,6 +32,8 @@ use uncore::difficulty::CurrentDifficulty;
 use uncore::metric_recorder::SendMetric;
 use uncore::platform::plt::IS_WASM;
 use uncore::resources::board_data::BoardData;
+use uncore::resources::free_camera::FreeCamera;
+use uncore::resources::game_paused::gameplay_running;
 use uncore::resources::roomdb::RoomDB;
 use uncore::resources::visibility_data::VisibilityData;
 use uncore::types::board::fielddata::CollisionFieldData;
@@ -47,6 +50,7 @@ use unfog::resources::MiasmaConfig;
 use ungear::components::deployedgear::{DeployedGear, DeployedGearData};
 use ungear::components::playergear::PlayerGear;
 use ungearitems::components::salt::UVReactive;
+use unsettings::video::VideoSettings;
 use unstd::materials::CustomMaterial1;
 
 pub use uncore::components::board::mapcolour::MapColour;
@@ -174,16 +178,19 @@ fn player_visibility_system(
     gc: Res<GameConfig>,
     qp: Query<(&Position, &PlayerSprite)>,
     mut roomdb: ResMut<RoomDB>,
+    free_camera: Res<FreeCamera>,
 ) {
     let measure = PLAYER_VISIBILITY.time_measure();
 
-    // Find the active player's position
-    let Some(player_pos) = qp.iter().find_map(|(pos, player)| {
-        if player.id == gc.player_id {
-            Some(*pos)
-        } else {
-            None
-        }
+    // Find the active player's position, or the free camera's view when inspecting the map
+    let Some(player_pos) = free_camera.view_position().or_else(|| {
+        qp.iter().find_map(|(pos, player)| {
+            if player.id == gc.player_id {
+                Some(*pos)
+            } else {
+                None
+            }
+        })
     }) else {
         return;
     };
@@ -200,6 +207,7 @@ fn player_visibility_system(
         Some(&mut roomdb),
         false,
     );
+    vf.update_visible_hash();
     measure.end_ms();
 }
 
@@ -258,6 +266,7 @@ fn apply_lighting(
     )>,
     difficulty: Res<CurrentDifficulty>,
     miasma_config: Res<MiasmaConfig>,
+    video_settings: Res<Persistent<VideoSettings>>,
     mut visible: Local<HashSet<Entity>>,
 ) {
     let measure = APPLY_LIGHTING.time_measure();
@@ -442,7 +451,9 @@ fn apply_lighting(
     }
     bf.current_exposure_accel = bf.current_exposure_accel.powf(0.99);
     bf.current_exposure *= bf.current_exposure_accel;
-    let exposure = bf.current_exposure;
+    // Player brightness setting: a lower exposure divisor brightens the whole scene
+    // without affecting the eye adaptation above.
+    let exposure = bf.current_exposure / video_settings.brightness.gain();
     let mut lightdata_map: HashMap<BoardPosition, LightData> = HashMap::new();
 
     // Primes: 13,17,19,23,29,31,37,41,43,47,53,59,61,67,71,173,281,409,541,659,809
@@ -1086,7 +1097,9 @@ pub(crate) fn app_setup(app: &mut App) {
         Update,
         (
             player_visibility_system.run_if(in_state(AppState::InGame)),
-            apply_lighting.run_if(in_state(AppState::InGame)),
+            apply_lighting
+                .run_if(in_state(AppState::InGame))
+                .run_if(gameplay_running),
         ),
     );
 }
@@ -242,12 +242,22 @@ pub fn prebake_lighting_field(bf: &mut BoardData, qt: &Query<(Entity, &Position,
 
     // Store the prebaked data in BoardData
     bf.prebaked_lighting = prebaked;
+    bf.prebaked_metadata
+        .index_source_tiles(&bf.prebaked_lighting);
     // Pass the HashSet of all source IDs to find_wave_edge_tiles
     bf.prebaked_wave_edges = find_wave_edge_tiles(bf, &all_source_ids);
 
     // Add the call to prebake_propagation_data here
     prebake_propagation_data(bf);
 
+    // A new level: whatever the light field was built from no longer applies.
+    bf.lighting_sources.clear();
+    bf.lighting_door_states.clear();
+    bf.lighting_toggled_source = None;
+    bf.lighting_cache = None;
+    bf.light_flicker_factors.clear();
+    bf.lighting_dirty = true;
+
     info!(
         "Prebaked lighting field computed in: {:?}",
         build_start_time.elapsed()
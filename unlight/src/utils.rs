@@ -9,6 +9,8 @@ use uncore::{
     resources::board_data::BoardData,
     types::board::{
         fielddata::LightFieldData,
+        light::normalize_color,
+        light_cache::LightOwners,
         prebaked_lighting_data::{WaveEdge, WaveEdgeData},
     },
 };
@@ -72,6 +74,9 @@ pub fn is_light_active(
 }
 
 /// Blend two colours based on their intensity
+///
+/// Blending is done in RGB space; lights authored with a colour temperature
+/// are converted to RGB when baked, so they mix like any other colour.
 pub fn blend_colors(
     c1: (f32, f32, f32),
     lux1: f32,
@@ -120,10 +125,39 @@ pub fn identify_active_light_sources(
 }
 
 /// Apply prebaked light contributions from active sources
+///
+/// Only the tiles of the active sources are visited, using the per-source tile
+/// lists of the prebaked metadata. Boards without those lists fall back to
+/// scanning every tile.
 pub fn apply_prebaked_contributions(
     active_source_ids: &HashSet<u32>,
     bf: &BoardData,
     lfs: &mut Array3<LightFieldData>,
+) -> usize {
+    let source_tiles = &bf.prebaked_metadata.source_tiles;
+    if source_tiles.is_empty() {
+        return apply_prebaked_contributions_scan(active_source_ids, bf, lfs);
+    }
+    let mut tiles_lit = 0;
+    for source_id in active_source_ids {
+        let Some(tiles) = source_tiles.get(*source_id as usize) else {
+            continue;
+        };
+        for &pos_idx in tiles {
+            let light_info = &bf.prebaked_lighting[pos_idx].light_info;
+            lfs[pos_idx].lux = light_info.lux;
+            lfs[pos_idx].color = light_info.color;
+        }
+        tiles_lit += tiles.len();
+    }
+    tiles_lit
+}
+
+/// Apply prebaked light contributions from active sources by scanning every tile
+pub fn apply_prebaked_contributions_scan(
+    active_source_ids: &HashSet<u32>,
+    bf: &BoardData,
+    lfs: &mut Array3<LightFieldData>,
 ) -> usize {
     let mut tiles_lit = 0;
     let mut v_active = vec![false; bf.prebaked_propagation.len()];
@@ -168,12 +202,19 @@ pub fn update_exposure_and_stats(bf: &mut BoardData, lfs: &Array3<LightFieldData
     //     max_lux
     // );
 
+    // Clamp before computing exposure, so it follows what is actually shown.
+    bf.light_field = lfs.clone();
+    let lux_clamp = bf.lux_clamp;
+    for light in bf.light_field.iter_mut() {
+        light.lux = lux_clamp.apply(light.lux);
+        light.color = normalize_color(light.color);
+    }
+
     // Calculate exposure
-    let total_lux: f32 = lfs.iter().map(|x| x.lux).sum();
+    let total_lux: f32 = bf.light_field.iter().map(|x| x.lux).sum();
     let count = total_tiles as f32;
     let avg_lux = total_lux / count;
     bf.exposure_lux = (avg_lux + 2.0) / 2.0;
-    bf.light_field = lfs.clone();
 
     // info!("Final exposure_lux set to: {}", bf.exposure_lux);
 }
@@ -253,10 +294,14 @@ fn apply_iir_filter(
 }
 
 /// Propagates light from wave edge tiles past dynamic objects
+///
+/// With `owners`, every tile a source reads or lights is recorded, for the
+/// incremental rebuild.
 pub fn propagate_from_wave_edges(
     bf: &BoardData,
     lfs: &mut Array3<LightFieldData>,
     active_source_ids: &HashSet<u32>,
+    mut owners: Option<&mut LightOwners>,
 ) -> usize {
     // New struct to track position history for turn detection
     #[derive(Clone)]
@@ -312,8 +357,21 @@ pub fn propagate_from_wave_edges(
     //     stair_wave_edge_count
     // );
 
-    // Process queue using BFS
+    // Process queue using BFS, up to the step budget
+    let mut steps = 0;
     while let Some(edge_data) = queue.pop_front() {
+        if steps >= bf.propagation_step_budget {
+            warn!(
+                "Light propagation stopped after {} steps ({} wave edges left); keeping the partial result",
+                steps,
+                queue.len() + 1
+            );
+            if let Some(owners) = owners.as_deref_mut() {
+                owners.complete = false;
+            }
+            break;
+        }
+        steps += 1;
         let pos = edge_data.position;
         let max_lux_possible = edge_data.wave_edge.src_light_lux
             / (edge_data.wave_edge.distance_travelled * edge_data.wave_edge.distance_travelled);
@@ -379,6 +437,9 @@ pub fn propagate_from_wave_edges(
             };
 
             let neighbour_idx = neighbour_pos.ndidx();
+            if let Some(owners) = owners.as_deref_mut() {
+                owners.touch(neighbour_idx, edge_data.source_id);
+            }
 
             // Stop checking early if this neighbour is already too bright
             if lfs[neighbour_idx].lux > max_lux_possible * 4.0 {
@@ -534,6 +595,9 @@ pub fn propagate_from_wave_edges(
     //     "Light propagation: {} total steps, {} from stairs",
     //     propagation_count, stair_propagation_count
     // );
+    if let Some(owners) = owners {
+        owners.steps += steps;
+    }
     propagation_count
 }
 
@@ -702,3 +766,99 @@ pub fn create_stair_wave_edges(bf: &BoardData, lfs: &Array3<LightFieldData>) ->
     // );
     wave_edges
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+    use uncore::types::board::fielddata::CollisionFieldData;
+    use uncore::types::board::prebaked_lighting_data::LightInfo;
+
+    /// An open floor with a single bright wave edge in the middle.
+    fn open_field(size: usize) -> BoardData {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (size, size, 1);
+        bf.collision_field = Array3::from_elem(
+            bf.map_size,
+            CollisionFieldData {
+                player_free: true,
+                see_through: true,
+                ..default()
+            },
+        );
+        bf.prebaked_lighting = Array3::from_elem(bf.map_size, default());
+        bf.prebaked_propagation = vec![
+            Array2::from_elem((size, size), [false; 4]),
+            Array2::from_elem((size, size), [true; 4]),
+        ];
+        let centre = (size / 2) as i64;
+        bf.prebaked_wave_edges = vec![WaveEdgeData {
+            position: BoardPosition {
+                x: centre,
+                y: centre,
+                z: 0,
+            },
+            source_id: 1,
+            lux: 10.0,
+            color: (1.0, 1.0, 1.0),
+            wave_edge: WaveEdge {
+                src_light_lux: 10.0,
+                distance_travelled: 1.0,
+                ..default()
+            },
+        }];
+        bf
+    }
+
+    #[test]
+    fn test_propagation_stops_at_step_budget() {
+        let mut bf = open_field(64);
+        let sources = HashSet::from_iter([1]);
+
+        let mut lfs = Array3::from_elem(bf.map_size, LightFieldData::default());
+        let unbounded = propagate_from_wave_edges(&bf, &mut lfs, &sources, None);
+
+        bf.propagation_step_budget = 100;
+        let mut lfs = Array3::from_elem(bf.map_size, LightFieldData::default());
+        let bounded = propagate_from_wave_edges(&bf, &mut lfs, &sources, None);
+
+        // Every step queues at most one new edge per direction.
+        assert!(bounded <= 4 * 100);
+        assert!(bounded < unbounded);
+        assert!(lfs.iter().any(|l| l.lux > 0.0));
+    }
+
+    #[test]
+    fn test_source_tile_lists_match_scan() {
+        let mut bf = open_field(24);
+        bf.prebaked_propagation
+            .resize(4, Array2::from_elem((24, 24), [false; 4]));
+        for ((i, j, _), data) in bf.prebaked_lighting.indexed_iter_mut() {
+            // Three sources lighting separate areas: a corner square and two halves.
+            let source_id = match (i, j) {
+                (0..4, 0..4) => 3,
+                (0..12, _) => 1,
+                _ => 2,
+            };
+            data.light_info = LightInfo {
+                source_id: Some(source_id),
+                lux: (i * 24 + j) as f32 * 0.1,
+                color: (1.0, 0.5, source_id as f32 / 3.0),
+            };
+        }
+        bf.prebaked_metadata
+            .index_source_tiles(&bf.prebaked_lighting);
+
+        for sources in [vec![], vec![1], vec![2, 3], vec![1, 2, 3]] {
+            let sources = HashSet::from_iter(sources);
+            let mut scanned = Array3::from_elem(bf.map_size, LightFieldData::default());
+            let mut listed = scanned.clone();
+            let scanned_count = apply_prebaked_contributions_scan(&sources, &bf, &mut scanned);
+            let listed_count = apply_prebaked_contributions(&sources, &bf, &mut listed);
+            assert_eq!(listed_count, scanned_count);
+            for (a, b) in listed.iter().zip(scanned.iter()) {
+                assert_eq!((a.lux, a.color), (b.lux, b.color));
+            }
+        }
+    }
+}
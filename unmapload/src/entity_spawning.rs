@@ -6,6 +6,7 @@
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 use ordered_float::OrderedFloat;
+use rand::Rng;
 use rand::seq::SliceRandom;
 use uncore::components::animation::{AnimationTimer, CharacterAnimation};
 use uncore::components::board::direction::Direction;
@@ -20,8 +21,13 @@ use uncore::components::player::Stamina;
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::components::sprite_type::SpriteType;
 use uncore::random_seed;
+use uncore::resources::board_data::BoardData;
+use uncore::resources::cli_options::CliOptions;
+use uncore::resources::mission_autosave::PendingResume;
 use uncore::resources::summary_data::SummaryData;
 use uncore::types::game::SoundType;
+use uncore::types::ghost::definitions::{GhostSelection, GhostSet};
+use uncore::types::ghost::types::GhostType;
 use ungear::components::playergear::PlayerGear;
 use ungearitems::from_gearkind::FromPlayerGearKind as _;
 
@@ -89,7 +95,11 @@ pub fn spawn_player(
         .insert(PlayerGear::from_playergearkind(
             p.difficulty.0.player_gear.clone(),
         ))
-        .insert(PlayerSprite::new(1, player_position).with_controls(**p.control_settings))
+        .insert(
+            PlayerSprite::new(1, player_position)
+                .with_controls(**p.control_settings)
+                .with_health(p.difficulty.0.max_health),
+        )
         // Update the SpatialListener to use the ear offset from audio settings
         .insert(SpatialListener::new(
             -p.audio_settings.sound_output.to_ear_offset(),
@@ -127,8 +137,6 @@ pub fn spawn_ghosts(
     commands: &mut Commands,
     ghost_spawn_points: &mut [Position],
 ) {
-    // Clear existing evidence records and get RNG
-    p.bf.evidences.clear();
     let mut rng = random_seed::rng();
 
     // Select a ghost spawn point using the selection function
@@ -142,15 +150,16 @@ pub fn spawn_ghosts(
         });
 
     // Determine ghost type based on difficulty settings
-    let possible_ghost_types: Vec<_> = p.difficulty.0.ghost_set.as_vec();
-    let ghost_sprite = GhostSprite::new(ghost_spawn.to_board_position(), &possible_ghost_types);
+    let ghost_type = select_mission_ghost(
+        &mut p.bf,
+        p.difficulty.0.ghost_set,
+        p.pending_resume.as_deref(),
+        p.cli_options.as_deref(),
+        &mut rng,
+    );
+    let ghost_sprite = GhostSprite::new(ghost_spawn.to_board_position(), &[ghost_type]);
     let ghost_types = vec![ghost_sprite.class];
 
-    // Collect ghost evidences in board data
-    for evidence in ghost_sprite.class.evidences() {
-        p.bf.evidences.insert(evidence);
-    }
-
     // Store breach position in board data
     p.bf.breach_pos = ghost_spawn;
 
@@ -214,6 +223,25 @@ pub fn spawn_ghosts(
         });
 }
 
+/// Picks the ghost of the mission and records its evidences in the board
+/// data. A resumed mission keeps the ghost it had.
+fn select_mission_ghost(
+    bf: &mut BoardData,
+    ghost_set: GhostSet,
+    pending_resume: Option<&PendingResume>,
+    cli_options: Option<&CliOptions>,
+    rng: &mut impl Rng,
+) -> GhostType {
+    let selection = match pending_resume {
+        Some(PendingResume(snapshot)) => GhostSelection::Forced(snapshot.ghost_type),
+        None => cli_options.map(|o| o.ghost_selection).unwrap_or_default(),
+    };
+    let ghost_type = ghost_set.select(selection, rng);
+    bf.evidences.clear();
+    bf.evidences.extend(ghost_type.evidences());
+    ghost_type
+}
+
 /// Spawns ambient sound entities for the game environment.
 ///
 /// This function creates audio entities for various ambient sounds:
@@ -296,3 +324,29 @@ pub fn spawn_ambient_sounds(p: &LoadLevelSystemParam, commands: &mut Commands) {
             class: SoundType::Insane,
         });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_forced_ghost_evidences_are_on_the_board() {
+        let mut bf = BoardData::default();
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(7);
+        let cli_options = CliOptions {
+            ghost_selection: GhostSelection::Forced(GhostType::Kappa),
+            ..default()
+        };
+        // Kappa is outside of the TmpEMF set; forcing still uses it.
+        let ghost = select_mission_ghost(
+            &mut bf,
+            GhostSet::TmpEMF,
+            None,
+            Some(&cli_options),
+            &mut rng,
+        );
+        assert_eq!(ghost, GhostType::Kappa);
+        assert_eq!(bf.evidences, GhostType::Kappa.evidences());
+    }
+}
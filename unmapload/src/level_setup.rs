@@ -14,6 +14,8 @@ use uncore::components::ghost_influence::InfluenceType;
 use uncore::difficulty::CurrentDifficulty;
 use uncore::events::loadlevel::{LevelLoadedEvent, LevelReadyEvent};
 use uncore::resources::board_data::BoardData;
+use uncore::resources::cli_options::CliOptions;
+use uncore::resources::mission_autosave::PendingResume;
 use uncore::resources::roomdb::RoomDB;
 use uncore::types::board::fielddata::{CollisionFieldData, LightFieldData};
 use uncore::types::root::game_assets::GameAssets;
@@ -48,6 +50,8 @@ pub struct LoadLevelSystemParam<'w> {
     pub difficulty: Res<'w, CurrentDifficulty>,
     pub audio_settings: Res<'w, Persistent<unsettings::audio::AudioSettings>>,
     pub control_settings: Res<'w, Persistent<unsettings::controls::ControlKeys>>,
+    pub cli_options: Option<Res<'w, CliOptions>>,
+    pub pending_resume: Option<Res<'w, PendingResume>>,
 }
 
 /// Marker component to handle ghost influence assignment after level loading is complete
@@ -169,6 +173,7 @@ fn load_level_handler(
     p.bf.temperature_field_prev = Array3::from_elem(map_size, p.bf.ambient_temp);
     p.bf.temperature_activity = Array3::from_elem(map_size, 0.0);
     p.bf.collision_field = Array3::from_elem(map_size, CollisionFieldData::default());
+    p.bf.collision_revision = p.bf.collision_revision.wrapping_add(1);
     p.bf.connectivity_scores =
         Array3::from_elem(map_size, p.bf.temp_diffusion_config.default_score);
     p.bf.light_field = Array3::from_elem(map_size, LightFieldData::default());
@@ -216,7 +221,7 @@ fn load_level_handler(
     }) {
         // Get floor z-index from the layer's floor_number
         let floor_z = if let Some(floor_num) = layer.floor_number {
-            p.bf.floor_z_map.get(&floor_num).copied().unwrap_or(0)
+            p.bf.z_for_floor(floor_num).unwrap_or(0)
         } else {
             0 // Default to ground floor if no floor number is set
         };
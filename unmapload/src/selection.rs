@@ -86,7 +86,7 @@ pub fn select_influence_objects(
         let z_index = floor_z as usize;
 
         // Look up the original floor number (from the TMX file)
-        if let Some(&tiled_floor_num) = board_data.z_floor_map.get(&z_index) {
+        if let Some(tiled_floor_num) = board_data.floor_for_z(z_index) {
             // Check if this floor has specific requirements for ghost attracting objects
             if let Some(&attract_count) = board_data
                 .floor_mapping
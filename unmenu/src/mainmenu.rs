@@ -1,7 +1,10 @@
 use bevy::app::AppExit;
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
+use uncore::difficulty::CurrentDifficulty;
+use uncore::events::loadlevel::LoadLevelEvent;
 use uncore::platform::plt::VERSION;
+use uncore::resources::mission_autosave::{MissionAutosave, PendingResume};
 use uncore::resources::mission_select_mode::{CurrentMissionSelectMode, MissionSelectMode};
 use uncore::states::{AppState, MapHubState};
 use uncore::types::root::game_assets::GameAssets;
@@ -13,6 +16,7 @@ use unsettings::audio::AudioSettings;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
 pub enum MenuID {
+    ResumeMission,
     Campaign,
     CustomMission,
     Manual,
@@ -23,6 +27,7 @@ pub enum MenuID {
 impl std::fmt::Display for MenuID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let text = match &self {
+            MenuID::ResumeMission => "Resume Last Mission",
             MenuID::Campaign => "Story Campaign",
             MenuID::CustomMission => "Free Play",
             MenuID::Manual => "User Manual",
@@ -73,15 +78,21 @@ pub fn setup_ui(
     mut commands: Commands,
     handles: Res<GameAssets>,
     player_profile: Res<Persistent<PlayerProfileData>>,
+    autosave: Option<Res<Persistent<MissionAutosave>>>,
 ) {
-    let menu_items = vec![
+    let mut menu_items = vec![];
+    // Only offered when the last mission was cut short
+    if autosave.is_some_and(|a| a.mission.is_some()) {
+        menu_items.push((MenuID::ResumeMission, MenuID::ResumeMission.to_string()));
+    }
+    menu_items.extend([
         (MenuID::Campaign, MenuID::Campaign.to_string()),
         (MenuID::CustomMission, MenuID::CustomMission.to_string()),
         (MenuID::Manual, MenuID::Manual.to_string()),
         (MenuID::Settings, MenuID::Settings.to_string()),
         #[cfg(not(target_arch = "wasm32"))]
         (MenuID::Quit, MenuID::Quit.to_string()),
-    ];
+    ]);
 
     warn!("Setting up main menu with items: {:?}", menu_items);
 
@@ -134,11 +145,15 @@ pub fn cleanup(
 }
 
 pub fn menu_event(
+    mut commands: Commands,
     mut click_events: EventReader<MenuItemClicked>,
     mut exit: EventWriter<AppExit>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_map_hub_state: ResMut<NextState<MapHubState>>,
     mut current_mission_select_mode: ResMut<CurrentMissionSelectMode>,
+    mut difficulty: ResMut<CurrentDifficulty>,
+    mut ev_load_level: EventWriter<LoadLevelEvent>,
+    autosave: Option<Res<Persistent<MissionAutosave>>>,
     menu_items: Query<(&MenuID, &MenuItemInteractive)>,
 ) {
     for ev in click_events.read() {
@@ -152,6 +167,19 @@ pub fn menu_event(
             .find(|(_, interactive)| interactive.identifier == ev.pos)
         {
             match menu_id {
+                MenuID::ResumeMission => {
+                    let Some(snapshot) = autosave.as_ref().and_then(|a| a.mission.clone()) else {
+                        warn!("No mission to resume");
+                        continue;
+                    };
+                    *difficulty = CurrentDifficulty::new(snapshot.difficulty);
+                    ev_load_level.write(LoadLevelEvent {
+                        map_filepath: snapshot.map_filepath.clone(),
+                    });
+                    commands.insert_resource(PendingResume(snapshot));
+                    next_app_state.set(AppState::Loading);
+                    info!("Resuming the last mission");
+                }
                 MenuID::Campaign => {
                     // Set the mission select mode to Campaign
                     current_mission_select_mode.0 = MissionSelectMode::Campaign;
@@ -201,8 +229,7 @@ pub fn manage_title_song(
         }
     } else if should_play_song {
         // Only spawn the song if the volume is greater than 0
-        let desired_volume =
-            audio_settings.volume_music.as_f32() * audio_settings.volume_master.as_f32();
+        let desired_volume = audio_settings.music_volume();
         if desired_volume > 0.0 {
             commands
                 .spawn(MenuSound::default())
@@ -232,8 +259,7 @@ pub fn despawn_sound(
         let v = if menusound.despawn {
             vol / 1.02
         } else {
-            let desired_vol =
-                audio_settings.volume_music.as_f32() * audio_settings.volume_master.as_f32();
+            let desired_vol = audio_settings.music_volume();
             const STEPS: f32 = 120.0;
             if vol < desired_vol / 2.0 {
                 vol * 1.02
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy_platform::time::Instant;
 use unsettings::{
-    audio::AudioSettingsValue, game::GameplaySettingsValue, video::VideoSettingsValue,
+    audio::AudioSettingsValue,
+    game::GameplaySettingsValue,
+    video::{VideoSettings, VideoSettingsValue},
 };
 
 use crate::menus::ProfileSettingsValue;
@@ -36,6 +38,16 @@ pub struct CustomNameInput {
 #[derive(Component)]
 pub struct TextInputField;
 
+/// Query typed into the settings search screen
+#[derive(Component, Default)]
+pub struct SettingsSearchInput {
+    pub current_text: String,
+}
+
+/// Text line showing how many settings match the current search query
+#[derive(Component)]
+pub struct SettingsSearchFeedback;
+
 #[derive(Component, Debug, Clone, PartialEq, Eq, Hash, States, Default)]
 pub enum SettingsState {
     /// Selects which Setting file/category to edit in the UI (Audio, Video, etc)
@@ -47,8 +59,83 @@ pub enum SettingsState {
     Lv3ValueEdit(MenuSettingsLevel1),
     /// Allows the user to input a custom display name
     CustomNameInput,
+    /// Allows the user to type a query to find a setting across all categories
+    SearchInput,
+    /// Lists the settings matching the search query, from any category
+    SearchResults,
+    /// Asks the user to keep a display change before it is reverted automatically
+    VideoChangeConfirm,
+    /// Asks the user to confirm before Dev God Mode is enabled
+    DevCheatModeConfirm,
+}
+
+impl SettingsState {
+    /// Kind of menu drawn for this state.
+    pub fn menu_type(&self) -> MenuType {
+        match self {
+            SettingsState::Lv1ClassSelection => MenuType::MainCategories,
+            SettingsState::Lv2List | SettingsState::SearchResults => MenuType::CategorySettingList,
+            SettingsState::Lv3ValueEdit(_)
+            | SettingsState::CustomNameInput
+            | SettingsState::SearchInput
+            | SettingsState::VideoChangeConfirm
+            | SettingsState::DevCheatModeConfirm => MenuType::SettingEdit,
+        }
+    }
+
+    /// Keys available on this screen, shown at the bottom of the menu.
+    pub fn help_text(&self) -> String {
+        const NAVIGATE: &str = "[↑]/[↓]: Navigate";
+        match self {
+            SettingsState::Lv1ClassSelection => {
+                format!("{NAVIGATE} • [Enter]: Open • [Esc]: Back")
+            }
+            SettingsState::Lv2List => format!("{NAVIGATE} • [Enter]: Change • [Esc]: Back"),
+            SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Video) => format!(
+                "{NAVIGATE} • [Enter]: Apply (display changes ask to confirm) • [Esc]: Back"
+            ),
+            SettingsState::Lv3ValueEdit(_) => format!("{NAVIGATE} • [Enter]: Apply • [Esc]: Back"),
+            SettingsState::CustomNameInput => {
+                "Type your name • [Backspace]: Delete • [Enter]: Save • [Esc]: Cancel".to_string()
+            }
+            SettingsState::SearchInput => {
+                "Type to search • [Backspace]: Delete • [Enter]: Open • [Esc]: Cancel".to_string()
+            }
+            SettingsState::SearchResults => {
+                format!("{NAVIGATE} • [Enter]: Change • [Esc]: Back to Categories")
+            }
+            SettingsState::VideoChangeConfirm => {
+                format!("{NAVIGATE} • [Enter]: Select • [Esc]: Revert")
+            }
+            SettingsState::DevCheatModeConfirm => {
+                format!("{NAVIGATE} • [Enter]: Select • [Esc]: Cancel")
+            }
+        }
+    }
 }
 
+/// Seconds the user has to confirm a display change before it is reverted
+pub const VIDEO_CHANGE_CONFIRM_SECS: f32 = 10.0;
+
+/// Tracks a display change awaiting the user's confirmation
+#[derive(Resource, Debug, Default)]
+pub enum VideoChangeConfirmation {
+    #[default]
+    Idle,
+    /// The change is applied; `previous` is the snapshot to go back to
+    Pending {
+        previous: VideoSettings,
+        changed: VideoSettingsValue,
+        started_at: Instant,
+    },
+    /// The previous value is being restored through `SaveVideoSetting`
+    Reverting,
+}
+
+/// Text showing the seconds left before a display change is reverted
+#[derive(Component)]
+pub struct VideoConfirmCountdown;
+
 #[derive(Component)]
 pub struct SettingsStateTimer {
     pub state_entered_at: Instant,
@@ -78,6 +165,13 @@ pub enum MenuEvent {
     EditProfileSetting(ProfileSettingsMenu),
     StartCustomNameInput,
     DeleteCustomName(String),
+    StartSettingsSearch,
+    ExportSettingsBundle,
+    ImportSettingsBundle,
+    KeepVideoChange,
+    RevertVideoChange,
+    ConfirmDevCheatMode,
+    CancelDevCheatMode,
     SettingClassSelected(MenuSettingsLevel1),
     Back(MenuEvBack),
     #[default]
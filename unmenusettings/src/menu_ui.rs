@@ -1,9 +1,10 @@
 use crate::components::*;
 use crate::menus::MenuSettingsLevel1;
 use bevy::prelude::*;
-use uncore::states::AppState;
+use uncore::states::{AppState, GameState};
 use uncore::types::root::game_assets::GameAssets;
 use uncoremenu::components::{MenuMouseTracker, MenuRoot};
+use uncoremenu::scrollbar;
 use uncoremenu::templates;
 
 fn setup_ui_cam(mut commands: Commands) {
@@ -16,7 +17,14 @@ fn setup_ui_main_cat_system(
     qtui: Query<Entity, With<SettingsMenu>>,
 ) {
     let menu_items = MenuSettingsLevel1::iter_events();
-    setup_ui_main_cat(&mut commands, &handles, &qtui, "Settings", &menu_items);
+    setup_ui_main_cat(
+        &mut commands,
+        &handles,
+        &qtui,
+        "Settings",
+        &menu_items,
+        SettingsState::Lv1ClassSelection,
+    );
 }
 
 /// Helper function to set up the main categories UI for settings menu (not a system)
@@ -26,6 +34,7 @@ pub(crate) fn setup_ui_main_cat(
     qtui: &Query<Entity, With<SettingsMenu>>,
     title: impl Into<String>,
     menu_items: &[(String, MenuEvent)],
+    state: SettingsState,
 ) {
     for e in qtui.iter() {
         commands.entity(e).despawn();
@@ -40,7 +49,7 @@ pub(crate) fn setup_ui_main_cat(
             ..default()
         })
         .insert(SettingsMenu {
-            menu_type: MenuType::MainCategories,
+            menu_type: state.menu_type(),
             selected_item_idx: 0,
         })
         .with_children(|parent| {
@@ -74,59 +83,45 @@ pub(crate) fn setup_ui_main_cat(
 
             // Add a column container inside the content area for vertical layout
             content_area.with_children(|content| {
-                content
-                    .spawn(Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Column,
-                        align_items: AlignItems::FlexStart,
-                        justify_content: JustifyContent::FlexStart,
-                        overflow: Overflow::scroll_y(),
-                        ..default()
-                    })
-                    .with_children(|menu_list| {
-                        let mut idx = 0;
-
-                        // Add each menu item
-                        for (item_text, event) in menu_items.iter() {
-                            if !event.is_none() {
-                                templates::create_content_item(
-                                    menu_list,
-                                    item_text,
-                                    idx,
-                                    idx == 0, // First item selected by default
-                                    handles
-                                )
-                                .insert(MenuItem::new(idx, event.clone()));
-                                idx += 1;
-                            } else {
-                                // Add disabled item with grey colour
-                                templates::create_content_item_disabled(
-                                    menu_list,
-                                    item_text,
-                                    handles
-                                );
-                            }
+                scrollbar::build_scrollable_list(content, handles, |menu_list| {
+                    let mut idx = 0;
+
+                    // Add each menu item
+                    for (item_text, event) in menu_items.iter() {
+                        if !event.is_none() {
+                            templates::create_content_item(
+                                menu_list,
+                                item_text,
+                                idx,
+                                idx == 0, // First item selected by default
+                                handles
+                            )
+                            .insert(MenuItem::new(idx, event.clone()));
+                            idx += 1;
+                        } else {
+                            // Add disabled item with grey colour
+                            templates::create_content_item_disabled(
+                                menu_list,
+                                item_text,
+                                handles
+                            );
                         }
-
-                        // Add "Go Back" option
-                        templates::create_content_item(
-                            menu_list,
-                            "Go Back",
-                            idx,
-                            false,
-                            handles
-                        )
-                        .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
-                    });
+                    }
+
+                    // Add "Go Back" option
+                    templates::create_content_item(
+                        menu_list,
+                        "Go Back",
+                        idx,
+                        false,
+                        handles
+                    )
+                    .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
+                });
             });
 
             // Help text
-            templates::create_help_text(
-                parent,
-                handles,
-                Some("[Up]/[Down] arrows to navigate. Press [Enter] to select or [Escape] to go back".to_string())
-            );
+            templates::create_help_text(parent, handles, Some(state.help_text()));
         })
         .id();
 
@@ -155,19 +150,33 @@ fn cleanup(
     }
 }
 
+/// Keeps the settings screens above the game HUD when opened in-game.
+fn raise_over_game_ui(mut commands: Commands, qtui: Query<Entity, Added<SettingsMenu>>) {
+    for e in qtui.iter() {
+        commands.entity(e).insert(GlobalZIndex(1000));
+    }
+}
+
+fn setup_state_timer(mut commands: Commands) {
+    commands.spawn(SettingsStateTimer {
+        state_entered_at: bevy_platform::time::Instant::now(),
+    });
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(
         OnEnter(AppState::SettingsMenu),
-        (
-            setup_ui_cam,
-            setup_ui_main_cat_system,
-            |mut commands: Commands| {
-                commands.spawn(SettingsStateTimer {
-                    state_entered_at: bevy_platform::time::Instant::now(),
-                });
-            },
-        )
-            .chain(),
+        (setup_ui_cam, setup_ui_main_cat_system, setup_state_timer).chain(),
+    )
+    .add_systems(OnExit(AppState::SettingsMenu), cleanup)
+    // From the pause screen the game camera is still there to draw the UI.
+    .add_systems(
+        OnEnter(GameState::Settings),
+        (setup_ui_main_cat_system, setup_state_timer).chain(),
     )
-    .add_systems(OnExit(AppState::SettingsMenu), cleanup);
+    .add_systems(OnExit(GameState::Settings), cleanup)
+    .add_systems(
+        PostUpdate,
+        raise_over_game_ui.run_if(in_state(GameState::Settings)),
+    );
 }
@@ -3,9 +3,16 @@ use bevy_persistent::Persistent;
 use strum::IntoEnumIterator;
 use unsettings::{
     audio::{AudioLevel, AudioSettings, AudioSettingsValue},
-    game::{CameraControls, DevCheatMode, GameplaySettings, GameplaySettingsValue, MovementStyle},
+    game::{
+        AutosaveInterval, CameraControls, CameraFollow, CameraZoomIn, CameraZoomOut, DevCheatMode,
+        GameplaySettings, GameplaySettingsValue, HintFrequency, LowSanityGuidance, MovementStyle,
+        PathfindingMoves, StickDeadzone, StickResponse,
+    },
     profile::{ProfileSettings, Profilecolour},
-    video::{AspectRatio, VideoSettings, VideoSettingsValue, ZoomLevel, display::Resolution},
+    video::{
+        AspectRatio, Brightness, ColourblindMode, FpsOverlay, UiThemeKind, VideoSettings,
+        VideoSettingsValue, ZoomLevel, display::Resolution,
+    },
 };
 
 #[expect(non_camel_case_types)]
@@ -36,22 +43,65 @@ impl MenuSettingsLevel1 {
         }
     }
 
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MenuSettingsLevel1::Gameplay => "Gameplay & Controls",
+            MenuSettingsLevel1::Audio => "Audio & Sound",
+            MenuSettingsLevel1::Video => "Graphics & Display",
+            MenuSettingsLevel1::Profile => "Player Profile",
+        }
+    }
+
     pub fn iter_events() -> Vec<(String, MenuEvent)> {
         use strum::IntoEnumIterator;
-        Self::iter()
-            .map(|s| {
-                let display_name = match s {
-                    MenuSettingsLevel1::Gameplay => "Gameplay & Controls",
-                    MenuSettingsLevel1::Audio => "Audio & Sound",
-                    MenuSettingsLevel1::Video => "Graphics & Display",
-                    MenuSettingsLevel1::Profile => "Player Profile",
-                };
-                (display_name.to_string(), s.menu_event())
-            })
-            .collect::<Vec<_>>()
+        let mut events = Self::iter()
+            .map(|s| (s.display_name().to_string(), s.menu_event()))
+            .collect::<Vec<_>>();
+        events.push((
+            "Search Settings...".to_string(),
+            MenuEvent::StartSettingsSearch,
+        ));
+        events.push((
+            "Export All Settings".to_string(),
+            MenuEvent::ExportSettingsBundle,
+        ));
+        events.push((
+            "Import All Settings".to_string(),
+            MenuEvent::ImportSettingsBundle,
+        ));
+        events
     }
 }
 
+/// Finds the settings whose display name contains `query`, ignoring case.
+///
+/// Searches every category and returns entries labelled "Category > Setting"
+/// whose event opens the setting's value editor directly.
+pub fn search_settings(query: &str) -> Vec<(String, MenuEvent)> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return vec![];
+    }
+    let entries = GameplaySettingsMenu::iter()
+        .map(|s| (MenuSettingsLevel1::Gameplay, s.to_string(), s.menu_event()))
+        .chain(
+            VideoSettingsMenu::iter()
+                .map(|s| (MenuSettingsLevel1::Video, s.to_string(), s.menu_event())),
+        )
+        .chain(
+            AudioSettingsMenu::iter()
+                .map(|s| (MenuSettingsLevel1::Audio, s.to_string(), s.menu_event())),
+        )
+        .chain(
+            ProfileSettingsMenu::iter()
+                .map(|s| (MenuSettingsLevel1::Profile, s.to_string(), s.menu_event())),
+        );
+    entries
+        .filter(|(_, name, _)| name.to_lowercase().contains(&query))
+        .map(|(category, name, event)| (format!("{} > {}", category.display_name(), name), event))
+        .collect()
+}
+
 #[derive(strum::Display, strum::EnumIter, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AudioSettingsMenu {
     #[strum(to_string = "Master Volume")]
@@ -62,7 +112,7 @@ pub enum AudioSettingsMenu {
     VolumeEffects,
     #[strum(to_string = "Ambient Sounds")]
     VolumeAmbient,
-    #[strum(to_string = "Voice Chat")]
+    #[strum(to_string = "Walkie-Talkie Voice")]
     VolumeVoiceChat,
     #[strum(to_string = "Audio Output")]
     SoundOutput,
@@ -72,6 +122,12 @@ pub enum AudioSettingsMenu {
     FeedbackDelay,
     #[strum(to_string = "Audio Enhancement")]
     FeedbackEq,
+    #[strum(to_string = "Hold Button Ticks")]
+    HoldTickCue,
+    #[strum(to_string = "Volume Curve")]
+    VolumeCurve,
+    #[strum(to_string = "Mute When Unfocused")]
+    UnfocusedMute,
 }
 
 #[derive(strum::Display, strum::EnumIter, Debug, Clone, Copy, PartialEq, Eq)]
@@ -84,6 +140,14 @@ pub enum VideoSettingsMenu {
     UiScale,
     #[strum(to_string = "Font Size")]
     FontScale,
+    #[strum(to_string = "Show FPS")]
+    ShowFps,
+    #[strum(to_string = "Brightness")]
+    Brightness,
+    #[strum(to_string = "Colourblind Mode")]
+    ColourblindMode,
+    #[strum(to_string = "UI Theme")]
+    UiTheme,
 }
 
 #[derive(strum::Display, strum::EnumIter, Debug, Clone, Copy, PartialEq, Eq)]
@@ -107,6 +171,14 @@ impl VideoSettingsMenu {
             VideoSettingsMenu::FontScale => {
                 MenuEvent::EditVideoSetting(VideoSettingsMenu::FontScale)
             }
+            VideoSettingsMenu::ShowFps => MenuEvent::EditVideoSetting(VideoSettingsMenu::ShowFps),
+            VideoSettingsMenu::Brightness => {
+                MenuEvent::EditVideoSetting(VideoSettingsMenu::Brightness)
+            }
+            VideoSettingsMenu::ColourblindMode => {
+                MenuEvent::EditVideoSetting(VideoSettingsMenu::ColourblindMode)
+            }
+            VideoSettingsMenu::UiTheme => MenuEvent::EditVideoSetting(VideoSettingsMenu::UiTheme),
         }
     }
 
@@ -161,6 +233,38 @@ impl VideoSettingsMenu {
                     )
                 })
                 .collect(),
+            VideoSettingsMenu::ShowFps => FpsOverlay::iter()
+                .map(|v| {
+                    (
+                        v.to_string(),
+                        MenuEvent::SaveVideoSetting(VideoSettingsValue::show_fps(v)),
+                    )
+                })
+                .collect(),
+            VideoSettingsMenu::Brightness => Brightness::iter()
+                .map(|v| {
+                    (
+                        v.to_string(),
+                        MenuEvent::SaveVideoSetting(VideoSettingsValue::brightness(v)),
+                    )
+                })
+                .collect(),
+            VideoSettingsMenu::ColourblindMode => ColourblindMode::iter()
+                .map(|v| {
+                    (
+                        v.to_string(),
+                        MenuEvent::SaveVideoSetting(VideoSettingsValue::colourblind_mode(v)),
+                    )
+                })
+                .collect(),
+            VideoSettingsMenu::UiTheme => UiThemeKind::iter()
+                .map(|v| {
+                    (
+                        v.to_string(),
+                        MenuEvent::SaveVideoSetting(VideoSettingsValue::ui_theme(v)),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -277,9 +381,13 @@ impl AudioSettingsMenu {
             | Self::VolumeMusic
             | Self::VolumeAmbient
             | Self::VolumeVoiceChat => MenuEvent::EditAudioSetting(*self),
-            Self::SoundOutput | Self::AudioPositioning | Self::FeedbackDelay | Self::FeedbackEq => {
-                MenuEvent::EditAudioSetting(*self)
-            }
+            Self::SoundOutput
+            | Self::AudioPositioning
+            | Self::FeedbackDelay
+            | Self::FeedbackEq
+            | Self::HoldTickCue
+            | Self::VolumeCurve
+            | Self::UnfocusedMute => MenuEvent::EditAudioSetting(*self),
         }
     }
 
@@ -294,6 +402,9 @@ impl AudioSettingsMenu {
             AudioSettingsMenu::AudioPositioning => audio_settings.audio_positioning.to_string(),
             AudioSettingsMenu::FeedbackDelay => audio_settings.feedback_delay.to_string(),
             AudioSettingsMenu::FeedbackEq => audio_settings.feedback_eq.to_string(),
+            AudioSettingsMenu::HoldTickCue => audio_settings.hold_tick_cue.to_string(),
+            AudioSettingsMenu::VolumeCurve => audio_settings.volume_curve.to_string(),
+            AudioSettingsMenu::UnfocusedMute => audio_settings.unfocused_mute.to_string(),
         }
     }
 
@@ -421,6 +532,60 @@ impl AudioSettingsMenu {
                     })
                     .collect::<Vec<_>>()
             }
+            AudioSettingsMenu::HoldTickCue => {
+                use unsettings::audio::HoldTickCue;
+                let to_string = |s: HoldTickCue, v: &HoldTickCue| -> String {
+                    if s == *v {
+                        format!("[{s}]")
+                    } else {
+                        s.to_string()
+                    }
+                };
+                HoldTickCue::iter()
+                    .map(|s| {
+                        (
+                            to_string(s, &audio_settings.hold_tick_cue),
+                            MenuEvent::SaveAudioSetting(AudioSettingsValue::hold_tick_cue(s)),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }
+            AudioSettingsMenu::VolumeCurve => {
+                use unsettings::audio::VolumeCurve;
+                let to_string = |s: VolumeCurve, v: &VolumeCurve| -> String {
+                    if s == *v {
+                        format!("[{s}]")
+                    } else {
+                        s.to_string()
+                    }
+                };
+                VolumeCurve::iter()
+                    .map(|s| {
+                        (
+                            to_string(s, &audio_settings.volume_curve),
+                            MenuEvent::SaveAudioSetting(AudioSettingsValue::volume_curve(s)),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }
+            AudioSettingsMenu::UnfocusedMute => {
+                use unsettings::audio::UnfocusedMute;
+                let to_string = |s: UnfocusedMute, v: &UnfocusedMute| -> String {
+                    if s == *v {
+                        format!("[{s}]")
+                    } else {
+                        s.to_string()
+                    }
+                };
+                UnfocusedMute::iter()
+                    .map(|s| {
+                        (
+                            to_string(s, &audio_settings.unfocused_mute),
+                            MenuEvent::SaveAudioSetting(AudioSettingsValue::unfocused_mute(s)),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }
         }
     }
 
@@ -443,8 +608,26 @@ impl AudioSettingsMenu {
 pub enum GameplaySettingsMenu {
     #[strum(to_string = "Movement Mode")]
     MovementStyle,
+    #[strum(to_string = "Click Paths")]
+    PathfindingMoves,
+    #[strum(to_string = "Stick Deadzone")]
+    StickDeadzone,
+    #[strum(to_string = "Stick Response")]
+    StickResponse,
     #[strum(to_string = "Camera Movement")]
     CameraControls,
+    #[strum(to_string = "Camera Follow")]
+    CameraFollow,
+    #[strum(to_string = "Max Zoom In")]
+    CameraZoomIn,
+    #[strum(to_string = "Max Zoom Out")]
+    CameraZoomOut,
+    #[strum(to_string = "Hint Frequency")]
+    HintFrequency,
+    #[strum(to_string = "Low Sanity Guidance")]
+    LowSanityGuidance,
+    #[strum(to_string = "Autosave")]
+    AutosaveInterval,
     #[strum(to_string = "Dev God Mode")]
     DevCheatMode,
 }
@@ -453,7 +636,16 @@ impl GameplaySettingsMenu {
     pub fn menu_event(&self) -> MenuEvent {
         match self {
             GameplaySettingsMenu::MovementStyle => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::PathfindingMoves => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::StickDeadzone => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::StickResponse => MenuEvent::EditGameplaySetting(*self),
             GameplaySettingsMenu::CameraControls => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::CameraFollow => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::CameraZoomIn => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::CameraZoomOut => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::HintFrequency => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::LowSanityGuidance => MenuEvent::EditGameplaySetting(*self),
+            GameplaySettingsMenu::AutosaveInterval => MenuEvent::EditGameplaySetting(*self),
             GameplaySettingsMenu::DevCheatMode => MenuEvent::EditGameplaySetting(*self),
         }
     }
@@ -461,7 +653,18 @@ impl GameplaySettingsMenu {
     pub fn setting_value(&self, game_settings: &Res<Persistent<GameplaySettings>>) -> String {
         match self {
             GameplaySettingsMenu::MovementStyle => game_settings.movement_style.to_string(),
+            GameplaySettingsMenu::PathfindingMoves => game_settings.pathfinding_moves.to_string(),
+            GameplaySettingsMenu::StickDeadzone => game_settings.stick_deadzone.to_string(),
+            GameplaySettingsMenu::StickResponse => game_settings.stick_response.to_string(),
             GameplaySettingsMenu::CameraControls => game_settings.camera_controls.to_string(),
+            GameplaySettingsMenu::CameraFollow => game_settings.camera_follow.to_string(),
+            GameplaySettingsMenu::CameraZoomIn => game_settings.camera_zoom_in.to_string(),
+            GameplaySettingsMenu::CameraZoomOut => game_settings.camera_zoom_out.to_string(),
+            GameplaySettingsMenu::HintFrequency => game_settings.hint_frequency.to_string(),
+            GameplaySettingsMenu::LowSanityGuidance => {
+                game_settings.low_sanity_guidance.to_string()
+            }
+            GameplaySettingsMenu::AutosaveInterval => game_settings.autosave_interval.to_string(),
             GameplaySettingsMenu::DevCheatMode => game_settings.dev_cheat_mode.to_string(),
         }
     }
@@ -483,6 +686,42 @@ impl GameplaySettingsMenu {
                     )
                 })
                 .collect::<Vec<_>>(),
+            GameplaySettingsMenu::PathfindingMoves => PathfindingMoves::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.pathfinding_moves {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::pathfinding_moves(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::StickDeadzone => StickDeadzone::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.stick_deadzone {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::stick_deadzone(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::StickResponse => StickResponse::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.stick_response {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::stick_response(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
             GameplaySettingsMenu::CameraControls => CameraControls::iter()
                 .map(|s| {
                     (
@@ -495,6 +734,80 @@ impl GameplaySettingsMenu {
                     )
                 })
                 .collect::<Vec<_>>(),
+            GameplaySettingsMenu::CameraFollow => CameraFollow::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.camera_follow {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::camera_follow(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::CameraZoomIn => CameraZoomIn::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.camera_zoom_in {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::camera_zoom_in(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::CameraZoomOut => CameraZoomOut::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.camera_zoom_out {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::camera_zoom_out(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::HintFrequency => HintFrequency::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.hint_frequency {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::hint_frequency(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::LowSanityGuidance => LowSanityGuidance::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.low_sanity_guidance {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::low_sanity_guidance(
+                            s,
+                        )),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            GameplaySettingsMenu::AutosaveInterval => AutosaveInterval::iter()
+                .map(|s| {
+                    (
+                        if s == game_settings.autosave_interval {
+                            format!("[{s}]")
+                        } else {
+                            s.to_string()
+                        },
+                        MenuEvent::SaveGameplaySetting(GameplaySettingsValue::autosave_interval(s)),
+                    )
+                })
+                .collect::<Vec<_>>(),
             GameplaySettingsMenu::DevCheatMode => DevCheatMode::iter()
                 .map(|s| {
                     (
@@ -524,3 +837,34 @@ impl GameplaySettingsMenu {
             .collect::<Vec<_>>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_settings_is_case_insensitive() {
+        let results = search_settings("MASTER VOLUME");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, "Audio & Sound > Master Volume");
+        assert!(matches!(
+            results[0].1,
+            MenuEvent::EditAudioSetting(AudioSettingsMenu::VolumeMaster)
+        ));
+    }
+
+    #[test]
+    fn test_search_settings_spans_categories_and_handles_no_match() {
+        let results = search_settings("name");
+        assert!(results.iter().any(|(_, ev)| matches!(
+            ev,
+            MenuEvent::EditProfileSetting(ProfileSettingsMenu::DisplayName)
+        )));
+        assert!(results.iter().any(|(_, ev)| matches!(
+            ev,
+            MenuEvent::EditProfileSetting(ProfileSettingsMenu::Colour)
+        )));
+        assert!(search_settings("no such setting").is_empty());
+        assert!(search_settings("   ").is_empty());
+    }
+}
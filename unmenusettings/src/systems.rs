@@ -1,24 +1,31 @@
 use crate::components::{
     AudioSettingSelected, CustomNameInput, GameplaySettingSelected, MenuEvBack, MenuEvent,
     MenuItem, MenuSettingClassSelected, MenuType, ProfileSettingSelected, SaveAudioSetting,
-    SaveGameplaySetting, SaveProfileSetting, SaveVideoSetting, SettingsMenu, SettingsState,
-    SettingsStateTimer, TextInputField, VideoSettingSelected,
+    SaveGameplaySetting, SaveProfileSetting, SaveVideoSetting, SettingsMenu,
+    SettingsSearchFeedback, SettingsSearchInput, SettingsState, SettingsStateTimer, TextInputField,
+    VideoSettingSelected,
 };
 use crate::menu_ui::setup_ui_main_cat;
 use crate::menus::{
     AudioSettingsMenu, GameplaySettingsMenu, MenuSettingsLevel1, ProfileSettingsMenu,
-    ProfileSettingsValue, VideoSettingsMenu,
+    ProfileSettingsValue, VideoSettingsMenu, search_settings,
 };
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
+use bevy_platform::time::Instant;
 use uncore::colours::{MENU_ITEM_COLOR_OFF, MENU_ITEM_COLOR_ON};
-use uncore::states::AppState;
+use uncore::resources::ui_theme::{UiColourRole, UiFontRole, UiTheme};
+use uncore::states::{AppState, GameState};
 use uncore::types::root::game_assets::GameAssets;
-use uncoremenu::components::{MenuItemInteractive, MenuMouseTracker, MenuRoot};
-use uncoremenu::systems::MenuItemClicked;
+use uncoremenu::components::{MenuItemInteractive, MenuMouseTracker, MenuRoot, ThemedText};
+use uncoremenu::scrollbar;
+use uncoremenu::systems::{GRACE_PERIOD_SECS, MenuItemClicked, MenuItemSelected};
 use uncoremenu::templates;
 use unsettings::audio::AudioSettings;
-use unsettings::game::GameplaySettings;
+use unsettings::bundle::{ExportSettingsBundle, ImportSettingsBundle, default_bundle_path};
+use unsettings::changed::SettingsChanged;
+use unsettings::game::{DevCheatMode, GameplaySettings, GameplaySettingsValue};
+use unsettings::persist::{PersistRetry, persist_with_retry};
 use unsettings::profile::ProfileSettings;
 use unsettings::video::VideoSettings;
 
@@ -26,6 +33,7 @@ pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(
         Update,
         (
+            sync_selected_item_system,
             item_highlight_system,
             menu_routing_system,
             menu_back_event,
@@ -45,8 +53,19 @@ pub(crate) fn app_setup(app: &mut App) {
             update_custom_name_display_system,
             delete_custom_name_system,
         )
-            .run_if(in_state(AppState::SettingsMenu)),
+            .run_if(settings_menu_open),
     )
+    .add_systems(
+        Update,
+        (
+            settings_search_start_system,
+            settings_search_text_input_system,
+            update_settings_search_display_system,
+            video_change_countdown_system,
+        )
+            .run_if(settings_menu_open),
+    )
+    .init_resource::<VideoChangeConfirmation>()
     .add_event::<MenuEvent>()
     .add_event::<MenuEvBack>()
     .add_event::<MenuSettingClassSelected>()
@@ -60,21 +79,41 @@ pub(crate) fn app_setup(app: &mut App) {
     .add_event::<SaveProfileSetting>();
 }
 
+/// The settings menu is open, either from the main menu or from the pause
+/// screen during a mission.
+pub fn settings_menu_open(
+    app_state: Option<Res<State<AppState>>>,
+    game_state: Option<Res<State<GameState>>>,
+) -> bool {
+    app_state.is_some_and(|s| *s.get() == AppState::SettingsMenu)
+        || game_state.is_some_and(|s| *s.get() == GameState::Settings)
+}
+
+/// Mirrors the selection made through the shared menu navigation (arrows,
+/// paging, Home/End, mouse) into the settings menu.
+fn sync_selected_item_system(
+    mut ev_selected: EventReader<MenuItemSelected>,
+    mut menu: Query<&mut SettingsMenu>,
+) {
+    let Some(selected) = ev_selected.read().last() else {
+        return;
+    };
+    if let Ok(mut menu) = menu.single_mut() {
+        menu.selected_item_idx = selected.0;
+    }
+}
+
 fn item_highlight_system(
     menu: Query<&SettingsMenu>,
     mut menu_items: Query<(&MenuItem, &mut TextColor)>,
+    theme: Res<UiTheme>,
 ) {
     let Ok(menu) = menu.single() else {
         return;
     }; // Assuming you have only one Menu component
     for (item, mut text_color) in &mut menu_items {
         let is_selected = item.idx == menu.selected_item_idx;
-        let colour = if is_selected {
-            MENU_ITEM_COLOR_ON
-        } else {
-            MENU_ITEM_COLOR_OFF
-        };
-        text_color.0 = colour;
+        text_color.0 = theme.highlight_colour(is_selected);
     }
 }
 
@@ -90,6 +129,9 @@ fn menu_routing_system(
     mut ev_save_video_setting: EventWriter<SaveVideoSetting>,
     mut ev_profile_setting: EventWriter<ProfileSettingSelected>,
     mut ev_save_profile_setting: EventWriter<SaveProfileSetting>,
+    mut ev_export_bundle: EventWriter<ExportSettingsBundle>,
+    mut ev_import_bundle: EventWriter<ImportSettingsBundle>,
+    mut video_confirmation: ResMut<VideoChangeConfirmation>,
 ) {
     for ev in ev_menu.read() {
         match ev {
@@ -146,6 +188,44 @@ fn menu_routing_system(
             MenuEvent::DeleteCustomName(_) => {
                 // This will be handled by the delete_custom_name_system
             }
+            MenuEvent::StartSettingsSearch => {
+                // This will be handled by the settings_search_start_system
+            }
+            MenuEvent::ExportSettingsBundle => {
+                ev_export_bundle.write(ExportSettingsBundle {
+                    path: default_bundle_path(),
+                });
+            }
+            MenuEvent::ImportSettingsBundle => {
+                ev_import_bundle.write(ImportSettingsBundle {
+                    path: default_bundle_path(),
+                });
+            }
+            MenuEvent::KeepVideoChange => {
+                if matches!(*video_confirmation, VideoChangeConfirmation::Pending { .. }) {
+                    *video_confirmation = VideoChangeConfirmation::Idle;
+                    ev_back.write(MenuEvBack);
+                }
+            }
+            MenuEvent::ConfirmDevCheatMode => {
+                ev_save_game_setting.write(SaveGameplaySetting {
+                    value: GameplaySettingsValue::dev_cheat_mode(DevCheatMode::Enabled),
+                });
+            }
+            MenuEvent::CancelDevCheatMode => {
+                ev_back.write(MenuEvBack);
+            }
+            MenuEvent::RevertVideoChange => {
+                if let VideoChangeConfirmation::Pending {
+                    previous, changed, ..
+                } = &*video_confirmation
+                {
+                    let revert = previous.value_for(changed);
+                    warn!("Reverting video setting to {:?}", revert);
+                    *video_confirmation = VideoChangeConfirmation::Reverting;
+                    ev_save_video_setting.write(SaveVideoSetting(revert));
+                }
+            }
         }
     }
 }
@@ -154,23 +234,40 @@ fn menu_back_event(
     mut events: EventReader<MenuEvBack>,
     mut next_state: ResMut<NextState<SettingsState>>,
     mut app_next_state: ResMut<NextState<AppState>>,
+    game_state: Res<State<GameState>>,
+    mut game_next_state: ResMut<NextState<GameState>>,
     settings_state: Res<State<SettingsState>>,
     mut ev_menu: EventWriter<MenuSettingClassSelected>,
+    mut ev_menu_event: EventWriter<MenuEvent>,
     mut commands: Commands,
     handles: Res<GameAssets>,
     qtui: Query<Entity, With<SettingsMenu>>,
+    video_confirmation: Res<VideoChangeConfirmation>,
 ) {
     for _ev in events.read() {
         match settings_state.get() {
             SettingsState::Lv1ClassSelection => {
-                app_next_state.set(AppState::MainMenu);
+                // Opened from the pause screen: go back to it, the mission
+                // is still running underneath.
+                if *game_state.get() == GameState::Settings {
+                    game_next_state.set(GameState::Pause);
+                } else {
+                    app_next_state.set(AppState::MainMenu);
+                }
                 next_state.set(SettingsState::default());
             }
             SettingsState::Lv2List => {
                 next_state.set(SettingsState::Lv1ClassSelection);
                 // Redraw Main Menu:
                 let menu_items = MenuSettingsLevel1::iter_events();
-                setup_ui_main_cat(&mut commands, &handles, &qtui, "Settings", &menu_items);
+                setup_ui_main_cat(
+                    &mut commands,
+                    &handles,
+                    &qtui,
+                    "Settings",
+                    &menu_items,
+                    SettingsState::Lv1ClassSelection,
+                );
             }
             SettingsState::Lv3ValueEdit(menu) => {
                 ev_menu.write(MenuSettingClassSelected { menu: *menu });
@@ -178,6 +275,33 @@ fn menu_back_event(
             SettingsState::CustomNameInput => {
                 next_state.set(SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Profile));
             }
+            SettingsState::VideoChangeConfirm => {
+                if matches!(*video_confirmation, VideoChangeConfirmation::Pending { .. }) {
+                    // Backing out of the prompt means the change was not accepted
+                    ev_menu_event.write(MenuEvent::RevertVideoChange);
+                } else {
+                    ev_menu.write(MenuSettingClassSelected {
+                        menu: MenuSettingsLevel1::Video,
+                    });
+                }
+            }
+            SettingsState::DevCheatModeConfirm => {
+                ev_menu.write(MenuSettingClassSelected {
+                    menu: MenuSettingsLevel1::Gameplay,
+                });
+            }
+            SettingsState::SearchInput | SettingsState::SearchResults => {
+                next_state.set(SettingsState::Lv1ClassSelection);
+                let menu_items = MenuSettingsLevel1::iter_events();
+                setup_ui_main_cat(
+                    &mut commands,
+                    &handles,
+                    &qtui,
+                    "Settings",
+                    &menu_items,
+                    SettingsState::Lv1ClassSelection,
+                );
+            }
         }
     }
 }
@@ -204,6 +328,7 @@ fn menu_settings_class_selected(
                     &qtui,
                     "Audio Settings",
                     &menu_items,
+                    SettingsState::Lv2List,
                 );
                 next_state.set(SettingsState::Lv2List);
             }
@@ -215,6 +340,7 @@ fn menu_settings_class_selected(
                     &qtui,
                     "Gameplay Settings",
                     &menu_items,
+                    SettingsState::Lv2List,
                 );
                 next_state.set(SettingsState::Lv2List);
             }
@@ -226,6 +352,7 @@ fn menu_settings_class_selected(
                     &qtui,
                     "Video Settings",
                     &menu_items,
+                    SettingsState::Lv2List,
                 );
                 next_state.set(SettingsState::Lv2List);
             }
@@ -237,6 +364,7 @@ fn menu_settings_class_selected(
                     &qtui,
                     "Profile Settings",
                     &menu_items,
+                    SettingsState::Lv2List,
                 );
                 next_state.set(SettingsState::Lv2List);
             }
@@ -301,47 +429,35 @@ fn menu_audio_setting_selected(
 
                 // Add a column container inside the content area for vertical layout
                 content_area.with_children(|content| {
-                    content
-                        .spawn(Node {
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Column,
-                            align_items: AlignItems::FlexStart,
-                            justify_content: JustifyContent::FlexStart,
-                            overflow: Overflow::scroll_y(),
-                            ..default()
-                        })
-                        .with_children(|menu_list| {
-                            let mut idx = 0;
-
-                            // Add each menu item
-                            for (item_text, event) in menu_items.iter() {
-                                if !event.is_none() {
-                                    templates::create_content_item(
-                                        menu_list,
-                                        item_text,
-                                        idx,
-                                        idx == 0, // First item selected by default
-                                        &handles,
-                                    )
-                                    .insert(MenuItem::new(idx, event.clone()));
-                                    idx += 1;
-                                }
+                    scrollbar::build_scrollable_list(content, &handles, |menu_list| {
+                        let mut idx = 0;
+
+                        // Add each menu item
+                        for (item_text, event) in menu_items.iter() {
+                            if !event.is_none() {
+                                templates::create_content_item(
+                                    menu_list,
+                                    item_text,
+                                    idx,
+                                    idx == 0, // First item selected by default
+                                    &handles,
+                                )
+                                .insert(MenuItem::new(idx, event.clone()));
+                                idx += 1;
                             }
+                        }
 
-                            // Add "Go Back" option
-                            templates::create_content_item(
-                                menu_list, "Go Back", idx, false, &handles,
-                            )
+                        // Add "Go Back" option
+                        templates::create_content_item(menu_list, "Go Back", idx, false, &handles)
                             .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
-                        });
+                    });
                 });
 
                 // Help text
                 templates::create_help_text(
                     parent,
                     &handles,
-                    Some("[↑]/[↓]: Navigate • [Enter]: Select • [Esc]: Back".to_string()),
+                    Some(SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Audio).help_text()),
                 );
             });
 
@@ -352,7 +468,9 @@ fn menu_audio_setting_selected(
 fn menu_save_audio_setting(
     mut events: EventReader<SaveAudioSetting>,
     mut ev_back: EventWriter<MenuEvBack>,
+    mut ev_changed: EventWriter<SettingsChanged<AudioSettings>>,
     mut audio_settings: ResMut<Persistent<AudioSettings>>,
+    mut audio_retry: ResMut<PersistRetry<AudioSettings>>,
 ) {
     use unsettings::audio::AudioSettingsValue as v;
 
@@ -386,10 +504,20 @@ fn menu_save_audio_setting(
             v::feedback_eq(feedback_eq) => {
                 audio_settings.feedback_eq = feedback_eq;
             }
+            v::hold_tick_cue(hold_tick_cue) => {
+                audio_settings.hold_tick_cue = hold_tick_cue;
+            }
+            v::volume_curve(volume_curve) => {
+                audio_settings.volume_curve = volume_curve;
+            }
+            v::unfocused_mute(unfocused_mute) => {
+                audio_settings.unfocused_mute = unfocused_mute;
+            }
         }
-        if let Err(e) = audio_settings.persist() {
+        if let Err(e) = persist_with_retry(&mut *audio_settings, &mut audio_retry) {
             error!("Error persisting Audio Settings: {e:?}");
         }
+        ev_changed.write(SettingsChanged::new(**audio_settings));
         ev_back.write(MenuEvBack);
     }
 }
@@ -452,47 +580,35 @@ fn menu_gameplay_setting_selected(
 
                 // Add a column container inside the content area for vertical layout
                 content_area.with_children(|content| {
-                    content
-                        .spawn(Node {
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Column,
-                            align_items: AlignItems::FlexStart,
-                            justify_content: JustifyContent::FlexStart,
-                            overflow: Overflow::scroll_y(),
-                            ..default()
-                        })
-                        .with_children(|menu_list| {
-                            let mut idx = 0;
-
-                            // Add each menu item
-                            for (item_text, event) in menu_items.iter() {
-                                if !event.is_none() {
-                                    templates::create_content_item(
-                                        menu_list,
-                                        item_text,
-                                        idx,
-                                        idx == 0, // First item selected by default
-                                        &handles,
-                                    )
-                                    .insert(MenuItem::new(idx, event.clone()));
-                                    idx += 1;
-                                }
+                    scrollbar::build_scrollable_list(content, &handles, |menu_list| {
+                        let mut idx = 0;
+
+                        // Add each menu item
+                        for (item_text, event) in menu_items.iter() {
+                            if !event.is_none() {
+                                templates::create_content_item(
+                                    menu_list,
+                                    item_text,
+                                    idx,
+                                    idx == 0, // First item selected by default
+                                    &handles,
+                                )
+                                .insert(MenuItem::new(idx, event.clone()));
+                                idx += 1;
                             }
+                        }
 
-                            // Add "Go Back" option
-                            templates::create_content_item(
-                                menu_list, "Go Back", idx, false, &handles,
-                            )
+                        // Add "Go Back" option
+                        templates::create_content_item(menu_list, "Go Back", idx, false, &handles)
                             .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
-                        });
+                    });
                 });
 
                 // Help text
                 templates::create_help_text(
                     parent,
                     &handles,
-                    Some("[↑]/[↓]: Navigate • [Enter]: Select • [Esc]: Back".to_string()),
+                    Some(SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Gameplay).help_text()),
                 );
             });
 
@@ -501,13 +617,43 @@ fn menu_gameplay_setting_selected(
 }
 
 fn menu_save_gameplay_setting(
+    mut commands: Commands,
     mut events: EventReader<SaveGameplaySetting>,
     mut ev_back: EventWriter<MenuEvBack>,
+    mut ev_changed: EventWriter<SettingsChanged<GameplaySettings>>,
+    mut ev_profile_changed: EventWriter<SettingsChanged<ProfileSettings>>,
     mut gameplay_settings: ResMut<Persistent<GameplaySettings>>,
+    mut gameplay_retry: ResMut<PersistRetry<GameplaySettings>>,
+    mut profile_settings: ResMut<Persistent<ProfileSettings>>,
+    mut profile_retry: ResMut<PersistRetry<ProfileSettings>>,
+    settings_state: Res<State<SettingsState>>,
+    mut next_state: ResMut<NextState<SettingsState>>,
+    handles: Res<GameAssets>,
+    qtui: Query<Entity, With<SettingsMenu>>,
 ) {
     use unsettings::game::GameplaySettingsValue as v;
 
     for ev in events.read() {
+        // Enabling God Mode goes through a confirmation prompt first; the
+        // prompt sends the same value again once the player agrees.
+        let confirmed = *settings_state.get() == SettingsState::DevCheatModeConfirm;
+        if let v::dev_cheat_mode(mode) = ev.value
+            && mode.is_enabled()
+            && !gameplay_settings.dev_cheat_mode.is_enabled()
+        {
+            if !confirmed {
+                setup_ui_dev_cheat_confirm(&mut commands, &handles, &qtui);
+                next_state.set(SettingsState::DevCheatModeConfirm);
+                continue;
+            }
+            if !profile_settings.cheats_used {
+                profile_settings.cheats_used = true;
+                if let Err(e) = persist_with_retry(&mut *profile_settings, &mut profile_retry) {
+                    error!("Error persisting Profile Settings: {e:?}");
+                }
+                ev_profile_changed.write(SettingsChanged::new((**profile_settings).clone()));
+            }
+        }
         warn!("Save Gameplay Setting: {:?}", ev.value);
         match ev.value {
             v::movement_style(movement_style) => {
@@ -516,16 +662,44 @@ fn menu_save_gameplay_setting(
             v::camera_controls(camera_controls) => {
                 gameplay_settings.camera_controls = camera_controls;
             }
+            v::pathfinding_moves(pathfinding_moves) => {
+                gameplay_settings.pathfinding_moves = pathfinding_moves;
+            }
+            v::stick_deadzone(stick_deadzone) => {
+                gameplay_settings.stick_deadzone = stick_deadzone;
+            }
+            v::stick_response(stick_response) => {
+                gameplay_settings.stick_response = stick_response;
+            }
+            v::low_sanity_guidance(low_sanity_guidance) => {
+                gameplay_settings.low_sanity_guidance = low_sanity_guidance;
+            }
+            v::autosave_interval(autosave_interval) => {
+                gameplay_settings.autosave_interval = autosave_interval;
+            }
+            v::camera_follow(camera_follow) => {
+                gameplay_settings.camera_follow = camera_follow;
+            }
+            v::camera_zoom_in(camera_zoom_in) => {
+                gameplay_settings.camera_zoom_in = camera_zoom_in;
+            }
+            v::camera_zoom_out(camera_zoom_out) => {
+                gameplay_settings.camera_zoom_out = camera_zoom_out;
+            }
             v::character_controls(character_controls) => {
                 gameplay_settings.character_controls = character_controls;
             }
             v::dev_cheat_mode(dev_cheat_mode) => {
                 gameplay_settings.dev_cheat_mode = dev_cheat_mode;
             }
+            v::hint_frequency(hint_frequency) => {
+                gameplay_settings.hint_frequency = hint_frequency;
+            }
         }
-        if let Err(e) = gameplay_settings.persist() {
+        if let Err(e) = persist_with_retry(&mut *gameplay_settings, &mut gameplay_retry) {
             error!("Error persisting Gameplay Settings: {e:?}");
         }
+        ev_changed.write(SettingsChanged::new(**gameplay_settings));
         ev_back.write(MenuEvBack);
     }
 }
@@ -536,21 +710,19 @@ fn menu_integration_system(
     menu_items: Query<(&MenuItem, &MenuItemInteractive)>,
     state_timer: Query<&SettingsStateTimer>,
 ) {
-    // Define a small grace period to ignore events from previous state
-    const GRACE_PERIOD_SECS: f32 = 0.1;
-
     // Get time since state entered
     if let Ok(timer) = state_timer.single() {
         let time_in_state = timer.state_entered_at.elapsed().as_secs_f32();
 
-        // Ignore events that happened too soon after state transition
+        // Ignore events from the previous state that happened too soon after
+        // the state transition
         if time_in_state < GRACE_PERIOD_SECS {
             menu_clicks.clear();
             return;
         }
 
         for click_event in menu_clicks.read() {
-            if click_event.state != AppState::SettingsMenu {
+            if !matches!(click_event.state, AppState::SettingsMenu | AppState::InGame) {
                 warn!(
                     "MenuItemClicked event received in state: {:?}",
                     click_event.state
@@ -634,47 +806,35 @@ fn menu_video_setting_selected(
 
                 // Add a column container inside the content area for vertical layout
                 content_area.with_children(|content| {
-                    content
-                        .spawn(Node {
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Column,
-                            align_items: AlignItems::FlexStart,
-                            justify_content: JustifyContent::FlexStart,
-                            overflow: Overflow::scroll_y(),
-                            ..default()
-                        })
-                        .with_children(|menu_list| {
-                            let mut idx = 0;
-
-                            // Add each menu item
-                            for (item_text, event) in menu_items.iter() {
-                                if !event.is_none() {
-                                    templates::create_content_item(
-                                        menu_list,
-                                        item_text,
-                                        idx,
-                                        idx == 0, // First item selected by default
-                                        &handles,
-                                    )
-                                    .insert(MenuItem::new(idx, event.clone()));
-                                    idx += 1;
-                                }
+                    scrollbar::build_scrollable_list(content, &handles, |menu_list| {
+                        let mut idx = 0;
+
+                        // Add each menu item
+                        for (item_text, event) in menu_items.iter() {
+                            if !event.is_none() {
+                                templates::create_content_item(
+                                    menu_list,
+                                    item_text,
+                                    idx,
+                                    idx == 0, // First item selected by default
+                                    &handles,
+                                )
+                                .insert(MenuItem::new(idx, event.clone()));
+                                idx += 1;
                             }
+                        }
 
-                            // Add "Go Back" option
-                            templates::create_content_item(
-                                menu_list, "Go Back", idx, false, &handles,
-                            )
+                        // Add "Go Back" option
+                        templates::create_content_item(menu_list, "Go Back", idx, false, &handles)
                             .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
-                        });
+                    });
                 });
 
                 // Help text
                 templates::create_help_text(
                     parent,
                     &handles,
-                    Some("[↑]/[↓]: Navigate • [Enter]: Select • [Esc]: Back".to_string()),
+                    Some(SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Video).help_text()),
                 );
             });
 
@@ -684,12 +844,20 @@ fn menu_video_setting_selected(
 
 /// Handles saving video settings
 fn menu_save_video_setting(
+    mut commands: Commands,
     mut events: EventReader<SaveVideoSetting>,
     mut ev_back: EventWriter<MenuEvBack>,
+    mut ev_changed: EventWriter<SettingsChanged<VideoSettings>>,
     mut video_settings: ResMut<Persistent<VideoSettings>>,
+    mut video_retry: ResMut<PersistRetry<VideoSettings>>,
+    mut video_confirmation: ResMut<VideoChangeConfirmation>,
+    mut next_state: ResMut<NextState<SettingsState>>,
+    handles: Res<GameAssets>,
+    qtui: Query<Entity, With<SettingsMenu>>,
 ) {
     for ev in events.read() {
         warn!("Saving video setting: {:?}", ev.0);
+        let previous = (**video_settings).clone();
 
         match ev.0 {
             unsettings::video::VideoSettingsValue::resolution(value) => {
@@ -707,12 +875,257 @@ fn menu_save_video_setting(
             unsettings::video::VideoSettingsValue::vsync(value) => {
                 video_settings.vsync = value;
             }
+            unsettings::video::VideoSettingsValue::show_fps(value) => {
+                video_settings.show_fps = value;
+            }
+            unsettings::video::VideoSettingsValue::brightness(value) => {
+                video_settings.brightness = value;
+            }
+            unsettings::video::VideoSettingsValue::colourblind_mode(value) => {
+                video_settings.colourblind_mode = value;
+            }
+            unsettings::video::VideoSettingsValue::ui_theme(value) => {
+                video_settings.ui_theme = value;
+            }
         }
 
-        if let Err(e) = video_settings.persist() {
+        if let Err(e) = persist_with_retry(&mut *video_settings, &mut video_retry) {
             error!("Error persisting Video Settings: {e:?}");
         }
-        ev_back.write(MenuEvBack);
+        ev_changed.write(SettingsChanged::new((**video_settings).clone()));
+
+        if matches!(*video_confirmation, VideoChangeConfirmation::Reverting) {
+            *video_confirmation = VideoChangeConfirmation::Idle;
+            ev_back.write(MenuEvBack);
+        } else if ev.0.needs_confirmation() && previous != **video_settings {
+            setup_ui_video_confirm(&mut commands, &handles, &qtui);
+            *video_confirmation = VideoChangeConfirmation::Pending {
+                previous,
+                changed: ev.0,
+                started_at: Instant::now(),
+            };
+            next_state.set(SettingsState::VideoChangeConfirm);
+        } else {
+            ev_back.write(MenuEvBack);
+        }
+    }
+}
+
+/// Builds the "keep these settings?" prompt shown after a display change
+fn setup_ui_video_confirm(
+    commands: &mut Commands,
+    handles: &Res<GameAssets>,
+    qtui: &Query<Entity, With<SettingsMenu>>,
+) {
+    // Clean up old UI
+    for e in qtui.iter() {
+        commands.entity(e).despawn();
+    }
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        })
+        .insert(SettingsMenu {
+            menu_type: MenuType::SettingEdit,
+            selected_item_idx: 0,
+        })
+        .with_children(|parent| {
+            // Background
+            templates::create_background(parent, handles);
+
+            // Logo
+            templates::create_logo(parent, handles);
+
+            templates::create_breadcrumb_navigation(
+                parent,
+                handles,
+                "Video Settings",
+                "Confirm Display Change",
+            );
+
+            let mut content_area = templates::create_selectable_content_area(parent, handles, 0);
+
+            content_area.insert(MenuMouseTracker::default());
+
+            content_area.insert(MenuRoot { selected_item: 0 });
+
+            content_area.with_children(|content| {
+                content
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::FlexStart,
+                        justify_content: JustifyContent::FlexStart,
+                        ..default()
+                    })
+                    .with_children(|menu_list| {
+                        menu_list
+                            .spawn(Text::new("Keep these display settings?"))
+                            .insert(TextFont {
+                                font: handles.fonts.titillium.w400_regular.clone(),
+                                font_size: 24.0,
+                                ..default()
+                            })
+                            .insert(TextColor(MENU_ITEM_COLOR_ON))
+                            .insert(ThemedText::with_colour(
+                                UiFontRole::Item,
+                                UiColourRole::ItemOn,
+                            ));
+
+                        menu_list
+                            .spawn(Text::new(""))
+                            .insert(TextFont {
+                                font: handles.fonts.titillium.w400_regular.clone(),
+                                font_size: 20.0,
+                                ..default()
+                            })
+                            .insert(TextColor(MENU_ITEM_COLOR_OFF))
+                            .insert(ThemedText::with_colour(
+                                UiFontRole::Item,
+                                UiColourRole::ItemOff,
+                            ))
+                            .insert(VideoConfirmCountdown);
+
+                        templates::create_content_item(
+                            menu_list,
+                            "Keep These Settings",
+                            0,
+                            true,
+                            handles,
+                        )
+                        .insert(MenuItem::new(0, MenuEvent::KeepVideoChange));
+
+                        templates::create_content_item(menu_list, "Revert", 1, false, handles)
+                            .insert(MenuItem::new(1, MenuEvent::RevertVideoChange));
+                    });
+            });
+
+            templates::create_help_text(
+                parent,
+                handles,
+                Some(SettingsState::VideoChangeConfirm.help_text()),
+            );
+        });
+}
+
+/// Builds the prompt asking the player to confirm enabling Dev God Mode
+fn setup_ui_dev_cheat_confirm(
+    commands: &mut Commands,
+    handles: &Res<GameAssets>,
+    qtui: &Query<Entity, With<SettingsMenu>>,
+) {
+    for e in qtui.iter() {
+        commands.entity(e).despawn();
+    }
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        })
+        .insert(SettingsMenu {
+            menu_type: MenuType::SettingEdit,
+            selected_item_idx: 0,
+        })
+        .with_children(|parent| {
+            templates::create_background(parent, handles);
+            templates::create_logo(parent, handles);
+            templates::create_breadcrumb_navigation(
+                parent,
+                handles,
+                "Gameplay Settings",
+                "Enable Dev God Mode",
+            );
+
+            let mut content_area = templates::create_selectable_content_area(parent, handles, 0);
+            content_area.insert(MenuMouseTracker::default());
+            content_area.insert(MenuRoot { selected_item: 0 });
+
+            content_area.with_children(|content| {
+                content
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::FlexStart,
+                        justify_content: JustifyContent::FlexStart,
+                        ..default()
+                    })
+                    .with_children(|menu_list| {
+                        menu_list
+                            .spawn(Text::new("Enable Dev God Mode?"))
+                            .insert(TextFont {
+                                font: handles.fonts.titillium.w400_regular.clone(),
+                                font_size: 24.0,
+                                ..default()
+                            })
+                            .insert(TextColor(MENU_ITEM_COLOR_ON))
+                            .insert(ThemedText::with_colour(
+                                UiFontRole::Item,
+                                UiColourRole::ItemOn,
+                            ));
+
+                        menu_list
+                            .spawn(Text::new(
+                                "Your profile will be marked as having used cheats, even if you turn it off later.",
+                            ))
+                            .insert(TextFont {
+                                font: handles.fonts.titillium.w400_regular.clone(),
+                                font_size: 20.0,
+                                ..default()
+                            })
+                            .insert(TextColor(MENU_ITEM_COLOR_OFF))
+                            .insert(ThemedText::with_colour(
+                                UiFontRole::Item,
+                                UiColourRole::ItemOff,
+                            ));
+
+                        // Cancel comes first so a stray [Enter] doesn't enable it
+                        templates::create_content_item(menu_list, "Cancel", 0, true, handles)
+                            .insert(MenuItem::new(0, MenuEvent::CancelDevCheatMode));
+
+                        templates::create_content_item(
+                            menu_list,
+                            "Enable God Mode",
+                            1,
+                            false,
+                            handles,
+                        )
+                        .insert(MenuItem::new(1, MenuEvent::ConfirmDevCheatMode));
+                    });
+            });
+
+            templates::create_help_text(
+                parent,
+                handles,
+                Some(SettingsState::DevCheatModeConfirm.help_text()),
+            );
+        });
+}
+
+/// Counts down the display change prompt and reverts the change on timeout
+fn video_change_countdown_system(
+    video_confirmation: Res<VideoChangeConfirmation>,
+    mut countdown_query: Query<&mut Text, With<VideoConfirmCountdown>>,
+    mut menu_events: EventWriter<MenuEvent>,
+) {
+    let VideoChangeConfirmation::Pending { started_at, .. } = &*video_confirmation else {
+        return;
+    };
+    let remaining = VIDEO_CHANGE_CONFIRM_SECS - started_at.elapsed().as_secs_f32();
+    if remaining <= 0.0 {
+        menu_events.write(MenuEvent::RevertVideoChange);
+        return;
+    }
+    for mut text in countdown_query.iter_mut() {
+        text.0 = format!("Reverting in {} seconds...", remaining.ceil() as u32);
     }
 }
 
@@ -774,47 +1187,35 @@ fn menu_profile_setting_selected(
 
                 // Add a column container inside the content area for vertical layout
                 content_area.with_children(|content| {
-                    content
-                        .spawn(Node {
-                            width: Val::Percent(100.0),
-                            height: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Column,
-                            align_items: AlignItems::FlexStart,
-                            justify_content: JustifyContent::FlexStart,
-                            overflow: Overflow::scroll_y(),
-                            ..default()
-                        })
-                        .with_children(|menu_list| {
-                            let mut idx = 0;
-
-                            // Add each menu item
-                            for (item_text, event) in menu_items.iter() {
-                                if !event.is_none() {
-                                    templates::create_content_item(
-                                        menu_list,
-                                        item_text,
-                                        idx,
-                                        idx == 0, // First item selected by default
-                                        &handles,
-                                    )
-                                    .insert(MenuItem::new(idx, event.clone()));
-                                    idx += 1;
-                                }
+                    scrollbar::build_scrollable_list(content, &handles, |menu_list| {
+                        let mut idx = 0;
+
+                        // Add each menu item
+                        for (item_text, event) in menu_items.iter() {
+                            if !event.is_none() {
+                                templates::create_content_item(
+                                    menu_list,
+                                    item_text,
+                                    idx,
+                                    idx == 0, // First item selected by default
+                                    &handles,
+                                )
+                                .insert(MenuItem::new(idx, event.clone()));
+                                idx += 1;
                             }
+                        }
 
-                            // Add "Go Back" option
-                            templates::create_content_item(
-                                menu_list, "Go Back", idx, false, &handles,
-                            )
+                        // Add "Go Back" option
+                        templates::create_content_item(menu_list, "Go Back", idx, false, &handles)
                             .insert(MenuItem::new(idx, MenuEvent::Back(MenuEvBack)));
-                        });
+                    });
                 });
 
                 // Help text
                 templates::create_help_text(
                     parent,
                     &handles,
-                    Some("[↑]/[↓]: Navigate • [Enter]: Select • [Esc]: Back".to_string()),
+                    Some(SettingsState::Lv3ValueEdit(MenuSettingsLevel1::Profile).help_text()),
                 );
             });
 
@@ -826,7 +1227,9 @@ fn menu_profile_setting_selected(
 fn menu_save_profile_setting(
     mut events: EventReader<SaveProfileSetting>,
     mut ev_back: EventWriter<MenuEvBack>,
+    mut ev_changed: EventWriter<SettingsChanged<ProfileSettings>>,
     mut profile_settings: ResMut<Persistent<ProfileSettings>>,
+    mut profile_retry: ResMut<PersistRetry<ProfileSettings>>,
 ) {
     for ev in events.read() {
         warn!("Saving profile setting: {:?}", ev.value);
@@ -840,9 +1243,10 @@ fn menu_save_profile_setting(
             }
         }
 
-        if let Err(e) = profile_settings.persist() {
+        if let Err(e) = persist_with_retry(&mut *profile_settings, &mut profile_retry) {
             error!("Error persisting Profile Settings: {e:?}");
         }
+        ev_changed.write(SettingsChanged::new((**profile_settings).clone()));
         ev_back.write(MenuEvBack);
     }
 }
@@ -859,6 +1263,89 @@ fn handle_escape(
     }
 }
 
+/// Builds the full-screen text input used by the custom name and settings
+/// search screens. `input` goes on the field entity; `footer` spawns the lines
+/// below it. The caller despawns the previous UI.
+fn spawn_text_input_overlay(
+    commands: &mut Commands,
+    handles: &GameAssets,
+    breadcrumb: (&str, &str),
+    prompt: &str,
+    input: impl Bundle,
+    state: SettingsState,
+    footer: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        })
+        .insert(SettingsMenu {
+            menu_type: MenuType::SettingEdit,
+            selected_item_idx: 0,
+        })
+        .with_children(|parent| {
+            // Background
+            templates::create_background(parent, handles);
+
+            // Logo
+            templates::create_logo(parent, handles);
+
+            // Create breadcrumb navigation
+            templates::create_breadcrumb_navigation(parent, handles, breadcrumb.0, breadcrumb.1);
+
+            // Create content area
+            let mut content_area = templates::create_selectable_content_area(parent, handles, 0);
+
+            content_area.insert(MenuRoot { selected_item: 0 });
+
+            content_area.with_children(|content| {
+                content
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    })
+                    .with_children(|input_container| {
+                        // Instructions
+                        templates::create_content_item(input_container, prompt, 0, false, handles);
+
+                        // Text input field
+                        input_container
+                            .spawn(Node {
+                                width: Val::Px(400.0),
+                                height: Val::Px(50.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            })
+                            .insert(BackgroundColor(Color::srgb(0.2, 0.2, 0.2)))
+                            .insert(TextInputField)
+                            .insert(input)
+                            .with_children(|text_container| {
+                                text_container
+                                    .spawn(Text::new(""))
+                                    .insert(TextFont {
+                                        font: handles.fonts.titillium.w400_regular.clone(),
+                                        font_size: 24.0,
+                                        ..default()
+                                    })
+                                    .insert(TextColor(Color::WHITE));
+                            });
+
+                        footer(input_container);
+                    });
+            });
+
+            // Help text
+            templates::create_help_text(parent, handles, Some(state.help_text()));
+        });
+}
+
 /// Handles starting custom name input
 fn custom_name_input_system(
     mut commands: Commands,
@@ -874,102 +1361,23 @@ fn custom_name_input_system(
                 commands.entity(e).despawn();
             }
 
-            // Create new UI for custom name input
-            commands
-                .spawn(Node {
-                    width: Val::Percent(100.0),
-                    height: Val::Percent(100.0),
-                    position_type: PositionType::Absolute,
-                    ..default()
-                })
-                .insert(SettingsMenu {
-                    menu_type: MenuType::SettingEdit,
-                    selected_item_idx: 0,
-                })
-                .with_children(|parent| {
-                    // Background
-                    templates::create_background(parent, &handles);
-
-                    // Logo
-                    templates::create_logo(parent, &handles);
-
-                    // Create breadcrumb navigation
-                    templates::create_breadcrumb_navigation(
-                        parent,
-                        &handles,
-                        "Profile Settings > Display Name",
-                        "Custom Name Input",
-                    );
-
-                    // Create content area
-                    let mut content_area =
-                        templates::create_selectable_content_area(parent, &handles, 0);
-
-                    content_area.insert(MenuRoot { selected_item: 0 });
-
-                    content_area.with_children(|content| {
-                        content
-                            .spawn(Node {
-                                width: Val::Percent(100.0),
-                                height: Val::Percent(100.0),
-                                flex_direction: FlexDirection::Column,
-                                align_items: AlignItems::Center,
-                                justify_content: JustifyContent::Center,
-                                ..default()
-                            })
-                            .with_children(|input_container| {
-                                // Instructions
-                                templates::create_content_item(
-                                    input_container,
-                                    "Type your custom display name:",
-                                    0,
-                                    false,
-                                    &handles,
-                                );
-
-                                // Text input field
-                                input_container
-                                    .spawn(Node {
-                                        width: Val::Px(400.0),
-                                        height: Val::Px(50.0),
-                                        border: UiRect::all(Val::Px(2.0)),
-                                        ..default()
-                                    })
-                                    .insert(BackgroundColor(Color::srgb(0.2, 0.2, 0.2)))
-                                    .insert(TextInputField)
-                                    .insert(CustomNameInput::default())
-                                    .with_children(|text_container| {
-                                        text_container
-                                            .spawn(Text::new(""))
-                                            .insert(TextFont {
-                                                font: handles.fonts.titillium.w400_regular.clone(),
-                                                font_size: 24.0,
-                                                ..default()
-                                            })
-                                            .insert(TextColor(Color::WHITE));
-                                    });
-
-                                // Instructions
-                                templates::create_content_item(
-                                    input_container,
-                                    "Press ENTER to save, ESC to cancel",
-                                    1,
-                                    false,
-                                    &handles,
-                                );
-                            });
-                    });
-
-                    // Help text
-                    templates::create_help_text(
-                        parent,
+            spawn_text_input_overlay(
+                &mut commands,
+                &handles,
+                ("Profile Settings > Display Name", "Custom Name Input"),
+                "Type your custom display name:",
+                CustomNameInput::default(),
+                SettingsState::CustomNameInput,
+                |input_container| {
+                    templates::create_content_item(
+                        input_container,
+                        "Press ENTER to save, ESC to cancel",
+                        1,
+                        false,
                         &handles,
-                        Some(
-                            "Type your name and press [Enter] to save or [Escape] to cancel"
-                                .to_string(),
-                        ),
                     );
-                });
+                },
+            );
 
             next_state.set(SettingsState::CustomNameInput);
         }
@@ -981,6 +1389,7 @@ fn custom_name_text_input_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut custom_input_query: Query<&mut CustomNameInput, With<TextInputField>>,
     mut profile_settings: ResMut<Persistent<ProfileSettings>>,
+    mut profile_retry: ResMut<PersistRetry<ProfileSettings>>,
     mut next_state: ResMut<NextState<SettingsState>>,
     mut menu_events: EventWriter<MenuEvent>,
 ) {
@@ -997,7 +1406,7 @@ fn custom_name_text_input_system(
     {
         if !custom_input.current_text.trim().is_empty() {
             profile_settings.display_name = custom_input.current_text.trim().to_string();
-            if let Err(e) = profile_settings.persist() {
+            if let Err(e) = persist_with_retry(&mut *profile_settings, &mut profile_retry) {
                 error!("Error persisting Profile Settings: {e:?}");
             }
             // Save the setting using the proper event
@@ -1052,8 +1461,10 @@ fn update_custom_name_display_system(
 fn delete_custom_name_system(
     mut events: EventReader<MenuEvent>,
     mut profile_settings: ResMut<Persistent<ProfileSettings>>,
+    mut profile_retry: ResMut<PersistRetry<ProfileSettings>>,
     mut next_state: ResMut<NextState<SettingsState>>,
     mut ev_profile_setting: EventWriter<ProfileSettingSelected>,
+    mut ev_changed: EventWriter<SettingsChanged<ProfileSettings>>,
 ) {
     for event in events.read() {
         if let MenuEvent::DeleteCustomName(name_to_delete) = event {
@@ -1066,11 +1477,12 @@ fn delete_custom_name_system(
                 );
                 // Reset to default "Player" name
                 profile_settings.display_name = "Player".to_string();
-                if let Err(e) = profile_settings.persist() {
+                if let Err(e) = persist_with_retry(&mut *profile_settings, &mut profile_retry) {
                     error!("Error persisting Profile Settings after deletion: {e:?}");
                 } else {
                     info!("Successfully deleted custom name and reset to default");
                 }
+                ev_changed.write(SettingsChanged::new((**profile_settings).clone()));
             } else {
                 warn!(
                     "Attempted to delete '{}' but current name is '{}'",
@@ -1089,6 +1501,139 @@ fn delete_custom_name_system(
     }
 }
 
+/// Handles opening the settings search screen
+fn settings_search_start_system(
+    mut commands: Commands,
+    mut events: EventReader<MenuEvent>,
+    mut next_state: ResMut<NextState<SettingsState>>,
+    handles: Res<GameAssets>,
+    qtui: Query<Entity, With<SettingsMenu>>,
+) {
+    for ev in events.read() {
+        if !matches!(ev, MenuEvent::StartSettingsSearch) {
+            continue;
+        }
+        // Clean up old UI
+        for e in qtui.iter() {
+            commands.entity(e).despawn();
+        }
+
+        spawn_text_input_overlay(
+            &mut commands,
+            &handles,
+            ("Settings", "Search"),
+            "Type the name of a setting:",
+            SettingsSearchInput::default(),
+            SettingsState::SearchInput,
+            |input_container| {
+                // Match count / "no matches" feedback
+                input_container
+                    .spawn(Text::new(""))
+                    .insert(TextFont {
+                        font: handles.fonts.titillium.w400_regular.clone(),
+                        font_size: 20.0,
+                        ..default()
+                    })
+                    .insert(TextColor(MENU_ITEM_COLOR_OFF))
+                    .insert(ThemedText::with_colour(
+                        UiFontRole::Item,
+                        UiColourRole::ItemOff,
+                    ))
+                    .insert(SettingsSearchFeedback);
+            },
+        );
+
+        next_state.set(SettingsState::SearchInput);
+    }
+}
+
+/// Handles typing into the settings search field and opening the results
+fn settings_search_text_input_system(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    settings_state: Res<State<SettingsState>>,
+    mut search_input_query: Query<&mut SettingsSearchInput, With<TextInputField>>,
+    mut next_state: ResMut<NextState<SettingsState>>,
+    mut menu_events: EventWriter<MenuEvent>,
+    handles: Res<GameAssets>,
+    qtui: Query<Entity, With<SettingsMenu>>,
+) {
+    if *settings_state.get() != SettingsState::SearchInput {
+        return;
+    }
+    let Ok(mut search_input) = search_input_query.single_mut() else {
+        return;
+    };
+
+    if keyboard_input.just_pressed(KeyCode::Backspace) {
+        search_input.current_text.pop();
+    }
+
+    for key_code in keyboard_input.get_just_pressed() {
+        if let Some(char) = key_code_to_char(*key_code, &keyboard_input)
+            && search_input.current_text.len() < 30
+        {
+            search_input.current_text.push(char);
+        }
+    }
+
+    if !keyboard_input.just_pressed(KeyCode::Enter) {
+        return;
+    }
+    let results = search_settings(&search_input.current_text);
+    match results.as_slice() {
+        // Nothing to open; the feedback line already says so
+        [] => {}
+        // A single match jumps straight into its value editor
+        [(_, event)] => {
+            menu_events.write(event.clone());
+        }
+        _ => {
+            let title = format!("Search: {}", search_input.current_text.trim());
+            setup_ui_main_cat(
+                &mut commands,
+                &handles,
+                &qtui,
+                title,
+                &results,
+                SettingsState::SearchResults,
+            );
+            next_state.set(SettingsState::SearchResults);
+        }
+    }
+}
+
+/// Updates the search field text and the match count feedback
+fn update_settings_search_display_system(
+    search_input_query: Query<(Entity, &SettingsSearchInput), Changed<SettingsSearchInput>>,
+    mut text_query: Query<&mut Text, Without<SettingsSearchFeedback>>,
+    mut feedback_query: Query<&mut Text, With<SettingsSearchFeedback>>,
+    children_query: Query<&Children>,
+) {
+    for (search_entity, search_input) in search_input_query.iter() {
+        if let Ok(children) = children_query.get(search_entity) {
+            for child in children.iter() {
+                if let Ok(mut text) = text_query.get_mut(child) {
+                    text.0 = search_input.current_text.clone();
+                }
+            }
+        }
+
+        let feedback = if search_input.current_text.trim().is_empty() {
+            String::new()
+        } else {
+            match search_settings(&search_input.current_text).len() {
+                0 => "No matches".to_string(),
+                1 => "1 match".to_string(),
+                n => format!("{n} matches"),
+            }
+        };
+        for mut text in feedback_query.iter_mut() {
+            text.0 = feedback.clone();
+        }
+    }
+}
+
 /// Helper function to convert KeyCode to character, checking for shift modifier
 fn key_code_to_char(key_code: KeyCode, keyboard_input: &ButtonInput<KeyCode>) -> Option<char> {
     let is_shift_pressed =
@@ -1135,3 +1680,36 @@ fn key_code_to_char(key_code: KeyCode, keyboard_input: &ButtonInput<KeyCode>) ->
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unsettings::audio::{AudioLevel, AudioSettingsValue};
+    use unsettings::persist::testing::temp_persistent;
+
+    #[test]
+    fn test_saving_audio_setting_emits_settings_changed() {
+        let (audio_settings, _dir) = temp_persistent("audio", AudioSettings::default());
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<SaveAudioSetting>()
+            .add_event::<MenuEvBack>()
+            .add_event::<SettingsChanged<AudioSettings>>()
+            .insert_resource(audio_settings)
+            .init_resource::<PersistRetry<AudioSettings>>()
+            .add_systems(Update, menu_save_audio_setting);
+
+        app.world_mut().send_event(SaveAudioSetting {
+            value: AudioSettingsValue::volume_master(AudioLevel::Vol050),
+        });
+        app.update();
+
+        let events = app
+            .world()
+            .resource::<Events<SettingsChanged<AudioSettings>>>();
+        let changed: Vec<_> = events.iter_current_update_events().collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].settings.volume_master, AudioLevel::Vol050);
+    }
+}
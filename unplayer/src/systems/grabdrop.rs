@@ -7,12 +7,15 @@ use uncore::components::game::GameSprite;
 use uncore::components::player::HeldObject;
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::components::sprite_type::SpriteType;
+use uncore::events::hint::OnScreenHintEvent;
+use uncore::resources::localization::Localization;
 use uncore::types::gear::equipmentposition::Hand;
 use uncore::types::root::game_assets::GameAssets;
 use ungear::components::deployedgear::{DeployedGear, DeployedGearData};
 use ungear::components::playergear::PlayerGear;
 use ungear::gear_stuff::GearStuff;
 use ungear::gear_usable::GearUsable;
+use unsettings::controls::{ControlAction, ControlInput};
 
 /// Allows the player to pick up a pickable object from the environment.
 ///
@@ -21,7 +24,7 @@ use ungear::gear_usable::GearUsable;
 /// player, and the player's right-hand gear is disabled. Only one object can be
 /// held at a time.
 fn grab_object(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     mut players: Query<(&mut PlayerGear, &Position, &Direction, &PlayerSprite)>,
     deployables: Query<(Entity, &Position), With<DeployedGear>>,
     // Query for all entities with Behaviour
@@ -29,7 +32,9 @@ fn grab_object(
     mut gs: GearStuff,
 ) {
     for (mut player_gear, player_pos, player_dir, player) in players.iter_mut() {
-        if keyboard_input.just_pressed(player.controls.grab) && player_gear.held_item.is_none() {
+        if controls.just_pressed(&player.controls, ControlAction::Grab)
+            && player_gear.held_item.is_none()
+        {
             // If there's any gear deployed nearby do not consider furniture.
             if deployables
                 .iter()
@@ -103,13 +108,13 @@ fn grab_object(
 /// invalid, an "invalid drop" sound effect is played, and the object is not
 /// dropped.
 fn drop_object(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     mut players: Query<(&mut PlayerGear, &Position, &PlayerSprite), Without<Behaviour>>,
     mut objects: Query<(Entity, &mut Position), (Without<PlayerSprite>, With<FloorItemCollidable>)>,
     mut gs: GearStuff,
 ) {
     for (mut player_gear, player_pos, player) in players.iter_mut() {
-        if keyboard_input.just_pressed(player.controls.drop) {
+        if controls.just_pressed(&player.controls, ControlAction::Drop) {
             // Take the held object from the player's gear (this removes it temporarily)
             if let Some(held_object) = player_gear.held_item.take() {
                 // Check for valid Drop location
@@ -200,7 +205,7 @@ fn update_held_object_position(
 /// System for deploying a piece of gear from the player's right hand into the game
 /// world.
 fn deploy_gear(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     mut players: Query<(&mut PlayerGear, &Position, &PlayerSprite, &Direction)>,
     mut commands: Commands,
     q_collidable: Query<(Entity, &Position), With<FloorItemCollidable>>,
@@ -208,7 +213,7 @@ fn deploy_gear(
     handles: Res<GameAssets>,
 ) {
     for (mut player_gear, player_pos, player, dir) in players.iter_mut() {
-        if keyboard_input.just_pressed(player.controls.drop)
+        if controls.just_pressed(&player.controls, ControlAction::Drop)
             && player_gear.right_hand.kind.is_some()
             && player_gear.held_item.is_none()
         {
@@ -220,11 +225,13 @@ fn deploy_gear(
                 .any(|(_entity, object_pos)| target_tile.to_position().distance(object_pos) < 0.5);
             if is_valid_tile && !is_obstructed {
                 let scoord = player_pos.to_screen_coord();
+                let mut gear = player_gear.right_hand.take();
+                gear.on_deploy();
                 let gear_sprite = Sprite {
                     image: handles.images.gear.clone(),
                     texture_atlas: Some(TextureAtlas {
                         layout: handles.images.gear_atlas.clone(),
-                        index: player_gear.right_hand.get_sprite_idx() as usize,
+                        index: gear.get_sprite_idx() as usize,
                     }),
                     ..Default::default()
                 };
@@ -239,9 +246,7 @@ fn deploy_gear(
                     .insert(*player_pos)
                     .insert(FloorItemCollidable)
                     .insert(GameSprite)
-                    .insert(DeployedGearData {
-                        gear: player_gear.right_hand.take(),
-                    })
+                    .insert(DeployedGearData { gear })
                     .insert(SpriteType::Other);
                 player_gear.cycle(&Hand::Right);
 
@@ -255,13 +260,19 @@ fn deploy_gear(
     }
 }
 
+const INVENTORY_FULL_HINT_KEY: &str = "hint.inventory_full.deployed_gear";
+const INVENTORY_FULL_HINT: &str =
+    "Inventory full. Deploy or drop something before picking this up.";
+
 /// System for retrieving deployed gear and adding it to the player's right hand.
 fn retrieve_gear(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     mut players: Query<(&Position, &PlayerSprite, &mut PlayerGear)>,
     q_deployed: Query<(Entity, &Position, &DeployedGearData)>,
     mut commands: Commands,
     mut gs: GearStuff,
+    mut ev_hint: EventWriter<OnScreenHintEvent>,
+    localization: Res<Localization>,
 ) {
     // FIXME: This code, along with grabbing items are in conflict. It will be
     // possible for a player to grab equipment from the floor and a location item at
@@ -270,7 +281,7 @@ fn retrieve_gear(
     // routing the remaining stuff to do via an Event to the system that handles that
     // exact thing.
     for (player_pos, player, mut player_gear) in players.iter_mut() {
-        if keyboard_input.just_pressed(player.controls.grab) {
+        if controls.just_pressed(&player.controls, ControlAction::Grab) {
             // Find the closest deployed gear
             let mut closest_gear: Option<(Entity, f32)> = None;
             for (entity, gear_pos, _) in q_deployed.iter() {
@@ -290,25 +301,27 @@ fn retrieve_gear(
             if let Some((closest_gear_entity, _)) = closest_gear
                 && let Ok((_, _, deployed_gear_data)) = q_deployed.get(closest_gear_entity)
             {
-                // Inventory Shifting Logic:
-                if player_gear.right_hand.kind.is_some() {
-                    // Right hand is occupied, try to shift to inventory
-                    if let Some(empty_slot_index) = player_gear
-                        .inventory
-                        .iter()
-                        .position(|gear| gear.kind.is_none())
-                    {
-                        // Move right-hand gear to the empty slot
-                        player_gear.inventory[empty_slot_index] = player_gear.right_hand.take();
-                    } else {
-                        // No empty slot - play invalid action sound and skip retrieval
-                        gs.play_audio("sounds/invalid-action-buzz.ogg".into(), 0.3, player_pos);
-                        return;
-                    }
+                if player_gear.is_full() {
+                    // No empty slot - play invalid action sound and skip retrieval
+                    gs.play_audio("sounds/invalid-action-buzz.ogg".into(), 0.3, player_pos);
+                    ev_hint.write(OnScreenHintEvent {
+                        hint_text: localization
+                            .text(INVENTORY_FULL_HINT_KEY, INVENTORY_FULL_HINT)
+                            .to_string(),
+                    });
+                    return;
                 }
 
-                // Now the right hand is free, proceed with retrieval
+                // The retrieved gear goes to the right hand, whatever was there is
+                // stowed in the free slot.
+                let old_right = player_gear.right_hand.take();
                 player_gear.right_hand = deployed_gear_data.gear.clone();
+                player_gear.right_hand.on_pickup();
+                if old_right.kind.is_some()
+                    && let Err(e) = player_gear.try_add(old_right)
+                {
+                    warn!("Could not stow right hand gear: {e}");
+                }
                 commands.entity(closest_gear_entity).despawn();
 
                 // Play "Grab Item" sound effect (reused for gear retrieval)
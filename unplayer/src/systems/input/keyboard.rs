@@ -1,29 +1,81 @@
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
 use uncore::{
+    behaviour::{
+        Behaviour,
+        component::{Interactive, RoomState},
+    },
     components::{
+        board::position::Position,
         move_to::MoveToTarget,
+        player::{Hiding, LastInteractive},
         player_sprite::PlayerSprite,
         waypoint::{Waypoint, WaypointOwner, WaypointQueue},
     },
-    resources::player_input::PlayerInput,
+    events::{
+        npc_help::NpcHelpEvent,
+        roomchanged::{InteractionExecutionType, RoomChangedEvent},
+    },
+    resources::{free_camera::FreeCamera, player_input::PlayerInput},
 };
+use unsettings::controls::{ControlAction, ControlInput};
 use unsettings::game::{GameplaySettings, MovementStyle};
+use unstd::systemparam::interactivestuff::InteractiveStuff;
+
+/// Rotates a movement vector for the screen-space orthogonal movement style.
+fn apply_movement_style(movement: Vec2, style: MovementStyle) -> Vec2 {
+    if matches!(style, MovementStyle::ScreenSpaceOrthogonal) {
+        const PERSPECTIVE_X: [f32; 2] = [1.0, 1.0];
+        const PERSPECTIVE_Y: [f32; 2] = [-1.0, 1.0];
+        Vec2::new(
+            movement.x * PERSPECTIVE_X[0] + movement.y * PERSPECTIVE_Y[0],
+            movement.x * PERSPECTIVE_X[1] + movement.y * PERSPECTIVE_Y[1],
+        )
+    } else {
+        movement
+    }
+}
+
+/// Movement for a left stick position.
+///
+/// Pushes inside the deadzone are ignored and the rest of the range is rescaled
+/// so speed starts at zero at its edge, then shaped by the response curve. The
+/// direction is turned like the movement keys for the movement style.
+pub fn stick_movement(stick: Vec2, settings: &GameplaySettings) -> Vec2 {
+    let deadzone = settings.stick_deadzone.radius();
+    let deflection = stick.length().min(1.0);
+    if deflection <= deadzone {
+        return Vec2::ZERO;
+    }
+    let speed = settings
+        .stick_response
+        .apply((deflection - deadzone) / (1.0 - deadzone));
+    apply_movement_style(stick, settings.movement_style).normalize() * speed
+}
 
-/// System that handles keyboard input for player movement.
+/// System that handles keyboard and gamepad input for player movement.
 ///
-/// This system reads keyboard input and converts it to movement vectors in the PlayerInput resource.
-/// It also handles movement style transformations (e.g., screen-space orthogonal movement) and
-/// clears any active click-to-move targets and waypoint queues when keyboard movement is detected.
+/// This system reads the movement keys, or the left stick when no key is held, and converts
+/// them to movement vectors in the PlayerInput resource. It also handles movement style
+/// transformations (e.g., screen-space orthogonal movement) and clears any active
+/// click-to-move targets and waypoint queues when keyboard or stick movement is detected.
+/// Gamepads are read every frame, so one plugged in mid-mission works right away.
 pub fn keyboard_input_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
     mut commands: Commands,
     mut player_input: ResMut<PlayerInput>,
     players: Query<(Entity, &PlayerSprite)>,
     mut waypoint_queues: Query<&mut WaypointQueue>,
     q_existing_waypoints: Query<Entity, (With<Waypoint>, With<WaypointOwner>)>,
     game_settings: Res<Persistent<GameplaySettings>>,
+    free_camera: Res<FreeCamera>,
 ) {
+    if free_camera.active {
+        // Movement keys pan the free camera instead.
+        player_input.movement = Vec2::ZERO;
+        return;
+    }
     for (entity, player) in players.iter() {
         let mut movement = Vec2::ZERO;
 
@@ -41,22 +93,22 @@ pub fn keyboard_input_system(
         }
 
         // Apply MovementStyle transformation (from original keyboard_player)
-        if matches!(
-            game_settings.movement_style,
-            MovementStyle::ScreenSpaceOrthogonal
-        ) {
-            const PERSPECTIVE_X: [f32; 2] = [1.0, 1.0];
-            const PERSPECTIVE_Y: [f32; 2] = [-1.0, 1.0];
-            let od = movement;
-            movement.x = od.x * PERSPECTIVE_X[0] + od.y * PERSPECTIVE_Y[0];
-            movement.y = od.x * PERSPECTIVE_X[1] + od.y * PERSPECTIVE_Y[1];
-        }
-
+        movement = apply_movement_style(movement, game_settings.movement_style);
         if movement != Vec2::ZERO {
             // Normalise the movement vector if it's not zero
             movement = movement.normalize();
+        } else if player.controls.gamepad.movement {
+            // The stick pushed the furthest wins when several gamepads are connected
+            let stick = gamepads
+                .iter()
+                .map(|gamepad| gamepad.left_stick())
+                .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+                .unwrap_or_default();
+            movement = stick_movement(stick, &game_settings);
+        }
 
-            // Clear any click-to-move target and waypoint queue when using keyboard
+        if movement != Vec2::ZERO {
+            // Clear any click-to-move target and waypoint queue when using keyboard or stick
             commands.entity(entity).remove::<MoveToTarget>();
 
             // Clear waypoint queue and despawn waypoint entities
@@ -74,3 +126,113 @@ pub fn keyboard_input_system(
         player_input.movement = movement;
     }
 }
+
+/// Maximum distance from the player to the control point of an interactive for
+/// the activate key to reach it.
+const KEYBOARD_INTERACTION_DISTANCE: f32 = 1.4;
+
+/// System that lets the player interact using the activate key instead of clicking.
+///
+/// Picks the nearest interactive on the player's floor whose control point is
+/// within `KEYBOARD_INTERACTION_DISTANCE` and executes the interaction directly. Pressing
+/// the key with nothing in reach does nothing. Hidden players are skipped, as
+/// the same key is used to leave the hiding spot.
+pub fn keyboard_interaction_system(
+    mut commands: Commands,
+    controls: ControlInput,
+    players: Query<(Entity, &Position, &PlayerSprite), Without<Hiding>>,
+    interactables: Query<
+        (
+            Entity,
+            &Position,
+            &Interactive,
+            &Behaviour,
+            Option<&RoomState>,
+        ),
+        Without<PlayerSprite>,
+    >,
+    mut interactive_stuff: InteractiveStuff,
+    mut ev_room: EventWriter<RoomChangedEvent>,
+    mut ev_npc: EventWriter<NpcHelpEvent>,
+) {
+    for (player_entity, pos, player) in players.iter() {
+        if !controls.just_pressed(&player.controls, ControlAction::Activate) {
+            continue;
+        }
+        let player_floor = pos.z.round();
+        let Some((entity, item_pos, interactive, behaviour, room_state)) = interactables
+            .iter()
+            .filter(|(_, item_pos, _, _, _)| item_pos.z.round() == player_floor)
+            .map(|item| {
+                let (_, item_pos, interactive, behaviour, _) = item;
+                let cp_delta = interactive.control_point_delta(behaviour);
+                let control_point = Position {
+                    x: item_pos.x + cp_delta.x,
+                    y: item_pos.y + cp_delta.y,
+                    z: item_pos.z + cp_delta.z,
+                    global_z: item_pos.global_z,
+                };
+                (pos.distance(&control_point), item)
+            })
+            .filter(|(dist, _)| *dist < KEYBOARD_INTERACTION_DISTANCE)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, item)| item)
+        else {
+            continue;
+        };
+        commands
+            .entity(player_entity)
+            .insert(LastInteractive { entity });
+        if behaviour.is_npc() {
+            ev_npc.write(NpcHelpEvent::new(entity));
+        }
+        if interactive_stuff.execute_interaction(
+            entity,
+            item_pos,
+            Some(interactive),
+            behaviour,
+            room_state,
+            InteractionExecutionType::ChangeState,
+        ) {
+            ev_room.write(RoomChangedEvent::default());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unsettings::game::{StickDeadzone, StickResponse};
+
+    #[test]
+    fn test_stick_maps_to_movement() {
+        let settings = GameplaySettings::default();
+        // Corners of square gates are capped at full speed.
+        assert!((stick_movement(Vec2::ONE, &settings).length() - 1.0).abs() < 1e-5);
+
+        // Screen-space movement turns the stick like the movement keys.
+        let screen = GameplaySettings {
+            movement_style: MovementStyle::ScreenSpaceOrthogonal,
+            ..default()
+        };
+        let right = stick_movement(Vec2::X, &screen);
+        let keys = apply_movement_style(Vec2::X, MovementStyle::ScreenSpaceOrthogonal).normalize();
+        assert!((right - keys).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_stick_deadzone_and_response_curve() {
+        let mut settings = GameplaySettings {
+            stick_deadzone: StickDeadzone::Large,
+            ..default()
+        };
+        assert_eq!(stick_movement(Vec2::new(0.2, -0.2), &settings), Vec2::ZERO);
+        assert_eq!(stick_movement(Vec2::new(0.0, 0.29), &settings), Vec2::ZERO);
+
+        // Halfway between the deadzone and a full push.
+        let mid = Vec2::new(0.0, 0.65);
+        assert!((stick_movement(mid, &settings).y - 0.5).abs() < 1e-5);
+        settings.stick_response = StickResponse::Quadratic;
+        assert!((stick_movement(mid, &settings).y - 0.25).abs() < 1e-5);
+    }
+}
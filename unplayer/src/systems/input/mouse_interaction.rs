@@ -3,6 +3,7 @@ use bevy::{
     picking::events::{Out, Over, Pointer},
     prelude::*,
 };
+use bevy_persistent::Persistent;
 use uncore::{
     behaviour::{Behaviour, component::Interactive},
     components::{board::position::Position, game_config::GameConfig, player_sprite::PlayerSprite},
@@ -11,6 +12,7 @@ use uncore::{
 use ungear::components::playergear::PlayerGear;
 use ungear::gear_stuff::GearStuff;
 use ungear::gear_usable::GearUsable;
+use unsettings::game::GameplaySettings;
 
 /// System that handles right-click to activate right-hand gear.
 ///
@@ -36,11 +38,23 @@ pub(crate) fn mouse_right_click_gear_system(
 /// through their gear items, similar to the Q key functionality.
 /// Scrolling up (positive Y) cycles forward (same as Q key).
 /// Scrolling down (negative Y) cycles backward (reverse direction).
+/// Scrolling with the camera zoom key held zooms the camera instead, when
+/// camera controls are enabled.
 pub(crate) fn mouse_scroll_gear_system(
     mut scroll_events: EventReader<MouseWheel>,
     mut q_gear: Query<(&PlayerSprite, &mut PlayerGear)>,
     looking_gear: Res<LookingGear>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_settings: Res<Persistent<GameplaySettings>>,
 ) {
+    let zooming = game_settings.camera_controls.on()
+        && q_gear
+            .iter()
+            .any(|(ps, _)| keyboard_input.pressed(ps.controls.camera_zoom));
+    if zooming {
+        scroll_events.clear();
+        return;
+    }
     for event in scroll_events.read() {
         // Determine scroll direction and whether to cycle
         let (should_cycle, cycle_forward) = match event.unit {
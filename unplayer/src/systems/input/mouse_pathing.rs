@@ -1,10 +1,6 @@
 use bevy::{prelude::*, window::PrimaryWindow};
 use uncore::{
-    components::{
-        board::{PERSPECTIVE_X, PERSPECTIVE_Y, PERSPECTIVE_Z, position::Position},
-        game::GCameraArena,
-        player_sprite::PlayerSprite,
-    },
+    components::{board::position::Position, game::GCameraArena, player_sprite::PlayerSprite},
     resources::{board_data::BoardData, mouse_visibility::MouseVisibility},
 };
 
@@ -12,6 +8,8 @@ use uncore::{
 ///
 /// This function performs the inverse transformation of `Position::to_screen_coord()`,
 /// solving a system of linear equations to determine world coordinates from screen position.
+/// Camera zoom lives in the scale of `camera_transform`, which Bevy already
+/// accounts for in `viewport_to_world_2d`, so picking works at any zoom level.
 ///
 /// # Arguments
 /// * `screen_pos` - The screen position (e.g., cursor position)
@@ -33,27 +31,7 @@ fn screen_to_world_coords(
         .viewport_to_world_2d(camera_transform, screen_pos)
         .ok()?;
 
-    // Calculate the determinant of the 2x2 isometric projection matrix
-    let det = PERSPECTIVE_X[0] * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * PERSPECTIVE_X[1];
-    if det.abs() < 1e-6 {
-        return None; // Matrix is not invertible
-    }
-    let inv_det = 1.0 / det;
-
-    // Adjust screen coordinates by removing the Z-level contribution
-    let b_x = world_pos_on_near_plane.x - target_z * PERSPECTIVE_Z[0];
-    let b_y = world_pos_on_near_plane.y - target_z * PERSPECTIVE_Z[1];
-
-    // Apply the inverse transformation matrix to find world X and Y coordinates
-    let world_x = inv_det * (b_x * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * b_y);
-    let world_y = inv_det * (PERSPECTIVE_X[0] * b_y - b_x * PERSPECTIVE_X[1]);
-
-    Some(Position {
-        x: world_x,
-        y: world_y,
-        z: target_z,
-        global_z: 0.0,
-    })
+    Position::from_screen_coord(world_pos_on_near_plane, target_z)
 }
 
 /// Simple obstacle avoidance by checking if the straight path is clear.
@@ -228,6 +206,7 @@ pub fn click_to_move_pathing_system(
     else {
         return;
     };
+    let target = board_data.clamp_position(target);
 
     // Use obstacle avoidance to find a walkable target position
     if let Some(walkable_target) = find_walkable_target(player_pos, target, &board_data) {
@@ -263,3 +242,46 @@ pub fn click_to_move_pathing_system(
 pub fn click_to_move_update_system() {
     // This system is deprecated - waypoint systems now handle movement
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::camera::CameraProjection;
+
+    /// Mirrors `Camera::ndc_to_world` for an orthographic projection, which
+    /// can't be called in a test because the camera needs a render target.
+    fn ndc_to_world_2d(camera_transform: &GlobalTransform, ndc: Vec2) -> Vec2 {
+        let projection = OrthographicProjection::default_2d();
+        let clip_from_view = projection.get_clip_from_view();
+        let ndc_to_world = camera_transform.compute_matrix() * clip_from_view.inverse();
+        ndc_to_world.project_point3(ndc.extend(1.0)).truncate()
+    }
+
+    #[test]
+    fn test_picking_follows_camera_zoom() {
+        let focus = Position {
+            x: 12.0,
+            y: 7.0,
+            z: 0.0,
+            global_z: 0.0,
+        };
+        let centre = focus.to_screen_coord().truncate();
+        let edge_at = |scale: f32| {
+            let transform = GlobalTransform::from(
+                Transform::from_translation(centre.extend(0.0)).with_scale(Vec3::splat(scale)),
+            );
+            let picked_centre =
+                Position::from_screen_coord(ndc_to_world_2d(&transform, Vec2::ZERO), 0.0).unwrap();
+            assert!(picked_centre.distance(&focus) < 1e-3);
+            Position::from_screen_coord(ndc_to_world_2d(&transform, Vec2::X), 0.0).unwrap()
+        };
+
+        // The point under the edge of the view moves away from the focus in
+        // proportion to the zoom.
+        let base = edge_at(1.0).distance(&focus);
+        for scale in [1.0 / 3.0, 0.5, 2.0, 4.0] {
+            let dist = edge_at(scale).distance(&focus);
+            assert!((dist - base * scale).abs() < base * 1e-3, "scale {scale}");
+        }
+    }
+}
@@ -14,10 +14,12 @@ pub mod walk_target_indicator;
 pub mod waypoint;
 
 use bevy::prelude::*;
+use uncore::resources::path_cache::PathCache;
 use uncore::states::AppState;
 use uncore::states::GameState;
 
 pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<PathCache>();
     grabdrop::app_setup(app);
     hide::app_setup(app);
 
@@ -37,10 +39,14 @@ pub(crate) fn app_setup(app: &mut App) {
             input::mouse_interaction::visibility_hover_cleanup_system,
             // Waypoint systems handle all click-to-move and click-to-interact
             waypoint::waypoint_creation_system,
+            waypoint::go_to_truck_system,
+            waypoint::repeat_last_interaction_system,
             waypoint::waypoint_following_system,
             waypoint::waypoint_queue_cleanup_system,
             // Movement system runs after input and waypoints
             movement::player_movement_system,
+            // Keyboard interaction uses the position after movement
+            input::keyboard::keyboard_interaction_system,
             // Stairs system runs last
             keyboard::stairs_player,
         )
@@ -7,14 +7,11 @@ use uncore::components::board::position::Position;
 use uncore::components::player::{Hiding, Stamina};
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::difficulty::CurrentDifficulty;
-use uncore::events::npc_help::NpcHelpEvent;
-use uncore::events::roomchanged::{InteractionExecutionType, RoomChangedEvent};
 use uncore::resources::board_data::BoardData;
 use uncore::resources::mouse_visibility::MouseVisibility;
 use uncore::resources::player_input::PlayerInput;
 use uncore::systemparam::collision_handler::CollisionHandler;
 use ungear::components::playergear::PlayerGear;
-use unstd::systemparam::interactivestuff::InteractiveStuff;
 
 const PLAYER_SPEED: f32 = 0.04;
 const RUN_ADD_MULTIPLIER: f32 = 1.3;
@@ -32,7 +29,6 @@ const DIR_RED: f32 = 1.001;
 /// - Applying movement speed, running, and stamina calculations
 /// - Collision detection and handling
 /// - Direction updates and animation
-/// - Interaction with objects (E key)
 /// - Running state management
 ///
 /// This system decouples movement logic from input sources, allowing both keyboard
@@ -61,9 +57,6 @@ pub fn player_movement_system(
         ),
         Without<PlayerSprite>,
     >,
-    mut interactive_stuff: InteractiveStuff,
-    mut ev_room: EventWriter<RoomChangedEvent>,
-    mut ev_npc: EventWriter<NpcHelpEvent>,
     difficulty: Res<CurrentDifficulty>,
     board_data: Res<BoardData>,
     mut avg_running: Local<f32>,
@@ -107,7 +100,8 @@ pub fn player_movement_system(
             dz: 0.0,
         };
 
-        d = d.normalised();
+        // Keys and click-to-move push at full speed; a stick can push less
+        d = d.normalised() * input_vec.length().min(1.0);
         let col_delta_n = (col_delta * 100.0).clamp_length_max(1.0);
         let col_dotp = (d.dx * col_delta_n.x + d.dy * col_delta_n.y).clamp(0.0, 1.0);
         d.dx -= col_delta_n.x * col_dotp;
@@ -181,47 +175,6 @@ pub fn player_movement_system(
             .to_vec(),
         );
 
-        // Handle Interaction (E key)
-        if keyboard_input.just_pressed(player.controls.activate) {
-            let mut max_dist = 1.4;
-            let mut selected_entity = None;
-            for (entity, item_pos, interactive, behaviour, _) in interactables.iter() {
-                let cp_delta = interactive.control_point_delta(behaviour);
-                let item_pos = Position {
-                    x: item_pos.x + cp_delta.x,
-                    y: item_pos.y + cp_delta.y,
-                    z: item_pos.z + cp_delta.z,
-                    global_z: item_pos.global_z,
-                };
-                let new_dist = pos.delta(item_pos);
-                let dref = new_dist;
-                let dist = dref.distance();
-                if dist < max_dist {
-                    max_dist = dist + 0.00001;
-                    selected_entity = Some(entity);
-                }
-            }
-            if let Some(entity) = selected_entity {
-                for (entity, item_pos, interactive, behaviour, rs) in
-                    interactables.iter().filter(|(e, _, _, _, _)| *e == entity)
-                {
-                    if behaviour.is_npc() {
-                        ev_npc.write(NpcHelpEvent::new(entity));
-                    }
-                    if interactive_stuff.execute_interaction(
-                        entity,
-                        item_pos,
-                        Some(interactive),
-                        behaviour,
-                        rs,
-                        InteractionExecutionType::ChangeState,
-                    ) {
-                        ev_room.write(RoomChangedEvent::default());
-                    }
-                }
-            }
-        }
-
         if !mouse_visibility.is_visible {
             if d.distance() > 0.1 {
                 *dir = player.movement;
@@ -6,7 +6,11 @@ use uncore::{
     behaviour::component::Stairs,
     behaviour::{Behaviour, Orientation},
     components::board::{boardposition::BoardPosition, position::Position},
-    resources::{board_data::BoardData, visibility_data::VisibilityData},
+    resources::{
+        board_data::BoardData,
+        path_cache::{PathCache, PathKey},
+        visibility_data::VisibilityData,
+    },
 };
 
 /// A* pathfinding node for the priority queue
@@ -45,9 +49,21 @@ impl PartialOrd for PathNode {
     }
 }
 
-/// Calculates Manhattan distance heuristic between two board positions
-fn heuristic(a: &BoardPosition, b: &BoardPosition) -> i32 {
-    ((a.x - b.x).abs() + (a.y - b.y).abs()) as i32
+/// Cost of a step up, down, left or right.
+const ORTHOGONAL_COST: i32 = 10;
+/// Cost of a diagonal step, roughly `ORTHOGONAL_COST * sqrt(2)`.
+const DIAGONAL_COST: i32 = 14;
+
+/// Distance heuristic between two board positions: Manhattan distance for
+/// 4-way movement, octile distance when diagonal steps are allowed.
+fn heuristic(a: &BoardPosition, b: &BoardPosition, diagonal: bool) -> i32 {
+    let dx = (a.x - b.x).abs() as i32;
+    let dy = (a.y - b.y).abs() as i32;
+    if diagonal {
+        ORTHOGONAL_COST * (dx + dy) + (DIAGONAL_COST - 2 * ORTHOGONAL_COST) * dx.min(dy)
+    } else {
+        ORTHOGONAL_COST * (dx + dy)
+    }
 }
 
 /// Helper function to check if a board position is visible to the player
@@ -64,12 +80,28 @@ fn is_visible(
     false // Out of bounds or no visibility data means not visible
 }
 
-/// Gets valid neighbouring positions for pathfinding
+/// Gets valid neighbouring positions for pathfinding, with the cost of the step
 fn get_neighbours(
     pos: &BoardPosition,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
-) -> Vec<BoardPosition> {
+    diagonal: bool,
+) -> Vec<(BoardPosition, i32)> {
+    neighbours_where(pos, diagonal, |p| {
+        is_walkable_and_visible(p, board_data, visibility_data)
+    })
+}
+
+/// Neighbours of `pos` for which `passable` holds.
+///
+/// Diagonal steps are only offered when `diagonal` is set, and only when both
+/// orthogonal tiles they pass between are passable too, so paths never cut
+/// through the corner of a wall.
+fn neighbours_where(
+    pos: &BoardPosition,
+    diagonal: bool,
+    passable: impl Fn(&BoardPosition) -> bool,
+) -> Vec<(BoardPosition, i32)> {
     let mut neighbours = Vec::new();
 
     // Check 4-directional movement (up, down, left, right)
@@ -80,20 +112,25 @@ fn get_neighbours(
         (1, 0),  // Right
     ];
 
+    let offset = |dx: i64, dy: i64| BoardPosition {
+        x: pos.x + dx,
+        y: pos.y + dy,
+        z: pos.z, // Stay on same floor for now
+    };
+
     for (dx, dy) in directions {
-        let neighbour = BoardPosition {
-            x: pos.x + dx,
-            y: pos.y + dy,
-            z: pos.z, // Stay on same floor for now
-        };
+        let neighbour = offset(dx, dy);
+        if passable(&neighbour) {
+            neighbours.push((neighbour, ORTHOGONAL_COST));
+        }
+    }
 
-        // Check if the neighbour is within bounds, walkable, and visible
-        if let Some(idx) = neighbour.ndidx_checked(board_data.map_size)
-            && let Some(collision_data) = board_data.collision_field.get(idx)
-            && collision_data.player_free
-            && is_visible(&neighbour, board_data, visibility_data)
-        {
-            neighbours.push(neighbour);
+    if diagonal {
+        for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+            let neighbour = offset(dx, dy);
+            if passable(&offset(dx, 0)) && passable(&offset(0, dy)) && passable(&neighbour) {
+                neighbours.push((neighbour, DIAGONAL_COST));
+            }
         }
     }
 
@@ -134,6 +171,7 @@ pub fn find_path(
     goal: Position,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
+    diagonal: bool,
 ) -> Vec<BoardPosition> {
     let start_board = start.to_board_position();
     let goal_board = goal.to_board_position();
@@ -183,7 +221,7 @@ pub fn find_path(
     let mut g_costs = HashMap::new();
 
     // Initialise with start position
-    let start_h = heuristic(&start_board, &goal_board);
+    let start_h = heuristic(&start_board, &goal_board, diagonal);
     open_set.push(PathNode::new(start_board.clone(), 0, start_h));
     g_costs.insert(start_board.clone(), 0);
 
@@ -200,12 +238,14 @@ pub fn find_path(
         closed_set.insert(current_pos.clone());
 
         // Check all neighbours
-        for neighbour in get_neighbours(&current_pos, board_data, visibility_data) {
+        for (neighbour, step_cost) in
+            get_neighbours(&current_pos, board_data, visibility_data, diagonal)
+        {
             if closed_set.contains(&neighbour) {
                 continue;
             }
 
-            let tentative_g = current_node.g_cost + 1; // Movement cost is always 1
+            let tentative_g = current_node.g_cost + step_cost;
 
             let neighbour_g = g_costs.get(&neighbour).copied().unwrap_or(i32::MAX);
 
@@ -214,7 +254,7 @@ pub fn find_path(
                 came_from.insert(neighbour.clone(), current_pos.clone());
                 g_costs.insert(neighbour.clone(), tentative_g);
 
-                let h_cost = heuristic(&neighbour, &goal_board);
+                let h_cost = heuristic(&neighbour, &goal_board, diagonal);
                 open_set.push(PathNode::new(neighbour, tentative_g, h_cost));
             }
         }
@@ -234,6 +274,7 @@ pub fn find_path_to_interactive(
     goal: Position,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
+    diagonal: bool,
 ) -> Vec<BoardPosition> {
     let start_board = start.to_board_position();
     let goal_board = goal.to_board_position();
@@ -277,7 +318,7 @@ pub fn find_path_to_interactive(
     let mut g_costs = HashMap::new();
 
     // Initialise with start position
-    let start_h = heuristic(&start_board, &goal_board);
+    let start_h = heuristic(&start_board, &goal_board, diagonal);
     open_set.push(PathNode::new(start_board.clone(), 0, start_h));
     g_costs.insert(start_board.clone(), 0);
 
@@ -294,14 +335,18 @@ pub fn find_path_to_interactive(
         closed_set.insert(current_pos.clone());
 
         // Check all neighbours - use special function that treats goal as walkable
-        for neighbour in
-            get_neighbours_to_interactive(&current_pos, board_data, visibility_data, &goal_board)
-        {
+        for (neighbour, step_cost) in get_neighbours_to_interactive(
+            &current_pos,
+            board_data,
+            visibility_data,
+            &goal_board,
+            diagonal,
+        ) {
             if closed_set.contains(&neighbour) {
                 continue;
             }
 
-            let tentative_g = current_node.g_cost + 1; // Movement cost is always 1
+            let tentative_g = current_node.g_cost + step_cost;
 
             let neighbour_g = g_costs.get(&neighbour).copied().unwrap_or(i32::MAX);
 
@@ -310,7 +355,7 @@ pub fn find_path_to_interactive(
                 came_from.insert(neighbour.clone(), current_pos.clone());
                 g_costs.insert(neighbour.clone(), tentative_g);
 
-                let h_cost = heuristic(&neighbour, &goal_board);
+                let h_cost = heuristic(&neighbour, &goal_board, diagonal);
                 open_set.push(PathNode::new(neighbour, tentative_g, h_cost));
             }
         }
@@ -321,6 +366,64 @@ pub fn find_path_to_interactive(
     Vec::new()
 }
 
+/// Same as [`find_path`], but reuses a previous result for the same start and
+/// goal tiles while the board collision, doors and visible tiles are unchanged.
+pub fn find_path_cached(
+    cache: &mut PathCache,
+    start: Position,
+    goal: Position,
+    board_data: &BoardData,
+    visibility_data: &VisibilityData,
+    diagonal: bool,
+) -> Vec<BoardPosition> {
+    let key = path_key(start, goal, false, diagonal);
+    cached_path(cache, key, board_data, visibility_data, || {
+        find_path(start, goal, board_data, visibility_data, diagonal)
+    })
+}
+
+/// Same as [`find_path_to_interactive`], but reuses a previous result for the
+/// same start and goal tiles while the board collision, doors and visible
+/// tiles are unchanged.
+pub fn find_path_to_interactive_cached(
+    cache: &mut PathCache,
+    start: Position,
+    goal: Position,
+    board_data: &BoardData,
+    visibility_data: &VisibilityData,
+    diagonal: bool,
+) -> Vec<BoardPosition> {
+    let key = path_key(start, goal, true, diagonal);
+    cached_path(cache, key, board_data, visibility_data, || {
+        find_path_to_interactive(start, goal, board_data, visibility_data, diagonal)
+    })
+}
+
+fn path_key(start: Position, goal: Position, to_interactive: bool, diagonal: bool) -> PathKey {
+    PathKey {
+        start: start.to_board_position(),
+        goal: goal.to_board_position(),
+        to_interactive,
+        diagonal,
+    }
+}
+
+fn cached_path(
+    cache: &mut PathCache,
+    key: PathKey,
+    board_data: &BoardData,
+    visibility_data: &VisibilityData,
+    search: impl FnOnce() -> Vec<BoardPosition>,
+) -> Vec<BoardPosition> {
+    cache.sync(board_data, visibility_data);
+    if let Some(path) = cache.get(&key) {
+        return path;
+    }
+    let path = search();
+    cache.insert(key, path.clone());
+    path
+}
+
 /// Gets valid neighbouring positions for pathfinding to interactive objects.
 /// Treats the goal position as walkable even if it has collision.
 fn get_neighbours_to_interactive(
@@ -328,46 +431,31 @@ fn get_neighbours_to_interactive(
     board_data: &BoardData,
     visibility_data: &VisibilityData,
     goal: &BoardPosition,
-) -> Vec<BoardPosition> {
-    let mut neighbours = Vec::new();
-
-    // Check 4-directional movement (up, down, left, right)
-    let directions = [
-        (0, -1), // Up
-        (0, 1),  // Down
-        (-1, 0), // Left
-        (1, 0),  // Right
-    ];
-
-    for (dx, dy) in directions {
-        let neighbour = BoardPosition {
-            x: pos.x + dx,
-            y: pos.y + dy,
-            z: pos.z, // Stay on same floor for now
-        };
-
-        // Check if the neighbour is within bounds
-        if let Some(idx) = neighbour.ndidx_checked(board_data.map_size) {
-            // If this is the goal position, always treat it as walkable (but still check visibility)
-            if neighbour == *goal {
-                if is_visible(&neighbour, board_data, visibility_data) {
-                    neighbours.push(neighbour);
-                }
-            } else if let Some(collision_data) = board_data.collision_field.get(idx) {
-                // For non-goal positions, check walkability and visibility normally
-                if collision_data.player_free && is_visible(&neighbour, board_data, visibility_data)
-                {
-                    neighbours.push(neighbour);
-                }
-            }
-        }
+    diagonal: bool,
+) -> Vec<(BoardPosition, i32)> {
+    let mut neighbours = neighbours_where(pos, diagonal, |p| {
+        is_walkable_and_visible(p, board_data, visibility_data)
+    });
+
+    // The goal is reachable from any orthogonal neighbour, even if it has
+    // collision (but it still has to be visible). Diagonals into it would cut
+    // the corner of whatever blocks it, so they are left out.
+    let goal_is_orthogonal = (pos.x - goal.x).abs() + (pos.y - goal.y).abs() == 1;
+    if goal_is_orthogonal
+        && pos.z == goal.z
+        && goal.ndidx_checked(board_data.map_size).is_some()
+        && is_visible(goal, board_data, visibility_data)
+        && !neighbours.iter().any(|(n, _)| n == goal)
+    {
+        neighbours.push((goal.clone(), ORTHOGONAL_COST));
     }
 
     neighbours
 }
 
-/// Smooths a path by removing unnecessary waypoints using line-of-sight checks.
-/// This creates more natural-looking paths that move diagonally when possible
+/// Smooths a path by removing unnecessary waypoints using line-of-sight checks
+/// (string pulling). Only the tiles where the path has to turn are kept, which
+/// creates more natural-looking paths that move diagonally across open rooms
 /// while still avoiding collisions and invisible areas.
 pub fn smooth_path(
     path: Vec<BoardPosition>,
@@ -401,52 +489,61 @@ pub fn smooth_path(
 }
 
 /// Checks if there's a clear line of sight between two board positions.
-/// Uses a simple ray-casting approach to sample points along the line.
-/// Also checks visibility in addition to collision.
+///
+/// Walks every tile the straight line between both tile centres crosses, so
+/// thin walls can't slip between samples. Every crossed tile has to be
+/// walkable, see-through and visible. Where the line passes exactly through a
+/// tile corner, both tiles beside it must be clear, so the line never cuts
+/// the corner of a wall.
 fn has_line_of_sight(
     from: &BoardPosition,
     to: &BoardPosition,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
 ) -> bool {
-    // If positions are the same, there's always line of sight
-    if from == to {
-        return true;
-    }
-
-    // If positions are adjacent (Manhattan distance 1), check direct connection
-    let dx = (to.x - from.x).abs();
-    let dy = (to.y - from.y).abs();
-    if dx <= 1 && dy <= 1 && dx + dy <= 2 {
-        // Adjacent or diagonal neighbours - check if destination is walkable and visible
-        return is_walkable_and_visible(to, board_data, visibility_data);
-    }
-
-    // For longer distances, sample points along the line
-    let distance = ((to.x - from.x).pow(2) + (to.y - from.y).pow(2)) as f64;
-    let distance = distance.sqrt();
-
-    // Sample every 0.5 units along the line
-    let sample_count = (distance * 2.0).ceil() as i32;
-
-    for i in 1..sample_count {
-        let t = i as f64 / sample_count as f64;
-        let sample_x = from.x as f64 + (to.x - from.x) as f64 * t;
-        let sample_y = from.y as f64 + (to.y - from.y) as f64 * t;
-
-        let sample_pos = BoardPosition {
-            x: sample_x.round() as i64,
-            y: sample_y.round() as i64,
-            z: from.z, // Stay on same Z level
-        };
-
-        if !is_walkable_and_visible(&sample_pos, board_data, visibility_data) {
+    let is_clear = |x: i64, y: i64| {
+        let pos = BoardPosition { x, y, z: from.z };
+        is_see_through(&pos, board_data)
+            && is_walkable_and_visible(&pos, board_data, visibility_data)
+    };
+
+    let (nx, ny) = ((to.x - from.x).abs(), (to.y - from.y).abs());
+    let (sx, sy) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+    let (mut x, mut y) = (from.x, from.y);
+    let (mut ix, mut iy) = (0, 0);
+
+    while ix < nx || iy < ny {
+        // Compares where the line leaves the current tile: through its side
+        // in x (< 0), its side in y (> 0) or exactly through the corner.
+        let decision = (1 + 2 * ix) * ny - (1 + 2 * iy) * nx;
+        if decision == 0 {
+            if !is_clear(x + sx, y) || !is_clear(x, y + sy) {
+                return false;
+            }
+            x += sx;
+            y += sy;
+            ix += 1;
+            iy += 1;
+        } else if decision < 0 {
+            x += sx;
+            ix += 1;
+        } else {
+            y += sy;
+            iy += 1;
+        }
+        if !is_clear(x, y) {
             return false;
         }
     }
 
-    // Also check the final destination
-    is_walkable_and_visible(to, board_data, visibility_data)
+    true
+}
+
+/// Helper function to check if light (and line of sight) can pass through a board position
+fn is_see_through(pos: &BoardPosition, board_data: &BoardData) -> bool {
+    pos.ndidx_checked(board_data.map_size)
+        .and_then(|idx| board_data.collision_field.get(idx))
+        .is_some_and(|collision_data| collision_data.see_through)
 }
 
 /// Helper function to check if a board position is walkable
@@ -697,3 +794,128 @@ pub fn calculate_stair_waypoints(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+    use uncore::types::board::fielddata::CollisionFieldData;
+
+    fn floor() -> CollisionFieldData {
+        CollisionFieldData {
+            player_free: true,
+            see_through: true,
+            ..default()
+        }
+    }
+
+    fn open_board() -> (BoardData, VisibilityData) {
+        let mut bf = BoardData::from_world(&mut World::new());
+        bf.map_size = (6, 6, 1);
+        bf.collision_field = Array3::from_elem(bf.map_size, floor());
+        let mut vis = VisibilityData {
+            visibility_field: Array3::from_elem(bf.map_size, 1.0),
+            ..default()
+        };
+        vis.update_visible_hash();
+        (bf, vis)
+    }
+
+    #[test]
+    fn test_path_cache_hit_and_invalidation() {
+        let (mut bf, mut vis) = open_board();
+        let mut cache = PathCache::default();
+        let start = Position::new_i64(0, 0, 0);
+        let goal = Position::new_i64(4, 4, 0);
+
+        let path = find_path_cached(&mut cache, start, goal, &bf, &vis, false);
+        assert_eq!(path.last(), Some(&goal.to_board_position()));
+        assert_eq!(cache.len(), 1);
+
+        // A wall that the cached path ignores proves the result came from the cache.
+        bf.collision_field.fill(CollisionFieldData::default());
+        assert_eq!(
+            find_path_cached(&mut cache, start, goal, &bf, &vis, false),
+            path
+        );
+
+        // A door changing state drops the cached path.
+        bf.lighting_door_states.insert((2, 2, 0), false);
+        assert!(find_path_cached(&mut cache, start, goal, &bf, &vis, false).is_empty());
+        assert!(cache.is_empty());
+
+        // So does a collision rebuild.
+        bf.collision_field.fill(floor());
+        find_path_cached(&mut cache, start, goal, &bf, &vis, false);
+        assert_eq!(cache.len(), 1);
+        bf.collision_revision += 1;
+        bf.collision_field.fill(CollisionFieldData::default());
+        assert!(find_path_cached(&mut cache, start, goal, &bf, &vis, false).is_empty());
+
+        // And the goal going out of sight.
+        bf.collision_field.fill(floor());
+        find_path_cached(&mut cache, start, goal, &bf, &vis, false);
+        assert_eq!(cache.len(), 1);
+        vis.visibility_field[goal.to_board_position().ndidx()] = -0.001;
+        vis.update_visible_hash();
+        assert!(find_path_cached(&mut cache, start, goal, &bf, &vis, false).is_empty());
+    }
+
+    #[test]
+    fn test_diagonal_step_needs_both_orthogonals_clear() {
+        let (mut bf, vis) = open_board();
+        let start = Position::new_i64(0, 0, 0);
+        let goal = Position::new_i64(1, 1, 0);
+        let side_a = BoardPosition { x: 1, y: 0, z: 0 };
+        let side_b = BoardPosition { x: 0, y: 1, z: 0 };
+
+        let neighbours = get_neighbours(&start.to_board_position(), &bf, &vis, true);
+        assert!(neighbours.contains(&(goal.to_board_position(), DIAGONAL_COST)));
+        let neighbours = get_neighbours(&start.to_board_position(), &bf, &vis, false);
+        assert!(neighbours.iter().all(|(_, cost)| *cost == ORTHOGONAL_COST));
+        assert_eq!(
+            find_path(start, goal, &bf, &vis, true),
+            vec![start.to_board_position(), goal.to_board_position()]
+        );
+
+        // A wall on either side forces the path around the corner.
+        for (wall, detour) in [(&side_a, &side_b), (&side_b, &side_a)] {
+            bf.collision_field.fill(floor());
+            bf.collision_field[wall.ndidx()].player_free = false;
+            let neighbours = get_neighbours(&start.to_board_position(), &bf, &vis, true);
+            assert!(
+                neighbours
+                    .iter()
+                    .all(|(n, _)| *n != goal.to_board_position())
+            );
+            let path = find_path(start, goal, &bf, &vis, true);
+            assert_eq!(path.len(), 3);
+            assert_eq!(&path[1], detour);
+        }
+    }
+
+    #[test]
+    fn test_straight_corridor_collapses_to_one_waypoint() {
+        let (mut bf, vis) = open_board();
+        bf.collision_field.fill(CollisionFieldData::default());
+        for x in 0..6 {
+            bf.collision_field[(x, 2, 0)] = floor();
+        }
+        let start = Position::new_i64(0, 2, 0);
+        let goal = Position::new_i64(5, 2, 0);
+
+        let path = find_path(start, goal, &bf, &vis, false);
+        assert_eq!(
+            path,
+            vec![start.to_board_position(), goal.to_board_position()]
+        );
+
+        // Turning into a side corridor keeps the waypoint at the turn.
+        for y in 3..6 {
+            bf.collision_field[(5, y, 0)] = floor();
+        }
+        let path = find_path(start, Position::new_i64(5, 5, 0), &bf, &vis, false);
+        assert_eq!(path.len(), 3);
+        assert_eq!(path[1], goal.to_board_position());
+    }
+}
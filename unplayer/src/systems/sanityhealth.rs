@@ -2,11 +2,14 @@ use uncore::DEBUG_PLAYER;
 use uncore::components::board::position::Position;
 use uncore::components::game_config::GameConfig;
 use uncore::components::game_ui::DamageBackground;
+use uncore::components::ghost_sprite::GhostSprite;
 use uncore::components::player::Stamina;
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::difficulty::CurrentDifficulty;
 use uncore::resources::board_data::BoardData;
+use uncore::resources::hunt_state::HuntState;
 use uncore::resources::roomdb::RoomDB;
+use uncore::sanity;
 use uncore::types::grade::Grade;
 use uncore::utils::PrintingTimer;
 use uncore::utils::light::lerp_color;
@@ -26,6 +29,8 @@ fn lose_sanity(
     mut timer: Local<PrintingTimer>,
     mut mean_sound: Local<MeanSound>,
     mut qp: Query<(&mut PlayerSprite, &Position)>,
+    qg: Query<&Position, With<GhostSprite>>,
+    hunt_state: Res<HuntState>,
     bf: Res<BoardData>,
     roomdb: Res<RoomDB>,
     // Access the difficulty settings
@@ -60,6 +65,17 @@ fn lose_sanity(
         }
         let crazy = lux.max(0.00001).recip() / f_temp * f_temp2 * mean_sound.0 * 10.0
             + mean_sound.0 / f_temp * f_temp2;
+        let near_ghost = qg
+            .iter()
+            .any(|gpos| pos.distance(gpos) < sanity::GHOST_PROXIMITY_DISTANCE);
+        // Darkness is already in `crazy` through the lux, so only the ghost
+        // multipliers of the drain model apply on top of it.
+        let drain_rate = sanity::compute_drain(
+            difficulty.0.sanity_drain_rate,
+            false,
+            near_ghost,
+            hunt_state.active,
+        );
         let sanity_recover: f32 = if ps.sanity() < difficulty.0.max_recoverable_sanity {
             4.0 / 100.0 / difficulty.0.sanity_drain_rate
         } else {
@@ -67,9 +83,7 @@ fn lose_sanity(
         };
         // Only apply sanity loss if Dev God Mode is not enabled
         if !gameplay_settings.dev_cheat_mode.is_enabled() {
-            ps.crazyness += (crazy.clamp(0.000000001, 10000000.0).sqrt()
-                * 0.2
-                * difficulty.0.sanity_drain_rate
+            ps.crazyness += (crazy.clamp(0.000000001, 10000000.0).sqrt() * 0.2 * drain_rate
                 - sanity_recover * ps.crazyness / (1.0 + mean_sound.0 * 10.0))
                 * dt;
         } else {
@@ -80,12 +94,13 @@ fn lose_sanity(
             ps.crazyness = 0.0;
         }
         ps.mean_sound = mean_sound.0;
-        if ps.health < 100.0 && ps.health > 0.0 {
-            ps.health += (0.1 * dt + (1.0 - ps.health / 100.0) * dt * 10.0)
+        let max_health = difficulty.0.max_health;
+        if ps.health < max_health && ps.health > 0.0 {
+            ps.health += (0.1 * dt + (1.0 - ps.health / max_health) * dt * 10.0)
                 * difficulty.0.health_recovery_rate;
         }
-        if ps.health > 100.0 {
-            ps.health = 100.0;
+        if ps.health > max_health {
+            ps.health = max_health;
         }
         if timer.just_finished() && DEBUG_PLAYER {
             // dbg!(ps.sanity(), mean_sound.0, ps.health);
@@ -106,13 +121,13 @@ fn recover_sanity(
     timer.tick(time.delta());
     for mut ps in &mut qp {
         if ps.id == gc.player_id {
-            // --- Gradual Health Recovery --- Health points recovered per second
-            const HEALTH_RECOVERY_RATE: f32 = 2.0;
-            if ps.health < 100.0 {
-                ps.health += HEALTH_RECOVERY_RATE * dt;
+            // --- Gradual Health Recovery ---
+            let max_health = difficulty.0.max_health;
+            if ps.health < max_health {
+                ps.health += difficulty.0.truck_health_regen * dt;
 
-                // Clamp health to a maximum of 100%
-                ps.health = ps.health.min(100.0);
+                // Clamp health to the difficulty's maximum
+                ps.health = ps.health.min(max_health);
             }
             if ps.sanity() < difficulty.0.max_recoverable_sanity {
                 ps.crazyness /= 1.07_f32.powf(dt);
@@ -129,6 +144,7 @@ fn recover_sanity(
 fn visual_health(
     qp: Query<&PlayerSprite>,
     gc: Res<GameConfig>,
+    difficulty: Res<CurrentDifficulty>,
     mut qb: Query<(
         Option<&mut ImageNode>,
         &mut BackgroundColor,
@@ -139,7 +155,7 @@ fn visual_health(
         if player.id != gc.player_id {
             continue;
         }
-        let health = (player.health.clamp(0.0, 100.0) / 100.0).clamp(0.0, 1.0);
+        let health = (player.health / difficulty.0.max_health).clamp(0.0, 1.0);
         let crazyness = (1.0 - player.sanity() / 100.0).clamp(0.0, 1.0);
         for (mut o_uiimage, mut bgcolour, dmg) in &mut qb {
             let rhealth = (1.0 - health).powf(dmg.exp);
@@ -168,7 +184,7 @@ fn update_player_stamina(
 ) {
     for (player_sprite, mut stamina) in players.iter_mut() {
         // Adjust stamina parameters based on health
-        let health_percentage = player_sprite.health / 100.0;
+        let health_percentage = player_sprite.health / difficulty.0.max_health;
 
         // When health is low, stamina depletes faster and recovers slower
         if health_percentage < 0.3 {
@@ -1,21 +1,30 @@
 use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_persistent::Persistent;
 use uncore::{
     behaviour::{Behaviour, component::Interactive, component::Stairs},
     components::{
-        board::{PERSPECTIVE_X, PERSPECTIVE_Y, PERSPECTIVE_Z, position::Position},
+        board::position::Position,
         game::{GCameraArena, GameSprite},
+        player::LastInteractive,
         player_sprite::PlayerSprite,
         waypoint::{Waypoint, WaypointOwner, WaypointQueue, WaypointType},
     },
-    events::roomchanged::{InteractionExecutionType, RoomChangedEvent},
+    events::{
+        roomchanged::{InteractionExecutionType, RoomChangedEvent},
+        truck::GoToTruckEvent,
+    },
     resources::{
-        board_data::BoardData, mouse_visibility::MouseVisibility, player_input::PlayerInput,
-        visibility_data::VisibilityData,
+        board_data::BoardData, mouse_visibility::MouseVisibility, path_cache::PathCache,
+        player_input::PlayerInput, visibility_data::VisibilityData,
     },
 };
+use unsettings::game::GameplaySettings;
 use unstd::systemparam::interactivestuff::InteractiveStuff;
 
-use super::pathfinding::{detect_stair_area, find_path, find_path_to_interactive};
+use super::pathfinding::{detect_stair_area, find_path_cached, find_path_to_interactive_cached};
+
+/// Maximum distance from the player at which an interactive can be used directly.
+pub const INTERACTION_DISTANCE: f32 = 1.2;
 
 /// System that creates waypoint entities when the player clicks.
 /// Handles both interactive objects (via picking) and ground clicks (via raw mouse input).
@@ -40,6 +49,8 @@ pub fn waypoint_creation_system(
     mouse_visibility: Res<MouseVisibility>,
     board_data: Res<BoardData>,
     visibility_data: Res<VisibilityData>,
+    mut path_cache: ResMut<PathCache>,
+    game_settings: Res<Persistent<GameplaySettings>>,
 ) {
     // Only process clicks when mouse is visible
     if !mouse_visibility.is_visible {
@@ -55,7 +66,8 @@ pub fn waypoint_creation_system(
     };
 
     // Find the active player's position and floor
-    let player_floor = player_pos.z.round() as i32;
+    let player_z = player_pos.z.round().max(0.0) as usize;
+    let player_floor = board_data.floor_for_z(player_z);
 
     // Track if any interactive object was clicked via picking events
     let mut interactive_clicked = false;
@@ -71,19 +83,20 @@ pub fn waypoint_creation_system(
         if let Ok((interactive_entity, interactive_pos, _interactive, _behaviour, _room_state)) =
             q_interactives.get(click_event.target)
         {
-            let interactive_floor = interactive_pos.z.round() as i32;
+            let interactive_z = interactive_pos.z.round().max(0.0) as usize;
+            let interactive_floor = board_data.floor_for_z(interactive_z);
 
             // Only allow clicks on interactives that are on the same floor as the player
-            if interactive_floor != player_floor {
+            if interactive_z != player_z {
                 debug!(
-                    "waypoint_creation_system: Ignoring click on interactive entity {:?} - player on floor {}, interactive on floor {}",
+                    "waypoint_creation_system: Ignoring click on interactive entity {:?} - player on floor {:?}, interactive on floor {:?}",
                     interactive_entity, player_floor, interactive_floor
                 );
                 continue; // Skip this click
             }
 
             debug!(
-                "waypoint_creation_system: Creating waypoint to interactive entity {:?} on floor {}",
+                "waypoint_creation_system: Creating waypoint to interactive entity {:?} on floor {:?}",
                 interactive_entity, interactive_floor
             );
 
@@ -96,7 +109,6 @@ pub fn waypoint_creation_system(
             );
 
             // Check if we're close enough to interact directly
-            const INTERACTION_DISTANCE: f32 = 1.2;
             let distance = player_pos.distance(interactive_pos);
 
             if distance <= INTERACTION_DISTANCE {
@@ -120,6 +132,8 @@ pub fn waypoint_creation_system(
                     &mut waypoint_queue,
                     &board_data,
                     &visibility_data,
+                    &mut path_cache,
+                    game_settings.pathfinding_moves.diagonal(),
                 );
             }
             interactive_clicked = true;
@@ -138,10 +152,13 @@ pub fn waypoint_creation_system(
             return;
         };
 
-        // Convert cursor position to world coordinates
-        if let Some(target) =
-            screen_to_world_coords(cursor_pos, player_pos.z, camera, camera_transform)
-        {
+        // Convert cursor position to world coordinates, preferring the floor the
+        // player can actually see at that spot (matters around stairs)
+        let candidates =
+            screen_to_world_candidates(cursor_pos, player_pos.z, camera, camera_transform);
+        if let Some(target) = pick_visible_candidate(&candidates, player_pos.z, &visibility_data) {
+            // Clicks outside the floor walk to the nearest spot on the map
+            let target = board_data.clamp_position(target);
             debug!("Ground click detected at {:?}", target);
 
             // First check if the click is in a stairs area
@@ -178,12 +195,58 @@ pub fn waypoint_creation_system(
                     &mut waypoint_queue,
                     &board_data,
                     &visibility_data,
+                    &mut path_cache,
+                    game_settings.pathfinding_moves.diagonal(),
                 );
             }
         }
     }
 }
 
+/// Sets a path from the player to the nearest van entry on their floor when a
+/// `GoToTruckEvent` is received.
+pub fn go_to_truck_system(
+    mut commands: Commands,
+    mut ev_go_to_truck: EventReader<GoToTruckEvent>,
+    mut q_player: Query<(Entity, &Position, &mut WaypointQueue), With<PlayerSprite>>,
+    q_existing_waypoints: Query<Entity, (With<Waypoint>, With<WaypointOwner>)>,
+    q_interactives: Query<(Entity, &Position, &Behaviour), With<Interactive>>,
+    board_data: Res<BoardData>,
+    visibility_data: Res<VisibilityData>,
+    mut path_cache: ResMut<PathCache>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+) {
+    if ev_go_to_truck.read().count() == 0 {
+        return;
+    }
+    let Ok((player_entity, player_pos, mut waypoint_queue)) = q_player.single_mut() else {
+        return;
+    };
+    let player_z = player_pos.z.round();
+    let Some((van_entry, van_pos)) = q_interactives
+        .iter()
+        .filter(|(_, pos, behaviour)| behaviour.is_van_entry() && pos.z.round() == player_z)
+        .map(|(entity, pos, _)| (entity, *pos))
+        .min_by(|(_, a), (_, b)| a.distance2(player_pos).total_cmp(&b.distance2(player_pos)))
+    else {
+        debug!("go_to_truck_system: No van entry on the player's floor");
+        return;
+    };
+    create_pathfinding_waypoints_to_interaction(
+        &mut commands,
+        &q_existing_waypoints,
+        player_entity,
+        *player_pos,
+        van_pos,
+        van_entry,
+        &mut waypoint_queue,
+        &board_data,
+        &visibility_data,
+        &mut path_cache,
+        game_settings.pathfinding_moves.diagonal(),
+    );
+}
+
 /// System that makes the player follow waypoints.
 /// Replaces the old click-to-move update system.
 pub fn waypoint_following_system(
@@ -209,7 +272,6 @@ pub fn waypoint_following_system(
                 let to_target = target - current;
 
                 const ARRIVAL_THRESHOLD: f32 = 0.1;
-                const INTERACTION_DISTANCE: f32 = 1.2;
 
                 // Check if we should handle the waypoint action
                 let should_complete_waypoint = match &waypoint.waypoint_type {
@@ -224,6 +286,9 @@ pub fn waypoint_following_system(
                         {
                             let distance = player_pos.distance(interactive_pos);
                             if distance <= INTERACTION_DISTANCE {
+                                commands.entity(player_entity).insert(LastInteractive {
+                                    entity: *interaction_target,
+                                });
                                 // Execute the interaction
                                 if interactive_stuff.execute_interaction(
                                     *interaction_target,
@@ -263,6 +328,70 @@ pub fn waypoint_following_system(
     }
 }
 
+/// System that sends the player back to the last used interactive to use it again.
+///
+/// If the remembered entity is gone the memory is cleared. Objects on another
+/// floor are ignored, like clicks on them are.
+pub fn repeat_last_interaction_system(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut q_player: Query<(
+        Entity,
+        &Position,
+        &PlayerSprite,
+        &LastInteractive,
+        &mut WaypointQueue,
+    )>,
+    q_existing_waypoints: Query<Entity, (With<Waypoint>, With<WaypointOwner>)>,
+    q_interactives: Query<&Position, (With<Interactive>, Without<PlayerSprite>)>,
+    board_data: Res<BoardData>,
+    visibility_data: Res<VisibilityData>,
+    mut path_cache: ResMut<PathCache>,
+    game_settings: Res<Persistent<GameplaySettings>>,
+) {
+    for (player_entity, player_pos, player, last, mut waypoint_queue) in q_player.iter_mut() {
+        if !keyboard_input.just_pressed(player.controls.repeat_interaction) {
+            continue;
+        }
+        let Ok(interactive_pos) = q_interactives.get(last.entity) else {
+            commands.entity(player_entity).remove::<LastInteractive>();
+            continue;
+        };
+        if interactive_pos.z.round() != player_pos.z.round() {
+            continue;
+        }
+        if player_pos.distance(interactive_pos) <= INTERACTION_DISTANCE {
+            clear_player_waypoints(
+                &mut commands,
+                &q_existing_waypoints,
+                player_entity,
+                &mut waypoint_queue,
+            );
+            create_interaction_waypoint(
+                &mut commands,
+                player_entity,
+                *interactive_pos,
+                last.entity,
+                &mut waypoint_queue,
+            );
+        } else {
+            create_pathfinding_waypoints_to_interaction(
+                &mut commands,
+                &q_existing_waypoints,
+                player_entity,
+                *player_pos,
+                *interactive_pos,
+                last.entity,
+                &mut waypoint_queue,
+                &board_data,
+                &visibility_data,
+                &mut path_cache,
+                game_settings.pathfinding_moves.diagonal(),
+            );
+        }
+    }
+}
+
 /// Helper function to clear all waypoints belonging to a player
 fn clear_player_waypoints(
     commands: &mut Commands,
@@ -352,39 +481,49 @@ pub fn waypoint_queue_cleanup_system(
     }
 }
 
-/// Converts screen coordinates to world coordinates using the game's isometric projection.
-fn screen_to_world_coords(
+/// Converts screen coordinates to world positions on the player's floor and the
+/// floors directly above and below it, topmost first.
+fn screen_to_world_candidates(
     screen_pos: Vec2,
-    target_z: f32,
+    player_z: f32,
     camera: &Camera,
     camera_transform: &GlobalTransform,
-) -> Option<Position> {
+) -> Vec<Position> {
     // Get the world position on the camera's near plane using Bevy's built-in conversion
-    let world_pos_on_near_plane = camera
-        .viewport_to_world_2d(camera_transform, screen_pos)
-        .ok()?;
-
-    // Calculate the determinant of the 2x2 isometric projection matrix
-    let det = PERSPECTIVE_X[0] * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * PERSPECTIVE_X[1];
-    if det.abs() < 1e-6 {
-        return None; // Matrix is not invertible
-    }
-    let inv_det = 1.0 / det;
-
-    // Adjust screen coordinates by removing the Z-level contribution
-    let b_x = world_pos_on_near_plane.x - target_z * PERSPECTIVE_Z[0];
-    let b_y = world_pos_on_near_plane.y - target_z * PERSPECTIVE_Z[1];
-
-    // Apply the inverse transformation matrix to find world X and Y coordinates
-    let world_x = inv_det * (b_x * PERSPECTIVE_Y[1] - PERSPECTIVE_Y[0] * b_y);
-    let world_y = inv_det * (PERSPECTIVE_X[0] * b_y - b_x * PERSPECTIVE_X[1]);
-
-    Some(Position {
-        x: world_x,
-        y: world_y,
-        z: target_z,
-        global_z: 0.0,
-    })
+    let Ok(world_pos_on_near_plane) = camera.viewport_to_world_2d(camera_transform, screen_pos)
+    else {
+        return vec![];
+    };
+    floor_candidates(world_pos_on_near_plane, player_z)
+}
+
+/// Inverts the isometric projection of `point` for each floor adjacent to `player_z`.
+fn floor_candidates(point: Vec2, player_z: f32) -> Vec<Position> {
+    let floor = player_z.round();
+    [floor + 1.0, floor, floor - 1.0]
+        .into_iter()
+        .filter(|z| *z >= 0.0)
+        .filter_map(|z| Position::from_screen_coord(point, z))
+        .collect()
+}
+
+/// Picks the topmost candidate whose tile is visible to the player, falling back
+/// to the candidate on the player's own floor.
+fn pick_visible_candidate(
+    candidates: &[Position],
+    player_z: f32,
+    visibility_data: &VisibilityData,
+) -> Option<Position> {
+    let vis_field = &visibility_data.visibility_field;
+    candidates
+        .iter()
+        .find(|pos| {
+            pos.to_board_position()
+                .ndidx_checked(vis_field.dim())
+                .is_some_and(|idx| vis_field[idx] > 0.00001)
+        })
+        .or_else(|| candidates.iter().find(|pos| pos.z == player_z.round()))
+        .copied()
 }
 
 /// Helper function to create waypoints using pathfinding
@@ -397,6 +536,8 @@ fn create_pathfinding_waypoints(
     waypoint_queue: &mut WaypointQueue,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
+    path_cache: &mut PathCache,
+    diagonal: bool,
 ) {
     // Clear existing waypoints first
     clear_player_waypoints(
@@ -407,7 +548,14 @@ fn create_pathfinding_waypoints(
     );
 
     // Use pathfinding to get a sequence of board positions
-    let path = find_path(start_pos, target_pos, board_data, visibility_data);
+    let path = find_path_cached(
+        path_cache,
+        start_pos,
+        target_pos,
+        board_data,
+        visibility_data,
+        diagonal,
+    );
 
     if path.is_empty() {
         debug!("No path found from {:?} to {:?}", start_pos, target_pos);
@@ -450,6 +598,8 @@ fn create_pathfinding_waypoints_to_interaction(
     waypoint_queue: &mut WaypointQueue,
     board_data: &BoardData,
     visibility_data: &VisibilityData,
+    path_cache: &mut PathCache,
+    diagonal: bool,
 ) {
     // Clear existing waypoints first
     clear_player_waypoints(
@@ -460,7 +610,14 @@ fn create_pathfinding_waypoints_to_interaction(
     );
 
     // Use pathfinding to get a sequence of board positions (treating target as walkable)
-    let path = find_path_to_interactive(start_pos, target_pos, board_data, visibility_data);
+    let path = find_path_to_interactive_cached(
+        path_cache,
+        start_pos,
+        target_pos,
+        board_data,
+        visibility_data,
+        diagonal,
+    );
 
     if path.is_empty() {
         debug!("No path found from {:?} to {:?}", start_pos, target_pos);
@@ -577,3 +734,54 @@ fn create_stair_waypoints(
 
     debug!("Created 2 waypoints for stair traversal");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array3;
+
+    #[test]
+    fn test_floor_candidates_invert_projection_per_floor() {
+        let ground = Position {
+            x: 10.0,
+            y: 4.0,
+            z: 0.0,
+            global_z: 0.0,
+        };
+        let point = ground.to_screen_coord().truncate();
+        let candidates = floor_candidates(point, 0.0);
+
+        // No floor below 0: first floor and ground floor, topmost first
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].z, 1.0);
+        assert_eq!(candidates[1].z, 0.0);
+        assert!((candidates[1].x - ground.x).abs() < 0.001);
+        assert!((candidates[1].y - ground.y).abs() < 0.001);
+
+        // Each candidate projects back onto the clicked point
+        for pos in &candidates {
+            let screen = pos.to_screen_coord().truncate();
+            assert!(screen.distance(point) < 0.001, "{pos:?} -> {screen:?}");
+        }
+        // The upper floor is shifted on the board, not just in z
+        assert!((candidates[0].x - ground.x).abs() > 0.5);
+    }
+
+    #[test]
+    fn test_pick_visible_candidate_prefers_visible_floor() {
+        let candidates = vec![Position::new_i64(3, 3, 1), Position::new_i64(5, 5, 0)];
+        let mut visibility_data = VisibilityData {
+            visibility_field: Array3::from_elem((8, 8, 2), -0.001),
+            ..default()
+        };
+
+        // Nothing visible: stay on the player's floor
+        let picked = pick_visible_candidate(&candidates, 0.2, &visibility_data).unwrap();
+        assert_eq!(picked.z, 0.0);
+
+        // Upper floor tile visible (e.g. looking up the stairs)
+        visibility_data.visibility_field[(3, 3, 1)] = 0.8;
+        let picked = pick_visible_candidate(&candidates, 0.2, &visibility_data).unwrap();
+        assert_eq!(picked.z, 1.0);
+    }
+}
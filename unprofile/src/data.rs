@@ -113,3 +113,95 @@ pub struct PlayerProfileData {
     #[serde(default)]
     pub times_evidence_acknowledged_in_journal: HashMap<Evidence, u32>,
 }
+
+/// Times an evidence has to be acknowledged on gear before the gear stops
+/// blinking to point it out.
+pub const GEAR_HINT_ACKNOWLEDGE_THRESHOLD: u32 = 3;
+
+impl PlayerProfileData {
+    /// True while gear indicating `evidence` should still blink to teach the
+    /// player about it.
+    pub fn gear_hint_needed(&self, evidence: Evidence) -> bool {
+        self.times_evidence_acknowledged_on_gear
+            .get(&evidence)
+            .copied()
+            .unwrap_or(0)
+            < GEAR_HINT_ACKNOWLEDGE_THRESHOLD
+    }
+
+    /// Records that the player confirmed `evidence` while their gear was
+    /// indicating it. Counting stops at the hint threshold; returns true if
+    /// the count changed.
+    pub fn acknowledge_evidence_on_gear(&mut self, evidence: Evidence) -> bool {
+        let count = self
+            .times_evidence_acknowledged_on_gear
+            .entry(evidence)
+            .or_insert(0);
+        if *count >= GEAR_HINT_ACKNOWLEDGE_THRESHOLD {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    /// Closes the insurance deposit at the end of a mission.
+    ///
+    /// `costs` (lost gear, penalties) are deducted from the held deposit and the
+    /// remainder is moved to the bank. Costs beyond the deposit are absorbed by
+    /// the insurance, so nothing is ever taken from the bank. The deposit is
+    /// emptied in all cases. Returns the amount returned to the bank.
+    pub fn settle_deposit(&mut self, costs: i64) -> i64 {
+        let held = self.progression.insurance_deposit.max(0);
+        let returned = (held - costs.max(0)).max(0);
+        self.progression.bank += returned;
+        self.progression.insurance_deposit = 0;
+        returned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gear_hint_stops_after_acknowledgements() {
+        let mut profile = PlayerProfileData::default();
+        for _ in 0..GEAR_HINT_ACKNOWLEDGE_THRESHOLD {
+            assert!(profile.gear_hint_needed(Evidence::CPM500));
+            assert!(profile.acknowledge_evidence_on_gear(Evidence::CPM500));
+        }
+        assert!(!profile.gear_hint_needed(Evidence::CPM500));
+        assert!(!profile.acknowledge_evidence_on_gear(Evidence::CPM500));
+        assert!(profile.gear_hint_needed(Evidence::SpiritBox));
+    }
+
+    fn profile_with_deposit(bank: i64, deposit: i64) -> PlayerProfileData {
+        let mut profile = PlayerProfileData::default();
+        profile.progression.bank = bank;
+        profile.progression.insurance_deposit = deposit;
+        profile
+    }
+
+    #[test]
+    fn test_settle_deposit_partial_deduction() {
+        let mut profile = profile_with_deposit(100, 500);
+        assert_eq!(profile.settle_deposit(120), 380);
+        assert_eq!(profile.progression.bank, 480);
+        assert_eq!(profile.progression.insurance_deposit, 0);
+    }
+
+    #[test]
+    fn test_settle_deposit_without_costs_returns_everything() {
+        let mut profile = profile_with_deposit(0, 250);
+        assert_eq!(profile.settle_deposit(0), 250);
+        assert_eq!(profile.progression.bank, 250);
+    }
+
+    #[test]
+    fn test_settle_deposit_costs_exceeding_deposit() {
+        let mut profile = profile_with_deposit(100, 50);
+        assert_eq!(profile.settle_deposit(400), 0);
+        assert_eq!(profile.progression.bank, 100);
+        assert_eq!(profile.progression.insurance_deposit, 0);
+    }
+}
@@ -12,7 +12,8 @@ pub struct AudioSettings {
     pub volume_effects: AudioLevel,
     /// The volume level for ambient sounds.
     pub volume_ambient: AudioLevel,
-    /// The volume level for voice chat.
+    /// The volume level for voice chat. Without networking this is the
+    /// walkie-talkie radio channel used for narration.
     pub volume_voice_chat: AudioLevel,
     /// The sound output mode (e.g., mono, headphones, speakers).
     pub sound_output: SoundOutput,
@@ -22,6 +23,16 @@ pub struct AudioSettings {
     pub feedback_delay: FeedbackDelay,
     /// The feedback EQ setting.
     pub feedback_eq: FeedbackEQ,
+    /// Extra cue while holding a button, for players who can't rely on the
+    /// rising tone.
+    #[serde(default)]
+    pub hold_tick_cue: HoldTickCue,
+    /// How volume levels map to the gain applied to sounds.
+    #[serde(default)]
+    pub volume_curve: VolumeCurve,
+    /// Whether all audio is silenced while the game window isn't focused.
+    #[serde(default)]
+    pub unfocused_mute: UnfocusedMute,
 }
 
 impl Default for AudioSettings {
@@ -36,6 +47,9 @@ impl Default for AudioSettings {
             audio_positioning: Default::default(),
             feedback_delay: Default::default(),
             feedback_eq: Default::default(),
+            hold_tick_cue: Default::default(),
+            volume_curve: Default::default(),
+            unfocused_mute: Default::default(),
         }
     }
 }
@@ -62,6 +76,12 @@ pub enum AudioSettingsValue {
     feedback_delay(FeedbackDelay),
     /// The feedback EQ setting.
     feedback_eq(FeedbackEQ),
+    /// The hold button tick cue setting.
+    hold_tick_cue(HoldTickCue),
+    /// The volume curve setting.
+    volume_curve(VolumeCurve),
+    /// The mute when unfocused setting.
+    unfocused_mute(UnfocusedMute),
 }
 
 /// Represents the different volume levels.
@@ -116,6 +136,33 @@ pub enum AudioLevel {
     Vol100,
 }
 
+impl AudioSettings {
+    /// Gain for `level` using the configured volume curve.
+    pub fn gain(&self, level: AudioLevel) -> f32 {
+        level.gain(self.volume_curve)
+    }
+
+    /// Final linear volume for walkie-talkie playback (voice channel × master).
+    pub fn walkie_volume(&self) -> f32 {
+        self.gain(self.volume_voice_chat) * self.gain(self.volume_master)
+    }
+
+    /// Final linear volume for sound effects (effects channel × master).
+    pub fn effects_volume(&self) -> f32 {
+        self.gain(self.volume_effects) * self.gain(self.volume_master)
+    }
+
+    /// Final linear volume for music (music channel × master).
+    pub fn music_volume(&self) -> f32 {
+        self.gain(self.volume_music) * self.gain(self.volume_master)
+    }
+
+    /// Final linear volume for ambient sounds (ambient channel × master).
+    pub fn ambient_volume(&self) -> f32 {
+        self.gain(self.volume_ambient) * self.gain(self.volume_master)
+    }
+}
+
 impl AudioLevel {
     /// Converts the `AudioLevel` to an `f32` volume multiplier.
     ///
@@ -126,6 +173,21 @@ impl AudioLevel {
         v * v
     }
 
+    /// Gain for this level on the given curve.
+    pub fn gain(&self, curve: VolumeCurve) -> f32 {
+        match curve {
+            VolumeCurve::Standard => self.as_f32(),
+            VolumeCurve::Perceptual => {
+                let v = self.as_f32_linear();
+                if v <= 0.0 {
+                    0.0
+                } else {
+                    10f32.powf(PERCEPTUAL_RANGE_DB * (v - 1.0) / 20.0)
+                }
+            }
+        }
+    }
+
     /// Converts the `AudioLevel` to an `f32` volume multiplier.
     ///
     /// This uses a linear curve.
@@ -146,6 +208,34 @@ impl AudioLevel {
     }
 }
 
+/// Attenuation, in dB, at the bottom of the perceptual curve. Each 10% step
+/// adds a tenth of it.
+const PERCEPTUAL_RANGE_DB: f32 = 40.0;
+
+/// How volume levels are turned into a gain.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+)]
+pub enum VolumeCurve {
+    /// The square of the level, as volumes always worked (default).
+    #[default]
+    Standard,
+    /// Every step changes loudness by the same number of decibels, so steps
+    /// sound evenly spaced.
+    Perceptual,
+}
+
 /// Represents the different sound output modes.
 #[derive(
     Serialize,
@@ -258,3 +348,95 @@ pub enum FeedbackEQ {
     /// Disable feedback EQ.
     No,
 }
+
+/// Whether hold-to-confirm buttons also tick at regular steps of progress.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+)]
+pub enum HoldTickCue {
+    /// Only the rising tone (default).
+    #[default]
+    Off,
+    /// A short tick every quarter of the way.
+    #[strum(to_string = "Ticking")]
+    Ticking,
+}
+
+impl HoldTickCue {
+    pub fn on(&self) -> bool {
+        matches!(self, HoldTickCue::Ticking)
+    }
+}
+
+/// Whether the game goes silent while its window is in the background.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+)]
+pub enum UnfocusedMute {
+    /// Keep playing when alt-tabbing (default).
+    #[default]
+    #[strum(to_string = "Keep Playing")]
+    KeepPlaying,
+    /// Silence everything until the window is focused again.
+    #[strum(to_string = "Mute")]
+    Mute,
+}
+
+impl UnfocusedMute {
+    pub fn on(&self) -> bool {
+        matches!(self, UnfocusedMute::Mute)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    fn db(gain: f32) -> f32 {
+        20.0 * gain.log10()
+    }
+
+    #[test]
+    fn test_volume_curves() {
+        for curve in [VolumeCurve::Standard, VolumeCurve::Perceptual] {
+            assert_eq!(AudioLevel::Vol000.gain(curve), 0.0);
+            assert_eq!(AudioLevel::Vol100.gain(curve), 1.0);
+        }
+        assert_eq!(AudioLevel::Vol050.gain(VolumeCurve::Standard), 0.25);
+        assert!((AudioLevel::Vol050.gain(VolumeCurve::Perceptual) - 0.1).abs() < 1e-6);
+
+        // Perceptual steps are evenly spaced in dB; the standard curve bunches
+        // most of its range into the lowest steps.
+        let steps = |curve| -> Vec<f32> {
+            let gains: Vec<f32> = AudioLevel::iter().skip(1).map(|l| l.gain(curve)).collect();
+            gains.windows(2).map(|w| db(w[1]) - db(w[0])).collect()
+        };
+        let perceptual = steps(VolumeCurve::Perceptual);
+        assert!(perceptual.iter().all(|d| (d - 4.0).abs() < 1e-3));
+        let standard = steps(VolumeCurve::Standard);
+        assert!(standard[0] > 3.0 * standard[standard.len() - 1]);
+    }
+}
@@ -0,0 +1,313 @@
+use crate::audio::AudioSettings;
+use crate::changed::SettingsChanged;
+use crate::game::GameplaySettings;
+use crate::persist::{PersistRetry, persist_with_retry};
+use crate::profile::ProfileSettings;
+use crate::video::VideoSettings;
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Version written into exported bundles, for future migrations.
+pub const SETTINGS_BUNDLE_VERSION: u32 = 1;
+
+/// File name used for the bundle inside the config directory.
+pub const SETTINGS_BUNDLE_FILE: &str = "settings_bundle.json";
+
+/// All player settings in a single JSON document.
+///
+/// Each section is kept as a raw JSON value so that a broken or outdated
+/// section can be rejected on import without discarding the others.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SettingsBundle {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gameplay: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<serde_json::Value>,
+}
+
+/// Errors that can occur while reading or writing a settings bundle.
+#[derive(Error, Debug)]
+pub enum SettingsBundleError {
+    /// The bundle file could not be read or written.
+    #[error("Could not access settings bundle: {0}")]
+    Io(#[from] std::io::Error),
+    /// The bundle is not valid JSON, or a section could not be serialized.
+    #[error("Invalid settings bundle: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Sections successfully parsed from a bundle.
+///
+/// A `None` section was either missing from the bundle or rejected; rejected
+/// ones are listed in `rejected` along with the reason.
+#[derive(Debug, Default)]
+pub struct ImportedSettings {
+    pub gameplay: Option<GameplaySettings>,
+    pub video: Option<VideoSettings>,
+    pub audio: Option<AudioSettings>,
+    pub profile: Option<ProfileSettings>,
+    pub rejected: Vec<(&'static str, String)>,
+}
+
+impl SettingsBundle {
+    pub fn new(
+        gameplay: &GameplaySettings,
+        video: &VideoSettings,
+        audio: &AudioSettings,
+        profile: &ProfileSettings,
+    ) -> Result<Self, SettingsBundleError> {
+        Ok(Self {
+            version: SETTINGS_BUNDLE_VERSION,
+            gameplay: Some(serde_json::to_value(gameplay)?),
+            video: Some(serde_json::to_value(video)?),
+            audio: Some(serde_json::to_value(audio)?),
+            profile: Some(serde_json::to_value(profile)?),
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, SettingsBundleError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SettingsBundleError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Validates every section independently.
+    pub fn parse_sections(&self) -> ImportedSettings {
+        let mut rejected = Vec::new();
+        ImportedSettings {
+            gameplay: parse_section("gameplay", &self.gameplay, &mut rejected),
+            video: parse_section("video", &self.video, &mut rejected),
+            audio: parse_section("audio", &self.audio, &mut rejected),
+            profile: parse_section("profile", &self.profile, &mut rejected),
+            rejected,
+        }
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<(), SettingsBundleError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self, SettingsBundleError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+fn parse_section<T: DeserializeOwned>(
+    name: &'static str,
+    value: &Option<serde_json::Value>,
+    rejected: &mut Vec<(&'static str, String)>,
+) -> Option<T> {
+    let value = value.as_ref()?;
+    match serde_json::from_value(value.clone()) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => {
+            rejected.push((name, e.to_string()));
+            None
+        }
+    }
+}
+
+/// Default location of the bundle, next to the other settings files.
+pub fn default_bundle_path() -> PathBuf {
+    crate::plugin::config_dir().join(SETTINGS_BUNDLE_FILE)
+}
+
+/// Request to write all settings into a bundle file.
+#[derive(Event, Debug, Clone)]
+pub struct ExportSettingsBundle {
+    pub path: PathBuf,
+}
+
+/// Request to load a bundle file and persist every valid section.
+#[derive(Event, Debug, Clone)]
+pub struct ImportSettingsBundle {
+    pub path: PathBuf,
+}
+
+pub fn export_settings_bundle(
+    mut events: EventReader<ExportSettingsBundle>,
+    gameplay: Res<Persistent<GameplaySettings>>,
+    video: Res<Persistent<VideoSettings>>,
+    audio: Res<Persistent<AudioSettings>>,
+    profile: Res<Persistent<ProfileSettings>>,
+) {
+    for ev in events.read() {
+        let result = SettingsBundle::new(&gameplay, &video, &audio, &profile)
+            .and_then(|bundle| bundle.write_to(&ev.path));
+        match result {
+            Ok(()) => info!("Settings exported to {}", ev.path.display()),
+            Err(e) => error!("Error exporting settings to {}: {e}", ev.path.display()),
+        }
+    }
+}
+
+/// Profile to store after an import. An import never clears the cheats flag,
+/// and enabling Dev God Mode through one sets it like the settings menu does.
+fn keep_cheats_flag(
+    current: &ProfileSettings,
+    imported: Option<ProfileSettings>,
+    cheats_enabled: bool,
+) -> Option<ProfileSettings> {
+    let cheats_used = current.cheats_used || cheats_enabled;
+    match imported {
+        Some(mut value) => {
+            value.cheats_used |= cheats_used;
+            Some(value)
+        }
+        None if cheats_used && !current.cheats_used => Some(ProfileSettings {
+            cheats_used: true,
+            ..current.clone()
+        }),
+        None => None,
+    }
+}
+
+pub fn import_settings_bundle(
+    mut events: EventReader<ImportSettingsBundle>,
+    mut gameplay: ResMut<Persistent<GameplaySettings>>,
+    mut video: ResMut<Persistent<VideoSettings>>,
+    mut audio: ResMut<Persistent<AudioSettings>>,
+    mut profile: ResMut<Persistent<ProfileSettings>>,
+    mut ev_gameplay: EventWriter<SettingsChanged<GameplaySettings>>,
+    mut ev_video: EventWriter<SettingsChanged<VideoSettings>>,
+    mut ev_audio: EventWriter<SettingsChanged<AudioSettings>>,
+    mut ev_profile: EventWriter<SettingsChanged<ProfileSettings>>,
+    mut gameplay_retry: ResMut<PersistRetry<GameplaySettings>>,
+    mut video_retry: ResMut<PersistRetry<VideoSettings>>,
+    mut audio_retry: ResMut<PersistRetry<AudioSettings>>,
+    mut profile_retry: ResMut<PersistRetry<ProfileSettings>>,
+) {
+    for ev in events.read() {
+        let bundle = match SettingsBundle::read_from(&ev.path) {
+            Ok(bundle) => bundle,
+            Err(e) => {
+                error!("Error importing settings from {}: {e}", ev.path.display());
+                continue;
+            }
+        };
+        let imported = bundle.parse_sections();
+        for (section, reason) in &imported.rejected {
+            warn!("Skipping invalid '{section}' section of settings bundle: {reason}");
+        }
+        if let Some(value) = imported.gameplay {
+            **gameplay = value;
+            if let Err(e) = persist_with_retry(&mut *gameplay, &mut gameplay_retry) {
+                error!("Error persisting Gameplay Settings: {e:?}");
+            }
+            ev_gameplay.write(SettingsChanged::new(**gameplay));
+        }
+        if let Some(value) = imported.video {
+            **video = value;
+            if let Err(e) = persist_with_retry(&mut *video, &mut video_retry) {
+                error!("Error persisting Video Settings: {e:?}");
+            }
+            ev_video.write(SettingsChanged::new((**video).clone()));
+        }
+        if let Some(value) = imported.audio {
+            **audio = value;
+            if let Err(e) = persist_with_retry(&mut *audio, &mut audio_retry) {
+                error!("Error persisting Audio Settings: {e:?}");
+            }
+            ev_audio.write(SettingsChanged::new(**audio));
+        }
+        let imported_profile = keep_cheats_flag(
+            &profile,
+            imported.profile,
+            gameplay.dev_cheat_mode.is_enabled(),
+        );
+        if let Some(value) = imported_profile {
+            **profile = value;
+            if let Err(e) = persist_with_retry(&mut *profile, &mut profile_retry) {
+                error!("Error persisting Profile Settings: {e:?}");
+            }
+            ev_profile.write(SettingsChanged::new((**profile).clone()));
+        }
+        info!("Settings imported from {}", ev.path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::DevCheatMode;
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let gameplay = GameplaySettings {
+            dev_cheat_mode: DevCheatMode::Enabled,
+            ..default()
+        };
+        let profile = ProfileSettings {
+            display_name: "Investigator".to_string(),
+            ..default()
+        };
+        let bundle = SettingsBundle::new(
+            &gameplay,
+            &VideoSettings::default(),
+            &AudioSettings::default(),
+            &profile,
+        )
+        .unwrap();
+        let json = bundle.to_json().unwrap();
+        let imported = SettingsBundle::from_json(&json).unwrap().parse_sections();
+
+        assert!(imported.rejected.is_empty());
+        assert_eq!(imported.gameplay, Some(gameplay));
+        assert_eq!(imported.video, Some(VideoSettings::default()));
+        assert_eq!(imported.audio, Some(AudioSettings::default()));
+        assert_eq!(imported.profile.unwrap().display_name, "Investigator");
+    }
+
+    #[test]
+    fn test_missing_and_invalid_sections_are_skipped() {
+        let json = r#"{ "version": 1, "audio": { "volume_master": "Loud" } }"#;
+        let imported = SettingsBundle::from_json(json).unwrap().parse_sections();
+
+        assert!(imported.gameplay.is_none());
+        assert!(imported.video.is_none());
+        assert!(imported.profile.is_none());
+        assert!(imported.audio.is_none());
+        assert_eq!(imported.rejected.len(), 1);
+        assert_eq!(imported.rejected[0].0, "audio");
+    }
+
+    #[test]
+    fn test_import_keeps_cheats_flag() {
+        let flagged = ProfileSettings {
+            cheats_used: true,
+            ..default()
+        };
+        let clean = ProfileSettings::default();
+
+        // A bundle from before cheats were used doesn't clear the flag.
+        let kept = keep_cheats_flag(&flagged, Some(clean.clone()), false).unwrap();
+        assert!(kept.cheats_used);
+
+        // Enabling cheats through an import sets it, with or without a profile section.
+        assert!(
+            keep_cheats_flag(&clean, Some(clean.clone()), true)
+                .unwrap()
+                .cheats_used
+        );
+        assert!(keep_cheats_flag(&clean, None, true).unwrap().cheats_used);
+
+        assert!(keep_cheats_flag(&clean, None, false).is_none());
+        assert!(keep_cheats_flag(&flagged, None, true).is_none());
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Sent after a settings resource has been changed and saved.
+///
+/// Systems that cache values derived from settings (UI scale, window mode,
+/// volumes...) can listen for this instead of polling `Persistent<T>` every
+/// frame. It carries a copy of the settings as they were saved.
+#[derive(Event, Debug, Clone)]
+pub struct SettingsChanged<T: Send + Sync + 'static> {
+    pub settings: T,
+}
+
+impl<T: Send + Sync + 'static> SettingsChanged<T> {
+    pub fn new(settings: T) -> Self {
+        Self { settings }
+    }
+}
@@ -1,3 +1,4 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -42,6 +43,142 @@ pub struct ControlKeys {
     pub camera_left: KeyCode,
     /// Key for moving the camera right.
     pub camera_right: KeyCode,
+    /// Key to hold while using the mouse wheel to zoom the camera.
+    #[serde(default = "default_camera_zoom_key")]
+    pub camera_zoom: KeyCode,
+    /// Key for replaying the last walkie-talkie message.
+    #[serde(default = "default_replay_hint_key")]
+    pub replay_hint: KeyCode,
+    /// Key for walking back to the last used object and using it again.
+    #[serde(default = "default_repeat_interaction_key")]
+    pub repeat_interaction: KeyCode,
+    /// Key for entering the truck when standing next to it, and leaving it.
+    #[serde(default = "default_toggle_truck_key")]
+    pub toggle_truck: KeyCode,
+    /// Key for confirming the evidence the held gear is showing right now.
+    #[serde(default = "default_confirm_evidence_key")]
+    pub confirm_evidence: KeyCode,
+    /// Key for showing and hiding the minimap.
+    #[serde(default = "default_minimap_toggle_key")]
+    pub minimap_toggle: KeyCode,
+    /// Key for showing the floor below on the minimap.
+    #[serde(default = "default_minimap_floor_down_key")]
+    pub minimap_floor_down: KeyCode,
+    /// Key for showing the floor above on the minimap.
+    #[serde(default = "default_minimap_floor_up_key")]
+    pub minimap_floor_up: KeyCode,
+    /// Gamepad buttons for the gear controls.
+    #[serde(default)]
+    pub gamepad: GamepadBindings,
+}
+
+/// A control that can be bound to both a key and a gamepad button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlAction {
+    /// Interacting with objects; also hides in and leaves hiding spots.
+    Activate,
+    Grab,
+    Drop,
+    Cycle,
+    Swap,
+    Trigger,
+    Torch,
+}
+
+/// Defines the gamepad controls for a player. Movement uses the left stick.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct GamepadBindings {
+    /// Whether the left stick moves the player.
+    pub movement: bool,
+    /// Button for interacting with objects and hiding.
+    pub activate: GamepadButton,
+    /// Button for grabbing objects.
+    pub grab: GamepadButton,
+    /// Button for dropping objects.
+    pub drop: GamepadButton,
+    /// Button for cycling through inventory items.
+    pub cycle: GamepadButton,
+    /// Button for swapping left and right hand items.
+    pub swap: GamepadButton,
+    /// Button for triggering the right-hand item.
+    pub trigger: GamepadButton,
+    /// Button for triggering the left-hand item.
+    pub torch: GamepadButton,
+}
+
+impl GamepadBindings {
+    pub const DEFAULT: Self = GamepadBindings {
+        movement: true,
+        activate: GamepadButton::South,
+        grab: GamepadButton::RightTrigger,
+        drop: GamepadButton::LeftTrigger,
+        cycle: GamepadButton::North,
+        swap: GamepadButton::West,
+        trigger: GamepadButton::RightTrigger2,
+        torch: GamepadButton::LeftTrigger2,
+    };
+    /// For players without a gamepad; no gamepad has this button.
+    pub const NONE: Self = GamepadBindings {
+        movement: false,
+        activate: GamepadButton::Other(u8::MAX),
+        grab: GamepadButton::Other(u8::MAX),
+        drop: GamepadButton::Other(u8::MAX),
+        cycle: GamepadButton::Other(u8::MAX),
+        swap: GamepadButton::Other(u8::MAX),
+        trigger: GamepadButton::Other(u8::MAX),
+        torch: GamepadButton::Other(u8::MAX),
+    };
+
+    pub fn button(&self, action: ControlAction) -> GamepadButton {
+        match action {
+            ControlAction::Activate => self.activate,
+            ControlAction::Grab => self.grab,
+            ControlAction::Drop => self.drop,
+            ControlAction::Cycle => self.cycle,
+            ControlAction::Swap => self.swap,
+            ControlAction::Trigger => self.trigger,
+            ControlAction::Torch => self.torch,
+        }
+    }
+}
+
+impl Default for GamepadBindings {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+fn default_replay_hint_key() -> KeyCode {
+    KeyCode::KeyV
+}
+
+fn default_repeat_interaction_key() -> KeyCode {
+    KeyCode::KeyX
+}
+
+fn default_toggle_truck_key() -> KeyCode {
+    KeyCode::KeyB
+}
+
+fn default_confirm_evidence_key() -> KeyCode {
+    KeyCode::KeyZ
+}
+
+fn default_camera_zoom_key() -> KeyCode {
+    KeyCode::AltLeft
+}
+
+fn default_minimap_toggle_key() -> KeyCode {
+    KeyCode::KeyM
+}
+
+fn default_minimap_floor_down_key() -> KeyCode {
+    KeyCode::Comma
+}
+
+fn default_minimap_floor_up_key() -> KeyCode {
+    KeyCode::Period
 }
 
 impl Default for ControlKeys {
@@ -66,16 +203,72 @@ impl Default for ControlKeys {
             camera_down: KeyCode::ArrowDown,
             camera_left: KeyCode::ArrowLeft,
             camera_right: KeyCode::ArrowRight,
+            camera_zoom: KeyCode::AltLeft,
+            replay_hint: KeyCode::KeyV,
+            repeat_interaction: KeyCode::KeyX,
+            toggle_truck: KeyCode::KeyB,
+            confirm_evidence: KeyCode::KeyZ,
+            minimap_toggle: KeyCode::KeyM,
+            minimap_floor_down: KeyCode::Comma,
+            minimap_floor_up: KeyCode::Period,
+            gamepad: GamepadBindings::DEFAULT,
         }
     }
 }
 
+/// Reads controls from the keyboard and from every connected gamepad, through
+/// the bindings in a player's `ControlKeys`. Gamepads are looked up every
+/// frame, so one plugged in mid-mission works right away.
+#[derive(SystemParam)]
+pub struct ControlInput<'w, 's> {
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ControlInput<'_, '_> {
+    pub fn pressed(&self, controls: &ControlKeys, action: ControlAction) -> bool {
+        self.keyboard.pressed(controls.key(action))
+            || self
+                .gamepads
+                .iter()
+                .any(|g| g.pressed(controls.gamepad.button(action)))
+    }
+
+    pub fn just_pressed(&self, controls: &ControlKeys, action: ControlAction) -> bool {
+        self.keyboard.just_pressed(controls.key(action))
+            || self
+                .gamepads
+                .iter()
+                .any(|g| g.just_pressed(controls.gamepad.button(action)))
+    }
+
+    pub fn just_released(&self, controls: &ControlKeys, action: ControlAction) -> bool {
+        self.keyboard.just_released(controls.key(action))
+            || self
+                .gamepads
+                .iter()
+                .any(|g| g.just_released(controls.gamepad.button(action)))
+    }
+}
+
 /// System for handling player movement, interaction, and collision.
 ///
 /// This system processes player input, updates the player's position and
 /// direction, handles interactions with interactive objects, and manages
 /// collisions with the environment.
 impl ControlKeys {
+    pub fn key(&self, action: ControlAction) -> KeyCode {
+        match action {
+            ControlAction::Activate => self.activate,
+            ControlAction::Grab => self.grab,
+            ControlAction::Drop => self.drop,
+            ControlAction::Cycle => self.cycle,
+            ControlAction::Swap => self.swap,
+            ControlAction::Trigger => self.trigger,
+            ControlAction::Torch => self.torch,
+        }
+    }
+
     pub const WASD: Self = ControlKeys {
         up: KeyCode::KeyW,
         down: KeyCode::KeyS,
@@ -96,6 +289,15 @@ impl ControlKeys {
         camera_down: KeyCode::ArrowDown,
         camera_left: KeyCode::ArrowLeft,
         camera_right: KeyCode::ArrowRight,
+        camera_zoom: KeyCode::AltLeft,
+        replay_hint: KeyCode::KeyV,
+        repeat_interaction: KeyCode::KeyX,
+        toggle_truck: KeyCode::KeyB,
+        confirm_evidence: KeyCode::KeyZ,
+        minimap_toggle: KeyCode::KeyM,
+        minimap_floor_down: KeyCode::Comma,
+        minimap_floor_up: KeyCode::Period,
+        gamepad: GamepadBindings::DEFAULT,
     };
     pub const ARROWS: Self = ControlKeys {
         up: KeyCode::ArrowUp,
@@ -117,6 +319,15 @@ impl ControlKeys {
         camera_down: KeyCode::ArrowDown,
         camera_left: KeyCode::ArrowLeft,
         camera_right: KeyCode::ArrowRight,
+        camera_zoom: KeyCode::AltLeft,
+        replay_hint: KeyCode::KeyV,
+        repeat_interaction: KeyCode::KeyX,
+        toggle_truck: KeyCode::KeyB,
+        confirm_evidence: KeyCode::KeyZ,
+        minimap_toggle: KeyCode::KeyM,
+        minimap_floor_down: KeyCode::Comma,
+        minimap_floor_up: KeyCode::Period,
+        gamepad: GamepadBindings::DEFAULT,
     };
     pub const IJKL: Self = ControlKeys {
         up: KeyCode::KeyI,
@@ -138,6 +349,15 @@ impl ControlKeys {
         camera_down: KeyCode::ArrowDown,
         camera_left: KeyCode::ArrowLeft,
         camera_right: KeyCode::ArrowRight,
+        camera_zoom: KeyCode::AltRight,
+        replay_hint: KeyCode::NonConvert,
+        repeat_interaction: KeyCode::NonConvert,
+        toggle_truck: KeyCode::NonConvert,
+        confirm_evidence: KeyCode::NonConvert,
+        minimap_toggle: KeyCode::NonConvert,
+        minimap_floor_down: KeyCode::NonConvert,
+        minimap_floor_up: KeyCode::NonConvert,
+        gamepad: GamepadBindings::NONE,
     };
     pub const NONE: Self = ControlKeys {
         up: KeyCode::NonConvert,
@@ -159,5 +379,14 @@ impl ControlKeys {
         camera_down: KeyCode::NonConvert,
         camera_left: KeyCode::NonConvert,
         camera_right: KeyCode::NonConvert,
+        camera_zoom: KeyCode::NonConvert,
+        replay_hint: KeyCode::NonConvert,
+        repeat_interaction: KeyCode::NonConvert,
+        toggle_truck: KeyCode::NonConvert,
+        confirm_evidence: KeyCode::NonConvert,
+        minimap_toggle: KeyCode::NonConvert,
+        minimap_floor_down: KeyCode::NonConvert,
+        minimap_floor_up: KeyCode::NonConvert,
+        gamepad: GamepadBindings::NONE,
     };
 }
@@ -0,0 +1,107 @@
+//! Silences the game while its window is in the background, for players who
+//! enable "Mute When Unfocused" in the audio settings.
+//!
+//! Sinks are muted through `AudioSinkPlayback::mute`, which remembers their
+//! volume instead of overwriting it. The stored levels are never touched, and
+//! systems that keep adjusting sink volumes (ambient, walkie, menu music) still
+//! do so while muted, so on refocus every channel comes back at its current
+//! level; a channel set to 0% stays silent.
+
+use crate::audio::AudioSettings;
+use bevy::audio::{AudioSinkPlayback, SpatialAudioSink};
+use bevy::prelude::*;
+use bevy::window::WindowFocused;
+use bevy_persistent::Persistent;
+
+/// Whether the game window currently has focus.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WindowFocus {
+    pub focused: bool,
+}
+
+impl Default for WindowFocus {
+    fn default() -> Self {
+        Self { focused: true }
+    }
+}
+
+/// True when the settings ask for silence and the window is in the background.
+pub fn should_mute(audio_settings: &AudioSettings, focus: WindowFocus) -> bool {
+    audio_settings.unfocused_mute.on() && !focus.focused
+}
+
+pub fn track_window_focus(
+    mut ev_focused: EventReader<WindowFocused>,
+    mut focus: ResMut<WindowFocus>,
+) {
+    for ev in ev_focused.read() {
+        focus.focused = ev.focused;
+    }
+}
+
+/// Mutes or unmutes every playing sink to match the focus state.
+///
+/// Runs every frame so sounds started while unfocused are muted too.
+pub fn apply_unfocused_mute(
+    audio_settings: Res<Persistent<AudioSettings>>,
+    focus: Res<WindowFocus>,
+    mut q_sinks: Query<&mut AudioSink>,
+    mut q_spatial_sinks: Query<&mut SpatialAudioSink>,
+) {
+    let mute = should_mute(&audio_settings, *focus);
+    for sink in q_sinks.iter_mut() {
+        sync_mute(sink, mute);
+    }
+    for sink in q_spatial_sinks.iter_mut() {
+        sync_mute(sink, mute);
+    }
+}
+
+fn sync_mute<S: AudioSinkPlayback>(mut sink: Mut<S>, mute: bool) {
+    if sink.is_muted() == mute {
+        return;
+    }
+    if mute {
+        sink.mute();
+    } else {
+        sink.unmute();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::UnfocusedMute;
+
+    #[test]
+    fn test_mutes_only_when_enabled_and_unfocused() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<WindowFocused>()
+            .init_resource::<WindowFocus>()
+            .add_systems(Update, track_window_focus);
+
+        app.world_mut().send_event(WindowFocused {
+            window: Entity::PLACEHOLDER,
+            focused: false,
+        });
+        app.update();
+        let focus = *app.world().resource::<WindowFocus>();
+        assert!(!focus.focused);
+
+        let mut settings = AudioSettings::default();
+        assert!(!should_mute(&settings, focus));
+        settings.unfocused_mute = UnfocusedMute::Mute;
+        assert!(should_mute(&settings, focus));
+
+        app.world_mut().send_event(WindowFocused {
+            window: Entity::PLACEHOLDER,
+            focused: true,
+        });
+        app.update();
+        assert!(!should_mute(
+            &settings,
+            *app.world().resource::<WindowFocus>()
+        ));
+    }
+}
@@ -10,6 +10,36 @@ pub struct GameplaySettings {
     pub camera_controls: CameraControls,
     pub character_controls: CharacterControls,
     pub dev_cheat_mode: DevCheatMode,
+    #[serde(default)]
+    pub hint_frequency: HintFrequency,
+    #[serde(default)]
+    pub camera_zoom_in: CameraZoomIn,
+    #[serde(default)]
+    pub camera_zoom_out: CameraZoomOut,
+    #[serde(default)]
+    pub camera_follow: CameraFollow,
+    #[serde(default)]
+    pub pathfinding_moves: PathfindingMoves,
+    #[serde(default)]
+    pub stick_deadzone: StickDeadzone,
+    #[serde(default)]
+    pub stick_response: StickResponse,
+    #[serde(default)]
+    pub low_sanity_guidance: LowSanityGuidance,
+    #[serde(default)]
+    pub autosave_interval: AutosaveInterval,
+}
+
+impl GameplaySettings {
+    /// Smallest and largest scale allowed for the arena camera.
+    ///
+    /// A scale below 1.0 zooms in; above 1.0 zooms out.
+    pub fn camera_zoom_range(&self) -> (f32, f32) {
+        (
+            self.camera_zoom_in.min_scale(),
+            self.camera_zoom_out.max_scale(),
+        )
+    }
 }
 
 #[expect(non_camel_case_types)]
@@ -19,6 +49,15 @@ pub enum GameplaySettingsValue {
     camera_controls(CameraControls),
     character_controls(CharacterControls),
     dev_cheat_mode(DevCheatMode),
+    hint_frequency(HintFrequency),
+    camera_zoom_in(CameraZoomIn),
+    camera_zoom_out(CameraZoomOut),
+    camera_follow(CameraFollow),
+    pathfinding_moves(PathfindingMoves),
+    stick_deadzone(StickDeadzone),
+    stick_response(StickResponse),
+    low_sanity_guidance(LowSanityGuidance),
+    autosave_interval(AutosaveInterval),
 }
 
 #[derive(
@@ -65,6 +104,9 @@ pub enum CameraControls {
     On,
     #[strum(to_string = "Disabled")]
     Off,
+    /// Camera keys plus panning when the cursor touches the screen edges.
+    #[strum(to_string = "Edge Scroll")]
+    EdgeScroll,
 }
 
 impl CameraControls {
@@ -72,8 +114,13 @@ impl CameraControls {
         match self {
             CameraControls::On => true,
             CameraControls::Off => false,
+            CameraControls::EdgeScroll => true,
         }
     }
+
+    pub fn edge_scroll(&self) -> bool {
+        matches!(self, CameraControls::EdgeScroll)
+    }
 }
 
 #[derive(
@@ -125,3 +172,361 @@ impl DevCheatMode {
         matches!(self, DevCheatMode::Enabled)
     }
 }
+
+/// How often the walkie-talkie offers hints.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum HintFrequency {
+    #[strum(to_string = "Off (urgent only)")]
+    Off,
+    #[strum(to_string = "Rare")]
+    Rare,
+    #[default]
+    #[strum(to_string = "Normal")]
+    Normal,
+    #[strum(to_string = "Frequent")]
+    Frequent,
+}
+
+impl HintFrequency {
+    /// Multiplier applied to every walkie event cooldown.
+    ///
+    /// Returns `None` when non-urgent hints are disabled.
+    pub fn cooldown_multiplier(&self) -> Option<f64> {
+        match self {
+            HintFrequency::Off => None,
+            HintFrequency::Rare => Some(2.0),
+            HintFrequency::Normal => Some(1.0),
+            HintFrequency::Frequent => Some(0.5),
+        }
+    }
+}
+
+/// What happens when the walkie warns that sanity is very low and the player
+/// hasn't gone back to the van.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum LowSanityGuidance {
+    /// Only the walkie message.
+    #[default]
+    #[strum(to_string = "Walkie Only")]
+    WalkieOnly,
+    /// The walkie message, and a path to the van is set for the player.
+    #[strum(to_string = "Path to Van")]
+    PathToVan,
+}
+
+/// How often the mission in progress is saved, so it can be resumed after a
+/// crash.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum AutosaveInterval {
+    #[strum(to_string = "Off")]
+    Off,
+    #[strum(to_string = "Every 30 Seconds")]
+    Secs30,
+    #[default]
+    #[strum(to_string = "Every Minute")]
+    Secs60,
+    #[strum(to_string = "Every 2 Minutes")]
+    Secs120,
+}
+
+impl AutosaveInterval {
+    /// Seconds between autosaves, or `None` when autosave is off.
+    pub fn secs(&self) -> Option<f32> {
+        match self {
+            AutosaveInterval::Off => None,
+            AutosaveInterval::Secs30 => Some(30.0),
+            AutosaveInterval::Secs60 => Some(60.0),
+            AutosaveInterval::Secs120 => Some(120.0),
+        }
+    }
+}
+
+/// How the arena camera catches up with the player.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum CameraFollow {
+    /// The original follow, which accelerates toward the player and looks a
+    /// bit ahead of the movement.
+    #[default]
+    #[strum(to_string = "Standard")]
+    Standard,
+    #[strum(to_string = "Instant")]
+    Instant,
+    #[strum(to_string = "Low Lag")]
+    LowLag,
+    #[strum(to_string = "High Lag")]
+    HighLag,
+}
+
+impl CameraFollow {
+    /// Fraction of the way to the player covered per 60 fps frame, or `None`
+    /// for the standard follow.
+    pub fn lerp_factor(&self) -> Option<f32> {
+        match self {
+            CameraFollow::Standard => None,
+            CameraFollow::Instant => Some(1.0),
+            CameraFollow::LowLag => Some(0.25),
+            CameraFollow::HighLag => Some(0.06),
+        }
+    }
+
+    /// Interpolation amount for a step of `dt` 60 fps frames, so the ease
+    /// feels the same at any frame rate.
+    pub fn lerp_alpha(&self, dt: f32) -> Option<f32> {
+        self.lerp_factor()
+            .map(|factor| 1.0 - (1.0 - factor).powf(dt.max(0.0)))
+    }
+}
+
+/// How far the arena camera may zoom in.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum CameraZoomIn {
+    #[strum(to_string = "1.5x")]
+    X150,
+    #[default]
+    #[strum(to_string = "2x")]
+    X200,
+    #[strum(to_string = "3x")]
+    X300,
+}
+
+impl CameraZoomIn {
+    pub fn min_scale(&self) -> f32 {
+        match self {
+            CameraZoomIn::X150 => 1.0 / 1.5,
+            CameraZoomIn::X200 => 1.0 / 2.0,
+            CameraZoomIn::X300 => 1.0 / 3.0,
+        }
+    }
+}
+
+/// How far the arena camera may zoom out, for small screens.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum CameraZoomOut {
+    #[strum(to_string = "1.5x")]
+    X150,
+    #[default]
+    #[strum(to_string = "2x")]
+    X200,
+    #[strum(to_string = "3x")]
+    X300,
+    #[strum(to_string = "4x")]
+    X400,
+}
+
+impl CameraZoomOut {
+    pub fn max_scale(&self) -> f32 {
+        match self {
+            CameraZoomOut::X150 => 1.5,
+            CameraZoomOut::X200 => 2.0,
+            CameraZoomOut::X300 => 3.0,
+            CameraZoomOut::X400 => 4.0,
+        }
+    }
+}
+
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum PathfindingMoves {
+    /// Click-to-move paths only step up, down, left and right.
+    #[default]
+    #[strum(to_string = "4 Directions")]
+    FourWay,
+    /// Click-to-move paths may also step diagonally, but never across the
+    /// corner of a wall.
+    #[strum(to_string = "8 Directions")]
+    EightWay,
+}
+
+impl PathfindingMoves {
+    pub fn diagonal(&self) -> bool {
+        matches!(self, PathfindingMoves::EightWay)
+    }
+}
+
+/// How far the movement stick has to be pushed before the player moves.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum StickDeadzone {
+    #[strum(to_string = "Small (10%)")]
+    Small,
+    #[default]
+    #[strum(to_string = "Medium (20%)")]
+    Medium,
+    /// For worn sticks that drift.
+    #[strum(to_string = "Large (30%)")]
+    Large,
+}
+
+impl StickDeadzone {
+    pub fn radius(&self) -> f32 {
+        match self {
+            StickDeadzone::Small => 0.1,
+            StickDeadzone::Medium => 0.2,
+            StickDeadzone::Large => 0.3,
+        }
+    }
+}
+
+/// How the movement stick deflection past the deadzone maps to speed.
+#[derive(
+    Reflect,
+    Component,
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Sequence,
+    strum::Display,
+    strum::EnumIter,
+)]
+pub enum StickResponse {
+    #[default]
+    #[strum(to_string = "Linear")]
+    Linear,
+    /// Slower for small pushes, for careful steps near hiding spots.
+    #[strum(to_string = "Quadratic")]
+    Quadratic,
+}
+
+impl StickResponse {
+    /// Maps a deflection in `0.0..=1.0` to a speed in `0.0..=1.0`.
+    pub fn apply(&self, deflection: f32) -> f32 {
+        let deflection = deflection.clamp(0.0, 1.0);
+        match self {
+            StickResponse::Linear => deflection,
+            StickResponse::Quadratic => deflection * deflection,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camera_follow_is_frame_rate_independent() {
+        assert_eq!(CameraFollow::Standard.lerp_alpha(1.0), None);
+        assert_eq!(CameraFollow::Instant.lerp_alpha(0.5), Some(1.0));
+
+        let follow = CameraFollow::HighLag;
+        let one_step = follow.lerp_alpha(2.0).unwrap();
+        let half = follow.lerp_alpha(1.0).unwrap();
+        let two_steps = 1.0 - (1.0 - half) * (1.0 - half);
+        assert!((one_step - two_steps).abs() < 1e-6);
+        assert!(follow.lerp_alpha(1.0) < CameraFollow::LowLag.lerp_alpha(1.0));
+    }
+}
@@ -1,6 +1,10 @@
 pub mod audio;
+pub mod bundle;
+pub mod changed;
 pub mod controls;
+pub mod focus_mute;
 pub mod game;
+pub mod persist;
 pub mod plugin;
 pub mod profile;
 pub mod video;
@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use bevy_persistent::prelude::{PersistenceError, Persistent};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// Attempts used by the save systems before giving up on a write.
+pub const PERSIST_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; it doubles after every failed attempt.
+pub const PERSIST_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Backoff state of a write that is retried on later frames.
+#[derive(Debug, Clone)]
+pub struct RetryState {
+    attempts: u32,
+    delay: Duration,
+    /// Timer until the next attempt and the attempts made so far.
+    pending: Option<(Timer, u32)>,
+}
+
+impl RetryState {
+    pub fn new(attempts: u32, delay: Duration) -> Self {
+        Self {
+            attempts,
+            delay,
+            pending: None,
+        }
+    }
+
+    /// Whether a failed write is waiting for its next attempt.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Forgets any pending retry; the next recorded attempt is the first one.
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+
+    /// Records the outcome of an attempt. A failure schedules the next attempt
+    /// with exponential backoff, and the error is only returned once no
+    /// attempts are left.
+    pub fn record<E>(&mut self, result: Result<(), E>) -> Result<(), E> {
+        let made = self.pending.take().map_or(0, |(_, made)| made) + 1;
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) if made >= self.attempts => Err(e),
+            Err(_) => {
+                let delay = self.delay * 2u32.saturating_pow(made - 1);
+                self.pending = Some((Timer::new(delay, TimerMode::Once), made));
+                Ok(())
+            }
+        }
+    }
+
+    /// Advances the backoff timer; returns true when the next attempt is due.
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        self.pending
+            .as_mut()
+            .is_some_and(|(timer, _)| timer.tick(delta).finished())
+    }
+}
+
+/// Retry state for writes of `Persistent<T>`, so a failed write is attempted
+/// again on a later frame instead of blocking the current one.
+#[derive(Resource, Debug, Clone)]
+pub struct PersistRetry<T> {
+    pub state: RetryState,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> PersistRetry<T> {
+    pub fn new(attempts: u32, delay: Duration) -> Self {
+        Self {
+            state: RetryState::new(attempts, delay),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for PersistRetry<T> {
+    fn default() -> Self {
+        Self::new(PERSIST_ATTEMPTS, PERSIST_RETRY_DELAY)
+    }
+}
+
+/// Persists the resource, scheduling a retry through `retry` on failure.
+///
+/// Transient filesystem errors (e.g. a file briefly locked by an antivirus on
+/// Windows) usually clear up after a short wait. The error is only returned
+/// once no attempts are left; a scheduled retry that fails for the last time
+/// is logged by `retry_failed_persist`.
+pub fn persist_with_retry<T: Resource + Serialize + DeserializeOwned>(
+    persistent: &mut Persistent<T>,
+    retry: &mut PersistRetry<T>,
+) -> Result<(), PersistenceError> {
+    retry.state.reset();
+    retry.state.record(persistent.persist())
+}
+
+/// Attempts a failed write of `Persistent<T>` again once its delay is over.
+pub fn retry_failed_persist<T: Resource + Serialize + DeserializeOwned>(
+    time: Res<Time>,
+    persistent: Option<Res<Persistent<T>>>,
+    mut retry: ResMut<PersistRetry<T>>,
+) {
+    let Some(persistent) = persistent else {
+        return;
+    };
+    if !retry.state.tick(time.delta()) {
+        return;
+    }
+    if let Err(e) = retry.state.record(persistent.persist()) {
+        error!("Error persisting {}: {e:?}", std::any::type_name::<T>());
+    }
+}
+
+/// Registers the retry state and system for writes of `Persistent<T>`.
+pub fn app_setup_retry<T: Resource + Serialize + DeserializeOwned>(app: &mut App) {
+    app.init_resource::<PersistRetry<T>>()
+        .add_systems(Update, retry_failed_persist::<T>);
+}
+
+/// Temporary storage for `Persistent<T>` resources in tests.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing {
+    use bevy::prelude::*;
+    use bevy_persistent::prelude::{Persistent, StorageFormat};
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A fresh directory under the system temp dir, removed on drop.
+    #[derive(Debug)]
+    pub struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> Self {
+            static NEXT: AtomicUsize = AtomicUsize::new(0);
+            let dir = std::env::temp_dir().join(format!(
+                "unhaunter-{name}-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Opens the `Persistent<T>` stored at `path`, writing `default` if the
+    /// file does not exist yet.
+    pub fn open_persistent<T: Resource + Serialize + DeserializeOwned>(
+        path: &Path,
+        default: T,
+    ) -> Persistent<T> {
+        Persistent::<T>::builder()
+            .name(path.file_stem().unwrap().to_string_lossy())
+            .format(StorageFormat::RonPrettyWithStructNames)
+            .path(path)
+            .default(default)
+            .build()
+            .unwrap()
+    }
+
+    /// Creates a `Persistent<T>` saved as `<name>.ron` in a new temporary
+    /// directory. Keep the directory alive for as long as the resource is
+    /// used.
+    pub fn temp_persistent<T: Resource + Serialize + DeserializeOwned>(
+        name: &str,
+        default: T,
+    ) -> (Persistent<T>, TempDir) {
+        let dir = TempDir::new(name);
+        let persistent = open_persistent(&dir.path().join(format!("{name}.ron")), default);
+        (persistent, dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Resource, Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct Counter(u32);
+
+    #[test]
+    fn test_failing_once_then_succeeding_saves_value() {
+        let (mut counter, dir) = testing::temp_persistent("counter", Counter::default());
+        let path = dir.path().join("counter.ron");
+        let mut retry = PersistRetry::<Counter>::default();
+        // A directory where the file should be makes the first write fail.
+        std::fs::remove_file(&path).unwrap();
+        std::fs::create_dir_all(&path).unwrap();
+
+        counter.0 = 42;
+        assert!(persist_with_retry(&mut counter, &mut retry).is_ok());
+        assert!(retry.state.is_pending());
+
+        std::fs::remove_dir(&path).unwrap();
+        assert!(retry.state.tick(PERSIST_RETRY_DELAY));
+        assert!(retry.state.record(counter.persist()).is_ok());
+        assert!(!retry.state.is_pending());
+
+        let saved = testing::open_persistent(&path, Counter::default());
+        assert_eq!(*saved, Counter(42));
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_gives_up_after_last_attempt() {
+        let mut state = RetryState::new(3, Duration::from_millis(50));
+
+        assert!(state.record(Err(1)).is_ok());
+        assert!(state.tick(Duration::from_millis(50)));
+        assert!(state.record(Err(2)).is_ok());
+        assert!(!state.tick(Duration::from_millis(50)));
+        assert!(state.tick(Duration::from_millis(50)));
+
+        assert_eq!(state.record(Err(3)), Err(3));
+        assert!(!state.is_pending());
+    }
+}
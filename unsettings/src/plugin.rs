@@ -1,6 +1,7 @@
+use crate::changed::SettingsChanged;
 use bevy::prelude::*;
 use bevy_persistent::prelude::*;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub struct UnhaunterSettingsPlugin;
 
@@ -20,18 +21,62 @@ impl Plugin for UnhaunterSettingsPlugin {
         ))
         .insert_resource(create_persistent::<crate::controls::ControlKeys>(
             "control_settings.ron",
-        ));
+        ))
+        .add_event::<SettingsChanged<crate::game::GameplaySettings>>()
+        .add_event::<SettingsChanged<crate::video::VideoSettings>>()
+        .add_event::<SettingsChanged<crate::audio::AudioSettings>>()
+        .add_event::<SettingsChanged<crate::profile::ProfileSettings>>()
+        .add_event::<SettingsChanged<crate::controls::ControlKeys>>()
+        .add_event::<crate::bundle::ExportSettingsBundle>()
+        .add_event::<crate::bundle::ImportSettingsBundle>()
+        .init_resource::<crate::focus_mute::WindowFocus>()
+        .add_systems(
+            Startup,
+            crate::video::video_system::apply_window_settings_on_startup,
+        )
+        .add_systems(
+            Update,
+            (
+                crate::bundle::export_settings_bundle,
+                crate::bundle::import_settings_bundle,
+                crate::video::video_system::apply_ui_scale_on_change,
+                crate::video::video_system::apply_window_settings_on_change,
+                (
+                    crate::video::fps_overlay::toggle_fps_overlay,
+                    crate::video::fps_overlay::update_fps_overlay,
+                )
+                    .chain(),
+            ),
+        )
+        // After PostUpdate, so sinks created this frame are caught as well.
+        .add_systems(
+            Last,
+            (
+                crate::focus_mute::track_window_focus,
+                crate::focus_mute::apply_unfocused_mute,
+            )
+                .chain(),
+        );
+        crate::persist::app_setup_retry::<crate::game::GameplaySettings>(app);
+        crate::persist::app_setup_retry::<crate::video::VideoSettings>(app);
+        crate::persist::app_setup_retry::<crate::audio::AudioSettings>(app);
+        crate::persist::app_setup_retry::<crate::profile::ProfileSettings>(app);
     }
 }
 
+/// Directory where the settings files are stored.
+pub fn config_dir() -> PathBuf {
+    dirs::config_dir()
+        .map(|native_config_dir| native_config_dir.join("unhaunter-game").join("config"))
+        .unwrap_or(Path::new("local").join("config"))
+}
+
 fn create_persistent<
     T: serde::Serialize + serde::de::DeserializeOwned + Resource + Default + Send + Sync + 'static,
 >(
     file_path: &str,
 ) -> Persistent<T> {
-    let config_dir = dirs::config_dir()
-        .map(|native_config_dir| native_config_dir.join("unhaunter-game").join("config"))
-        .unwrap_or(Path::new("local").join("config"));
+    let config_dir = config_dir();
 
     Persistent::<T>::builder()
         .name(file_path.trim_end_matches(".ron"))
@@ -5,6 +5,10 @@ use serde::{Deserialize, Serialize};
 pub struct ProfileSettings {
     pub display_name: String,
     pub color: Profilecolour,
+    /// Set once Dev God Mode has been enabled on this profile. It is never
+    /// cleared, so scores from a profile that used cheats can be told apart.
+    #[serde(default)]
+    pub cheats_used: bool,
 }
 
 #[derive(
@@ -0,0 +1,61 @@
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+
+/// Marker for the FPS / frame-time overlay text
+#[derive(Component)]
+pub struct FpsOverlayText;
+
+/// Shows or hides the overlay according to the `show_fps` video setting.
+///
+/// The overlay sits in the top-right corner, away from the in-game HUD at the
+/// bottom of the screen. Sizes are in logical pixels so `UiScale` applies.
+pub fn toggle_fps_overlay(
+    mut commands: Commands,
+    video_settings: Res<Persistent<crate::video::VideoSettings>>,
+    qo: Query<Entity, With<FpsOverlayText>>,
+) {
+    let enabled = video_settings.show_fps.on();
+    let spawned = !qo.is_empty();
+    if enabled && !spawned {
+        commands
+            .spawn(Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(4.0),
+                right: Val::Px(6.0),
+                ..default()
+            })
+            .insert(Text::new(""))
+            .insert(TextFont {
+                font_size: 12.0,
+                ..default()
+            })
+            .insert(TextColor(Color::srgba(0.9, 0.9, 0.9, 0.8)))
+            .insert(GlobalZIndex(i32::MAX))
+            .insert(FpsOverlayText);
+    } else if !enabled && spawned {
+        for e in qo.iter() {
+            commands.entity(e).despawn();
+        }
+    }
+}
+
+/// Refreshes the overlay text from Bevy's frame-time diagnostics
+pub fn update_fps_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    mut qo: Query<&mut Text, With<FpsOverlayText>>,
+) {
+    let Ok(mut text) = qo.single_mut() else {
+        return;
+    };
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|d| d.smoothed());
+    let frame_time = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|d| d.smoothed());
+    text.0 = match (fps, frame_time) {
+        (Some(fps), Some(ms)) => format!("{fps:.0} FPS  {ms:.1} ms"),
+        _ => "-- FPS".to_string(),
+    };
+}
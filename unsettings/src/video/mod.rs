@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
 pub mod display;
+pub mod fps_overlay;
 pub mod video_system;
 
 #[derive(Component, Resource, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -11,6 +12,14 @@ pub struct VideoSettings {
     pub ui_zoom: ZoomLevel,
     pub refresh_rate: RefreshRate,
     pub vsync: VSyncMode,
+    #[serde(default)]
+    pub show_fps: FpsOverlay,
+    #[serde(default)]
+    pub brightness: Brightness,
+    #[serde(default)]
+    pub colourblind_mode: ColourblindMode,
+    #[serde(default)]
+    pub ui_theme: UiThemeKind,
 }
 
 impl Default for VideoSettings {
@@ -21,6 +30,10 @@ impl Default for VideoSettings {
             ui_zoom: ZoomLevel::Zoom100,
             refresh_rate: RefreshRate::Auto,
             vsync: VSyncMode::Auto,
+            show_fps: FpsOverlay::Off,
+            brightness: Brightness::Normal,
+            colourblind_mode: ColourblindMode::None,
+            ui_theme: UiThemeKind::Standard,
         }
     }
 }
@@ -33,6 +46,51 @@ pub enum VideoSettingsValue {
     ui_zoom(ZoomLevel),
     refresh_rate(RefreshRate),
     vsync(VSyncMode),
+    show_fps(FpsOverlay),
+    brightness(Brightness),
+    colourblind_mode(ColourblindMode),
+    ui_theme(UiThemeKind),
+}
+
+impl VideoSettingsValue {
+    /// Whether a misconfigured value could leave the game unusable, so the
+    /// change must be confirmed before it is kept.
+    pub fn needs_confirmation(&self) -> bool {
+        match self {
+            VideoSettingsValue::resolution(_)
+            | VideoSettingsValue::aspect_ratio(_)
+            | VideoSettingsValue::refresh_rate(_) => true,
+            VideoSettingsValue::ui_zoom(_)
+            | VideoSettingsValue::vsync(_)
+            | VideoSettingsValue::show_fps(_)
+            | VideoSettingsValue::brightness(_)
+            | VideoSettingsValue::colourblind_mode(_)
+            | VideoSettingsValue::ui_theme(_) => false,
+        }
+    }
+}
+
+impl VideoSettings {
+    /// Returns the value these settings hold for the same field as `value`.
+    pub fn value_for(&self, value: &VideoSettingsValue) -> VideoSettingsValue {
+        match value {
+            VideoSettingsValue::resolution(_) => VideoSettingsValue::resolution(self.resolution),
+            VideoSettingsValue::aspect_ratio(_) => {
+                VideoSettingsValue::aspect_ratio(self.aspect_ratio)
+            }
+            VideoSettingsValue::ui_zoom(_) => VideoSettingsValue::ui_zoom(self.ui_zoom),
+            VideoSettingsValue::refresh_rate(_) => {
+                VideoSettingsValue::refresh_rate(self.refresh_rate)
+            }
+            VideoSettingsValue::vsync(_) => VideoSettingsValue::vsync(self.vsync),
+            VideoSettingsValue::show_fps(_) => VideoSettingsValue::show_fps(self.show_fps),
+            VideoSettingsValue::brightness(_) => VideoSettingsValue::brightness(self.brightness),
+            VideoSettingsValue::colourblind_mode(_) => {
+                VideoSettingsValue::colourblind_mode(self.colourblind_mode)
+            }
+            VideoSettingsValue::ui_theme(_) => VideoSettingsValue::ui_theme(self.ui_theme),
+        }
+    }
 }
 
 // Re-export the AspectRatio from display module
@@ -216,3 +274,140 @@ impl VSyncMode {
         }
     }
 }
+
+/// Whether the FPS / frame-time overlay is drawn
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+    Hash,
+)]
+pub enum FpsOverlay {
+    #[default]
+    #[strum(to_string = "Off")]
+    Off,
+    #[strum(to_string = "On")]
+    On,
+}
+
+impl FpsOverlay {
+    pub fn on(&self) -> bool {
+        matches!(self, FpsOverlay::On)
+    }
+}
+
+/// Brightness boost applied on top of the lighting exposure, for dim panels.
+///
+/// The range is deliberately narrow: it lifts near-black scenes without
+/// turning darkness into a non-issue.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+    Hash,
+)]
+pub enum Brightness {
+    #[strum(to_string = "90%")]
+    Dimmer,
+    #[default]
+    #[strum(to_string = "100%")]
+    Normal,
+    #[strum(to_string = "110%")]
+    Plus10,
+    #[strum(to_string = "120%")]
+    Plus20,
+    #[strum(to_string = "135%")]
+    Plus35,
+    #[strum(to_string = "150%")]
+    Plus50,
+}
+
+impl Brightness {
+    /// Lowest gain ever applied to the scene
+    pub const MIN_GAIN: f32 = 0.9;
+    /// Highest gain ever applied to the scene
+    pub const MAX_GAIN: f32 = 1.5;
+
+    /// Multiplier applied to scene brightness, clamped to `MIN_GAIN..=MAX_GAIN`.
+    pub fn gain(&self) -> f32 {
+        let gain = match self {
+            Brightness::Dimmer => 0.9,
+            Brightness::Normal => 1.0,
+            Brightness::Plus10 => 1.1,
+            Brightness::Plus20 => 1.2,
+            Brightness::Plus35 => 1.35,
+            Brightness::Plus50 => 1.5,
+        };
+        gain.clamp(Self::MIN_GAIN, Self::MAX_GAIN)
+    }
+}
+
+/// Colour vision deficiency the UI palette should compensate for
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+    Hash,
+)]
+pub enum ColourblindMode {
+    #[default]
+    #[strum(to_string = "None")]
+    None,
+    #[strum(to_string = "Protanopia")]
+    Protanopia,
+    #[strum(to_string = "Deuteranopia")]
+    Deuteranopia,
+    #[strum(to_string = "Tritanopia")]
+    Tritanopia,
+}
+
+/// Look of the menus: fonts and colours
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Reflect,
+    Component,
+    strum::EnumIter,
+    strum::Display,
+    Hash,
+)]
+pub enum UiThemeKind {
+    #[default]
+    #[strum(to_string = "Standard")]
+    Standard,
+    #[strum(to_string = "High Contrast")]
+    HighContrast,
+}
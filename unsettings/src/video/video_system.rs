@@ -1,125 +1,100 @@
 use bevy::prelude::*;
-use bevy::window::WindowResolution;
+use bevy::window::PrimaryWindow;
 use bevy_persistent::Persistent;
 
-/// Resource to track if video settings have changed and need to be applied
-#[derive(Resource, Default)]
-pub struct VideoSettingsChanged(pub bool);
+use crate::changed::SettingsChanged;
 
-/// System to apply video settings to the window and UI
-pub fn apply_video_settings(
-    mut windows: Query<&mut Window>,
-    video_settings: Res<Persistent<crate::video::VideoSettings>>,
-    mut settings_changed: ResMut<VideoSettingsChanged>,
-    mut last_settings: Local<Option<crate::video::VideoSettings>>,
+/// Re-applies the UI scale whenever the video settings are saved
+pub fn apply_ui_scale_on_change(
+    mut events: EventReader<SettingsChanged<crate::video::VideoSettings>>,
+    mut ui_scale: ResMut<UiScale>,
 ) {
-    // Only apply settings if they have changed
-    if !settings_changed.0 {
+    let Some(ev) = events.read().last() else {
         return;
-    }
-
-    let mut window = match windows.single_mut() {
-        Ok(window) => window,
-        Err(_) => return,
     };
-
-    // Check if settings actually changed
-    let current_settings = (**video_settings).clone();
-    if let Some(last) = last_settings.as_ref()
-        && *last == current_settings
-    {
-        settings_changed.0 = false;
-        return;
+    let zoom_factor = ev.settings.ui_zoom.as_f32();
+    if ui_scale.0 != zoom_factor {
+        ui_scale.0 = zoom_factor;
+        info!("Applied UI scale: {}%", (zoom_factor * 100.0) as u32);
     }
+}
 
-    // Apply resolution changes
-    let target_resolution = WindowResolution::new(
-        video_settings.resolution.width as f32,
-        video_settings.resolution.height as f32,
+/// Sets the window resolution and VSync mode from the video settings
+fn apply_window_settings(window: &mut Window, settings: &crate::video::VideoSettings) {
+    let (width, height) = (
+        settings.resolution.width as f32,
+        settings.resolution.height as f32,
     );
-
-    if window.resolution != target_resolution {
-        window.resolution = target_resolution;
+    if window.resolution.width() != width || window.resolution.height() != height {
+        window.resolution.set(width, height);
         info!(
             "Applied resolution: {}x{}",
-            video_settings.resolution.width, video_settings.resolution.height
+            settings.resolution.width, settings.resolution.height
         );
     }
-
-    // Apply VSync changes
-    if let Some(present_mode) = video_settings.vsync.to_present_mode() {
+    if let Some(present_mode) = settings.vsync.to_present_mode()
+        && window.present_mode != present_mode
+    {
         window.present_mode = present_mode;
         info!("Applied VSync mode: {:?}", present_mode);
     }
-
-    // Apply UI zoom scaling
-    let zoom_factor = video_settings.ui_zoom.as_f32();
-    info!("Applied UI zoom: {}%", (zoom_factor * 100.0) as u32);
-
-    // Note: UI scaling should be handled by the UI system, not here
-    // This is just for logging purposes
-
-    // Store current settings and mark as applied
-    *last_settings = Some(current_settings);
-    settings_changed.0 = false;
 }
 
-/// System to handle aspect ratio changes
-pub fn apply_aspect_ratio_settings(
+/// Applies the saved window settings to the primary window at startup
+pub fn apply_window_settings_on_startup(
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
     video_settings: Res<Persistent<crate::video::VideoSettings>>,
-    settings_changed: ResMut<VideoSettingsChanged>,
 ) {
-    // Only run if settings have changed
-    if !settings_changed.0 {
-        return;
-    }
-
-    if let Some(target_ratio) = video_settings.aspect_ratio.ratio() {
-        let current_ratio = video_settings.resolution.aspect_ratio();
-
-        if (current_ratio - target_ratio).abs() > 0.01 {
-            // In a real implementation, this would adjust the viewport or letterboxing
-            info!(
-                "Aspect ratio setting: {} (current: {:.2})",
-                video_settings.aspect_ratio, current_ratio
-            );
-        }
+    if let Ok(mut window) = windows.single_mut() {
+        apply_window_settings(&mut window, &video_settings);
     }
 }
 
-/// System to detect when video settings change and trigger reapplication
-pub fn detect_video_settings_changes(
-    video_settings: Res<Persistent<crate::video::VideoSettings>>,
-    mut settings_changed: ResMut<VideoSettingsChanged>,
-    mut last_settings: Local<Option<crate::video::VideoSettings>>,
+/// Re-applies the window settings whenever the video settings are saved.
+///
+/// A display change is saved before the "keep these settings?" prompt, so the
+/// player sees it while confirming; a revert saves and applies the previous
+/// settings again.
+pub fn apply_window_settings_on_change(
+    mut events: EventReader<SettingsChanged<crate::video::VideoSettings>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
 ) {
-    let current_settings = (**video_settings).clone();
-
-    if let Some(last) = last_settings.as_ref() {
-        if *last != current_settings {
-            info!("Video settings changed, marking for reapplication");
-            settings_changed.0 = true;
-        }
-    } else {
-        // First run, mark for initial application
-        settings_changed.0 = true;
+    let Some(ev) = events.read().last() else {
+        return;
+    };
+    if let Ok(mut window) = windows.single_mut() {
+        apply_window_settings(&mut window, &ev.settings);
     }
-
-    *last_settings = Some(current_settings);
 }
 
-/// System to apply UI scaling based on video settings
-pub fn apply_ui_scaling(
-    video_settings: Res<Persistent<crate::video::VideoSettings>>,
-    settings_changed: ResMut<VideoSettingsChanged>,
-    mut ui_scale: ResMut<UiScale>,
-) {
-    // Only apply if settings have changed
-    if !settings_changed.0 {
-        return;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video::{VSyncMode, VideoSettings, display::Resolution};
+    use bevy::window::PresentMode;
+
+    #[test]
+    fn test_saved_video_settings_are_applied_to_window() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<SettingsChanged<VideoSettings>>()
+            .add_systems(Update, apply_window_settings_on_change);
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+
+        let settings = VideoSettings {
+            resolution: Resolution::new(1280, 720),
+            vsync: VSyncMode::Off,
+            ..default()
+        };
+        app.world_mut().send_event(SettingsChanged::new(settings));
+        app.update();
+
+        let window = app.world().get::<Window>(window).unwrap();
+        assert_eq!(window.resolution.width(), 1280.0);
+        assert_eq!(window.resolution.height(), 720.0);
+        assert_eq!(window.present_mode, PresentMode::Immediate);
     }
-
-    let zoom_factor = video_settings.ui_zoom.as_f32();
-    ui_scale.0 = zoom_factor;
-    info!("Applied UI scale: {}%", (zoom_factor * 100.0) as u32);
 }
@@ -68,6 +68,7 @@ pub fn rebuild_collision_data(
         [bf.map_size.0, bf.map_size.1, bf.map_size.2]
     );
     bf.collision_field.fill(CollisionFieldData::default());
+    bf.collision_revision = bf.collision_revision.wrapping_add(1);
 
     for (_entity, pos, behaviour) in qt.iter().filter(|(_e, _p, b)| b.p.movement.walkable) {
         let bpos = pos.to_board_position();
@@ -9,10 +9,11 @@ use uncore::resources::maps::Maps;
 use uncore::resources::summary_data::SummaryData;
 use uncore::states::AppState;
 use uncore::states::GameState;
-use uncore::types::grade::Grade;
+use uncore::types::grade::{Grade, GradeThresholds};
 use uncore::types::root::game_assets::GameAssets;
 use uncore::utils::time::format_time;
 use unprofile::data::PlayerProfileData;
+use unsettings::profile::ProfileSettings;
 
 pub fn setup(mut commands: Commands) {
     // ui camera
@@ -45,7 +46,7 @@ pub fn update_time(
     qp: Query<&PlayerSprite>,
     difficulty: Res<CurrentDifficulty>,
 ) {
-    if *game_state == GameState::Pause {
+    if game_state.is_paused() {
         return;
     }
     sd.difficulty = difficulty.clone();
@@ -595,16 +596,15 @@ pub fn calculate_rewards_and_grades(
         if let Some(map) = maps.maps.iter().find(|map| map.path == sd.map_path) {
             // Use mission_data from the map instead of TmxMap properties directly
             let mission_data = &map.mission_data;
-            let base_score = sd.base_score;
 
             // Determine grade for successful mission using mission data
-            sd.grade_achieved = Grade::from_score(
-                base_score,
-                mission_data.grade_a_score_threshold,
-                mission_data.grade_b_score_threshold,
-                mission_data.grade_c_score_threshold,
-                mission_data.grade_d_score_threshold,
-            );
+            sd.grade_thresholds = GradeThresholds {
+                a: mission_data.grade_a_score_threshold,
+                b: mission_data.grade_b_score_threshold,
+                c: mission_data.grade_c_score_threshold,
+                d: mission_data.grade_d_score_threshold,
+            };
+            sd.grade_achieved = sd.grade();
 
             // Set base reward only if mission was successful and mission data found
             sd.mission_reward_base = mission_data.mission_reward_base;
@@ -649,6 +649,7 @@ pub fn calculate_rewards_and_grades(
 pub fn finalise_profile_update(
     sd: Res<SummaryData>,
     mut player_profile: ResMut<Persistent<PlayerProfileData>>,
+    profile_settings: Res<Persistent<ProfileSettings>>,
     app_state: Res<State<AppState>>,
     maps: Res<Maps>,
 ) {
@@ -692,9 +693,13 @@ pub fn finalise_profile_update(
         map_stats.total_mission_completed_time_seconds += sd.time_taken_secs as f64;
     }
 
-    // Update best score and grade
-    map_stats.best_score = map_stats.best_score.max(sd.full_score);
-    map_stats.best_grade = map_stats.best_grade.max(sd.grade_achieved);
+    // Update best score and grade, unless this profile has used cheats
+    if profile_settings.cheats_used {
+        info!("Cheats were used on this profile: best score and grade are left unchanged");
+    } else {
+        map_stats.best_score = map_stats.best_score.max(sd.full_score);
+        map_stats.best_grade = map_stats.best_grade.max(sd.grade_achieved);
+    }
 
     if sd.mission_successful {
         // Update global statistics
@@ -0,0 +1,174 @@
+//! Periodic autosave of the mission in progress, so a crash doesn't lose it.
+//!
+//! The snapshot is written every few seconds (see the `autosave_interval`
+//! gameplay setting) and on key events: leaving the van, crafting repellent
+//! and the end of a hunt. It's cleared when the mission ends normally, so the
+//! main menu only offers to resume missions that were cut short.
+
+use crate::systems::truck_ui_systems::{RepellentCraftTracker, init_repellent_tracker};
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+use uncore::components::board::position::Position;
+use uncore::components::game_config::GameConfig;
+use uncore::components::player_sprite::PlayerSprite;
+use uncore::difficulty::CurrentDifficulty;
+use uncore::events::hunt::HuntEnded;
+use uncore::events::truck::TruckUIEvent;
+use uncore::resources::board_data::BoardData;
+use uncore::resources::ghost_guess::GhostGuess;
+use uncore::resources::mission_autosave::{MissionAutosave, MissionSnapshot, PendingResume};
+use uncore::resources::summary_data::SummaryData;
+use uncore::states::AppState;
+use uncore::types::gear_kind::PlayerGearKind;
+use ungear::components::playergear::PlayerGear;
+use ungearitems::from_gearkind::FromPlayerGearKind as _;
+use unsettings::game::GameplaySettings;
+use unsettings::persist::{PersistRetry, persist_with_retry};
+
+fn gear_kind(gear: &PlayerGear) -> PlayerGearKind {
+    PlayerGearKind {
+        left_hand: gear.left_hand.kind,
+        right_hand: gear.right_hand.kind,
+        inventory: gear.inventory.iter().map(|g| g.kind).collect(),
+    }
+}
+
+fn write_autosave(
+    autosave: &mut Persistent<MissionAutosave>,
+    retry: &mut PersistRetry<MissionAutosave>,
+    mission: Option<MissionSnapshot>,
+) {
+    if autosave.mission == mission {
+        return;
+    }
+    autosave.mission = mission;
+    if let Err(e) = persist_with_retry(autosave, retry) {
+        error!("Failed to persist the mission autosave: {:?}", e);
+    }
+}
+
+fn autosave_mission(
+    time: Res<Time>,
+    autosave: Option<ResMut<Persistent<MissionAutosave>>>,
+    mut autosave_retry: ResMut<PersistRetry<MissionAutosave>>,
+    settings: Res<Persistent<GameplaySettings>>,
+    bf: Res<BoardData>,
+    difficulty: Res<CurrentDifficulty>,
+    summary: Option<Res<SummaryData>>,
+    ghost_guess: Res<GhostGuess>,
+    craft_tracker: Res<RepellentCraftTracker>,
+    gc: Res<GameConfig>,
+    q_player: Query<(&PlayerSprite, &Position, &PlayerGear)>,
+    mut ev_truck: EventReader<TruckUIEvent>,
+    mut ev_hunt_ended: EventReader<HuntEnded>,
+    mut since_save: Local<f32>,
+) {
+    let truck_event = ev_truck
+        .read()
+        .filter(|ev| matches!(ev, TruckUIEvent::ExitTruck | TruckUIEvent::CraftRepellent))
+        .count()
+        > 0;
+    let hunt_ended = ev_hunt_ended.read().count() > 0;
+    let Some(mut autosave) = autosave else {
+        return;
+    };
+    let Some(interval) = settings.autosave_interval.secs() else {
+        return;
+    };
+    *since_save += time.delta_secs();
+    if *since_save < interval && !truck_event && !hunt_ended {
+        return;
+    }
+    let Some(summary) = summary else {
+        return;
+    };
+    let Some(&ghost_type) = summary.ghost_types.first() else {
+        return;
+    };
+    let Some((_, pos, gear)) = q_player.iter().find(|(p, ..)| p.id == gc.player_id) else {
+        return;
+    };
+    *since_save = 0.0;
+
+    let snapshot = MissionSnapshot {
+        map_filepath: bf.map_path.clone(),
+        difficulty: difficulty.0.difficulty,
+        ghost_type,
+        player_position: [pos.x, pos.y, pos.z],
+        player_gear: gear_kind(gear),
+        ghost_guess: ghost_guess.ghost_type,
+        evidences_found: ghost_guess.evidences_found.iter().copied().collect(),
+        evidences_missing: ghost_guess.evidences_missing.iter().copied().collect(),
+        repellents_crafted: craft_tracker.crafted_count,
+        time_taken_secs: summary.time_taken_secs,
+    };
+    write_autosave(&mut autosave, &mut autosave_retry, Some(snapshot));
+}
+
+/// The mission is over (or was abandoned from the menu), so there's nothing
+/// left to resume.
+fn clear_autosave(
+    autosave: Option<ResMut<Persistent<MissionAutosave>>>,
+    mut autosave_retry: ResMut<PersistRetry<MissionAutosave>>,
+) {
+    if let Some(mut autosave) = autosave {
+        write_autosave(&mut autosave, &mut autosave_retry, None);
+    }
+}
+
+/// Puts back the state saved in the snapshot being resumed, once the level is
+/// loaded and the mission has started.
+fn resume_mission(
+    mut commands: Commands,
+    pending: Option<Res<PendingResume>>,
+    gc: Res<GameConfig>,
+    mut ghost_guess: ResMut<GhostGuess>,
+    mut craft_tracker: ResMut<RepellentCraftTracker>,
+    summary: Option<ResMut<SummaryData>>,
+    mut q_player: Query<(&PlayerSprite, &mut Position, &mut PlayerGear)>,
+) {
+    let Some(pending) = pending else {
+        return;
+    };
+    commands.remove_resource::<PendingResume>();
+    let snapshot = &pending.0;
+    info!("Resuming mission on {:?}", snapshot.map_filepath);
+
+    if let Some((_, mut pos, mut gear)) = q_player.iter_mut().find(|(p, ..)| p.id == gc.player_id) {
+        let [x, y, z] = snapshot.player_position;
+        *pos = Position {
+            x,
+            y,
+            z,
+            global_z: pos.global_z,
+        };
+        *gear = PlayerGear::from_playergearkind(snapshot.player_gear.clone());
+    }
+    ghost_guess.ghost_type = snapshot.ghost_guess;
+    ghost_guess.evidences_found = snapshot.evidences_found.iter().copied().collect();
+    ghost_guess.evidences_missing = snapshot.evidences_missing.iter().copied().collect();
+    craft_tracker.crafted_count = snapshot.repellents_crafted.min(craft_tracker.max_crafts);
+    if let Some(mut summary) = summary {
+        summary.time_taken_secs = snapshot.time_taken_secs;
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    let path = unsettings::plugin::config_dir().join("mission_autosave.ron");
+    match MissionAutosave::open(path) {
+        Ok(autosave) => {
+            app.insert_resource(autosave);
+        }
+        Err(e) => error!(
+            "Could not open the mission autosave, autosave is off: {:?}",
+            e
+        ),
+    }
+    unsettings::persist::app_setup_retry::<MissionAutosave>(app);
+    app.add_systems(Update, autosave_mission.run_if(in_state(AppState::InGame)))
+        .add_systems(
+            OnEnter(AppState::InGame),
+            resume_mission.after(init_repellent_tracker),
+        )
+        .add_systems(OnExit(AppState::InGame), clear_autosave);
+}
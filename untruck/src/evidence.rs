@@ -1,14 +1,23 @@
 use super::uibutton::{TruckButtonState, TruckButtonType, TruckUIButton};
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
+use uncore::colours::UiPalette;
 use uncore::components::game_ui::EvidenceUI;
 use uncore::components::{game_config::GameConfig, player_sprite::PlayerSprite};
+use uncore::events::loadlevel::LevelReadyEvent;
 use uncore::resources::looking_gear::LookingGear;
+use uncore::resources::player_journal::{JournalMark, PlayerJournal};
 use uncore::states::{AppState, GameState};
-use uncore::types::evidence::Evidence;
+use uncore::types::evidence::{Evidence, EvidenceSet};
 use uncore::types::evidence_status::EvidenceStatus;
 use ungear::components::playergear::PlayerGear;
+use ungear::gear_usable::GearUsable;
 use unprofile::data::PlayerProfileData;
+use unwalkiecore::{WalkieEvent, WalkiePlay};
+
+/// Evidence confirmed from gear readings during the current mission.
+#[derive(Resource, Debug, Default)]
+pub struct GearConfirmedEvidence(pub EvidenceSet);
 
 pub fn update_evidence_ui(
     gc: Res<GameConfig>,
@@ -17,6 +26,7 @@ pub fn update_evidence_ui(
     interaction_query: Query<&TruckUIButton, With<Button>>,
     mut writer: TextUiWriter,
     looking_gear: Res<LookingGear>,
+    palette: Res<UiPalette>,
 ) {
     for (ps, playergear) in q_gear.iter() {
         if gc.player_id == ps.id {
@@ -31,7 +41,7 @@ pub fn update_evidence_ui(
                         .unwrap_or(TruckButtonState::Off),
                     None => TruckButtonState::Off,
                 };
-                let status = EvidenceStatus::from_gearkind(o_evidence, ev_state);
+                let status = EvidenceStatus::from_gearkind(o_evidence, ev_state, &palette);
                 if let Some((_entity, _depth, mut text, _font, _color)) = writer.get(txt_entity, 1)
                     && *text != status.title
                 {
@@ -60,7 +70,6 @@ pub fn keyboard_evidence(
     players: Query<(&PlayerSprite, &PlayerGear)>,
     mut interaction_query: Query<&mut TruckUIButton, With<Button>>,
     looking_gear: Res<LookingGear>,
-    mut profile_data: ResMut<Persistent<PlayerProfileData>>,
 ) {
     for (player, playergear) in &players {
         if gc.player_id != player.id {
@@ -73,36 +82,133 @@ pub fn keyboard_evidence(
         if keyboard_input.just_pressed(player.controls.change_evidence) {
             for mut t in &mut interaction_query {
                 if t.class == TruckButtonType::Evidence(evidence) {
-                    // Call pressed() first to change the button state
+                    // Gear acknowledgements are counted by the hint acknowledge
+                    // system, only when the gear is indicating the evidence.
                     t.pressed();
+                }
+            }
+        }
+    }
+}
 
-                    // Track gear acknowledgment if button is now pressed (evidence found)
-                    if t.status == TruckButtonState::Pressed {
-                        const GEAR_HINT_THRESHOLD: u32 = 3; // Same threshold as journal
-                        let ack_count_entry = profile_data
-                            .times_evidence_acknowledged_on_gear
-                            .entry(evidence)
-                            .or_insert(0);
+/// Confirms evidence the gear is showing: it goes into the journal, counts as
+/// a gear acknowledgement and gets the walkie confirmation.
+///
+/// Each evidence is confirmed at most once per mission; returns false if it
+/// already was.
+pub fn confirm_evidence(
+    evidence: Evidence,
+    confirmed: &mut GearConfirmedEvidence,
+    journal: &mut PlayerJournal,
+    profile_data: &mut PlayerProfileData,
+    walkie_play: &mut WalkiePlay,
+    time: f64,
+) -> bool {
+    if confirmed.0.contains(evidence) {
+        return false;
+    }
+    confirmed.0.insert(evidence);
+    journal.set_mark(evidence, JournalMark::Confirmed);
+    profile_data.acknowledge_evidence_on_gear(evidence);
+    if walkie_play.set(WalkieEvent::evidence_confirmed(evidence), time) {
+        walkie_play.set_evidence_hint(evidence, time);
+    }
+    true
+}
 
-                        if *ack_count_entry < GEAR_HINT_THRESHOLD {
-                            *ack_count_entry += 1;
-                            profile_data.set_changed(); // Mark Persistent data as changed
-                            // info!("Gear hint for {:?} acknowledged. New count: {}", evidence, *ack_count_entry);
-                        }
-                    }
-                }
+pub fn keyboard_confirm_evidence(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gc: Res<GameConfig>,
+    players: Query<(&PlayerSprite, &PlayerGear)>,
+    mut interaction_query: Query<&mut TruckUIButton, With<Button>>,
+    looking_gear: Res<LookingGear>,
+    mut confirmed: ResMut<GearConfirmedEvidence>,
+    mut journal: ResMut<PlayerJournal>,
+    mut profile_data: ResMut<Persistent<PlayerProfileData>>,
+    mut walkie_play: ResMut<WalkiePlay>,
+) {
+    for (player, playergear) in &players {
+        if gc.player_id != player.id
+            || !keyboard_input.just_pressed(player.controls.confirm_evidence)
+        {
+            continue;
+        }
+        let Some(evidence) = playergear.get_hand(&looking_gear.hand()).current_evidence() else {
+            continue;
+        };
+        if !confirm_evidence(
+            evidence,
+            &mut confirmed,
+            &mut journal,
+            &mut profile_data,
+            &mut walkie_play,
+            time.elapsed_secs_f64(),
+        ) {
+            continue;
+        }
+        profile_data.set_changed();
+        // Keep the journal buttons, which the journal follows, in agreement.
+        for mut t in &mut interaction_query {
+            if t.class == TruckButtonType::Evidence(evidence) {
+                t.status = TruckButtonState::Pressed;
             }
         }
     }
 }
 
+fn reset_confirmed_evidence(
+    mut ev_level_ready: EventReader<LevelReadyEvent>,
+    mut confirmed: ResMut<GearConfirmedEvidence>,
+) {
+    for _ in ev_level_ready.read() {
+        confirmed.0 = EvidenceSet::default();
+    }
+}
+
 pub fn app_setup(app: &mut App) {
-    app.add_systems(
-        FixedUpdate,
-        update_evidence_ui.run_if(in_state(GameState::None).and(in_state(AppState::InGame))),
-    )
-    .add_systems(
-        Update,
-        keyboard_evidence.run_if(in_state(GameState::None).and(in_state(AppState::InGame))),
-    );
+    app.init_resource::<GearConfirmedEvidence>()
+        .add_systems(Update, reset_confirmed_evidence)
+        .add_systems(
+            FixedUpdate,
+            update_evidence_ui.run_if(in_state(GameState::None).and(in_state(AppState::InGame))),
+        )
+        .add_systems(
+            Update,
+            (keyboard_evidence, keyboard_confirm_evidence)
+                .run_if(in_state(GameState::None).and(in_state(AppState::InGame))),
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirming_freezing_temp_logs_once() {
+        let mut confirmed = GearConfirmedEvidence::default();
+        let mut journal = PlayerJournal::default();
+        let mut profile = PlayerProfileData::default();
+        let mut walkie_play = WalkiePlay::default();
+
+        for time in [1.0, 500.0] {
+            confirm_evidence(
+                Evidence::FreezingTemp,
+                &mut confirmed,
+                &mut journal,
+                &mut profile,
+                &mut walkie_play,
+                time,
+            );
+        }
+
+        assert_eq!(journal.confirmed(), &[Evidence::FreezingTemp]);
+        assert_eq!(
+            profile.times_evidence_acknowledged_on_gear[&Evidence::FreezingTemp],
+            1
+        );
+        let event = WalkieEvent::FreezingTempsEvidenceConfirmed;
+        assert_eq!(walkie_play.event, Some(event.clone()));
+        assert_eq!(walkie_play.played_events[&event].count, 1);
+    }
 }
@@ -2,15 +2,20 @@ use super::uibutton::{TruckButtonState, TruckButtonType, TruckUIButton};
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
 use bevy_platform::collections::HashSet;
+use uncore::colours;
 use uncore::components::game_config::GameConfig;
 use uncore::components::player_sprite::PlayerSprite;
-use uncore::components::truck::TruckUIGhostGuess;
+use uncore::components::truck::{
+    TruckUIEvidenceSuggestion, TruckUIGhostGuess, TruckUIJournalSummary,
+};
 use uncore::difficulty::CurrentDifficulty;
 use uncore::events::truck::TruckUIEvent;
 use uncore::resources::ghost_guess::GhostGuess;
+use uncore::resources::player_journal::{JournalMark, PlayerJournal};
 use uncore::resources::potential_id_timer::PotentialIDTimer;
 use uncore::states::{AppState, GameState};
-use uncore::types::evidence::Evidence;
+use uncore::types::evidence::{Evidence, EvidenceSet};
+use uncore::types::ghost::distinguisher;
 use uncore::types::ghost::types::GhostType;
 use ungear::components::playergear::PlayerGear;
 use unprofile::data::PlayerProfileData;
@@ -171,28 +176,38 @@ fn button_system(
         gg.evidences_missing = selected_evidences_missing.clone();
     }
 
-    let possible_ghosts: Vec<GhostType> = difficulty
-        .0
-        .ghost_set
-        .as_vec()
-        .into_iter()
-        .filter(|ghost_type| {
-            let ghost_ev = ghost_type.evidences();
-            let mut is_discarded = false;
-            for (_, _, _, _, tui_button) in &interaction_query {
-                if let TruckButtonType::Ghost(gh) = tui_button.class
-                    && gh == *ghost_type
-                    && tui_button.status == TruckButtonState::Discard
-                {
-                    is_discarded = true;
-                    break;
-                }
+    let discarded_ghosts: Vec<GhostType> = interaction_query
+        .iter()
+        .filter_map(|(_, _, _, _, tui_button)| match tui_button.class {
+            TruckButtonType::Ghost(gh) if tui_button.status == TruckButtonState::Discard => {
+                Some(gh)
             }
-            !is_discarded
-                && ghost_ev.is_superset(&selected_evidences_found)
-                && ghost_ev.is_disjoint(&selected_evidences_missing)
+            _ => None,
         })
         .collect();
+    let found = EvidenceSet::from(&selected_evidences_found);
+    let missing = EvidenceSet::from(&selected_evidences_missing);
+    let possible_ghosts: Vec<GhostType> =
+        distinguisher::matching_ghosts(&difficulty.0.ghost_set.as_vec(), found, missing)
+            .into_iter()
+            .filter(|ghost_type| !discarded_ghosts.contains(ghost_type))
+            .collect();
+
+    // Evidence that can actually be gathered with the gear of this mission.
+    let available_evidence: EvidenceSet = difficulty
+        .0
+        .truck_gear
+        .iter()
+        .filter_map(|gear_kind| Evidence::try_from(gear_kind).ok())
+        .collect();
+    let suggested_evidence = distinguisher::most_distinguishing_evidence(
+        &possible_ghosts,
+        found.union(missing),
+        available_evidence,
+    );
+    if gg.suggested_evidence != suggested_evidence {
+        gg.suggested_evidence = suggested_evidence;
+    }
 
     // a) Handle manual click on a ghost button
     if let Some(clicked_ghost) = clicked_ghost_type {
@@ -244,14 +259,7 @@ fn button_system(
         // --- NEW LOGIC FOR EVIDENCE BUTTONS ---
         if let TruckButtonType::Evidence(ev) = tui_button.class {
             // Primary check: is the gear for this evidence even available?
-            if !difficulty
-                .0
-                .truck_gear
-                .iter()
-                .filter_map(|gear_kind| Evidence::try_from(gear_kind).ok())
-                .collect::<HashSet<_>>()
-                .contains(&ev)
-            {
+            if !available_evidence.contains(ev) {
                 tui_button.disabled = true;
             } else if tui_button.status == TruckButtonState::Off {
                 // If gear is available, then apply the existing logic for 'Off' buttons
@@ -294,7 +302,15 @@ fn button_system(
         let current_text_color = tui_button.text_color(current_interaction);
 
         if !tui_button.blinking_hint_active {
-            border_color.0 = current_border_color;
+            border_color.0 = if suggested_evidence
+                .is_some_and(|ev| tui_button.class == TruckButtonType::Evidence(ev))
+                && tui_button.status == TruckButtonState::Off
+                && !tui_button.disabled
+            {
+                colours::TRUCKUI_ACCENT3_COLOR
+            } else {
+                current_border_color
+            };
         }
         *bgcolour = current_background_color.into();
         textcolour.0 = current_text_color;
@@ -363,12 +379,99 @@ fn ghost_guess_system(
     }
 }
 
+fn evidence_suggestion_system(
+    mut suggestion_query: Query<(Ref<TruckUIEvidenceSuggestion>, &mut Text)>,
+    gg: Res<GhostGuess>,
+) {
+    for (suggestion, mut text) in &mut suggestion_query {
+        if !gg.is_changed() && !suggestion.is_added() {
+            continue;
+        }
+        text.0 = match gg.suggested_evidence {
+            Some(ev) => format!("Most useful to check next: {}", ev.name()),
+            None => String::new(),
+        };
+    }
+}
+
+/// Keeps the `PlayerJournal` in sync with the journal evidence buttons.
+///
+/// The buttons are the single source of truth: they are toggled from the
+/// journal, from the gear's "mark evidence" key and by
+/// `ForceDiscardEvidenceEvent`, so following them covers every path.
+fn sync_player_journal_system(
+    q_buttons: Query<&TruckUIButton, Changed<TruckUIButton>>,
+    mut journal: ResMut<PlayerJournal>,
+) {
+    for button in &q_buttons {
+        let TruckButtonType::Evidence(evidence) = button.class else {
+            continue;
+        };
+        let mark = match button.status {
+            TruckButtonState::Off => JournalMark::Unknown,
+            TruckButtonState::Pressed => JournalMark::Confirmed,
+            TruckButtonState::Discard => JournalMark::Discarded,
+        };
+        if journal.mark(evidence) != mark {
+            journal.set_mark(evidence, mark);
+        }
+    }
+}
+
+fn evidence_list(evidences: impl Iterator<Item = Evidence>) -> String {
+    let names: Vec<&str> = evidences.map(|e| e.name()).collect();
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+/// Text for the evidence summary shown in the truck journal.
+fn journal_summary_text(journal: &PlayerJournal, candidates: &[GhostType]) -> String {
+    let mut text = format!(
+        "Confirmed: {}\nRuled out: {}\nStill to check: {}",
+        evidence_list(journal.confirmed().iter().copied()),
+        evidence_list(journal.discarded().iter()),
+        evidence_list(journal.remaining().iter()),
+    );
+    if journal.is_contradictory(candidates) {
+        text.push_str("\nNo known ghost matches these findings. Recheck the evidence.");
+    }
+    text
+}
+
+fn journal_summary_system(
+    mut q_summary: Query<(Ref<TruckUIJournalSummary>, &mut Text, &mut TextColor)>,
+    journal: Res<PlayerJournal>,
+    difficulty: Res<CurrentDifficulty>,
+) {
+    let candidates = difficulty.0.ghost_set.as_vec();
+    for (summary, mut text, mut colour) in &mut q_summary {
+        if !journal.is_changed() && !summary.is_added() {
+            continue;
+        }
+        text.0 = journal_summary_text(&journal, &candidates);
+        colour.0 = if journal.is_contradictory(&candidates) {
+            colours::BUTTON_END_MISSION_TXTCOLOR
+        } else {
+            colours::TRUCKUI_TEXT_COLOR
+        };
+    }
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_event::<ForceDiscardEvidenceEvent>()
-        .add_systems(Update, ghost_guess_system)
+        .add_systems(Update, (ghost_guess_system, evidence_suggestion_system))
         .add_systems(
             Update,
-            force_discard_evidence_system.run_if(in_state(AppState::InGame)),
+            (
+                force_discard_evidence_system,
+                sync_player_journal_system,
+                journal_summary_system,
+            )
+                .chain()
+                .run_if(in_state(AppState::InGame)),
         )
         .add_systems(
             FixedUpdate,
@@ -1,7 +1,9 @@
 use super::uibutton::TruckButtonType; // Assuming TruckUIGhostGuess and uibutton are still relevant here
 use bevy::prelude::*;
 use uncore::colours;
-use uncore::components::truck::TruckUIGhostGuess;
+use uncore::components::truck::{
+    TruckUIEvidenceSuggestion, TruckUIGhostGuess, TruckUIJournalSummary,
+};
 use uncore::difficulty::CurrentDifficulty; // Use CurrentDifficulty
 use uncore::platform::plt::{FONT_SCALE, UI_SCALE};
 use uncore::types::evidence::Evidence;
@@ -79,6 +81,22 @@ pub fn setup_journal_ui(
         }
     });
 
+    // Evidence summary
+    p.spawn((
+        Text::new(""),
+        TextFont {
+            font: handles.fonts.titillium.w400_regular.clone(),
+            font_size: 16.0 * FONT_SCALE,
+            ..default()
+        },
+        TextColor(colours::TRUCKUI_TEXT_COLOR),
+        Node {
+            margin: UiRect::all(Val::Px(4.0 * UI_SCALE)),
+            ..default()
+        },
+        TruckUIJournalSummary,
+    ));
+
     // ---- Ghost guess
     p.spawn(Node {
         margin: UiRect::all(Val::Px(4.0 * UI_SCALE)),
@@ -105,6 +123,16 @@ pub fn setup_journal_ui(
                 ..default()
             },
         ));
+        guess.spawn((
+            Text::new(""),
+            TextFont {
+                font: handles.fonts.titillium.w400_regular.clone(),
+                font_size: 18.0 * FONT_SCALE,
+                ..default()
+            },
+            TextColor(colours::TRUCKUI_ACCENT3_COLOR),
+            TruckUIEvidenceSuggestion,
+        ));
     });
 
     // Ghost selection
@@ -11,10 +11,13 @@
 //!
 //! * Buttons for crafting ghost repellents, exiting the truck, and ending the mission.
 //!
+//! * Autosave of the mission in progress, so it can be resumed after a crash.
+//!
 //! The truck UI provides a centralised interface for players to interact with the
 //! game's mechanics, track their progress, and make strategic decisions outside of
 //! the main exploration and investigation gameplay.
 pub mod activity;
+pub mod autosave;
 pub mod craft_repellent;
 pub mod evidence;
 pub mod journal;
@@ -3,11 +3,14 @@ use super::uibutton::{TruckButtonState, TruckButtonType, TruckUIButton};
 use crate::systems::truck_ui_systems::RepellentCraftTracker;
 use bevy::prelude::*;
 use uncore::colours;
+use uncore::colours::UiPalette;
 use uncore::components::game_config::GameConfig;
 use uncore::components::player_inventory::{Inventory, InventoryNext};
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::difficulty::CurrentDifficulty;
+use uncore::events::hint::OnScreenHintEvent;
 use uncore::platform::plt::{FONT_SCALE, UI_SCALE};
+use uncore::resources::localization::Localization;
 use uncore::states::GameState;
 use uncore::types::evidence::Evidence;
 use uncore::types::evidence_status::EvidenceStatus;
@@ -26,6 +29,8 @@ pub enum LoadoutButton {
     Inventory(Inventory),
     InventoryNext(InventoryNext),
     Van(Gear),
+    /// Van gear the current difficulty does not allow.
+    VanLocked(Gear),
 }
 
 #[derive(Debug, Event, Clone)]
@@ -186,6 +191,15 @@ pub fn setup_loadout_ui(
                             p.spawn(equipment(gear.get_sprite_idx()));
                         });
                 }
+                for gear in &tg.locked {
+                    let (mut image, node) = equipment(gear.get_sprite_idx());
+                    image.color = Color::WHITE.with_alpha(0.25);
+                    p.spawn(button())
+                        .insert(LoadoutButton::VanLocked(gear.clone()))
+                        .with_children(|p| {
+                            p.spawn((image, node));
+                        });
+                }
             });
         });
     };
@@ -265,6 +279,7 @@ fn update_loadout_buttons(
     interaction_query_journal_buttons: Query<&TruckUIButton, With<Button>>,
     mut ev_clk: EventWriter<EventButtonClicked>,
     gc: Res<GameConfig>,
+    palette: Res<UiPalette>,
 ) {
     let mut changed = false;
     let mut elem = None;
@@ -309,7 +324,7 @@ fn update_loadout_buttons(
                 let idx = invnext.idx.unwrap_or(0); // Default to 0 if None
                 p_gear.get_next(idx).unwrap_or_default()
             }
-            LoadoutButton::Van(gear) => gear.clone(),
+            LoadoutButton::Van(gear) | LoadoutButton::VanLocked(gear) => gear.clone(),
         }
     } else {
         // If nothing is hovered, potentially show help for the currently equipped right-hand item
@@ -325,6 +340,7 @@ fn update_loadout_buttons(
             },
             LoadoutButton::InventoryNext(_) => "(Click to unequip Backpack item)",
             LoadoutButton::Van(_) => "(Click to equip item)",
+            LoadoutButton::VanLocked(_) => "(Not available on this difficulty)",
         }
     } else {
         ""
@@ -347,7 +363,7 @@ fn update_loadout_buttons(
                 .unwrap_or(TruckButtonState::Off),
             None => TruckButtonState::Off,
         };
-        let status = EvidenceStatus::from_gearkind(o_evidence, ev_state);
+        let status = EvidenceStatus::from_gearkind(o_evidence, ev_state, &palette);
         let evidence_text = if status.title.trim().is_empty() {
             "".to_string()
         } else {
@@ -395,6 +411,9 @@ fn button_clicked(
     mut q_gear: Query<(&PlayerSprite, &mut PlayerGear)>,
     gc: Res<GameConfig>,
     mut craft_tracker: ResMut<RepellentCraftTracker>,
+    mut ev_hint: EventWriter<OnScreenHintEvent>,
+    localization: Res<Localization>,
+    difficulty: Res<CurrentDifficulty>,
 ) {
     let Some(ev) = ev_clk.read().next() else {
         return;
@@ -449,7 +468,27 @@ fn button_clicked(
             }
         }
         LoadoutButton::Van(gear) => {
-            p_gear.append(gear.clone());
+            if p_gear.try_add(gear.clone()).is_err() {
+                ev_hint.write(OnScreenHintEvent {
+                    hint_text: localization
+                        .text(
+                            "hint.inventory_full.truck",
+                            "Inventory full. Return an item to the van first.",
+                        )
+                        .to_string(),
+                });
+            }
+        }
+        LoadoutButton::VanLocked(gear) => {
+            ev_hint.write(OnScreenHintEvent {
+                hint_text: localization
+                    .text(
+                        "hint.gear_not_allowed",
+                        "The {gear} is not available on {difficulty}.",
+                    )
+                    .replace("{gear}", gear.get_display_name())
+                    .replace("{difficulty}", &difficulty.0.difficulty_name),
+            });
         }
     }
 }
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use uncore::events::truck::TruckUIEvent;
 use uncore::resources::ghost_guess::GhostGuess;
+use uncore::resources::player_journal::PlayerJournal;
 
 use super::loadoutui::EventButtonClicked;
 
@@ -10,7 +11,8 @@ impl Plugin for UnhaunterTruckPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<TruckUIEvent>()
             .add_event::<EventButtonClicked>()
-            .init_resource::<GhostGuess>();
+            .init_resource::<GhostGuess>()
+            .init_resource::<PlayerJournal>();
 
         super::evidence::app_setup(app);
         super::systems::app_setup(app);
@@ -18,5 +20,6 @@ impl Plugin for UnhaunterTruckPlugin {
         super::journal::app_setup(app);
         super::sanity::app_setup(app);
         super::loadoutui::app_setup(app);
+        super::autosave::app_setup(app);
     }
 }
@@ -0,0 +1,215 @@
+//! Gamepad navigation of the truck buttons.
+//!
+//! The truck takes the same `GamepadMenuInput` as the menus. Up and down move
+//! a focus outline over the buttons of the open tab, in reading order, and
+//! South (A) presses the focused button through its `Interaction`, so the
+//! usual button systems handle it. Hold buttons stay pressed until South is
+//! let go. Tabs are switched in `ui`, and East (B) closes the truck like
+//! Escape.
+
+use bevy::prelude::*;
+use bevy::ui::ComputedNode;
+use uncore::colours;
+use uncore::components::truck_ui_button::TruckUIButton;
+use uncore::states::GameState;
+use uncoremenu::gamepad::GamepadMenuInput;
+
+/// Truck button the gamepad focus is on.
+#[derive(Resource, Debug, Default)]
+pub struct TruckGamepadFocus {
+    pub button: Option<Entity>,
+    /// The focused hold button is being held down with the gamepad.
+    pub holding: bool,
+}
+
+fn focus_outline() -> Outline {
+    Outline::new(Val::Px(3.0), Val::Px(2.0), colours::TRUCKUI_ACCENT2_COLOR)
+}
+
+fn truck_gamepad_buttons(
+    mut commands: Commands,
+    mut gamepad_inputs: EventReader<GamepadMenuInput>,
+    mut focus: ResMut<TruckGamepadFocus>,
+    mut buttons: Query<
+        (
+            Entity,
+            &TruckUIButton,
+            &mut Interaction,
+            &ComputedNode,
+            &GlobalTransform,
+        ),
+        With<Button>,
+    >,
+) {
+    // Buttons of hidden tabs have no size.
+    let mut shown: Vec<_> = buttons
+        .iter()
+        .filter(|(_, button, _, node, _)| !button.disabled && node.size() != Vec2::ZERO)
+        .map(|(entity, _, _, _, transform)| {
+            let pos = transform.translation();
+            (entity, pos.y.round() as i32, pos.x)
+        })
+        .collect();
+    shown.sort_by(|a, b| a.1.cmp(&b.1).then(a.2.total_cmp(&b.2)));
+    let shown: Vec<Entity> = shown.into_iter().map(|(entity, _, _)| entity).collect();
+
+    let mut new_focus = focus.button.filter(|entity| shown.contains(entity));
+    let mut press = false;
+    let mut release = false;
+    for input in gamepad_inputs.read() {
+        match input {
+            GamepadMenuInput::Navigate(key @ (KeyCode::ArrowUp | KeyCode::ArrowDown)) => {
+                let Some(first) = shown.first() else {
+                    continue;
+                };
+                new_focus = Some(
+                    match new_focus.and_then(|f| shown.iter().position(|e| *e == f)) {
+                        Some(idx) if *key == KeyCode::ArrowUp => shown[idx.saturating_sub(1)],
+                        Some(idx) => shown[(idx + 1).min(shown.len() - 1)],
+                        None => *first,
+                    },
+                );
+            }
+            GamepadMenuInput::Activate => press = true,
+            GamepadMenuInput::Release => release = true,
+            _ => {}
+        }
+    }
+
+    if new_focus != focus.button {
+        if let Some(old) = focus.button {
+            if let Ok(mut cmd) = commands.get_entity(old) {
+                cmd.remove::<Outline>();
+            }
+            if focus.holding
+                && let Ok((_, _, mut interaction, _, _)) = buttons.get_mut(old)
+            {
+                interaction.set_if_neq(Interaction::None);
+            }
+        }
+        if let Some(new) = new_focus {
+            commands.entity(new).insert(focus_outline());
+        }
+        focus.button = new_focus;
+        focus.holding = false;
+    }
+
+    let Some(focused) = focus.button else {
+        return;
+    };
+    let Ok((_, button, mut interaction, _, _)) = buttons.get_mut(focused) else {
+        return;
+    };
+    if release {
+        focus.holding = false;
+        interaction.set_if_neq(Interaction::None);
+    } else if press {
+        focus.holding = button.hold_duration.is_some();
+        interaction.set_if_neq(Interaction::Pressed);
+    } else if focus.holding {
+        // The UI focus system drops the press when the cursor leaves the
+        // window; keep the hold going while South is down.
+        interaction.set_if_neq(Interaction::Pressed);
+    }
+}
+
+fn clear_truck_gamepad_focus(
+    mut commands: Commands,
+    mut focus: ResMut<TruckGamepadFocus>,
+    mut buttons: Query<&mut Interaction, With<TruckUIButton>>,
+) {
+    if let Some(old) = focus.button.take() {
+        if let Ok(mut cmd) = commands.get_entity(old) {
+            cmd.remove::<Outline>();
+        }
+        if focus.holding
+            && let Ok(mut interaction) = buttons.get_mut(old)
+        {
+            interaction.set_if_neq(Interaction::None);
+        }
+    }
+    focus.holding = false;
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<TruckGamepadFocus>()
+        .add_systems(
+            Update,
+            truck_gamepad_buttons.run_if(in_state(GameState::Truck)),
+        )
+        .add_systems(OnExit(GameState::Truck), clear_truck_gamepad_focus);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uncore::types::truck_button::TruckButtonType;
+
+    fn spawn_button(app: &mut App, class: TruckButtonType, x: f32, y: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Button,
+                Interaction::None,
+                TruckUIButton::from(class),
+                ComputedNode {
+                    size: Vec2::new(100.0, 40.0),
+                    ..default()
+                },
+                GlobalTransform::from_xyz(x, y, 0.0),
+            ))
+            .id()
+    }
+
+    fn send(app: &mut App, input: GamepadMenuInput) {
+        app.world_mut().send_event(input);
+        app.update();
+    }
+
+    #[test]
+    fn test_gamepad_moves_focus_and_presses() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::Truck)
+            .add_event::<GamepadMenuInput>();
+        app_setup(&mut app);
+        let exit = spawn_button(&mut app, TruckButtonType::ExitTruck, 0.0, 200.0);
+        let end = spawn_button(&mut app, TruckButtonType::EndMission, 0.0, 300.0);
+        let craft = spawn_button(&mut app, TruckButtonType::CraftRepellent, 0.0, 100.0);
+        app.update();
+
+        send(&mut app, GamepadMenuInput::Navigate(KeyCode::ArrowDown));
+        assert_eq!(
+            app.world().resource::<TruckGamepadFocus>().button,
+            Some(craft)
+        );
+        assert!(app.world().get::<Outline>(craft).is_some());
+
+        send(&mut app, GamepadMenuInput::Navigate(KeyCode::ArrowDown));
+        send(&mut app, GamepadMenuInput::Navigate(KeyCode::ArrowDown));
+        send(&mut app, GamepadMenuInput::Navigate(KeyCode::ArrowDown));
+        assert_eq!(
+            app.world().resource::<TruckGamepadFocus>().button,
+            Some(end)
+        );
+        assert!(app.world().get::<Outline>(craft).is_none());
+
+        // End mission is a hold button: it stays pressed until South is let go.
+        send(&mut app, GamepadMenuInput::Activate);
+        app.update();
+        assert_eq!(
+            *app.world().get::<Interaction>(end).unwrap(),
+            Interaction::Pressed
+        );
+        send(&mut app, GamepadMenuInput::Release);
+        assert_eq!(
+            *app.world().get::<Interaction>(end).unwrap(),
+            Interaction::None
+        );
+
+        send(&mut app, GamepadMenuInput::Navigate(KeyCode::ArrowUp));
+        assert_eq!(
+            app.world().resource::<TruckGamepadFocus>().button,
+            Some(exit)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use bevy_platform::collections::HashMap;
+use uncore::components::game_config::GameConfig;
+use uncore::components::player_sprite::PlayerSprite;
+use uncore::difficulty::CurrentDifficulty;
+use uncore::states::{AppState, GameState};
+use uncore::types::gear_kind::GearKind;
+use ungear::components::playergear::PlayerGear;
+use unwalkiecore::{WalkieEvent, WalkiePlay};
+
+/// The gear a player carries, ignoring which slot each piece is in.
+///
+/// Cycling or swapping hands doesn't count as changing the loadout; only taking
+/// or returning gear does.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoadoutSnapshot(HashMap<GearKind, usize>);
+
+impl LoadoutSnapshot {
+    pub fn of(player_gear: &PlayerGear) -> Self {
+        let mut counts = HashMap::new();
+        for (gear, _) in player_gear.as_vec() {
+            if gear.kind.is_some() {
+                *counts.entry(gear.kind).or_insert(0) += 1;
+            }
+        }
+        Self(counts)
+    }
+
+    pub fn contains(&self, kind: GearKind) -> bool {
+        self.0.contains_key(&kind)
+    }
+}
+
+/// A loadout left unchanged for longer than this is worth a reminder even
+/// when the right hand holds something.
+const UNCHANGED_LOADOUT_SECS: f64 = 120.0;
+
+/// Loadout of the local player when they last entered the truck, and when it
+/// last changed.
+#[derive(Resource, Debug, Default)]
+pub struct TruckEntryLoadout {
+    /// Cleared when the mission is ended from the truck, as there's nothing to
+    /// compare then.
+    pub snapshot: Option<LoadoutSnapshot>,
+    /// Elapsed time of the first truck exit, or the last one with a different
+    /// loadout.
+    pub last_changed: Option<f64>,
+    /// Set when the player left with an unchanged loadout, until the walkie
+    /// accepts the hint.
+    pub hint_pending: bool,
+}
+
+/// Whether leaving the truck with an unchanged loadout deserves the hint. Not
+/// while carrying the repellent (the player is going to expel the ghost), and
+/// otherwise only with an empty right hand or after the loadout stayed the
+/// same for a while.
+fn loadout_hint_due(gear: &PlayerGear, unchanged_for_secs: f64) -> bool {
+    !LoadoutSnapshot::of(gear).contains(GearKind::RepellentFlask)
+        && (gear.empty_right_handed() || unchanged_for_secs > UNCHANGED_LOADOUT_SECS)
+}
+
+fn player_gear<'a>(
+    q_gear: &'a Query<(&PlayerSprite, &PlayerGear)>,
+    gc: &GameConfig,
+) -> Option<&'a PlayerGear> {
+    q_gear
+        .iter()
+        .find(|(player, _)| player.id == gc.player_id)
+        .map(|(_, gear)| gear)
+}
+
+fn record_loadout_on_entry(
+    mut entry: ResMut<TruckEntryLoadout>,
+    q_gear: Query<(&PlayerSprite, &PlayerGear)>,
+    gc: Res<GameConfig>,
+) {
+    entry.snapshot = player_gear(&q_gear, &gc).map(LoadoutSnapshot::of);
+    // Leaving again is checked on its own.
+    entry.hint_pending = false;
+}
+
+fn reset_loadout_tracking(mut entry: ResMut<TruckEntryLoadout>) {
+    *entry = TruckEntryLoadout::default();
+}
+
+/// Queues `PlayerLeavesTruckWithoutChangingLoadout` when the player walks out
+/// of the truck carrying the same gear they came in with, if
+/// `loadout_hint_due`.
+fn check_loadout_on_exit(
+    mut entry: ResMut<TruckEntryLoadout>,
+    q_gear: Query<(&PlayerSprite, &PlayerGear)>,
+    gc: Res<GameConfig>,
+    difficulty: Res<CurrentDifficulty>,
+    time: Res<Time>,
+) {
+    let Some(entry_loadout) = entry.snapshot.take() else {
+        return;
+    };
+    // Early tutorials don't open the van for the player, the loadout is given.
+    if !difficulty.0.van_auto_open {
+        return;
+    }
+    let Some(gear) = player_gear(&q_gear, &gc) else {
+        return;
+    };
+    let now = time.elapsed_secs_f64();
+    if LoadoutSnapshot::of(gear) != entry_loadout {
+        entry.last_changed = Some(now);
+        return;
+    }
+    let last_changed = *entry.last_changed.get_or_insert(now);
+    entry.hint_pending = loadout_hint_due(gear, now - last_changed);
+}
+
+/// Plays the queued loadout hint; the walkie may be busy or cooling down, so
+/// it's retried every frame until it's accepted.
+fn play_pending_loadout_hint(
+    mut entry: ResMut<TruckEntryLoadout>,
+    time: Res<Time>,
+    mut walkie_play: ResMut<WalkiePlay>,
+) {
+    if entry.hint_pending
+        && walkie_play.set(
+            WalkieEvent::PlayerLeavesTruckWithoutChangingLoadout,
+            time.elapsed_secs_f64(),
+        )
+    {
+        entry.hint_pending = false;
+    }
+}
+
+pub(crate) fn app_setup(app: &mut App) {
+    app.init_resource::<TruckEntryLoadout>()
+        .add_systems(OnEnter(GameState::Truck), record_loadout_on_entry)
+        .add_systems(OnExit(GameState::Truck), check_loadout_on_exit)
+        .add_systems(
+            Update,
+            play_pending_loadout_hint
+                .run_if(in_state(AppState::InGame).and(in_state(GameState::None))),
+        )
+        .add_systems(OnExit(AppState::InGame), reset_loadout_tracking);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ungear::types::gear::Gear;
+
+    fn gear(kind: GearKind) -> Gear {
+        Gear { kind, data: None }
+    }
+
+    #[test]
+    fn test_loadout_snapshot_comparison() {
+        let before = PlayerGear {
+            left_hand: gear(GearKind::Flashlight),
+            right_hand: gear(GearKind::Thermometer),
+            inventory: vec![gear(GearKind::EMFMeter), Gear::none()],
+            held_item: None,
+        };
+
+        // Moving gear between slots is not a loadout change
+        let mut shuffled = before.clone();
+        shuffled.swap();
+        shuffled.inventory.swap(0, 1);
+        assert_eq!(LoadoutSnapshot::of(&before), LoadoutSnapshot::of(&shuffled));
+
+        // Taking a new piece of gear is
+        let mut changed = before.clone();
+        changed.inventory[1] = gear(GearKind::UVTorch);
+        assert_ne!(LoadoutSnapshot::of(&before), LoadoutSnapshot::of(&changed));
+
+        // And so is swapping one piece for another
+        let mut replaced = before.clone();
+        replaced.right_hand = gear(GearKind::Videocam);
+        assert_ne!(LoadoutSnapshot::of(&before), LoadoutSnapshot::of(&replaced));
+    }
+
+    fn loadout(right_hand: GearKind, inventory: GearKind) -> PlayerGear {
+        PlayerGear {
+            left_hand: gear(GearKind::Flashlight),
+            right_hand: gear(right_hand),
+            inventory: vec![gear(inventory), Gear::none()],
+            held_item: None,
+        }
+    }
+
+    #[test]
+    fn test_repellent_flask_suppresses_hint() {
+        let carrying = loadout(GearKind::None, GearKind::RepellentFlask);
+        assert!(!loadout_hint_due(&carrying, 0.0));
+        assert!(!loadout_hint_due(&carrying, 600.0));
+    }
+
+    #[test]
+    fn test_empty_right_hand_gets_hint() {
+        assert!(loadout_hint_due(
+            &loadout(GearKind::None, GearKind::EMFMeter),
+            0.0
+        ));
+        assert!(!loadout_hint_due(
+            &loadout(GearKind::Thermometer, GearKind::EMFMeter),
+            0.0
+        ));
+    }
+
+    #[test]
+    fn test_hint_after_loadout_unchanged_for_a_while() {
+        let holding = loadout(GearKind::Thermometer, GearKind::EMFMeter);
+        assert!(!loadout_hint_due(&holding, UNCHANGED_LOADOUT_SECS - 1.0));
+        assert!(loadout_hint_due(&holding, UNCHANGED_LOADOUT_SECS + 1.0));
+    }
+
+    #[test]
+    fn test_rejected_hint_is_retried() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TruckEntryLoadout {
+                hint_pending: true,
+                ..default()
+            })
+            .insert_resource(WalkiePlay {
+                priority_bar: f32::MAX,
+                last_message_time: f64::NEG_INFINITY,
+                ..default()
+            })
+            .add_systems(Update, play_pending_loadout_hint);
+
+        app.update();
+        assert!(app.world().resource::<TruckEntryLoadout>().hint_pending);
+
+        app.world_mut().resource_mut::<WalkiePlay>().priority_bar = 0.0;
+        app.update();
+        assert!(!app.world().resource::<TruckEntryLoadout>().hint_pending);
+    }
+}
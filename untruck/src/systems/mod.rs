@@ -1,9 +1,13 @@
 use bevy::app::App;
 
+pub mod gamepad_navigation;
 pub mod journal_blinking_system;
+pub mod loadout_tracking;
 pub mod truck_ui_systems;
 
 pub(crate) fn app_setup(app: &mut App) {
+    gamepad_navigation::app_setup(app);
     journal_blinking_system::app_setup(app);
+    loadout_tracking::app_setup(app);
     truck_ui_systems::app_setup(app);
 }
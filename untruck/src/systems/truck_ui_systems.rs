@@ -1,6 +1,10 @@
+use super::loadout_tracking::TruckEntryLoadout;
 use crate::craft_repellent::craft_repellent;
 use bevy::prelude::*;
 use bevy_persistent::Persistent;
+use uncore::behaviour::Behaviour;
+use uncore::colours::UiPalette;
+use uncore::components::board::position::Position;
 use uncore::components::game_config::GameConfig;
 use uncore::components::player_sprite::PlayerSprite;
 use uncore::components::truck::TruckUI;
@@ -11,15 +15,25 @@ use uncore::resources::board_data::BoardData;
 use uncore::resources::ghost_guess::GhostGuess;
 use uncore::resources::summary_data::SummaryData;
 use uncore::states::{AppState, GameState};
+use uncore::types::ghost::types::GhostType;
 use uncore::types::truck_button::TruckButtonType;
+use uncoremenu::gamepad::GamepadMenuInput;
+use ungear::components::deployedgear::DeployedGear;
 use ungear::components::playergear::PlayerGear;
+use ungearitems::components::thermometer::TemperatureMissionStats;
 use unprofile::data::PlayerProfileData;
 use unsettings::audio::AudioSettings;
+use unsettings::controls::ControlKeys;
+use unsettings::persist::{PersistRetry, persist_with_retry};
 
 // Component to mark the progress bar for hold buttons
 #[derive(Component)]
 pub struct ProgressIndicator;
 
+// Component to mark the percentage label inside a hold button progress bar
+#[derive(Component)]
+pub struct ProgressLabel;
+
 // Entity resource to track the audio player for the hold sound
 #[derive(Resource, Default)]
 pub struct HoldSoundEntity(pub Option<Entity>);
@@ -66,7 +80,7 @@ fn cleanup(mut commands: Commands, qtui: Query<Entity, With<TruckUI>>) {
 }
 
 // Initialise the repellent craft tracker when entering a mission
-fn init_repellent_tracker(
+pub(crate) fn init_repellent_tracker(
     mut craft_tracker: ResMut<RepellentCraftTracker>,
     difficulty: Res<CurrentDifficulty>,
 ) {
@@ -90,16 +104,65 @@ fn hide_ui(mut qtui: Query<&mut Visibility, With<TruckUI>>) {
     }
 }
 
+/// Percentage shown on a hold button's progress bar.
+///
+/// The bar width eases in with a square root so the hold feels responsive from
+/// the start; the label follows the same curve so both read the same.
+pub fn hold_progress_percent(progress: f32) -> u32 {
+    (progress.abs().sqrt() * 100.0).round() as u32
+}
+
+/// Distance from a van entry, in tiles, within which the toggle truck key
+/// opens the truck.
+const TRUCK_TOGGLE_RANGE: f32 = 3.0;
+
+/// Opens and closes the truck from the keyboard.
+///
+/// Escape only closes the truck; outside of it Escape belongs to the pause
+/// menu. The configurable toggle key does both, but only opens the truck when
+/// the player stands next to a van entry. The gamepad back button closes the
+/// truck like Escape.
 fn keyboard(
     game_state: Res<State<GameState>>,
     mut game_next_state: ResMut<NextState<GameState>>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut gamepad_inputs: EventReader<GamepadMenuInput>,
+    control_keys: Res<Persistent<ControlKeys>>,
+    gc: Res<GameConfig>,
+    bf: Res<BoardData>,
+    q_player: Query<(&PlayerSprite, &Position)>,
+    q_van_entry: Query<(&Position, &Behaviour)>,
 ) {
-    if *game_state.get() != GameState::Truck {
-        return;
-    }
-    if keyboard_input.just_pressed(KeyCode::Escape) {
-        game_next_state.set(GameState::None);
+    let toggle_pressed = keyboard_input.just_pressed(control_keys.toggle_truck);
+    let gamepad_back = gamepad_inputs
+        .read()
+        .any(|input| *input == GamepadMenuInput::Back);
+    match game_state.get() {
+        GameState::Truck => {
+            let back_pressed = keyboard_input.just_pressed(KeyCode::Escape) || gamepad_back;
+            if toggle_pressed || back_pressed {
+                game_next_state.set(GameState::None);
+            }
+        }
+        GameState::None if toggle_pressed => {
+            let near_truck = q_player
+                .iter()
+                .filter(|(player, _)| player.id == gc.player_id)
+                .any(|(_, player_pos)| {
+                    bf.nearest_interactive(player_pos, TRUCK_TOGGLE_RANGE, |e| {
+                        q_van_entry
+                            .get(e)
+                            .ok()
+                            .filter(|(_, behaviour)| behaviour.is_van_entry())
+                            .map(|(pos, _)| *pos)
+                    })
+                    .is_some()
+                });
+            if near_truck {
+                game_next_state.set(GameState::Truck);
+            }
+        }
+        _ => {}
     }
 }
 
@@ -129,6 +192,12 @@ fn keyboard(
 /// Plays "sounds/fadein-progress-1000ms.ogg" while the button is being held.
 /// The sound is stopped when the hold is cancelled.
 ///
+/// # Accessibility
+///
+/// The bar colour comes from the active `UiPalette` and carries a percentage
+/// label. With the `hold_tick_cue` audio setting enabled, a short click also
+/// plays at every quarter of the displayed progress.
+///
 /// # Events
 ///
 /// When a hold is completed, this system sends the appropriate event based on the
@@ -147,6 +216,9 @@ fn hold_button_system(
     mut ev_truckui: EventWriter<TruckUIEvent>,
     mut hold_sound: Local<Option<Entity>>,
     craft_tracker: Res<RepellentCraftTracker>,
+    palette: Res<UiPalette>,
+    mut label_query: Query<(&mut Text, &ChildOf), With<ProgressLabel>>,
+    mut last_tick: Local<u32>,
 ) {
     // Track which buttons are currently being held
     let mut active_buttons = Vec::new();
@@ -158,14 +230,22 @@ fn hold_button_system(
             continue;
         }
 
-        // Skip disabled buttons
-        if button.disabled {
-            continue;
-        }
-
         // Check if this is a craft repellent button and we've reached the limit
         if matches!(button.class, TruckButtonType::CraftRepellent) && !craft_tracker.can_craft() {
             button.disabled = true;
+        }
+
+        // Skip disabled buttons, dropping any hold that was in progress so the
+        // button doesn't stay stuck once it is enabled again
+        if button.disabled {
+            if button.cancel_hold() {
+                info!("Button hold cancelled (disabled): {:?}", button.class);
+                if let Some(entity) = hold_sound.take()
+                    && let Ok(mut cmd_e) = commands.get_entity(entity)
+                {
+                    cmd_e.despawn();
+                }
+            }
             continue;
         }
 
@@ -184,6 +264,7 @@ fn hold_button_system(
                     // Start holding
                     button.holding = true;
                     button.hold_timer = Some(0.0);
+                    *last_tick = 0;
 
                     info!("Button hold started: {:?}", button_class);
 
@@ -205,10 +286,26 @@ fn hold_button_system(
                                     height: Val::Px(20.0),    // Much taller for visibility
                                     ..default()
                                 },
-                                // Bright yellow for maximum visibility
-                                BackgroundColor(Color::srgba(1.0, 1.0, 0.0, 0.2)),
+                                // Bright yellow by default, follows the colourblind palette
+                                BackgroundColor(palette.hold_progress),
                                 ZIndex(999),
                             ))
+                            .with_children(|bar| {
+                                bar.spawn((
+                                    ProgressLabel,
+                                    Text::new("0%"),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                    Node {
+                                        position_type: PositionType::Absolute,
+                                        left: Val::Px(4.0),
+                                        ..default()
+                                    },
+                                ));
+                            })
                             .id();
 
                         // Add progress bar directly to button
@@ -226,10 +323,7 @@ fn hold_button_system(
                         ))
                         .insert(PlaybackSettings {
                             mode: bevy::audio::PlaybackMode::Despawn,
-                            volume: bevy::audio::Volume::Linear(
-                                1.0 * audio_settings.volume_master.as_f32()
-                                    * audio_settings.volume_effects.as_f32(),
-                            ),
+                            volume: bevy::audio::Volume::Linear(audio_settings.effects_volume()),
                             ..default()
                         })
                         .id();
@@ -245,14 +339,38 @@ fn hold_button_system(
 
                     // Update all progress bars for this button
                     let progress = (*hold_timer / hold_duration).clamp(0.0, 1.0);
+                    let percent = hold_progress_percent(progress);
 
                     for (progress_entity, parent) in &progress_query {
-                        if parent.parent() == button_entity
-                            && let Ok(mut node) = node_query.get_mut(progress_entity)
-                        {
+                        if parent.parent() != button_entity {
+                            continue;
+                        }
+                        if let Ok(mut node) = node_query.get_mut(progress_entity) {
                             // We only cover up to 99% to avoid overflowing the button due to the borders.
                             node.width = Val::Percent(progress.abs().sqrt() * 99.0);
                         }
+                        for (mut text, label_parent) in &mut label_query {
+                            if label_parent.parent() == progress_entity {
+                                text.0 = format!("{percent}%");
+                            }
+                        }
+                    }
+
+                    // Tick at 25%, 50% and 75%; completion has its own feedback.
+                    let step = percent / 25;
+                    if audio_settings.hold_tick_cue.on() && step > *last_tick && step < 4 {
+                        *last_tick = step;
+                        commands
+                            .spawn(AudioPlayer::new(
+                                asset_server.load("sounds/effects-chirp-click.ogg"),
+                            ))
+                            .insert(PlaybackSettings {
+                                mode: bevy::audio::PlaybackMode::Despawn,
+                                volume: bevy::audio::Volume::Linear(
+                                    audio_settings.effects_volume(),
+                                ),
+                                ..default()
+                            });
                     }
 
                     // Check if hold is complete
@@ -279,17 +397,14 @@ fn hold_button_system(
                         }
 
                         // Reset button state
-                        button.holding = false;
-                        button.hold_timer = None;
+                        button.cancel_hold();
                     }
                 }
             }
             _ => {
                 // Button is no longer pressed, reset state
-                if button.holding {
+                if button.cancel_hold() {
                     info!("Button hold cancelled: {:?}", button_class);
-                    button.holding = false;
-                    button.hold_timer = None;
 
                     // Stop sound
                     if let Some(entity) = hold_sound.take()
@@ -320,6 +435,57 @@ fn hold_button_system(
     }
 }
 
+/// Share of the insurance deposit withheld for each piece of gear left deployed
+/// in the location when the mission ends.
+const LOST_GEAR_DEPOSIT_FRACTION: f64 = 0.1;
+
+/// Settles the insurance deposit and fills in the mission outcome on the
+/// summary.
+///
+/// Failing to save the profile is logged rather than fatal: the mission still
+/// ends and the summary shows, with the settled values kept in memory until the
+/// next successful persist.
+fn end_mission(
+    player_profile: &mut Persistent<PlayerProfileData>,
+    profile_retry: &mut PersistRetry<PlayerProfileData>,
+    summary_data: &mut SummaryData,
+    map_path: &str,
+    lost_gear: usize,
+) {
+    let initial_deposit_held = player_profile.progression.insurance_deposit;
+    let costs = (initial_deposit_held as f64 * LOST_GEAR_DEPOSIT_FRACTION * lost_gear as f64)
+        .round() as i64;
+    if lost_gear > 0 {
+        info!("[EndMission] {lost_gear} gear left behind, costing ${costs}");
+    }
+    let returned = player_profile.settle_deposit(costs);
+
+    if let Err(e) = persist_with_retry(player_profile, profile_retry) {
+        error!(
+            "Failed to persist PlayerProfileData at mission end: {:?}",
+            e
+        );
+    }
+
+    // Set summary_data.current_mission_id from board_data.map_path
+    summary_data.map_path = map_path.to_string();
+
+    // Debug: Log the updated value of summary_data.current_mission_id
+    info!(
+        "[EndMission] Set summary_data.current_mission_id to: '{}'",
+        summary_data.map_path
+    );
+
+    summary_data.deposit_originally_held = initial_deposit_held;
+    summary_data.deposit_returned_to_bank = returned;
+    summary_data.costs_deducted_from_deposit = initial_deposit_held - returned;
+    summary_data.money_earned = 0;
+
+    // All ghosts unhaunted means a successful completion
+    summary_data.mission_successful =
+        summary_data.ghosts_unhaunted == summary_data.ghost_types.len() as u32;
+}
+
 fn truckui_event_handle(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -332,47 +498,35 @@ fn truckui_event_handle(
     audio_settings: Res<Persistent<AudioSettings>>,
     mut summary_data: ResMut<SummaryData>,
     board_data: Res<BoardData>,
-    mut player_profile: ResMut<Persistent<PlayerProfileData>>,
+    (mut player_profile, mut profile_retry): (
+        ResMut<Persistent<PlayerProfileData>>,
+        ResMut<PersistRetry<PlayerProfileData>>,
+    ),
     mut craft_tracker: ResMut<RepellentCraftTracker>,
+    temperature_stats: Res<TemperatureMissionStats>,
+    q_deployed: Query<(), With<DeployedGear>>,
+    mut entry_loadout: ResMut<TruckEntryLoadout>,
 ) {
     for ev in ev_truckui.read() {
         match ev {
             TruckUIEvent::EndMission => {
+                temperature_stats.log_summary();
+                // Leaving to the summary, not to the location: no loadout nagging
+                entry_loadout.snapshot = None;
+
                 // Debug: Log the current state of board_data.map_path
                 info!(
                     "[EndMission] Current board_data.map_path: '{}'",
                     board_data.map_path
                 );
 
-                let initial_deposit_held = player_profile.progression.insurance_deposit;
-
-                player_profile.progression.bank += initial_deposit_held;
-                player_profile.progression.insurance_deposit = 0;
-
-                if let Err(e) = player_profile.persist() {
-                    panic!("Failed to persist PlayerProfileData: {:?}", e);
-                }
-
-                // Set summary_data.current_mission_id from board_data.map_path
-                summary_data.map_path = board_data.map_path.clone();
-
-                // Debug: Log the updated value of summary_data.current_mission_id
-                info!(
-                    "[EndMission] Set summary_data.current_mission_id to: '{}'",
-                    summary_data.map_path
+                end_mission(
+                    &mut player_profile,
+                    &mut profile_retry,
+                    &mut summary_data,
+                    &board_data.map_path,
+                    q_deployed.iter().count(),
                 );
-
-                summary_data.deposit_originally_held = initial_deposit_held;
-                summary_data.deposit_returned_to_bank = initial_deposit_held;
-                summary_data.costs_deducted_from_deposit = 0;
-                summary_data.money_earned = 0;
-
-                if summary_data.ghosts_unhaunted == summary_data.ghost_types.len() as u32 {
-                    // All ghosts were unhaunted, successful completion
-                    summary_data.mission_successful = true;
-                } else {
-                    summary_data.mission_successful = false;
-                }
                 // grade_achieved is now determined in the summary screen based on mission_successful
 
                 game_next_state.set(GameState::None);
@@ -398,8 +552,7 @@ fn truckui_event_handle(
                             .insert(PlaybackSettings {
                                 mode: bevy::audio::PlaybackMode::Despawn,
                                 volume: bevy::audio::Volume::Linear(
-                                    1.0 * audio_settings.volume_master.as_f32()
-                                        * audio_settings.volume_effects.as_f32(),
+                                    audio_settings.effects_volume(),
                                 ),
                                 speed: 1.0,
                                 paused: false,
@@ -417,14 +570,29 @@ fn truckui_event_handle(
     }
 }
 
+/// Text for the craft button: remaining crafts and, below it, the ghost type the
+/// repellent will be made for, so a wrong journal guess shows before the hold
+/// completes.
+pub fn craft_button_label(remaining: u32, target: Option<GhostType>) -> String {
+    if remaining == 0 {
+        return "End Mission - No More Repellents".to_string();
+    }
+    let target_line = match target {
+        Some(ghost_type) => format!("Targets: {}", ghost_type.name()),
+        None => "No ghost selected in the journal".to_string(),
+    };
+    format!("Craft Repellent ({remaining})\n{target_line}")
+}
+
 // System to update the craft repellent button text based on remaining crafts
 fn update_craft_button_text(
     craft_tracker: Res<RepellentCraftTracker>,
+    gg: Res<GhostGuess>,
     mut q_button: Query<(&mut TruckUIButton, &Children), With<Button>>,
     mut q_text: Query<&mut Text>,
 ) {
-    // Only update when the resource has changed
-    if !craft_tracker.is_changed() {
+    // Only update when the craft count or the guess has changed
+    if !craft_tracker.is_changed() && !gg.is_changed() {
         return;
     }
 
@@ -434,16 +602,12 @@ fn update_craft_button_text(
             let can_craft = craft_tracker.can_craft();
 
             // Update button disabled state
-            button.disabled = !can_craft;
+            button.disabled = !can_craft || gg.ghost_type.is_none();
 
             // Find the text child and update text
             for &child in children {
                 if let Ok(mut text) = q_text.get_mut(child) {
-                    if remaining > 0 {
-                        text.0 = format!("Craft Repellent ({})", remaining);
-                    } else {
-                        text.0 = "End Mission - No More Repellents".to_string();
-                    }
+                    text.0 = craft_button_label(remaining, gg.ghost_type);
                     break;
                 }
             }
@@ -455,6 +619,7 @@ fn update_craft_button_text(
 pub(crate) fn app_setup(app: &mut App) {
     // Initialise the RepellentCraftTracker resource
     app.init_resource::<RepellentCraftTracker>();
+    unsettings::persist::app_setup_retry::<PlayerProfileData>(app);
 
     app.add_systems(OnExit(AppState::InGame), cleanup);
     app.add_systems(OnEnter(GameState::Truck), show_ui);
@@ -472,3 +637,139 @@ pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(OnEnter(AppState::InGame), init_repellent_tracker);
     app.add_systems(OnExit(AppState::InGame), reset_repellent_tracker);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unsettings::persist::testing::temp_persistent;
+
+    #[test]
+    fn test_hold_progress_percent_matches_bar() {
+        assert_eq!(hold_progress_percent(0.0), 0);
+        assert_eq!(hold_progress_percent(0.25), 50);
+        assert_eq!(hold_progress_percent(1.0), 100);
+        for i in 0..=20 {
+            let progress = i as f32 / 20.0;
+            let bar = progress.abs().sqrt() * 100.0;
+            assert!((hold_progress_percent(progress) as f32 - bar).abs() <= 0.5);
+        }
+    }
+
+    #[test]
+    fn test_craft_button_label_shows_target() {
+        let label = craft_button_label(2, Some(GhostType::BeanSidhe));
+        assert!(label.starts_with("Craft Repellent (2)"));
+        assert!(label.ends_with(&format!("Targets: {}", GhostType::BeanSidhe.name())));
+        assert!(craft_button_label(2, None).contains("No ghost selected"));
+        assert_eq!(
+            craft_button_label(0, Some(GhostType::BeanSidhe)),
+            "End Mission - No More Repellents"
+        );
+    }
+
+    #[test]
+    fn test_craft_limit_cancels_hold_in_progress() {
+        let (audio_settings, _dir) = temp_persistent("audio", AudioSettings::default());
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .add_event::<TruckUIEvent>()
+            .init_resource::<UiPalette>()
+            .insert_resource(audio_settings)
+            .insert_resource(RepellentCraftTracker {
+                crafted_count: 1,
+                max_crafts: 1,
+            })
+            .add_systems(Update, hold_button_system);
+
+        let mut button = TruckUIButton::from(TruckButtonType::CraftRepellent);
+        button.holding = true;
+        button.hold_timer = Some(0.5);
+        let button_entity = app
+            .world_mut()
+            .spawn((Button, Interaction::Pressed, button))
+            .id();
+        let bar = app.world_mut().spawn(ProgressIndicator).id();
+        app.world_mut().entity_mut(button_entity).add_child(bar);
+
+        app.update();
+
+        let button = app.world().get::<TruckUIButton>(button_entity).unwrap();
+        assert!(button.disabled);
+        assert!(!button.holding);
+        assert!(button.hold_timer.is_none());
+        assert!(app.world().get_entity(bar).is_err());
+    }
+
+    #[test]
+    fn test_configured_key_closes_truck() {
+        let (control_keys, _dir) = temp_persistent(
+            "controls",
+            ControlKeys {
+                toggle_truck: KeyCode::KeyK,
+                ..default()
+            },
+        );
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::state::app::StatesPlugin))
+            .insert_state(GameState::Truck)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<GamepadMenuInput>()
+            .init_resource::<GameConfig>()
+            .init_resource::<BoardData>()
+            .insert_resource(control_keys)
+            .add_systems(Update, keyboard);
+        app.update();
+
+        // The default key is not bound any more.
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyB);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Truck
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyK);
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::None
+        );
+    }
+
+    #[test]
+    fn test_end_mission_survives_persist_failure() {
+        let (mut profile, dir) = temp_persistent("player_profile", PlayerProfileData::default());
+        let path = dir.path().join("player_profile.ron");
+        // A directory where the file should be makes every write fail.
+        std::fs::remove_file(&path).unwrap();
+        std::fs::create_dir_all(&path).unwrap();
+        assert!(profile.persist().is_err());
+
+        profile.progression.bank = 100;
+        profile.progression.insurance_deposit = 200;
+        let mut summary_data = SummaryData::default();
+
+        end_mission(
+            &mut profile,
+            &mut PersistRetry::default(),
+            &mut summary_data,
+            "maps/test.tmx",
+            1,
+        );
+
+        assert_eq!(profile.progression.bank, 280);
+        assert_eq!(profile.progression.insurance_deposit, 0);
+        assert_eq!(summary_data.map_path, "maps/test.tmx");
+        assert_eq!(summary_data.deposit_originally_held, 200);
+        assert_eq!(summary_data.deposit_returned_to_bank, 180);
+        assert_eq!(summary_data.costs_deducted_from_deposit, 20);
+        assert!(summary_data.mission_successful);
+    }
+}
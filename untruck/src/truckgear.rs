@@ -1,11 +1,14 @@
 use bevy::prelude::*;
-use uncore::difficulty::DifficultyStruct;
+use uncore::difficulty::{Difficulty, DifficultyStruct};
 use ungear::types::gear::Gear;
 use ungearitems::from_gearkind::FromGearKind;
 
 #[derive(Debug, Resource, Clone)]
 pub struct TruckGear {
     pub inventory: Vec<Gear>,
+    /// Gear the difficulty does not allow. The loadout shows it greyed out so
+    /// the player knows it exists.
+    pub locked: Vec<Gear>,
 }
 
 impl TruckGear {
@@ -14,8 +17,46 @@ impl TruckGear {
             inventory: difficulty
                 .truck_gear
                 .iter()
+                .filter(|gk| difficulty.difficulty.is_gear_allowed(**gk))
                 .map(|gk| Gear::from_gearkind(*gk))
                 .collect::<Vec<_>>(),
+            locked: Difficulty::MasterChallenge
+                .truck_gear()
+                .into_iter()
+                .filter(|gk| !difficulty.difficulty.is_gear_allowed(*gk))
+                .map(Gear::from_gearkind)
+                .collect::<Vec<_>>(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uncore::types::gear_kind::GearKind;
+
+    #[test]
+    fn test_disallowed_gear_is_listed_as_locked() {
+        let tutorial =
+            TruckGear::from_difficulty(&Difficulty::TutorialChapter1.create_difficulty_struct());
+        let master =
+            TruckGear::from_difficulty(&Difficulty::MasterChallenge.create_difficulty_struct());
+        assert!(master.locked.is_empty());
+        assert_eq!(
+            tutorial.inventory.len() + tutorial.locked.len(),
+            master.inventory.len()
+        );
+        assert!(
+            tutorial
+                .locked
+                .iter()
+                .any(|g| g.kind == GearKind::SpiritBox)
+        );
+        assert!(
+            !tutorial
+                .inventory
+                .iter()
+                .any(|g| g.kind == GearKind::SpiritBox)
+        );
+    }
+}
@@ -9,6 +9,7 @@ use uncore::platform::plt::{FONT_SCALE, UI_SCALE};
 use uncore::states::{AppState, GameState};
 use uncore::types::root::game_assets::GameAssets;
 use uncore::types::truck_button::TruckButtonType; // Assuming this is where TruckButtonType is for .into_component()
+use uncoremenu::gamepad::{GamepadMenuInput, GamepadMenuRoot};
 use unstd::materials::UIPanelMaterial;
 
 /// Trait to prevent CurrentDifficulty spilling to uncore
@@ -349,6 +350,7 @@ fn setup_ui(
             BackgroundColor(colours::TRUCKUI_BGCOLOR),
         ))
         .insert(TruckUI)
+        .insert(GamepadMenuRoot)
         .with_children(truck_ui);
 }
 
@@ -397,32 +399,100 @@ fn update_tab_interactions(
         } else {
             tt.update_from_interaction(&int_val);
         }
-        let (mut textcolour, mut textfont) = text_query.get_mut(children[1]).unwrap();
-        textcolour.0 = tt.text_color();
-        textfont.font_size = tt.font_size();
-        if let Some(mat) = materials.get_mut(panmat) {
-            mat.color = tt.bg_color().into();
-        } else {
-            warn!("Material not found for TruckTab update.");
-        }
+        restyle_tab(&tt, children, panmat, &mut materials, &mut text_query);
     }
     if let Some(cnt) = new_selected_cnt {
-        for (mut style, tc) in &mut qc {
-            let new_dis = match cnt == *tc {
-                true => Display::Flex,
-                false => Display::None,
-            };
-            if new_dis != style.display {
-                style.display = new_dis;
-            }
+        show_tab_contents(&mut qc, &cnt);
+    }
+}
+
+/// Updates the text and background of a tab to its state.
+fn restyle_tab(
+    tt: &TruckTab,
+    children: &Children,
+    panmat: &MaterialNode<UIPanelMaterial>,
+    materials: &mut Assets<UIPanelMaterial>,
+    text_query: &mut Query<(&mut TextColor, &mut TextFont)>,
+) {
+    let (mut textcolour, mut textfont) = text_query.get_mut(children[1]).unwrap();
+    textcolour.0 = tt.text_color();
+    textfont.font_size = tt.font_size();
+    if let Some(mat) = materials.get_mut(panmat) {
+        mat.color = tt.bg_color().into();
+    } else {
+        warn!("Material not found for TruckTab update.");
+    }
+}
+
+/// Shows the contents of the given tab and hides the others.
+fn show_tab_contents(qc: &mut Query<(&mut Node, &TabContents)>, cnt: &TabContents) {
+    for (mut style, tc) in qc {
+        let new_dis = match cnt == tc {
+            true => Display::Flex,
+            false => Display::None,
+        };
+        if new_dis != style.display {
+            style.display = new_dis;
         }
     }
 }
 
+/// Switches tabs with the gamepad bumpers, in the order they are shown and
+/// skipping disabled tabs.
+fn gamepad_tab_system(
+    mut gamepad_inputs: EventReader<GamepadMenuInput>,
+    mut materials: ResMut<Assets<UIPanelMaterial>>,
+    mut qt: Query<(
+        &mut TruckTab,
+        &Children,
+        &MaterialNode<UIPanelMaterial>,
+        &GlobalTransform,
+    )>,
+    mut qc: Query<(&mut Node, &TabContents)>,
+    mut text_query: Query<(&mut TextColor, &mut TextFont)>,
+) {
+    let Some(step) = gamepad_inputs.read().find_map(|input| match input {
+        GamepadMenuInput::Navigate(KeyCode::PageUp) => Some(-1),
+        GamepadMenuInput::Navigate(KeyCode::PageDown) => Some(1),
+        _ => None,
+    }) else {
+        return;
+    };
+    let mut tabs: Vec<_> = qt
+        .iter()
+        .filter(|(tt, ..)| tt.state != TabState::Disabled)
+        .map(|(tt, _, _, transform)| (tt.contents.clone(), tt.state, transform.translation().x))
+        .collect();
+    if tabs.is_empty() {
+        return;
+    }
+    tabs.sort_by(|a, b| a.2.total_cmp(&b.2));
+    let current = tabs
+        .iter()
+        .position(|(_, state, _)| *state == TabState::Selected)
+        .unwrap_or_default();
+    let next = tabs[(current as i32 + step).rem_euclid(tabs.len() as i32) as usize]
+        .0
+        .clone();
+
+    for (mut tt, children, panmat, _) in &mut qt {
+        let state = if tt.contents == next {
+            TabState::Selected
+        } else if tt.state == TabState::Selected {
+            TabState::Default
+        } else {
+            continue;
+        };
+        tt.state = state;
+        restyle_tab(&tt, children, panmat, &mut materials, &mut text_query);
+    }
+    show_tab_contents(&mut qc, &next);
+}
+
 pub(crate) fn app_setup(app: &mut App) {
     app.add_systems(OnEnter(AppState::InGame), setup_ui)
         .add_systems(
             Update,
-            update_tab_interactions.run_if(in_state(GameState::Truck)),
+            (update_tab_interactions, gamepad_tab_system).run_if(in_state(GameState::Truck)),
         );
 }
@@ -8,7 +8,7 @@ use uncore::{
         player_sprite::PlayerSprite,
     },
     difficulty::CurrentDifficulty,
-    resources::{board_data::BoardData, roomdb::RoomDB},
+    resources::{board_data::BoardData, hunt_state::HuntState, roomdb::RoomDB},
     states::{AppState, GameState},
     types::{evidence::Evidence, gear_kind::GearKind, manual::ManualChapterIndex},
 };
@@ -347,7 +347,7 @@ fn trigger_did_not_cycle_to_other_gear_system(
     roomdb: Res<RoomDB>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     difficulty: Res<CurrentDifficulty>,
-    ghost_query: Query<&GhostSprite>, // Add ghost query to check hunting state
+    hunt_state: Res<HuntState>,
     mut tracker: Local<GearCycleUsageTracker>, // No Option, always track
 ) {
     // 1. System Run Condition & Chapter Check
@@ -379,8 +379,7 @@ fn trigger_did_not_cycle_to_other_gear_system(
     }
 
     // Check if any ghost is currently hunting - pause tracking if so
-    let ghost_hunting = ghost_query.iter().any(|g| g.hunting > 0.0);
-    if ghost_hunting {
+    if hunt_state.active {
         // Don't reset tracker, just return and pause tracking while ghost is hunting
         return;
     }
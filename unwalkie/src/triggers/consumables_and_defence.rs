@@ -6,67 +6,38 @@ use uncore::{
         board::position::Position, ghost_sprite::GhostSprite, player_sprite::PlayerSprite,
     },
     difficulty::CurrentDifficulty,
-    resources::roomdb::RoomDB,
+    resources::{hunt_state::HuntState, roomdb::RoomDB},
     states::{AppState, GameState},
     types::{gear_kind::GearKind, manual::ManualChapterIndex},
 };
 use ungear::components::playergear::PlayerGear;
-use ungearitems::{components::quartz::QuartzStoneData, prelude::SageBundleData};
+use ungearitems::prelude::{QuartzState, QuartzStoneData, SageBundleData, SageState};
 use untruck::truckgear::TruckGear;
 use unwalkiecore::{WalkieEvent, WalkiePlay};
 
-/// Triggers a feedback event when the player's quartz stone cracks, after the hunt is over or player leaves the location.
-fn quartz_cracked_feedback(
-    mut walkie_play: ResMut<WalkiePlay>,
-    qp: Query<(&PlayerSprite, &Position, &PlayerGear)>,
-    roomdb: Res<RoomDB>,
-    app_state: Res<State<AppState>>,
-    game_state: Res<State<GameState>>,
-    time: Res<Time>,
-    mut last_cracks: Local<Option<u8>>,
-) {
-    if app_state.get() != &AppState::InGame || *game_state.get() != GameState::None {
-        *last_cracks = None;
-        return;
-    }
-    let Some((_player, pos, gear)) = qp.iter().next() else {
-        return;
-    };
-    let player_bpos = pos.to_board_position();
-    if roomdb.room_tiles.get(&player_bpos).is_none() {
-        *last_cracks = None;
-        return;
-    }
-    for (g, _) in gear.as_vec() {
-        if let Some(quartz) = g
-            .data
-            .as_ref()
-            .and_then(|d| <dyn Any>::downcast_ref::<QuartzStoneData>(d.as_ref()))
-        {
-            if let Some(prev) = *last_cracks
-                && quartz.cracks > prev
-                && quartz.cracks < 4
-            {
-                // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
-                walkie_play.set(WalkieEvent::QuartzCrackedFeedback, time.elapsed_secs_f64());
-            }
-            *last_cracks = Some(quartz.cracks);
-        }
+/// Walkie event for a quartz stone going from `prev` to `now`, if any.
+fn quartz_transition_event(prev: QuartzState, now: QuartzState) -> Option<WalkieEvent> {
+    match (prev, now) {
+        _ if prev == now => None,
+        (_, QuartzState::Shattered) => Some(WalkieEvent::QuartzShatteredFeedback),
+        (_, QuartzState::Cracked(_)) => Some(WalkieEvent::QuartzCrackedFeedback),
+        (_, QuartzState::Intact) => None,
     }
 }
 
-/// Triggers a feedback event when the player's quartz stone shatters, after the hunt is over or player leaves the location.
-fn quartz_shattered_feedback(
+/// Triggers a feedback event when the player's quartz stone cracks or shatters
+/// while the player is in the location.
+fn quartz_feedback(
     mut walkie_play: ResMut<WalkiePlay>,
     qp: Query<(&PlayerSprite, &Position, &PlayerGear)>,
     roomdb: Res<RoomDB>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
     time: Res<Time>,
-    mut shattered: Local<bool>,
+    mut last_state: Local<Option<QuartzState>>,
 ) {
     if app_state.get() != &AppState::InGame || *game_state.get() != GameState::None {
-        *shattered = false;
+        *last_state = None;
         return;
     }
     let Some((_player, pos, gear)) = qp.iter().next() else {
@@ -74,7 +45,7 @@ fn quartz_shattered_feedback(
     };
     let player_bpos = pos.to_board_position();
     if roomdb.room_tiles.get(&player_bpos).is_none() {
-        *shattered = false;
+        *last_state = None;
         return;
     }
     for (g, _) in gear.as_vec() {
@@ -82,15 +53,14 @@ fn quartz_shattered_feedback(
             .data
             .as_ref()
             .and_then(|d| <dyn Any>::downcast_ref::<QuartzStoneData>(d.as_ref()))
-            && quartz.cracks >= 4
-            && !*shattered
         {
-            // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
-            walkie_play.set(
-                WalkieEvent::QuartzShatteredFeedback,
-                time.elapsed_secs_f64(),
-            );
-            *shattered = true;
+            let state = quartz.state();
+            if let Some(prev) = *last_state
+                && let Some(event) = quartz_transition_event(prev, state)
+            {
+                walkie_play.set(event, time.elapsed_secs_f64());
+            }
+            *last_state = Some(state);
         }
     }
 }
@@ -237,7 +207,7 @@ fn trigger_sage_unused_in_relevant_situation_system(
             && let Some(sage_data) =
                 <dyn Any>::downcast_ref::<SageBundleData>(sage_data_dyn.as_ref())
         {
-            return !sage_data.consumed; // Player has sage and it's not consumed
+            return sage_data.state() != SageState::Spent; // Player has sage and it's not consumed
         }
         false
     });
@@ -339,7 +309,7 @@ fn trigger_sage_activated_ineffectively_system(
     };
 
     // 4. Manage Tracker State
-    if sage_data.is_active && !sage_data.consumed {
+    if sage_data.state() == SageState::Burning {
         // Sage is currently burning
         if !tracker.is_tracking_this_sage_burn || tracker.player_entity_id != Some(player_entity) {
             // Start tracking this new burn, or re-track if player changed
@@ -357,8 +327,8 @@ fn trigger_sage_activated_ineffectively_system(
             // Sage was being tracked for this player, and now it's no longer active.
             // This means it was either consumed or deactivated (e.g. player dropped/stowed it).
             // If it was consumed, this is when we check effectiveness.
-            if sage_data.consumed {
-                // Check the consumed flag
+            if sage_data.state() == SageState::Spent {
+                // It burned out: check whether it calmed the ghost
                 let calm_increase =
                     ghost_sprite.calm_time_secs - tracker.initial_ghost_calm_time_secs;
                 if calm_increase < MIN_EFFECTIVE_SAGE_CALM_INCREASE {
@@ -429,6 +399,7 @@ fn trigger_sage_unused_defensively_during_hunt_system(
     mut walkie_play: ResMut<WalkiePlay>,
     player_query: Query<&PlayerGear, With<PlayerSprite>>,
     ghost_query: Query<&GhostSprite>,
+    hunt_state: Res<HuntState>,
     difficulty: Res<CurrentDifficulty>,
     mut tracker: ResMut<HuntSageUsageTracker>, // Use ResMut for the tracker
 ) {
@@ -450,17 +421,17 @@ fn trigger_sage_unused_defensively_during_hunt_system(
     let Ok(player_gear) = player_query.single() else {
         return;
     };
-    let Ok(ghost_sprite) = ghost_query.single() else {
+    if ghost_query.single().is_err() {
         // If ghost is gone, and we were in a hunt, treat hunt as ended.
         if matches!(tracker.phase, HuntPhaseForSageCheck::InHunt { .. }) {
             // info!("Ghost despawned during tracked hunt, resetting HuntSageUsageTracker.");
             *tracker = HuntSageUsageTracker::default();
         }
         return;
-    };
+    }
 
     // 4. Monitor Hunt State & Sage Usage
-    let current_ghost_is_hunting = ghost_sprite.hunting > 0.1; // Threshold for "actively hunting"
+    let current_ghost_is_hunting = hunt_state.active;
 
     match &mut tracker.phase {
         HuntPhaseForSageCheck::NotInHunt => {
@@ -484,7 +455,7 @@ fn trigger_sage_unused_defensively_during_hunt_system(
                         && let Some(sage_data_dyn) = gear_item.data.as_ref()
                         && let Some(sage_data) =
                             <dyn Any>::downcast_ref::<SageBundleData>(sage_data_dyn.as_ref())
-                        && !sage_data.consumed
+                        && sage_data.state() != SageState::Spent
                     {
                         player_has_unconsumed_sage_now = true;
                         break;
@@ -509,7 +480,7 @@ fn trigger_sage_unused_defensively_during_hunt_system(
                             && let Some(sage_data_dyn) = gear_item.data.as_ref()
                             && let Some(sage_data) =
                                 <dyn Any>::downcast_ref::<SageBundleData>(sage_data_dyn.as_ref())
-                            && sage_data.is_active
+                            && sage_data.state() == SageState::Burning
                         {
                             *sage_was_activated_during_this_hunt = true;
                             // info!("Sage activated by player during current hunt.");
@@ -523,8 +494,7 @@ fn trigger_sage_unused_defensively_during_hunt_system(
 }
 
 pub(crate) fn app_setup(app: &mut App) {
-    app.add_systems(Update, quartz_cracked_feedback);
-    app.add_systems(Update, quartz_shattered_feedback);
+    app.add_systems(Update, quartz_feedback);
     app.add_systems(Update, trigger_quartz_unused_in_relevant_situation_system);
     app.add_systems(Update, trigger_sage_unused_in_relevant_situation_system);
     app.add_systems(Update, trigger_sage_activated_ineffectively_system);
@@ -536,3 +506,27 @@ pub(crate) fn app_setup(app: &mut App) {
                 .after(reset_hunt_sage_tracker_on_mission_change),
         );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quartz_transitions_pick_walkie_event() {
+        use QuartzState::*;
+        assert_eq!(
+            quartz_transition_event(Intact, Cracked(1)),
+            Some(WalkieEvent::QuartzCrackedFeedback)
+        );
+        assert_eq!(
+            quartz_transition_event(Cracked(1), Cracked(2)),
+            Some(WalkieEvent::QuartzCrackedFeedback)
+        );
+        assert_eq!(
+            quartz_transition_event(Cracked(3), Shattered),
+            Some(WalkieEvent::QuartzShatteredFeedback)
+        );
+        assert_eq!(quartz_transition_event(Cracked(2), Cracked(2)), None);
+        assert_eq!(quartz_transition_event(Shattered, Shattered), None);
+    }
+}
@@ -175,16 +175,14 @@ fn trigger_room_lights_on_gear_needs_dark(
         return;
     };
     let player_bpos = player_pos.to_board_position();
-    let player_room = roomdb.room_tiles.get(&player_bpos);
-
-    if player_room.is_none() {
+    let Some(player_room) = roomdb.room_tiles.get(&player_bpos) else {
         return;
-    }
+    };
 
     // Use GearUsable::needs_darkness for the right hand gear
     if player_gear.right_hand.needs_darkness()
         && player_gear.right_hand.is_enabled()
-        && board_data.light_field[player_bpos.ndidx()].lux > 0.5
+        && board_data.average_room_lux(player_room, &roomdb) > board_data.light_level_thresholds.lit
     {
         // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
         walkie_play.set(
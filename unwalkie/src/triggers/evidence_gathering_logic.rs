@@ -480,23 +480,13 @@ fn trigger_evidence_confirmed_feedback_system(
             // TODO: Add PlayerProfileData check here to limit hints for experienced players
             // e.g., if player_profile.level > 5 && evidence_type == Evidence::FreezingTemp { continue; }
 
-            let walkie_event_to_send = match evidence_type {
-                Evidence::FreezingTemp => Some(WalkieEvent::FreezingTempsEvidenceConfirmed),
-                Evidence::FloatingOrbs => Some(WalkieEvent::FloatingOrbsEvidenceConfirmed),
-                Evidence::UVEctoplasm => Some(WalkieEvent::UVEctoplasmEvidenceConfirmed),
-                Evidence::EMFLevel5 => Some(WalkieEvent::EMFLevel5EvidenceConfirmed),
-                Evidence::EVPRecording => Some(WalkieEvent::EVPEvidenceConfirmed),
-                Evidence::SpiritBox => Some(WalkieEvent::SpiritBoxEvidenceConfirmed),
-                Evidence::RLPresence => Some(WalkieEvent::RLPresenceEvidenceConfirmed),
-                Evidence::CPM500 => Some(WalkieEvent::CPM500EvidenceConfirmed),
-            };
-
-            if let Some(event_to_send) = walkie_event_to_send {
-                // Attempt to set the event. If successful, mark it in the tracker.
-                if walkie_play.set(event_to_send, time.elapsed_secs_f64()) {
-                    // info!("[Walkie] Triggered {:?} confirmation.", evidence_type);
-                    walkie_play.set_evidence_hint(evidence_type, time.elapsed_secs_f64());
-                }
+            // Attempt to set the event. If successful, mark it in the tracker.
+            if walkie_play.set(
+                WalkieEvent::evidence_confirmed(evidence_type),
+                time.elapsed_secs_f64(),
+            ) {
+                // info!("[Walkie] Triggered {:?} confirmation.", evidence_type);
+                walkie_play.set_evidence_hint(evidence_type, time.elapsed_secs_f64());
             }
         }
     }
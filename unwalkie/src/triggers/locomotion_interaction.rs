@@ -1,47 +1,136 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
 use bevy_persistent::Persistent;
 
-use uncore::behaviour::component::Door;
+use uncore::behaviour::component::{Door, Interactive};
 use uncore::behaviour::{Behaviour, TileState};
 use uncore::components::board::position::Position;
 use uncore::components::player::Hiding;
 use uncore::components::player_sprite::PlayerSprite;
+use uncore::resources::board_data::BoardData;
+use uncore::resources::hunt_state::HuntState;
 use uncore::resources::roomdb::RoomDB;
 use uncore::states::{AppState, GameState};
 use uncore::types::gear_kind::GearKind;
 use ungear::components::playergear::PlayerGear;
 use unprofile::PlayerProfileData;
+use unsettings::controls::{ControlAction, ControlInput};
 use unwalkiecore::{WalkieEvent, WalkiePlay};
 
 const PLAYER_STUCK_MAX_DISTANCE: f32 = 1.0;
+/// Time window, in seconds, in which direction reversals are counted.
 const ERRATIC_MOVEMENT_EARLY_SECONDS: f32 = 5.0;
+/// Erratic movement is only looked for this close to the spawn point.
 const PLAYER_ERRATIC_MAX_DISTANCE: f32 = 6.0;
+/// Erratic movement is only looked for this many seconds after the level is ready.
+const ERRATIC_MOVEMENT_MISSION_SECONDS: f32 = 120.0;
+/// Distance the player must cover before their heading is sampled again. Keeps
+/// standing still or sliding along a wall from producing random headings.
+const ERRATIC_MIN_STEP: f32 = 0.3;
+/// Cosine of the smallest turn counted as a reversal (about 120 degrees).
+const ERRATIC_REVERSAL_COS: f32 = -0.5;
+/// Reversals within `ERRATIC_MOVEMENT_EARLY_SECONDS` that trigger the hint.
+const ERRATIC_REVERSAL_COUNT: usize = 6;
+
+/// How long after the level is ready the player can still be told they're
+/// stuck at the start.
+const PLAYER_STUCK_EARLY_SECONDS: f32 = 180.0;
+/// Seconds between two samples of the player position history.
+const POSITION_SAMPLE_INTERVAL: f32 = 0.25;
+
+/// Failed hide or grab/drop attempts that trigger the struggling hints.
+const STRUGGLE_ATTEMPTS: usize = 3;
+/// Time window, in seconds, in which failed attempts are counted.
+const STRUGGLE_WINDOW_SECONDS: f32 = 10.0;
+/// Distance to a hiding spot within which the activate control hides.
+const HIDE_REACH: f32 = 1.3;
+/// Distance to the control point of an interactive within which the activate
+/// control toggles it instead.
+const ACTIVATE_REACH: f32 = 1.4;
+/// Holding the activate control this long next to a hiding spot without
+/// hiding counts as a failed attempt.
+const HIDE_STRUGGLE_HOLD_SECONDS: f32 = 2.0;
+/// Time hidden after a hunt ends before the player is told it's safe to come
+/// out.
+const HIDDEN_TOO_LONG_SECONDS: f32 = 10.0;
+/// Distance to a hiding spot within which a hunted player is told to hide.
+const HUNT_HIDING_SPOT_RANGE: f32 = 2.0;
+/// Time the hunted player has to stay in the open next to a hiding spot
+/// before being told to hide.
+const HUNT_NEAR_HIDING_SPOT_SECONDS: f32 = 1.0;
+
+/// Recent player positions, used to tell whether the player is standing still.
+///
+/// Samples are timed with the history's own clock, which only advances while
+/// the detector runs, so time spent in the truck or paused doesn't count.
+#[derive(Debug, Default)]
+struct PositionHistory {
+    clock: f32,
+    samples: VecDeque<(f32, Position)>,
+}
+
+impl PositionHistory {
+    /// Advances the clock and records the position, keeping just enough
+    /// samples to cover the last `window` seconds.
+    fn record(&mut self, dt: f32, pos: Position, window: f32) {
+        self.clock += dt;
+        let due = self
+            .samples
+            .back()
+            .is_none_or(|(t, _)| self.clock - t >= POSITION_SAMPLE_INTERVAL);
+        if !due {
+            return;
+        }
+        self.samples.push_back((self.clock, pos));
+        while self.samples.len() > 2 && self.samples[1].0 <= self.clock - window {
+            self.samples.pop_front();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// True when the history covers at least `window` seconds and the player
+    /// never got `max_distance` away from where it started.
+    fn is_stationary(&self, window: f32, max_distance: f32) -> bool {
+        let (Some((first_time, first_pos)), Some((last_time, _))) =
+            (self.samples.front(), self.samples.back())
+        else {
+            return false;
+        };
+        last_time - first_time >= window
+            && self
+                .samples
+                .iter()
+                .all(|(_, pos)| pos.distance(first_pos) < max_distance)
+    }
+}
 
-/// Checks if the player is stuck at the spawn point for too long at the start of a mission.
+/// Checks if the player is stuck at the start of a mission.
 ///
-/// If the player hasn't entered the location and remains near the spawn for a threshold duration,
-/// triggers a walkie-talkie warning. The threshold is higher for experienced players.
+/// Early in the mission, if the player hasn't entered the location nor opened
+/// the truck, and their position history shows they barely moved for a
+/// threshold duration, triggers a walkie-talkie warning. The threshold is
+/// higher for experienced players.
 fn check_player_stuck_at_start(
     time: Res<Time>,
-    game_state: Res<State<GameState>>,
     app_state: Res<State<AppState>>,
+    board_data: Res<BoardData>,
     roomdb: Res<RoomDB>,
-    player_query: Query<(&Position, &PlayerSprite)>,
+    player_query: Query<&Position, With<PlayerSprite>>,
     mut walkie_play: ResMut<WalkiePlay>,
-    mut stuck_timer: Local<Stopwatch>,
+    mut history: Local<PositionHistory>,
     player_profile: Res<Persistent<PlayerProfileData>>,
 ) {
     let mut min_time_secs: f32 = 7.0;
     if app_state.get() != &AppState::InGame {
-        stuck_timer.reset();
-        return;
-    }
-    if *game_state.get() != GameState::None {
-        stuck_timer.reset();
+        history.clear();
         return;
     }
-    let Ok((player_position, player_sprite)) = player_query.single() else {
+    let Ok(player_position) = player_query.single() else {
         return;
     };
 
@@ -57,53 +146,90 @@ fn check_player_stuck_at_start(
     if player_profile.statistics.total_missions_completed > 9 {
         min_time_secs = 90.0;
     }
-    // If the player is already inside the location, reset the stuck time
-    if roomdb
+    // If the player is already inside the location or went to the truck,
+    // they're clearly not stuck.
+    let inside = roomdb
         .room_tiles
         .get(&player_position.to_board_position())
-        .is_some()
-    {
-        stuck_timer.reset();
+        .is_some();
+    if inside || walkie_play.truck_accessed {
+        history.clear();
         walkie_play.mark(WalkieEvent::PlayerStuckAtStart, time.elapsed_secs_f64());
         return;
     }
+    if time.elapsed_secs() - board_data.level_ready_time > PLAYER_STUCK_EARLY_SECONDS {
+        history.clear();
+        return;
+    }
 
-    let distance_from_spawn = player_position.distance(&player_sprite.spawn_position);
+    history.record(time.delta_secs(), *player_position, min_time_secs);
+    if history.is_stationary(min_time_secs, PLAYER_STUCK_MAX_DISTANCE)
+        && walkie_play.set(WalkieEvent::PlayerStuckAtStart, time.elapsed_secs_f64())
+    {
+        history.clear();
+    }
+}
 
-    if distance_from_spawn < PLAYER_STUCK_MAX_DISTANCE {
-        stuck_timer.tick(time.delta());
-    } else {
-        stuck_timer.reset();
-        walkie_play.mark(WalkieEvent::PlayerStuckAtStart, time.elapsed_secs_f64());
+/// Counts sharp changes of heading in the player's recent movement.
+#[derive(Debug, Default)]
+struct ReversalTracker {
+    clock: f32,
+    last_position: Option<Position>,
+    last_heading: Option<Vec2>,
+    /// Times of the reversals still inside the window.
+    reversals: VecDeque<f32>,
+}
+
+impl ReversalTracker {
+    /// Feeds a new player position, returning how many reversals happened in
+    /// the last `window` seconds.
+    fn record(&mut self, dt: f32, pos: Position, window: f32) -> usize {
+        self.clock += dt;
+        let last = *self.last_position.get_or_insert(pos);
+        let step = Vec2::new(pos.x - last.x, pos.y - last.y);
+        if step.length() >= ERRATIC_MIN_STEP {
+            let heading = step.normalize();
+            if self
+                .last_heading
+                .is_some_and(|prev| prev.dot(heading) < ERRATIC_REVERSAL_COS)
+            {
+                self.reversals.push_back(self.clock);
+            }
+            self.last_heading = Some(heading);
+            self.last_position = Some(pos);
+        }
+        while self
+            .reversals
+            .front()
+            .is_some_and(|t| self.clock - t > window)
+        {
+            self.reversals.pop_front();
+        }
+        self.reversals.len()
     }
 
-    if stuck_timer.elapsed_secs() > min_time_secs {
-        // warn!("Player stuck at start for {} seconds", stuck_timer.elapsed_secs());
-        walkie_play.set(WalkieEvent::PlayerStuckAtStart, time.elapsed_secs_f64());
+    fn clear(&mut self) {
+        *self = Self::default();
     }
 }
 
 /// Detects erratic movement patterns early in the mission for new players.
 ///
-/// If the player moves back and forth near the spawn without entering the location for several seconds,
-/// triggers a walkie-talkie warning. Only applies to players with few completed missions.
+/// If the player keeps reversing direction near the spawn without entering the
+/// location, which usually means they're mashing the movement keys, triggers a
+/// walkie-talkie warning. Only applies to players with few completed missions.
 fn check_erratic_movement_early(
     time: Res<Time>,
-    game_state: Res<State<GameState>>,
     app_state: Res<State<AppState>>,
+    board_data: Res<BoardData>,
     roomdb: Res<RoomDB>,
     player_query: Query<(&Position, &PlayerSprite)>,
     mut walkie_play: ResMut<WalkiePlay>,
-    mut not_entered_timer: Local<Stopwatch>,
-    mut avg_position: Local<Option<Position>>,
+    mut tracker: Local<ReversalTracker>,
     player_profile: Res<Persistent<PlayerProfileData>>,
 ) {
     if app_state.get() != &AppState::InGame {
-        not_entered_timer.reset();
-        *avg_position = None;
-        return;
-    }
-    if *game_state.get() != GameState::None {
+        tracker.clear();
         return;
     }
 
@@ -116,38 +242,35 @@ fn check_erratic_movement_early(
         return;
     };
 
-    let m_avg = avg_position.get_or_insert_with(|| *player_position);
-    *m_avg = m_avg.lerp(player_position, 0.5 * time.delta_secs());
-
     // Check if player is inside any room
     if roomdb
         .room_tiles
         .get(&player_position.to_board_position())
         .is_some()
     {
-        not_entered_timer.reset();
+        tracker.clear();
         walkie_play.mark(WalkieEvent::ErraticMovementEarly, time.elapsed_secs_f64());
         return;
     }
 
-    // If player is not in a room and in GameState::None, increment timer
     let distance_from_spawn = player_position.distance(&player_sprite.spawn_position);
-    let distance_from_avg = player_position.distance(m_avg);
-
-    if distance_from_avg > 3.0 {
-        not_entered_timer.reset();
-        return;
-    }
-    if distance_from_spawn > PLAYER_STUCK_MAX_DISTANCE
-        && distance_from_spawn < PLAYER_ERRATIC_MAX_DISTANCE
-        && player_sprite.movement.distance() > 60.0
+    let mission_time = time.elapsed_secs() - board_data.level_ready_time;
+    if distance_from_spawn > PLAYER_ERRATIC_MAX_DISTANCE
+        || mission_time > ERRATIC_MOVEMENT_MISSION_SECONDS
     {
-        // Ignore when the player is stuck or stopped.
-        not_entered_timer.tick(time.delta());
+        tracker.clear();
+        return;
     }
 
-    if not_entered_timer.elapsed_secs() > ERRATIC_MOVEMENT_EARLY_SECONDS {
-        walkie_play.set(WalkieEvent::ErraticMovementEarly, time.elapsed_secs_f64());
+    let reversals = tracker.record(
+        time.delta_secs(),
+        *player_position,
+        ERRATIC_MOVEMENT_EARLY_SECONDS,
+    );
+    if reversals >= ERRATIC_REVERSAL_COUNT
+        && walkie_play.set(WalkieEvent::ErraticMovementEarly, time.elapsed_secs_f64())
+    {
+        tracker.clear();
     }
 }
 
@@ -219,143 +342,219 @@ fn check_door_interaction_hesitation(
     }
 }
 
-/// Triggers a walkie-talkie event if the player attempts to grab an item when their inventory and hand are full,
-/// and this state of attempting to grab while full persists.
+/// Counts failed attempts at an action, like hiding or grabbing.
+#[derive(Debug, Default)]
+struct StruggleDetector {
+    /// Times of the failed attempts still inside the window.
+    failures: VecDeque<f32>,
+    /// Enough attempts failed, and the walkie hasn't accepted the hint yet.
+    pending: bool,
+}
+
+impl StruggleDetector {
+    /// Records a failed attempt at `now`. Returns true, and starts counting
+    /// again, once `threshold` attempts failed within `window` seconds.
+    fn record_failure(&mut self, now: f32, window: f32, threshold: usize) -> bool {
+        while self.failures.front().is_some_and(|t| now - t > window) {
+            self.failures.pop_front();
+        }
+        self.failures.push_back(now);
+        if self.failures.len() < threshold {
+            return false;
+        }
+        self.failures.clear();
+        self.pending = true;
+        true
+    }
+
+    /// Plays the pending hint. The walkie may be busy or cooling down, so it's
+    /// tried again on every call until it's accepted.
+    fn play_pending(&mut self, walkie_play: &mut WalkiePlay, event: WalkieEvent, time: f64) {
+        if self.pending && walkie_play.set(event, time) {
+            self.pending = false;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.failures.clear();
+        self.pending = false;
+    }
+}
+
+/// Triggers a walkie-talkie event if the player keeps failing to grab or drop.
+///
+/// A grab fails when the hand and inventory are full, and a drop fails when
+/// there's nothing to drop. `STRUGGLE_ATTEMPTS` failures within
+/// `STRUGGLE_WINDOW_SECONDS` trigger the hint.
 fn trigger_struggling_with_grab_drop(
     time: Res<Time>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
     mut walkie_play: ResMut<WalkiePlay>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    controls: ControlInput,
     player_query: Query<(&PlayerGear, &PlayerSprite)>,
-    mut full_and_failed_grab_timer: Local<Option<Stopwatch>>,
+    mut detector: Local<StruggleDetector>,
 ) {
-    // 1. System Run Condition
     if *app_state.get() != AppState::InGame || *game_state.get() != GameState::None {
-        *full_and_failed_grab_timer = None;
+        detector.clear();
         return;
     }
-
     let Ok((player_gear, player_sprite)) = player_query.single() else {
-        *full_and_failed_grab_timer = None;
+        detector.clear();
         return;
     };
-    if player_gear.held_item.is_some() {
-        // If the player is already grabbing something, just skip this hint.
-        *full_and_failed_grab_timer = None;
-        return;
-    }
-    // 2.b. Check Player Full State
-    let right_hand_full = !player_gear.empty_right_handed();
-    let inventory_full = player_gear
-        .as_vec()
-        .iter()
-        .all(|(g, _)| !matches!(g.kind, GearKind::None)); // Corrected to use GearKind::None
-    let player_is_completely_full = right_hand_full && inventory_full;
-
-    // 3.c. Detecting a Failed Grab Attempt to Start/Check Timer
-    if keyboard_input.just_pressed(player_sprite.controls.grab) && player_is_completely_full {
-        if full_and_failed_grab_timer.is_none() {
-            *full_and_failed_grab_timer = Some(Stopwatch::new());
-            // Timer starts, will be ticked below if it's Some.
-        }
-        // If timer was already Some (player pressed grab again while full and timer running), it just continues.
-    } else if !player_is_completely_full && full_and_failed_grab_timer.is_some() {
-        // Player is no longer full, so reset the timer.
-        *full_and_failed_grab_timer = None;
-    }
-
-    // 3.d. Triggering Logic (if timer is Some)
-    if let Some(ref mut timer_ref) = *full_and_failed_grab_timer {
-        timer_ref.tick(time.delta()); // Tick the timer each frame it's Some
-
-        // Re-check player_is_completely_full because they might have dropped/used an item
-        // through a means other than the grab key (e.g., using a consumable from inventory directly)
-        // which would not have reset the timer in the block above.
-        let updated_right_hand_full = !player_gear.empty_right_handed();
-        let updated_inventory_full = player_gear
-            .inventory // Assuming inventory is the correct field name
+
+    let completely_full = !player_gear.empty_right_handed()
+        && player_gear
+            .as_vec()
             .iter()
-            .all(|g| !matches!(g.kind, GearKind::None));
-        let updated_player_is_completely_full = updated_right_hand_full && updated_inventory_full;
-
-        if updated_player_is_completely_full {
-            if timer_ref.elapsed_secs() > 5.0 {
-                // Duration player struggles
-                // FIXME: Additional verification and tuning is needed for this trigger. It worked before, but it was too much.
-                if walkie_play.set(WalkieEvent::StrugglingWithGrabDrop, time.elapsed_secs_f64()) {
-                    *full_and_failed_grab_timer = None; // Reset timer after successful trigger
-                }
-            }
-        } else {
-            // Player resolved the full state while timer was running
-            *full_and_failed_grab_timer = None;
-        }
-    }
+            .all(|(g, _)| !matches!(g.kind, GearKind::None));
+    let failed_grab = controls.just_pressed(&player_sprite.controls, ControlAction::Grab)
+        && player_gear.held_item.is_none()
+        && completely_full;
+    let failed_drop = controls.just_pressed(&player_sprite.controls, ControlAction::Drop)
+        && player_gear.held_item.is_none()
+        && player_gear.empty_right_handed();
+    if failed_grab || failed_drop {
+        detector.record_failure(
+            time.elapsed_secs(),
+            STRUGGLE_WINDOW_SECONDS,
+            STRUGGLE_ATTEMPTS,
+        );
+    }
+    detector.play_pending(
+        &mut walkie_play,
+        WalkieEvent::StrugglingWithGrabDrop,
+        time.elapsed_secs_f64(),
+    );
 }
 
-/// Triggers a walkie-talkie event if the player struggles to hide by holding [E] for over 2 seconds while not hidden.
+/// Triggers a walkie-talkie event if the player keeps failing to hide.
 ///
-/// This detects when a player holds the hide key ([E]) for over 2 seconds consecutively
-/// without successfully hiding, indicating they're struggling because they're carrying a house item.
+/// An attempt fails when the activate control is let go next to a hiding spot
+/// without the player ending up hidden: usually because they're carrying a
+/// house item, or let go before the hold finished. Holding it for
+/// `HIDE_STRUGGLE_HOLD_SECONDS` without hiding also counts as a failure.
+/// Presses that toggle a door or a switch in reach are not attempts.
+/// `STRUGGLE_ATTEMPTS` failures within `STRUGGLE_WINDOW_SECONDS` trigger the
+/// hint.
 fn trigger_struggling_with_hide_unhide(
     time: Res<Time>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
     mut walkie_play: ResMut<WalkiePlay>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    player_query: Query<&PlayerSprite, Without<Hiding>>,
-    mut hide_key_timer: Local<Option<Stopwatch>>,
+    controls: ControlInput,
+    player_query: Query<(&PlayerSprite, &Position), Without<Hiding>>,
+    hiding_spots: Query<(&Position, &Behaviour)>,
+    interactives: Query<(&Position, &Interactive, &Behaviour)>,
+    mut hold_timer: Local<Option<Stopwatch>>,
+    mut detector: Local<StruggleDetector>,
 ) {
-    if app_state.get() != &AppState::InGame {
-        *hide_key_timer = None;
+    if *app_state.get() != AppState::InGame || *game_state.get() != GameState::None {
+        *hold_timer = None;
+        detector.clear();
         return;
     }
-    if *game_state.get() != GameState::None {
-        *hide_key_timer = None;
+    detector.play_pending(
+        &mut walkie_play,
+        WalkieEvent::StrugglingWithHideUnhide,
+        time.elapsed_secs_f64(),
+    );
+    // Only proceed if player is not hiding
+    let Ok((player_sprite, player_pos)) = player_query.single() else {
+        *hold_timer = None;
+        return;
+    };
+    let near_hiding_spot = hiding_spots.iter().any(|(pos, behaviour)| {
+        behaviour.p.object.hidingspot && player_pos.distance(pos) < HIDE_REACH
+    });
+    if !near_hiding_spot {
+        *hold_timer = None;
         return;
     }
 
-    // Only proceed if player is not hiding
-    let Ok(player_sprite) = player_query.single() else {
-        *hide_key_timer = None;
+    // Only presses that start while not hidden are attempts; the press that
+    // leaves a hiding spot is not, and neither is one that toggles a door or
+    // a switch in reach.
+    if controls.just_pressed(&player_sprite.controls, ControlAction::Activate)
+        && !interactive_in_reach(player_pos, &interactives)
+    {
+        *hold_timer = Some(Stopwatch::new());
+    }
+    let Some(timer) = hold_timer.as_mut() else {
         return;
     };
+    let failed = if controls.pressed(&player_sprite.controls, ControlAction::Activate) {
+        timer.tick(time.delta());
+        timer.elapsed_secs() > HIDE_STRUGGLE_HOLD_SECONDS
+    } else {
+        true
+    };
+    if failed {
+        *hold_timer = None;
+    }
 
-    // Check if the hide key (activate key, typically [E]) is currently pressed
-    let hide_key_pressed = keyboard_input.pressed(player_sprite.controls.activate);
+    if failed {
+        detector.record_failure(
+            time.elapsed_secs(),
+            STRUGGLE_WINDOW_SECONDS,
+            STRUGGLE_ATTEMPTS,
+        );
+    }
+}
 
-    if hide_key_pressed {
-        // Start or continue timer if key is pressed
-        if hide_key_timer.is_none() {
-            *hide_key_timer = Some(Stopwatch::new());
-        }
+/// Whether an interactive other than a hiding spot is within `ACTIVATE_REACH`
+/// of the player, so the activate control acts on it.
+fn interactive_in_reach(
+    player_pos: &Position,
+    interactives: &Query<(&Position, &Interactive, &Behaviour)>,
+) -> bool {
+    interactives.iter().any(|(pos, interactive, behaviour)| {
+        let cp_delta = interactive.control_point_delta(behaviour);
+        let control_point = Position {
+            x: pos.x + cp_delta.x,
+            y: pos.y + cp_delta.y,
+            z: pos.z + cp_delta.z,
+            global_z: pos.global_z,
+        };
+        !behaviour.p.object.hidingspot
+            && pos.z.round() == player_pos.z.round()
+            && player_pos.distance(&control_point) < ACTIVATE_REACH
+    })
+}
 
-        if let Some(ref mut timer) = *hide_key_timer {
-            timer.tick(time.delta());
+/// Time the player has spent hidden while it was safe to come out.
+#[derive(Debug, Default)]
+struct HiddenTimer {
+    safe_secs: f32,
+    /// The hint was given during this hiding session.
+    fired: bool,
+}
 
-            // If player has been holding [E] for over 2 seconds while not hidden, trigger event
-            if timer.elapsed_secs() > 2.0
-                && walkie_play.set(
-                    WalkieEvent::StrugglingWithHideUnhide,
-                    time.elapsed_secs_f64(),
-                )
-            {
-                // FIXME: Additional verification and tuning is needed for this trigger.
-                // Reset timer after successful trigger to avoid spam
-                *hide_key_timer = None;
-            }
+impl HiddenTimer {
+    /// Advances the timer. Leaving the hiding spot starts a new session, and
+    /// any danger (a hunt or a ghost close to one) starts the count over.
+    fn tick(&mut self, dt: f32, hidden: bool, safe: bool) {
+        if !hidden {
+            *self = Self::default();
+        } else if !safe {
+            self.safe_secs = 0.0;
+        } else {
+            self.safe_secs += dt;
         }
-    } else {
-        // Reset timer if key is not pressed
-        *hide_key_timer = None;
+    }
+
+    /// Whether the player should be told it's safe to come out.
+    fn is_due(&self) -> bool {
+        !self.fired && self.safe_secs > HIDDEN_TOO_LONG_SECONDS
     }
 }
 
 /// Triggers a walkie-talkie event if the player stays hidden for too long after a hunt ends.
 ///
-/// If the player remains hidden for 10+ seconds after the ghost's hunt ends, this event is triggered to inform them it's safe to unhide.
-/// This event will not fire if any ghost's rage is above 20% of its rage limit.
+/// If the player remains hidden for `HIDDEN_TOO_LONG_SECONDS` after the ghost's hunt ends, this
+/// event is triggered to inform them it's safe to unhide. It fires at most once per hiding
+/// session, and not while any ghost's rage is above 20% of its rage limit.
 fn trigger_player_stays_hidden_too_long(
     time: Res<Time>,
     app_state: Res<State<AppState>>,
@@ -363,104 +562,91 @@ fn trigger_player_stays_hidden_too_long(
     mut walkie_play: ResMut<WalkiePlay>,
     hiding_query: Query<Entity, With<Hiding>>,
     ghost_query: Query<&uncore::components::ghost_sprite::GhostSprite>,
-    mut post_hunt_hidden_timer: Local<Option<f32>>,
+    hunt_state: Res<HuntState>,
+    mut hidden_timer: Local<HiddenTimer>,
 ) {
-    if app_state.get() != &AppState::InGame {
-        *post_hunt_hidden_timer = None;
+    if app_state.get() != &AppState::InGame || *game_state.get() != GameState::None {
+        *hidden_timer = HiddenTimer::default();
         return;
     }
-    if *game_state.get() != GameState::None {
-        *post_hunt_hidden_timer = None;
-        return;
-    }
-    // Only proceed if player is hiding
-    if hiding_query.single().is_err() {
-        *post_hunt_hidden_timer = None;
-        return;
-    }
-    // Check if any ghost is currently hunting
-    let hunt_active = ghost_query.iter().any(|g| g.hunting > 0.0);
-    if hunt_active {
-        *post_hunt_hidden_timer = None;
-        return;
-    }
-    // Check if any ghost's rage is above 20% of its rage limit
-    let high_rage = ghost_query.iter().any(|g| g.rage > g.rage_limit * 0.2);
-    if high_rage {
-        *post_hunt_hidden_timer = None;
-        return;
-    }
-    // Player is hiding and hunt is over
-    let now = time.elapsed_secs_f64() as f32;
-    if let Some(start_time) = *post_hunt_hidden_timer {
-        if now - start_time > 10.0 {
-            // FIXME: Additional verification and tuning is needed for this trigger.
-            walkie_play.set(
-                WalkieEvent::PlayerStaysHiddenTooLong,
-                time.elapsed_secs_f64(),
-            );
-            // Only trigger once per hiding session
-            *post_hunt_hidden_timer = None;
-        }
-    } else {
-        // Start timer when hunt ends and player is still hiding
-        *post_hunt_hidden_timer = Some(now);
+    let hidden = hiding_query.single().is_ok();
+    let safe = !hunt_state.active && ghost_query.iter().all(|g| g.rage <= g.rage_limit * 0.2);
+    hidden_timer.tick(time.delta_secs(), hidden, safe);
+
+    if hidden_timer.is_due()
+        && walkie_play.set(
+            WalkieEvent::PlayerStaysHiddenTooLong,
+            time.elapsed_secs_f64(),
+        )
+    {
+        hidden_timer.fired = true;
     }
 }
 
+/// Whether the player is out in the open within reach of one of the hiding
+/// spots.
+fn exposed_near_hiding_spot<'a>(
+    player_pos: &Position,
+    hidden: bool,
+    hiding_spots: impl IntoIterator<Item = &'a Position>,
+) -> bool {
+    !hidden
+        && hiding_spots
+            .into_iter()
+            .any(|spot| player_pos.distance(spot) < HUNT_HIDING_SPOT_RANGE)
+}
+
 /// Triggers a walkie-talkie event if the player is near a hiding spot during a hunt but does not hide.
-/// Fires HuntActiveNearHidingSpotNoHide if ghost is hunting, player is not hiding, and a hiding spot is within 1.5 units for 2+ seconds.
+///
+/// Hiding spots are the interactives flagged as such in the map data. Fires
+/// HuntActiveNearHidingSpotNoHide once per hunt, after the ghost has locked on
+/// and the player has stayed in the open next to a hiding spot for
+/// `HUNT_NEAR_HIDING_SPOT_SECONDS`.
 fn trigger_hunt_active_near_hiding_spot_no_hide(
     time: Res<Time>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
     mut walkie_play: ResMut<WalkiePlay>,
-    player_query: Query<(&Position, Entity), Without<Hiding>>,
+    player_query: Query<(&Position, Has<Hiding>), With<PlayerSprite>>,
     hiding_spots: Query<(&Position, &Behaviour)>,
-    ghost_query: Query<&uncore::components::ghost_sprite::GhostSprite>,
-    mut near_hiding_timer: Local<Option<f32>>,
+    hunt_state: Res<HuntState>,
+    mut near_since: Local<Option<f32>>,
+    mut warned_this_hunt: Local<bool>,
 ) {
-    if app_state.get() != &AppState::InGame {
-        *near_hiding_timer = None;
-        return;
-    }
-    if *game_state.get() != GameState::None {
-        *near_hiding_timer = None;
-        return;
-    }
-    // Check if any ghost is actively hunting (hunting > 10.0)
-    let hunt_active = ghost_query.iter().any(|g| g.hunting > 10.0);
+    let hunt_active = hunt_state.active;
     if !hunt_active {
-        *near_hiding_timer = None;
+        *warned_this_hunt = false;
+    }
+    if app_state.get() != &AppState::InGame
+        || *game_state.get() != GameState::None
+        || !hunt_active
+        || *warned_this_hunt
+    {
+        *near_since = None;
         return;
     }
-    // Get player position (not hiding)
-    let Ok((player_pos, _)) = player_query.single() else {
-        *near_hiding_timer = None;
+    let Ok((player_pos, hidden)) = player_query.single() else {
+        *near_since = None;
         return;
     };
-    // Find a hiding spot within 1.5 units
-    let near_hiding = hiding_spots
+    let spots = hiding_spots
         .iter()
         .filter(|(_, behaviour)| behaviour.p.object.hidingspot)
-        .any(|(spot_pos, _)| player_pos.distance(spot_pos) < 1.5);
-    if near_hiding {
-        let now = time.elapsed_secs_f64() as f32;
-        if let Some(start) = *near_hiding_timer {
-            if now - start > 2.0 {
-                // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
-                walkie_play.set(
-                    WalkieEvent::HuntActiveNearHidingSpotNoHide,
-                    time.elapsed_secs_f64(),
-                );
-                // Only trigger once per hunt
-                *near_hiding_timer = None;
-            }
-        } else {
-            *near_hiding_timer = Some(now);
-        }
-    } else {
-        *near_hiding_timer = None;
+        .map(|(pos, _)| pos);
+    if !exposed_near_hiding_spot(player_pos, hidden, spots) {
+        *near_since = None;
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    let start = *near_since.get_or_insert(now);
+    if now - start > HUNT_NEAR_HIDING_SPOT_SECONDS
+        && walkie_play.set(
+            WalkieEvent::HuntActiveNearHidingSpotNoHide,
+            time.elapsed_secs_f64(),
+        )
+    {
+        *warned_this_hunt = true;
     }
 }
 
@@ -474,7 +660,7 @@ pub(crate) fn app_setup(app: &mut App) {
             check_player_stuck_at_start,
             check_erratic_movement_early,
             check_door_interaction_hesitation,
-            trigger_struggling_with_grab_drop, // This is the modified system
+            trigger_struggling_with_grab_drop,
             trigger_struggling_with_hide_unhide,
             trigger_player_stays_hidden_too_long,
             trigger_hunt_active_near_hiding_spot_no_hide,
@@ -482,3 +668,147 @@ pub(crate) fn app_setup(app: &mut App) {
             .run_if(in_state(GameState::None)), // Corrected in_state path
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(history: &mut PositionHistory, positions: impl Iterator<Item = Position>) {
+        for pos in positions {
+            history.record(0.1, pos, 10.0);
+        }
+    }
+
+    #[test]
+    fn test_stationary_history_counts_as_stuck() {
+        let mut history = PositionHistory::default();
+        // Fidgeting on the spot for 12 seconds.
+        feed(
+            &mut history,
+            (0..120).map(|i| {
+                Position::new_i64(2, 3, 0).lerp(&Position::new_i64(2, 4, 0), (i % 5) as f32 * 0.1)
+            }),
+        );
+        assert!(history.is_stationary(10.0, PLAYER_STUCK_MAX_DISTANCE));
+
+        // Not enough time has been recorded yet.
+        let mut short = PositionHistory::default();
+        feed(&mut short, (0..50).map(|_| Position::new_i64(2, 3, 0)));
+        assert!(!short.is_stationary(10.0, PLAYER_STUCK_MAX_DISTANCE));
+    }
+
+    #[test]
+    fn test_moving_history_is_not_stuck() {
+        let mut history = PositionHistory::default();
+        // Walking away at 0.5 tiles per second.
+        feed(
+            &mut history,
+            (0..120).map(|i| {
+                Position::new_i64(2, 3, 0).lerp(&Position::new_i64(8, 3, 0), i as f32 / 120.0)
+            }),
+        );
+        assert!(!history.is_stationary(10.0, PLAYER_STUCK_MAX_DISTANCE));
+    }
+
+    #[test]
+    fn test_jittery_path_counts_reversals() {
+        let mut tracker = ReversalTracker::default();
+        let mut reversals = 0;
+        // Zig-zagging left and right half a tile at a time, every 0.2 seconds.
+        for i in 0..20 {
+            let x = if i % 2 == 0 { 0.0 } else { 0.5 };
+            let pos = Position {
+                x,
+                y: i as f32 * 0.05,
+                ..Position::new_i64(0, 0, 0)
+            };
+            reversals = tracker.record(0.2, pos, ERRATIC_MOVEMENT_EARLY_SECONDS);
+        }
+        assert!(reversals >= ERRATIC_REVERSAL_COUNT);
+
+        // Walking in a gentle curve has no reversals.
+        let mut tracker = ReversalTracker::default();
+        for i in 0..40 {
+            let t = i as f32 * 0.1;
+            let pos = Position {
+                x: t * 2.0,
+                y: t * t * 0.3,
+                ..Position::new_i64(0, 0, 0)
+            };
+            reversals = tracker.record(0.1, pos, ERRATIC_MOVEMENT_EARLY_SECONDS);
+        }
+        assert_eq!(reversals, 0);
+    }
+
+    #[test]
+    fn test_struggle_needs_repeated_failures() {
+        let mut detector = StruggleDetector::default();
+        assert!(!detector.record_failure(1.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+        assert!(!detector.record_failure(4.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+        assert!(detector.record_failure(8.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+
+        // Counting starts again after firing.
+        assert!(!detector.record_failure(9.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+        assert!(!detector.record_failure(10.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+
+        // Failures spread out over more than the window don't add up.
+        assert!(!detector.record_failure(25.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+        assert!(!detector.record_failure(30.0, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+        assert!(!detector.record_failure(40.5, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS));
+    }
+
+    #[test]
+    fn test_struggle_hint_waits_for_walkie() {
+        let mut detector = StruggleDetector::default();
+        for now in [1.0, 2.0, 3.0] {
+            detector.record_failure(now, STRUGGLE_WINDOW_SECONDS, STRUGGLE_ATTEMPTS);
+        }
+        let mut walkie_play = WalkiePlay {
+            priority_bar: f32::MAX,
+            last_message_time: f64::NEG_INFINITY,
+            ..default()
+        };
+        detector.play_pending(&mut walkie_play, WalkieEvent::StrugglingWithGrabDrop, 3.0);
+        assert!(detector.pending);
+
+        walkie_play.priority_bar = 0.0;
+        detector.play_pending(&mut walkie_play, WalkieEvent::StrugglingWithGrabDrop, 4.0);
+        assert!(!detector.pending);
+    }
+
+    #[test]
+    fn test_hunted_player_next_to_hiding_spot() {
+        let player = Position::new_i64(4, 4, 0);
+        let spots = [Position::new_i64(5, 4, 0), Position::new_i64(12, 9, 0)];
+        assert!(exposed_near_hiding_spot(&player, false, &spots));
+        // Already hidden.
+        assert!(!exposed_near_hiding_spot(&player, true, &spots));
+        // Nowhere to hide nearby.
+        assert!(!exposed_near_hiding_spot(&player, false, &spots[1..]));
+    }
+
+    #[test]
+    fn test_hidden_timer_counts_from_hunt_end() {
+        let mut timer = HiddenTimer::default();
+        // Hiding during the hunt doesn't count.
+        for _ in 0..30 {
+            timer.tick(1.0, true, false);
+        }
+        assert!(!timer.is_due());
+
+        for _ in 0..10 {
+            timer.tick(1.0, true, true);
+        }
+        assert!(!timer.is_due());
+        timer.tick(0.5, true, true);
+        assert!(timer.is_due());
+        timer.fired = true;
+        timer.tick(5.0, true, true);
+        assert!(!timer.is_due());
+
+        // Unhiding resets it.
+        timer.tick(1.0, false, true);
+        timer.tick(5.0, true, true);
+        assert!(!timer.is_due());
+    }
+}
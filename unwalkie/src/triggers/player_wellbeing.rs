@@ -1,7 +1,12 @@
 use bevy::app::App;
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
+use bevy_persistent::Persistent;
+use std::time::Duration;
+use uncore::behaviour::Behaviour;
 use uncore::components::light::LightLevel;
+use uncore::components::waypoint::{Waypoint, WaypointQueue, WaypointType};
+use uncore::events::truck::GoToTruckEvent;
 use uncore::{
     components::{
         board::position::Position,
@@ -9,30 +14,138 @@ use uncore::{
         player::Hiding,            // Added Hiding
         player_sprite::PlayerSprite,
     },
-    resources::{board_data::BoardData, roomdb::RoomDB}, // Added BoardData
+    difficulty::CurrentDifficulty,
+    resources::{board_data::BoardData, hunt_state::HuntState, roomdb::RoomDB}, // Added BoardData
     states::{AppState, GameState},
 };
+use unsettings::game::{GameplaySettings, LowSanityGuidance};
 use unwalkiecore::{WalkieEvent, WalkiePlay}; // Corrected import for LightLevel
 
 // Constants for SanityDroppedBelowThresholdDarkness
-const LOW_LUX_THRESHOLD: f32 = 0.1;
+const LOW_LUX_THRESHOLD: f32 = uncore::sanity::DARKNESS_LUX_THRESHOLD;
 const MIN_TIME_IN_DARKNESS_FOR_HINT_SECONDS: f32 = 25.0; // Reduced from 45 to 25 seconds
 // SANITY_DROP_THRESHOLD_POINTS and MAX_SANITY_FOR_HINT_PERCENT are now shared
 const SANITY_DROP_THRESHOLD_POINTS_SHARED: f32 = 10.0; // Reduced from 15 to 10 points
 const MAX_SANITY_FOR_HINT_PERCENT_SHARED: f32 = 70.0; // Increased from 65% to 70%
 
+/// How far above the hint threshold sanity must climb before the sanity hints
+/// can fire again.
+const SANITY_HINT_REARM_MARGIN: f32 = 10.0;
+
 // Constants for SanityDroppedBelowThresholdGhost
-const GHOST_PROXIMITY_THRESHOLD: f32 = 3.0;
+const GHOST_PROXIMITY_THRESHOLD: f32 = uncore::sanity::GHOST_PROXIMITY_DISTANCE;
 const MIN_INTERACTION_DURATION_SECONDS: f32 = 7.0; // Reduced from 10 to 7 seconds
 
+/// Health below which the low health warning fires.
+const LOW_HEALTH_THRESHOLD: f32 = 50.0;
+
+/// How far above the threshold health must recover before the low health
+/// warning can fire again.
+const LOW_HEALTH_REARM_MARGIN: f32 = 10.0;
+
+/// Edge trigger with hysteresis for a sanity or health threshold.
+///
+/// A hint may fire once after the value goes below `threshold`; it is only
+/// re-armed when it recovers above `threshold + rearm_margin`. This keeps the
+/// hint from firing again and again while the value hovers near the threshold.
+/// Defaults to the sanity hint threshold.
+///
+/// `ceiling` is the highest value the player can recover to (sanity on the
+/// harder difficulties). The re-arm level is capped to it, and the threshold
+/// is kept a margin below it, so the latch can always re-arm.
+#[derive(Debug, Clone, Copy)]
+struct ThresholdLatch {
+    threshold: f32,
+    rearm_margin: f32,
+    ceiling: f32,
+    armed: bool,
+}
+
+impl Default for ThresholdLatch {
+    fn default() -> Self {
+        Self::new(MAX_SANITY_FOR_HINT_PERCENT_SHARED, SANITY_HINT_REARM_MARGIN)
+    }
+}
+
+impl ThresholdLatch {
+    fn new(threshold: f32, rearm_margin: f32) -> Self {
+        Self {
+            threshold,
+            rearm_margin,
+            ceiling: f32::INFINITY,
+            armed: true,
+        }
+    }
+
+    /// Sets the highest value the player can recover to.
+    fn set_ceiling(&mut self, ceiling: f32) {
+        self.ceiling = ceiling;
+    }
+
+    fn effective_threshold(&self) -> f32 {
+        self.threshold.min(self.ceiling - self.rearm_margin)
+    }
+
+    fn rearm_level(&self) -> f32 {
+        (self.threshold + self.rearm_margin).min(self.ceiling)
+    }
+
+    /// Re-arms the latch once the value is back above the hysteresis band.
+    fn observe(&mut self, value: f32) {
+        if value >= self.rearm_level() {
+            self.armed = true;
+        }
+    }
+
+    /// True when the value is below the threshold and no hint fired since the
+    /// last recovery.
+    fn can_fire(&self, value: f32) -> bool {
+        self.armed && value < self.effective_threshold()
+    }
+
+    /// Records that the hint fired.
+    fn disarm(&mut self) {
+        self.armed = false;
+    }
+}
+
+/// Sanity below which the player is told to go back to the van.
+const VERY_LOW_SANITY: f32 = 45.0;
+
+/// Seconds with very low sanity inside the location before the warning.
+const VERY_LOW_SANITY_WARNING_SECONDS: f32 = 15.0;
+
+/// Times how long the player has stayed in the location with very low sanity.
+/// Returns true when it's time to warn. The time only counts while the player
+/// isn't already walking a path to the van.
+fn no_truck_return_due(
+    stopwatch: &mut Stopwatch,
+    delta: Duration,
+    sanity: f32,
+    in_location: bool,
+    en_route_to_truck: bool,
+) -> bool {
+    if sanity >= VERY_LOW_SANITY || !in_location || en_route_to_truck {
+        stopwatch.reset();
+        return false;
+    }
+    stopwatch.tick(delta);
+    stopwatch.elapsed_secs() > VERY_LOW_SANITY_WARNING_SECONDS
+}
+
 /// Triggers a warning if the player's sanity drops below 45% and they don't return to the truck within 15 seconds.
 /// This has been made more sensitive to help players when their sanity is critically low.
+/// With the "Path to Van" guidance setting, a path to the van is set for the player too.
 fn very_low_sanity_no_truck_return(
     mut walkie_play: ResMut<WalkiePlay>,
-    qp: Query<(&PlayerSprite, &Position)>,
+    qp: Query<(&PlayerSprite, &Position, &WaypointQueue)>,
+    q_waypoints: Query<&Waypoint>,
+    q_behaviours: Query<&Behaviour>,
     roomdb: Res<RoomDB>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
+    gameplay_settings: Res<Persistent<GameplaySettings>>,
+    mut ev_go_to_truck: EventWriter<GoToTruckEvent>,
     mut stopwatch: Local<Stopwatch>,
     time: Res<Time>,
 ) {
@@ -40,65 +153,84 @@ fn very_low_sanity_no_truck_return(
         stopwatch.reset();
         return;
     }
-    let Some((player, pos)) = qp.iter().next() else {
+    let Some((player, pos, waypoint_queue)) = qp.iter().next() else {
         return;
     };
-    if player.sanity() >= 45.0 {
-        stopwatch.reset();
+    let in_location = roomdb.room_tiles.contains_key(&pos.to_board_position());
+    // Already heading to the van entry, no need to repeat the warning
+    let en_route_to_truck = waypoint_queue
+        .destination()
+        .and_then(|w| q_waypoints.get(w).ok())
+        .is_some_and(|w| match w.waypoint_type {
+            WaypointType::Interact(target) => {
+                q_behaviours.get(target).is_ok_and(|b| b.is_van_entry())
+            }
+            WaypointType::MoveTo => false,
+        });
+    if !no_truck_return_due(
+        &mut stopwatch,
+        time.delta(),
+        player.sanity(),
+        in_location,
+        en_route_to_truck,
+    ) {
         return;
     }
-    let player_bpos = pos.to_board_position();
-    if roomdb.room_tiles.get(&player_bpos).is_none() {
-        // Player is not inside the location, reset timer
-        stopwatch.reset();
-        return;
+    if walkie_play.set(
+        WalkieEvent::VeryLowSanityNoTruckReturn,
+        time.elapsed_secs_f64(),
+    ) && gameplay_settings.low_sanity_guidance == LowSanityGuidance::PathToVan
+    {
+        ev_go_to_truck.write(GoToTruckEvent);
     }
-    stopwatch.tick(time.delta());
-    if stopwatch.elapsed_secs() > 15.0 {
-        // Reduced from 20 seconds to 15 seconds for more responsive warnings
-        walkie_play.set(
-            WalkieEvent::VeryLowSanityNoTruckReturn,
-            time.elapsed_secs_f64(),
-        );
-        stopwatch.reset();
+    stopwatch.reset();
+}
+
+/// Low health latch, see `ThresholdLatch`.
+struct LowHealthLatch(ThresholdLatch);
+
+impl Default for LowHealthLatch {
+    fn default() -> Self {
+        Self(ThresholdLatch::new(
+            LOW_HEALTH_THRESHOLD,
+            LOW_HEALTH_REARM_MARGIN,
+        ))
     }
 }
 
-/// Triggers a warning if the player's health drops below 50% for 30 seconds while inside the location.
+/// Warns once when the player's health drops below `LOW_HEALTH_THRESHOLD`
+/// inside the location. It can warn again after health has recovered.
 fn low_health_general_warning(
     mut walkie_play: ResMut<WalkiePlay>,
     qp: Query<(&PlayerSprite, &Position)>,
     roomdb: Res<RoomDB>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
-    mut stopwatch: Local<Stopwatch>,
+    mut latch: Local<LowHealthLatch>,
     time: Res<Time>,
 ) {
+    if *app_state.get() != AppState::InGame {
+        *latch = LowHealthLatch::default();
+    }
     if app_state.get() != &AppState::InGame || *game_state.get() != GameState::None {
-        stopwatch.reset();
         return;
     }
     let Some((player, pos)) = qp.iter().next() else {
         return;
     };
-    if player.health >= 50.0 {
-        stopwatch.reset();
+    latch.0.observe(player.health);
+    if !latch.0.can_fire(player.health) || player.health <= 0.0 {
         return;
     }
     let player_bpos = pos.to_board_position();
     if roomdb.room_tiles.get(&player_bpos).is_none() {
-        // Player is not inside the location, reset timer
-        stopwatch.reset();
         return;
     }
-    stopwatch.tick(time.delta());
-    if stopwatch.elapsed_secs() > 30.0 {
-        // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
-        walkie_play.set(
-            WalkieEvent::LowHealthGeneralWarning,
-            time.elapsed_secs_f64(),
-        );
-        stopwatch.reset();
+    if walkie_play.set(
+        WalkieEvent::LowHealthGeneralWarning,
+        time.elapsed_secs_f64(),
+    ) {
+        latch.0.disarm();
     }
 }
 
@@ -114,8 +246,13 @@ fn trigger_sanity_dropped_due_to_darkness_system(
     game_state: Res<State<GameState>>,
     mut darkness_sanity_tracker: Local<Option<(f32, Stopwatch)>>, // (sanity_at_darkness_start, timer)
     mut hint_triggered_this_episode: Local<bool>,
+    mut sanity_latch: Local<ThresholdLatch>,
+    difficulty: Res<CurrentDifficulty>,
 ) {
     // 1. System Run Condition Checks
+    if *app_state.get() != AppState::InGame {
+        *sanity_latch = ThresholdLatch::default();
+    }
     if *app_state.get() != AppState::InGame || *game_state.get() != GameState::None {
         *darkness_sanity_tracker = None;
         *hint_triggered_this_episode = false;
@@ -127,6 +264,8 @@ fn trigger_sanity_dropped_due_to_darkness_system(
         *hint_triggered_this_episode = false;
         return;
     };
+    sanity_latch.set_ceiling(difficulty.0.max_recoverable_sanity);
+    sanity_latch.observe(player_sprite.sanity());
 
     // 2.b. Reset Conditions - Player not inside location or not in darkness
     let player_bpos = player_pos.to_board_position();
@@ -172,7 +311,7 @@ fn trigger_sanity_dropped_due_to_darkness_system(
         }
         // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
         if timer.elapsed_secs() >= MIN_TIME_IN_DARKNESS_FOR_HINT_SECONDS
-            && player_sprite.sanity() < MAX_SANITY_FOR_HINT_PERCENT_SHARED
+            && sanity_latch.can_fire(player_sprite.sanity())
             && (*initial_sanity - player_sprite.sanity()) >= SANITY_DROP_THRESHOLD_POINTS_SHARED // Dereference initial_sanity
             && walkie_play.set(
                 WalkieEvent::SanityDroppedBelowThresholdDarkness,
@@ -180,6 +319,7 @@ fn trigger_sanity_dropped_due_to_darkness_system(
             )
         {
             *hint_triggered_this_episode = true;
+            sanity_latch.disarm();
         }
     }
 }
@@ -189,14 +329,20 @@ fn trigger_sanity_dropped_due_to_ghost_system(
     time: Res<Time>,
     mut walkie_play: ResMut<WalkiePlay>,
     player_query: Query<(&PlayerSprite, &Position, Option<&Hiding>)>,
-    ghost_query: Query<(Entity, &GhostSprite, &Position)>, // Query Entity to track specific ghost
+    ghost_query: Query<(Entity, &Position), With<GhostSprite>>, // Query Entity to track specific ghost
+    hunt_state: Res<HuntState>,
     roomdb: Res<RoomDB>,
     app_state: Res<State<AppState>>,
     game_state: Res<State<GameState>>,
     mut interaction_sanity_tracker: Local<Option<(f32, Stopwatch, Entity)>>, // (sanity_at_interaction_start, timer, ghost_entity)
     mut hint_triggered_this_episode: Local<bool>,
+    mut sanity_latch: Local<ThresholdLatch>,
+    difficulty: Res<CurrentDifficulty>,
 ) {
     // 1. System Run Condition Checks
+    if *app_state.get() != AppState::InGame {
+        *sanity_latch = ThresholdLatch::default();
+    }
     if *app_state.get() != AppState::InGame || *game_state.get() != GameState::None {
         *interaction_sanity_tracker = None;
         *hint_triggered_this_episode = false;
@@ -208,6 +354,8 @@ fn trigger_sanity_dropped_due_to_ghost_system(
         *hint_triggered_this_episode = false;
         return;
     };
+    sanity_latch.set_ceiling(difficulty.0.max_recoverable_sanity);
+    sanity_latch.observe(player_sprite.sanity());
 
     // 2.b. Reset Conditions - Player not inside location or is hiding
     if roomdb
@@ -228,10 +376,9 @@ fn trigger_sanity_dropped_due_to_ghost_system(
 
     // 3.c. Defining "Ghost Interaction Period"
     if let Some(p_room_name) = player_room_name_opt {
-        for (ghost_entity, ghost_sprite, ghost_pos) in ghost_query.iter() {
+        for (ghost_entity, ghost_pos) in ghost_query.iter() {
             // 3.c.i. Non-hunting ghost
-            if ghost_sprite.hunting > 0.0 {
-                // Consider if > 0.1 is better if hunting has a brief ramp-up
+            if hunt_state.ghost == Some(ghost_entity) {
                 continue;
             }
             // 3.c.iii. Player in same room
@@ -282,7 +429,7 @@ fn trigger_sanity_dropped_due_to_ghost_system(
         }
         // FIXME: Verification needed: Not sure if this trigger actually fires. Don't recall it having fired in testing.
         if timer.elapsed_secs() >= MIN_INTERACTION_DURATION_SECONDS
-            && player_sprite.sanity() < MAX_SANITY_FOR_HINT_PERCENT_SHARED
+            && sanity_latch.can_fire(player_sprite.sanity())
             && (*initial_sanity - player_sprite.sanity()) >= SANITY_DROP_THRESHOLD_POINTS_SHARED // Dereference initial_sanity
             && walkie_play.set(
                 WalkieEvent::SanityDroppedBelowThresholdGhost,
@@ -290,6 +437,7 @@ fn trigger_sanity_dropped_due_to_ghost_system(
             )
         {
             *hint_triggered_this_episode = true;
+            sanity_latch.disarm();
         }
     }
 }
@@ -307,3 +455,101 @@ pub(crate) fn app_setup(app: &mut App) {
 // struct LightLevel {
 //     lux: f32,
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanity_latch_fires_once_per_crossing() {
+        let mut latch = ThresholdLatch::new(70.0, 10.0);
+        let mut fired = 0;
+        // Drop below, hover around the threshold, dip again without really
+        // recovering, then recover fully and drop once more.
+        for sanity in [
+            90.0, 69.0, 71.0, 68.0, 75.0, 65.0, 79.0, 60.0, 85.0, 66.0, 64.0,
+        ] {
+            latch.observe(sanity);
+            if latch.can_fire(sanity) {
+                latch.disarm();
+                fired += 1;
+            }
+        }
+        assert_eq!(fired, 2);
+        assert!(!latch.can_fire(50.0));
+    }
+
+    #[test]
+    fn test_sanity_latch_rearms_below_recoverable_cap() {
+        // Master only recovers sanity to 60, below the usual re-arm level.
+        let mut latch = ThresholdLatch::default();
+        latch.set_ceiling(60.0);
+        let mut fired = 0;
+        for sanity in [60.0, 45.0, 55.0, 40.0, 60.0, 52.0, 45.0] {
+            latch.observe(sanity);
+            if latch.can_fire(sanity) {
+                latch.disarm();
+                fired += 1;
+            }
+        }
+        assert_eq!(fired, 2);
+    }
+
+    #[test]
+    fn test_en_route_to_truck_suppresses_low_sanity_repeat() {
+        let second = Duration::from_secs(1);
+        let mut stopwatch = Stopwatch::new();
+        let mut fired = 0;
+        for _ in 0..60 {
+            if no_truck_return_due(&mut stopwatch, second, 30.0, true, false) {
+                stopwatch.reset();
+                fired += 1;
+            }
+        }
+        assert_eq!(fired, 3);
+
+        // Walking the path to the van never repeats the warning.
+        for _ in 0..60 {
+            assert!(!no_truck_return_due(
+                &mut stopwatch,
+                second,
+                30.0,
+                true,
+                true
+            ));
+        }
+        // Giving up on the path starts the count from zero.
+        for _ in 0..15 {
+            assert!(!no_truck_return_due(
+                &mut stopwatch,
+                second,
+                30.0,
+                true,
+                false
+            ));
+        }
+        assert!(no_truck_return_due(
+            &mut stopwatch,
+            second,
+            30.0,
+            true,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_low_health_latch_fires_once_per_crossing() {
+        let LowHealthLatch(mut latch) = LowHealthLatch::default();
+        let mut fired = 0;
+        // A hunt drains health, the player recovers a little, gets hit again,
+        // then heals in the truck and gets hunted once more.
+        for health in [100.0, 70.0, 49.0, 40.0, 55.0, 45.0, 80.0, 48.0, 30.0] {
+            latch.observe(health);
+            if latch.can_fire(health) {
+                latch.disarm();
+                fired += 1;
+            }
+        }
+        assert_eq!(fired, 2);
+    }
+}
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use bevy_platform::collections::HashSet;
 use std::any::Any;
-use uncore::components::repellent_particle::RepellentParticle;
+use uncore::components::repellent_particle::{
+    RepellentParticle, repellent_effectiveness, repellent_range_factor,
+};
 use uncore::difficulty::CurrentDifficulty;
 use uncore::types::gear_kind::GearKind;
 use uncore::types::ghost::types::GhostType;
@@ -9,7 +11,7 @@ use uncore::{
     components::{
         board::position::Position, ghost_sprite::GhostSprite, player_sprite::PlayerSprite,
     },
-    resources::roomdb::RoomDB,
+    resources::{hunt_state::HuntState, roomdb::RoomDB},
     states::{AppState, GameState},
 };
 use ungear::components::playergear::PlayerGear;
@@ -122,7 +124,6 @@ fn trigger_has_repellent_enters_location_system(
     }
 }
 
-const EFFECTIVE_REPELLENT_RANGE: f32 = 3.0;
 const TOO_FAR_DURATION_SECONDS: f64 = 5.0;
 
 // Local state to track when the "too far" condition started
@@ -140,6 +141,7 @@ fn trigger_repellent_used_too_far_system(
     player_query: Query<(&PlayerGear, &Position), With<PlayerSprite>>,
     ghost_query: Query<(&Position, &GhostSprite), Without<PlayerSprite>>,
     mut prev_repellent_state: Local<PrevRepellentState>,
+    difficulty: Res<CurrentDifficulty>,
 ) {
     // 1. System Run Condition Checks
     if *app_state.get() != AppState::InGame || *game_state.get() != GameState::None {
@@ -180,7 +182,8 @@ fn trigger_repellent_used_too_far_system(
             return;
         }
         let distance = player_pos.distance(&target_pos);
-        let is_too_far = distance > EFFECTIVE_REPELLENT_RANGE;
+        let is_too_far =
+            repellent_range_factor(distance, difficulty.0.repellent_effective_range) < 1.0;
 
         if is_too_far {
             if prev_repellent_state.too_far_started.is_none() {
@@ -208,8 +211,8 @@ const PARTICLE_NEARBY_THRESHOLD: f32 = 3.5; // How close particles need to be to
 #[derive(Default)]
 struct RepellentReactionTracker {
     repellent_activated_time: f32,
-    initial_ghost_hunting_state: f32, // Using f32 to directly compare with GhostSprite.hunting
-                                      // Potentially add ghost_entity_id if multiple ghosts were possible
+    /// Whether a hunt was already on when the repellent was activated.
+    hunt_active_at_start: bool,
 }
 
 // Local state to track if the repellent was active in the previous frame for activation detection
@@ -225,6 +228,7 @@ fn trigger_repellent_provokes_strong_reaction_system(
     mut walkie_play: ResMut<WalkiePlay>,
     player_query: Query<(&PlayerGear, &Position), With<PlayerSprite>>,
     mut ghost_query: Query<(&GhostSprite, &Position)>,
+    hunt_state: Res<HuntState>,
     repellent_particle_query: Query<&Position, With<RepellentParticle>>,
     mut tracker: Local<Option<RepellentReactionTracker>>,
     mut prev_rep_active_state: Local<PrevRepellentActiveState>,
@@ -271,7 +275,7 @@ fn trigger_repellent_provokes_strong_reaction_system(
         // Repellent was just activated this frame by the player
         *tracker = Some(RepellentReactionTracker {
             repellent_activated_time: time.elapsed_secs(),
-            initial_ghost_hunting_state: ghost_sprite.hunting,
+            hunt_active_at_start: hunt_state.active,
         });
     }
     prev_rep_active_state.was_active = current_repellent_is_active_and_has_qty;
@@ -281,12 +285,11 @@ fn trigger_repellent_provokes_strong_reaction_system(
         let time_since_activation = time.elapsed_secs() - tracker_data.repellent_activated_time;
 
         if time_since_activation <= REACTION_WINDOW_SECONDS {
-            let hunt_just_started =
-                ghost_sprite.hunting > 0.0 && tracker_data.initial_ghost_hunting_state == 0.0;
-            // Also consider if hunt_warning_active just became true, if initial_ghost_hunting_state was low and warning was false
+            let hunt_just_started = hunt_state.active && !tracker_data.hunt_active_at_start;
+            // Also consider if hunt_warning_active just became true, if no hunt was on
             let warning_just_started = ghost_sprite.hunt_warning_active
-                && ghost_sprite.hunting < 1.0
-                && tracker_data.initial_ghost_hunting_state < 1.0;
+                && !hunt_state.active
+                && !tracker_data.hunt_active_at_start;
 
             let particles_nearby = repellent_particle_query
                 .iter()
@@ -373,7 +376,7 @@ fn trigger_repellent_exhausted_correct_type_system(
                         // Condition 3: The flask's content type matches the current ghost's type
                         // Condition 4: The ghost has registered hits from the correct repellent type
                         // (ghost_sprite.repellent_hits implies hits from its own class type)
-                        if flask_content_type == ghost_sprite.class
+                        if repellent_effectiveness(flask_content_type, ghost_sprite.class) >= 1.0
                             && ghost_sprite.repellent_hits > 0
                         {
                             // This flask, of the correct type, is now empty, and the ghost was affected.
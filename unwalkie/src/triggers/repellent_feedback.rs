@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 use bevy_platform::collections::{HashMap, HashSet};
 use uncore::{
-    components::{ghost_sprite::GhostSprite, repellent_particle::RepellentParticle},
+    components::{
+        ghost_sprite::GhostSprite,
+        repellent_particle::{RepellentParticle, repellent_effectiveness},
+    },
     resources::ghost_guess::GhostGuess,
     states::AppState,
     types::{evidence::Evidence, ghost::types::GhostType},
@@ -75,6 +78,11 @@ fn repellent_feedback_trigger_system(
                 continue;
             };
             let real_ghost_type = ghost_sprite.class;
+            if repellent_effectiveness(repellent_type, real_ghost_type) > 0.0 {
+                // The repellent works on this ghost, nothing to correct.
+                hints_given.ready_to_play.remove(&repellent_type);
+                continue;
+            }
 
             let repellent_evidences = repellent_type.evidences();
             let real_evidences = real_ghost_type.evidences();
@@ -5,9 +5,12 @@ use uncore::{
     components::game_ui::WalkieText,
     events::{hint::OnScreenHintEvent, loadlevel::LevelReadyEvent},
     random_seed,
+    resources::localization::Localization,
     states::{AppState, GameState},
 };
 use unsettings::audio::AudioSettings;
+use unsettings::controls::ControlKeys;
+use unsettings::game::GameplaySettings;
 use unwalkie_types::VoiceLineData;
 use unwalkiecore::{WalkiePlay, WalkieSoundState, WalkieTalkingEvent};
 
@@ -31,6 +34,25 @@ fn state_tracking(
     }
 }
 
+/// Stops the transmission in progress when an urgent event has taken its place.
+fn walkie_preempt(
+    mut commands: Commands,
+    mut walkie_play: ResMut<WalkiePlay>,
+    q_sound_state: Query<Entity, With<WalkieSoundState>>,
+    mut qt: Query<&mut Text, With<WalkieText>>,
+) {
+    if !walkie_play.preempt_pending {
+        return;
+    }
+    walkie_play.preempt_pending = false;
+    for entity in q_sound_state.iter() {
+        commands.entity(entity).despawn();
+    }
+    for mut text in qt.iter_mut() {
+        text.0 = "".to_string();
+    }
+}
+
 fn walkie_talk(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -42,6 +64,7 @@ fn walkie_talk(
     mut qt: Query<&mut Text, With<WalkieText>>,
     mut stopwatch: Local<Stopwatch>,
     time: Res<Time>,
+    localization: Res<Localization>,
 ) {
     let mut rng = random_seed::rng();
     walkie_play.priority_bar /= 1.2;
@@ -69,22 +92,27 @@ fn walkie_talk(
         }
         return;
     }
-    let mut walkie_volume = 1.0;
-
     let new_state = match walkie_play.state {
         None => Some(WalkieSoundState::Intro),
         Some(WalkieSoundState::Intro) => {
-            let voice_lines: Vec<VoiceLineData> = walkie_event.sound_file_list();
-            if let Some(chosen_line) = voice_lines.choose(&mut rng).cloned() {
-                walkie_play.current_voice_line = Some(chosen_line);
-            } else {
-                walkie_play.current_voice_line = Some(VoiceLineData {
-                    ogg_path: "sounds/radio-on-zzt.ogg".to_string(),
-                    subtitle_text: "[NO SUBTITLE AVAILABLE]".to_string(),
-                    tags: vec![],
-                    length_seconds: 2,
-                });
+            // A replay already carries the line that was spoken the first time.
+            if walkie_play.current_voice_line.is_none() {
+                let voice_lines: Vec<VoiceLineData> = walkie_event.sound_file_list();
+                if let Some(chosen_line) = voice_lines.choose(&mut rng).cloned() {
+                    walkie_play.current_voice_line = Some(chosen_line);
+                } else {
+                    walkie_play.current_voice_line = Some(VoiceLineData {
+                        ogg_path: "sounds/radio-on-zzt.ogg".to_string(),
+                        subtitle_text: "[NO SUBTITLE AVAILABLE]".to_string(),
+                        tags: vec![],
+                        length_seconds: 2,
+                    });
+                }
             }
+            walkie_play.last_played = walkie_play
+                .current_voice_line
+                .clone()
+                .map(|line| (walkie_event.clone(), line));
 
             // Fire WalkieTalkingEvent when transitioning to the Talking state
             walkie_talking_writer.write(WalkieTalkingEvent {
@@ -94,7 +122,10 @@ fn walkie_talk(
             Some(WalkieSoundState::Talking)
         }
         Some(WalkieSoundState::Talking) => {
-            let hint_text = walkie_event.get_on_screen_actionable_hint_text();
+            let hint_text = localization.text(
+                &walkie_event.hint_text_key(),
+                walkie_event.get_on_screen_actionable_hint_text(),
+            );
             if !hint_text.is_empty() {
                 let saved_count = walkie_play
                     .other_mission_event_count
@@ -134,7 +165,11 @@ fn walkie_talk(
             Some(WalkieSoundState::Intro) => "**bzzrt**".to_string(),
             Some(WalkieSoundState::Talking) => {
                 if let Some(voice_line) = &walkie_play.current_voice_line {
-                    format!("{}  {}", text.0, voice_line.subtitle_text)
+                    let subtitle = localization.text(
+                        &walkie_event.subtitle_text_key(voice_line),
+                        &voice_line.subtitle_text,
+                    );
+                    format!("{}  {}", text.0, subtitle)
                 } else {
                     format!("{}  [ERROR: Missing subtitle]", text.0)
                 }
@@ -158,7 +193,6 @@ fn walkie_talk(
     let sound_file = match new_state_unwrapped {
         WalkieSoundState::Intro => "sounds/radio-on-zzt.ogg",
         WalkieSoundState::Talking => {
-            walkie_volume = 0.2;
             if let Some(voice_line) = &walkie_play.current_voice_line {
                 &voice_line.ogg_path
             } else {
@@ -176,9 +210,7 @@ fn walkie_talk(
         .insert(PlaybackSettings {
             mode: bevy::audio::PlaybackMode::Despawn,
             volume: Volume::Linear(
-                walkie_volume
-                    * audio_settings.volume_voice_chat.as_f32()
-                    * audio_settings.volume_master.as_f32(),
+                new_state_unwrapped.base_volume() * audio_settings.walkie_volume(),
             ),
             speed: 1.0,
             paused: false,
@@ -189,8 +221,93 @@ fn walkie_talk(
         .insert(new_state_unwrapped);
 }
 
+/// Applies voice channel volume changes to the transmission currently playing
+fn walkie_volume_update(
+    audio_settings: Res<Persistent<AudioSettings>>,
+    mut q_sound: Query<(&WalkieSoundState, &mut AudioSink)>,
+) {
+    if !audio_settings.is_changed() {
+        return;
+    }
+    for (sound_state, mut sink) in q_sound.iter_mut() {
+        sink.set_volume(Volume::Linear(
+            sound_state.base_volume() * audio_settings.walkie_volume(),
+        ));
+    }
+}
+
+/// Replays the last walkie message when the replay key is pressed.
+fn keyboard_replay_last_hint(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    control_keys: Res<Persistent<ControlKeys>>,
+    mut walkie_play: ResMut<WalkiePlay>,
+) {
+    if !keyboard_input.just_pressed(control_keys.replay_hint) {
+        return;
+    }
+    if !walkie_play.replay_last() {
+        info!("Nothing to replay on the walkie");
+    }
+}
+
+/// Copies the hint frequency setting into `WalkiePlay` so event selection can use it.
+fn sync_hint_frequency(
+    game_settings: Res<Persistent<GameplaySettings>>,
+    mut walkie_play: ResMut<WalkiePlay>,
+) {
+    if walkie_play.hint_frequency != game_settings.hint_frequency {
+        walkie_play.hint_frequency = game_settings.hint_frequency;
+    }
+}
+
 pub(crate) fn app_setup(app: &mut App) {
-    app.add_systems(Update, walkie_talk)
+    app.add_systems(Update, (walkie_preempt, walkie_talk).chain())
+        .add_systems(Update, walkie_volume_update)
+        .add_systems(Update, sync_hint_frequency)
+        .add_systems(
+            Update,
+            keyboard_replay_last_hint
+                .run_if(in_state(AppState::InGame).and(in_state(GameState::None))),
+        )
         .add_systems(Update, on_game_load)
         .add_systems(Update, state_tracking);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unwalkiecore::WalkieEvent;
+
+    #[test]
+    fn test_urgent_event_despawns_playing_audio() {
+        let mut app = App::new();
+        app.init_resource::<WalkiePlay>()
+            .add_systems(Update, walkie_preempt);
+
+        let mut walkie_play = WalkiePlay::default();
+        assert!(walkie_play.set(WalkieEvent::GearInVan, 0.0));
+        walkie_play.state = Some(WalkieSoundState::Talking);
+        app.insert_resource(walkie_play);
+        let playing = app.world_mut().spawn(WalkieSoundState::Talking).id();
+
+        // A non-urgent event waits for the current clip.
+        let mut walkie_play = app.world_mut().resource_mut::<WalkiePlay>();
+        assert!(!walkie_play.set(WalkieEvent::PlayerStuckAtStart, 1.0));
+        assert!(!walkie_play.preempt_pending);
+        app.update();
+        assert!(app.world().get_entity(playing).is_ok());
+
+        let mut walkie_play = app.world_mut().resource_mut::<WalkiePlay>();
+        assert!(walkie_play.set(WalkieEvent::HuntWarningNoPlayerEvasion, 1.0));
+        assert!(walkie_play.preempt_pending);
+        app.update();
+
+        assert!(app.world().get_entity(playing).is_err());
+        let walkie_play = app.world().resource::<WalkiePlay>();
+        assert_eq!(
+            walkie_play.event,
+            Some(WalkieEvent::HuntWarningNoPlayerEvasion)
+        );
+        assert_eq!(walkie_play.state, None);
+    }
+}
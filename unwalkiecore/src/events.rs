@@ -7,7 +7,14 @@ mod walkie_types;
 
 #[cfg(test)]
 mod test_effective_priority;
+#[cfg(test)]
+mod test_hint_frequency;
+#[cfg(test)]
+mod test_repeat_suppression;
+#[cfg(test)]
+mod test_text_keys;
 
+pub use walkie_config::should_suppress;
 pub use walkie_types::{
     WalkieEvent, WalkieEventPriority, WalkieRepeatbehaviour, WalkieTalkingEvent,
 };
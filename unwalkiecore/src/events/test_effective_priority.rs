@@ -97,4 +97,32 @@ mod tests {
         assert_eq!(event.effective_priority(10), WalkieEventPriority::Medium);
         assert_eq!(event.effective_priority(20), WalkieEventPriority::Medium);
     }
+
+    #[test]
+    fn test_chapter_intro_repeat_depends_on_chapter() {
+        use uncore::difficulty::Difficulty;
+
+        // Tutorial intros keep the default behaviour
+        let tutorial = WalkieEvent::ChapterIntro(Difficulty::TutorialChapter3);
+        assert_eq!(
+            tutorial.repeat_behaviour(),
+            WalkieRepeatbehaviour::NormalRepeat
+        );
+
+        // Challenge intros are suppressed sooner on repeat missions
+        let standard = WalkieEvent::ChapterIntro(Difficulty::StandardChallenge);
+        assert_eq!(
+            standard.repeat_behaviour(),
+            WalkieRepeatbehaviour::LowRepeat
+        );
+        let master = WalkieEvent::ChapterIntro(Difficulty::MasterChallenge);
+        assert_eq!(
+            master.repeat_behaviour(),
+            WalkieRepeatbehaviour::VeryLowRepeat
+        );
+        assert!(master.effective_priority(1).value() < tutorial.effective_priority(1).value());
+
+        // Cooldown within a mission is unchanged: effectively once
+        assert_eq!(master.time_to_play(1), tutorial.time_to_play(1));
+    }
 }
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+    use crate::events::{WalkieEvent, WalkieEventPriority};
+    use unsettings::game::HintFrequency;
+
+    #[test]
+    fn test_hint_frequency_scales_cooldown() {
+        let event = WalkieEvent::GearInVan;
+        let base = event.time_to_play(1);
+        assert_eq!(base, 120.0);
+
+        assert_eq!(
+            event.effective_time_to_play(1, HintFrequency::Normal),
+            Some(base)
+        );
+        assert_eq!(
+            event.effective_time_to_play(1, HintFrequency::Rare),
+            Some(base * 2.0)
+        );
+        assert_eq!(
+            event.effective_time_to_play(1, HintFrequency::Frequent),
+            Some(base * 0.5)
+        );
+        assert_eq!(event.effective_time_to_play(1, HintFrequency::Off), None);
+    }
+
+    #[test]
+    fn test_hint_frequency_off_keeps_urgent_events() {
+        let event = WalkieEvent::HuntWarningNoPlayerEvasion;
+        assert_eq!(event.priority(), WalkieEventPriority::Urgent);
+
+        assert_eq!(
+            event.effective_time_to_play(2, HintFrequency::Off),
+            Some(event.time_to_play(2))
+        );
+        // Other settings still scale urgent events.
+        assert_eq!(
+            event.effective_time_to_play(2, HintFrequency::Rare),
+            Some(event.time_to_play(2) * 2.0)
+        );
+    }
+}
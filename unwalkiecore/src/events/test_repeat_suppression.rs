@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use crate::events::{WalkieRepeatbehaviour, should_suppress};
+    use uncore::random_seed;
+
+    #[test]
+    fn test_should_suppress_compares_against_threshold() {
+        let repeat = WalkieRepeatbehaviour::NormalRepeat;
+        assert!(!should_suppress(&repeat, 0));
+        assert!(!should_suppress(&repeat, 3));
+        assert!(should_suppress(&repeat, 4));
+    }
+
+    #[test]
+    fn test_seeded_rolls_are_reproducible() {
+        random_seed::set_seed(1234);
+        let first: Vec<_> = (0..20)
+            .map(|_| WalkieRepeatbehaviour::roll_dice(4))
+            .collect();
+        random_seed::set_seed(1234);
+        let second: Vec<_> = (0..20)
+            .map(|_| WalkieRepeatbehaviour::roll_dice(4))
+            .collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_suppression_rates_match_thresholds() {
+        // After 4 previous plays the dice range is 0..=16.
+        const ROLLS: u32 = 20_000;
+        random_seed::set_seed(42);
+        for (repeat, threshold) in [
+            (WalkieRepeatbehaviour::VeryLowRepeat, 1),
+            (WalkieRepeatbehaviour::LowRepeat, 2),
+            (WalkieRepeatbehaviour::NormalRepeat, 3),
+            (WalkieRepeatbehaviour::HighRepeat, 6),
+            (WalkieRepeatbehaviour::AlwaysRepeat, 12),
+        ] {
+            assert_eq!(repeat.dice_threshold(), threshold);
+            let suppressed = (0..ROLLS)
+                .filter(|_| {
+                    let (dice, max_dice_value) = WalkieRepeatbehaviour::roll_dice(4);
+                    assert_eq!(max_dice_value, 16);
+                    should_suppress(&repeat, dice)
+                })
+                .count();
+            let rate = suppressed as f64 / ROLLS as f64;
+            let expected = (16 - threshold) as f64 / 17.0;
+            assert!(
+                (rate - expected).abs() < 0.02,
+                "{repeat:?}: rate {rate:.3}, expected {expected:.3}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_replay_is_never_suppressed() {
+        // No previous plays: the dice is always 0.
+        for _ in 0..100 {
+            let (dice, _) = WalkieRepeatbehaviour::roll_dice(0);
+            assert!(!should_suppress(
+                &WalkieRepeatbehaviour::VeryLowRepeat,
+                dice
+            ));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use crate::events::WalkieEvent;
+    use bevy_platform::collections::HashSet;
+    use uncore::types::gear_kind::GearKind;
+
+    #[test]
+    fn test_every_event_has_a_unique_text_key() {
+        let mut keys = HashSet::new();
+        for event in enum_iterator::all::<WalkieEvent>() {
+            let key = event.text_key();
+            assert!(
+                key.starts_with("walkie.") && key.len() > "walkie.".len(),
+                "{event:?} has an invalid text key: {key}"
+            );
+            assert!(
+                key.chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '_'),
+                "{event:?} text key is not snake_case: {key}"
+            );
+            assert!(keys.insert(key.clone()), "duplicate text key: {key}");
+        }
+    }
+
+    #[test]
+    fn test_text_key_includes_payload() {
+        assert_eq!(WalkieEvent::GearInVan.text_key(), "walkie.gear_in_van");
+        assert_eq!(
+            WalkieEvent::GearExplanation(GearKind::Thermometer).hint_text_key(),
+            "walkie.gear_explanation.thermometer.hint"
+        );
+    }
+}
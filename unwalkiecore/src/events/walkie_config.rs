@@ -1,4 +1,8 @@
 use crate::events::walkie_types::{WalkieEvent, WalkieEventPriority, WalkieRepeatbehaviour};
+use rand::Rng;
+use uncore::difficulty::Difficulty;
+use uncore::random_seed;
+use unsettings::game::HintFrequency;
 
 impl WalkieEventPriority {
     pub fn value(&self) -> f32 {
@@ -40,6 +44,16 @@ impl WalkieRepeatbehaviour {
         }
     }
 
+    /// Rolls the cross-mission suppression dice for an event already played
+    /// `saved_count` times in previous missions.
+    ///
+    /// Draws from `random_seed`, so results are reproducible after `random_seed::set_seed`.
+    pub fn roll_dice(saved_count: u32) -> (u32, u32) {
+        let max_dice_value = saved_count * saved_count.clamp(0, 4);
+        let dice = random_seed::rng().random_range(0..=max_dice_value);
+        (dice, max_dice_value)
+    }
+
     /// Returns the minimum delay multiplier for within-mission timing
     pub fn timing_multiplier(&self) -> f64 {
         match self {
@@ -52,6 +66,11 @@ impl WalkieRepeatbehaviour {
     }
 }
 
+/// Whether a dice roll suppresses an event with the given repeat behaviour.
+pub fn should_suppress(repeat: &WalkieRepeatbehaviour, dice: u32) -> bool {
+    dice > repeat.dice_threshold()
+}
+
 impl WalkieEvent {
     pub fn time_to_play(&self, count: u32) -> f64 {
         let count = count.max(1) as f64;
@@ -133,6 +152,19 @@ impl WalkieEvent {
         }
     }
 
+    /// Cooldown from `time_to_play` scaled by the player's hint frequency.
+    ///
+    /// Returns `None` if the event must not play at all. Urgent events ignore
+    /// `HintFrequency::Off` and keep their normal cooldown.
+    pub fn effective_time_to_play(&self, count: u32, frequency: HintFrequency) -> Option<f64> {
+        let multiplier = match frequency.cooldown_multiplier() {
+            Some(m) => m,
+            None if self.priority().is_urgent() => 1.0,
+            None => return None,
+        };
+        Some(self.time_to_play(count) * multiplier)
+    }
+
     pub fn priority(&self) -> WalkieEventPriority {
         match self {
             WalkieEvent::GearInVan => WalkieEventPriority::Low,
@@ -210,7 +242,17 @@ impl WalkieEvent {
     pub fn repeat_behaviour(&self) -> WalkieRepeatbehaviour {
         match self {
             // Introduction - this one is flavour text, which is nice to hear.
-            WalkieEvent::ChapterIntro(_) => WalkieRepeatbehaviour::NormalRepeat,
+            // Challenge intros are long and mostly heard by veterans, so they
+            // are skipped more eagerly on later missions.
+            WalkieEvent::ChapterIntro(difficulty) => match difficulty {
+                Difficulty::StandardChallenge | Difficulty::HardChallenge => {
+                    WalkieRepeatbehaviour::LowRepeat
+                }
+                Difficulty::ExpertChallenge | Difficulty::MasterChallenge => {
+                    WalkieRepeatbehaviour::VeryLowRepeat
+                }
+                _ => WalkieRepeatbehaviour::NormalRepeat,
+            },
 
             // One-time introductions and explanations - should rarely repeat
             WalkieEvent::GearExplanation(_) => WalkieRepeatbehaviour::VeryLowRepeat,
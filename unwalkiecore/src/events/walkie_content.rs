@@ -264,6 +264,160 @@ impl WalkieEvent {
         }
     }
 
+    /// Stable key identifying this event in localization tables, built from the
+    /// variant and its payload, e.g. `walkie.gear_explanation.thermometer`.
+    pub fn text_key(&self) -> String {
+        match self {
+            WalkieEvent::GearInVan => "walkie.gear_in_van".to_string(),
+            WalkieEvent::GhostNearHunt => "walkie.ghost_near_hunt".to_string(),
+            WalkieEvent::ChapterIntro(difficulty) => {
+                format!("walkie.chapter_intro.{}", difficulty_key(*difficulty))
+            }
+            WalkieEvent::GearExplanation(gear) => {
+                format!("walkie.gear_explanation.{}", gear_kind_key(*gear))
+            }
+            WalkieEvent::PlayerStuckAtStart => "walkie.player_stuck_at_start".to_string(),
+            WalkieEvent::ErraticMovementEarly => "walkie.erratic_movement_early".to_string(),
+            WalkieEvent::DoorInteractionHesitation => {
+                "walkie.door_interaction_hesitation".to_string()
+            }
+            WalkieEvent::StrugglingWithGrabDrop => "walkie.struggling_with_grab_drop".to_string(),
+            WalkieEvent::StrugglingWithHideUnhide => {
+                "walkie.struggling_with_hide_unhide".to_string()
+            }
+            WalkieEvent::HuntActiveNearHidingSpotNoHide => {
+                "walkie.hunt_active_near_hiding_spot_no_hide".to_string()
+            }
+            WalkieEvent::DarkRoomNoLightUsed => "walkie.dark_room_no_light_used".to_string(),
+            WalkieEvent::BreachShowcase => "walkie.breach_showcase".to_string(),
+            WalkieEvent::GhostShowcase => "walkie.ghost_showcase".to_string(),
+            WalkieEvent::RoomLightsOnGearNeedsDark => {
+                "walkie.room_lights_on_gear_needs_dark".to_string()
+            }
+            WalkieEvent::ThermometerNonFreezingFixation => {
+                "walkie.thermometer_non_freezing_fixation".to_string()
+            }
+            WalkieEvent::GearSelectedNotActivated => {
+                "walkie.gear_selected_not_activated".to_string()
+            }
+            WalkieEvent::LowHealthGeneralWarning => "walkie.low_health_general_warning".to_string(),
+            WalkieEvent::VeryLowSanityNoTruckReturn => {
+                "walkie.very_low_sanity_no_truck_return".to_string()
+            }
+            WalkieEvent::SanityDroppedBelowThresholdDarkness => {
+                "walkie.sanity_dropped_below_threshold_darkness".to_string()
+            }
+            WalkieEvent::SanityDroppedBelowThresholdGhost => {
+                "walkie.sanity_dropped_below_threshold_ghost".to_string()
+            }
+            WalkieEvent::QuartzCrackedFeedback => "walkie.quartz_cracked_feedback".to_string(),
+            WalkieEvent::QuartzShatteredFeedback => "walkie.quartz_shattered_feedback".to_string(),
+            WalkieEvent::PlayerStaysHiddenTooLong => {
+                "walkie.player_stays_hidden_too_long".to_string()
+            }
+            WalkieEvent::QuartzUnusedInRelevantSituation => {
+                "walkie.quartz_unused_in_relevant_situation".to_string()
+            }
+            WalkieEvent::SageUnusedInRelevantSituation => {
+                "walkie.sage_unused_in_relevant_situation".to_string()
+            }
+            WalkieEvent::SageActivatedIneffectively => {
+                "walkie.sage_activated_ineffectively".to_string()
+            }
+            WalkieEvent::SageUnusedDefensivelyDuringHunt => {
+                "walkie.sage_unused_defensively_during_hunt".to_string()
+            }
+            WalkieEvent::GhostExpelledPlayerLingers => {
+                "walkie.ghost_expelled_player_lingers".to_string()
+            }
+            WalkieEvent::HasRepellentEntersLocation => {
+                "walkie.has_repellent_enters_location".to_string()
+            }
+            WalkieEvent::RepellentUsedTooFar => "walkie.repellent_used_too_far".to_string(),
+            WalkieEvent::RepellentUsedGhostEnragesPlayerFlees => {
+                "walkie.repellent_used_ghost_enrages_player_flees".to_string()
+            }
+            WalkieEvent::RepellentExhaustedGhostPresentCorrectType => {
+                "walkie.repellent_exhausted_ghost_present_correct_type".to_string()
+            }
+            WalkieEvent::GhostExpelledPlayerMissed => {
+                "walkie.ghost_expelled_player_missed".to_string()
+            }
+            WalkieEvent::DidNotSwitchStartingGearInHotspot => {
+                "walkie.did_not_switch_starting_gear_in_hotspot".to_string()
+            }
+            WalkieEvent::DidNotCycleToOtherGear => "walkie.did_not_cycle_to_other_gear".to_string(),
+            WalkieEvent::JournalPointsToOneGhostNoCraft => {
+                "walkie.journal_points_to_one_ghost_no_craft".to_string()
+            }
+            WalkieEvent::EMFNonEMF5Fixation => "walkie.emf_non_emf_5_fixation".to_string(),
+            WalkieEvent::JournalConflictingEvidence => {
+                "walkie.journal_conflicting_evidence".to_string()
+            }
+            WalkieEvent::FreezingTempsEvidenceConfirmed => {
+                "walkie.freezing_temps_evidence_confirmed".to_string()
+            }
+            WalkieEvent::FloatingOrbsEvidenceConfirmed => {
+                "walkie.floating_orbs_evidence_confirmed".to_string()
+            }
+            WalkieEvent::UVEctoplasmEvidenceConfirmed => {
+                "walkie.uv_ectoplasm_evidence_confirmed".to_string()
+            }
+            WalkieEvent::EMFLevel5EvidenceConfirmed => {
+                "walkie.emf_level_5_evidence_confirmed".to_string()
+            }
+            WalkieEvent::EVPEvidenceConfirmed => "walkie.evp_evidence_confirmed".to_string(),
+            WalkieEvent::SpiritBoxEvidenceConfirmed => {
+                "walkie.spirit_box_evidence_confirmed".to_string()
+            }
+            WalkieEvent::RLPresenceEvidenceConfirmed => {
+                "walkie.rl_presence_evidence_confirmed".to_string()
+            }
+            WalkieEvent::CPM500EvidenceConfirmed => "walkie.cpm_500_evidence_confirmed".to_string(),
+            WalkieEvent::PotentialGhostIDWithNewEvidence => {
+                "walkie.potential_ghost_id_with_new_evidence".to_string()
+            }
+            WalkieEvent::ClearEvidenceFoundNoActionCKey => {
+                "walkie.clear_evidence_found_no_action_c_key".to_string()
+            }
+            WalkieEvent::ClearEvidenceFoundNoActionTruck => {
+                "walkie.clear_evidence_found_no_action_truck".to_string()
+            }
+            WalkieEvent::InTruckWithEvidenceNoJournal => {
+                "walkie.in_truck_with_evidence_no_journal".to_string()
+            }
+            WalkieEvent::HuntWarningNoPlayerEvasion => {
+                "walkie.hunt_warning_no_player_evasion".to_string()
+            }
+            WalkieEvent::AllObjectivesMetReminderToEndMission => {
+                "walkie.all_objectives_met_reminder_to_end_mission".to_string()
+            }
+            WalkieEvent::PlayerLeavesTruckWithoutChangingLoadout => {
+                "walkie.player_leaves_truck_without_changing_loadout".to_string()
+            }
+            WalkieEvent::IncorrectRepellentHint(evidence) => format!(
+                "walkie.incorrect_repellent_hint.{}",
+                evidence_key(*evidence)
+            ),
+        }
+    }
+
+    /// Localization key of the on-screen hint for this event.
+    pub fn hint_text_key(&self) -> String {
+        format!("{}.hint", self.text_key())
+    }
+
+    /// Localization key of a voice line subtitle, named after its audio file.
+    pub fn subtitle_text_key(&self, voice_line: &VoiceLineData) -> String {
+        let file_name = voice_line
+            .ogg_path
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches(".ogg");
+        format!("{}.{}", self.text_key(), file_name)
+    }
+
     /// Get the list of voice line data for the event.
     pub fn sound_file_list(&self) -> Vec<VoiceLineData> {
         self.to_concept().get_lines()
@@ -511,3 +665,55 @@ impl WalkieEvent {
         }
     }
 }
+
+fn difficulty_key(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::TutorialChapter1 => "tutorial_chapter_1",
+        Difficulty::TutorialChapter2 => "tutorial_chapter_2",
+        Difficulty::TutorialChapter3 => "tutorial_chapter_3",
+        Difficulty::TutorialChapter4 => "tutorial_chapter_4",
+        Difficulty::TutorialChapter5 => "tutorial_chapter_5",
+        Difficulty::StandardChallenge => "standard_challenge",
+        Difficulty::HardChallenge => "hard_challenge",
+        Difficulty::ExpertChallenge => "expert_challenge",
+        Difficulty::MasterChallenge => "master_challenge",
+    }
+}
+
+fn gear_kind_key(gear: GearKind) -> &'static str {
+    match gear {
+        GearKind::Thermometer => "thermometer",
+        GearKind::EMFMeter => "emf_meter",
+        GearKind::Recorder => "recorder",
+        GearKind::Flashlight => "flashlight",
+        GearKind::GeigerCounter => "geiger_counter",
+        GearKind::UVTorch => "uv_torch",
+        GearKind::IonMeter => "ion_meter",
+        GearKind::SpiritBox => "spirit_box",
+        GearKind::ThermalImager => "thermal_imager",
+        GearKind::RedTorch => "red_torch",
+        GearKind::Photocam => "photocam",
+        GearKind::Compass => "compass",
+        GearKind::EStaticMeter => "estatic_meter",
+        GearKind::Videocam => "videocam",
+        GearKind::MotionSensor => "motion_sensor",
+        GearKind::RepellentFlask => "repellent_flask",
+        GearKind::QuartzStone => "quartz_stone",
+        GearKind::Salt => "salt",
+        GearKind::SageBundle => "sage_bundle",
+        GearKind::None => "none",
+    }
+}
+
+fn evidence_key(evidence: Evidence) -> &'static str {
+    match evidence {
+        Evidence::FreezingTemp => "freezing_temp",
+        Evidence::FloatingOrbs => "floating_orbs",
+        Evidence::UVEctoplasm => "uv_ectoplasm",
+        Evidence::EMFLevel5 => "emf_level_5",
+        Evidence::EVPRecording => "evp_recording",
+        Evidence::SpiritBox => "spirit_box",
+        Evidence::RLPresence => "rl_presence",
+        Evidence::CPM500 => "cpm_500",
+    }
+}
@@ -166,3 +166,19 @@ pub enum WalkieEvent {
     /// Player is using the wrong repellent, hint to discard a specific evidence.
     IncorrectRepellentHint(Evidence),
 }
+
+impl WalkieEvent {
+    /// The confirmation message for the given evidence.
+    pub fn evidence_confirmed(evidence: Evidence) -> Self {
+        match evidence {
+            Evidence::FreezingTemp => WalkieEvent::FreezingTempsEvidenceConfirmed,
+            Evidence::FloatingOrbs => WalkieEvent::FloatingOrbsEvidenceConfirmed,
+            Evidence::UVEctoplasm => WalkieEvent::UVEctoplasmEvidenceConfirmed,
+            Evidence::EMFLevel5 => WalkieEvent::EMFLevel5EvidenceConfirmed,
+            Evidence::EVPRecording => WalkieEvent::EVPEvidenceConfirmed,
+            Evidence::SpiritBox => WalkieEvent::SpiritBoxEvidenceConfirmed,
+            Evidence::RLPresence => WalkieEvent::RLPresenceEvidenceConfirmed,
+            Evidence::CPM500 => WalkieEvent::CPM500EvidenceConfirmed,
+        }
+    }
+}
@@ -1,9 +1,8 @@
-use crate::events::WalkieEvent;
+use crate::events::{WalkieEvent, WalkieRepeatbehaviour, should_suppress};
 use bevy::prelude::*;
 use bevy_platform::collections::HashMap;
-use rand::Rng;
-use uncore::random_seed;
 use uncore::types::evidence::Evidence;
+use unsettings::game::HintFrequency;
 use unwalkie_types::VoiceLineData;
 
 #[derive(Clone, Debug, Default)]
@@ -23,8 +22,14 @@ pub struct WalkiePlay {
     pub last_message_time: f64,
     pub truck_accessed: bool,
     pub urgent_pending: bool,
+    /// An urgent event replaced the message in progress; its audio must be stopped.
+    pub preempt_pending: bool,
     pub evidence_hinted_not_logged_via_walkie: Option<(Evidence, f64)>,
     pub priority_bar: f32,
+    /// Mirror of the gameplay setting, kept in sync by the walkie plugin.
+    pub hint_frequency: HintFrequency,
+    /// Last event that reached the player, with the line that was spoken.
+    pub last_played: Option<(WalkieEvent, VoiceLineData)>,
 }
 
 impl Default for WalkiePlay {
@@ -38,9 +43,12 @@ impl Default for WalkiePlay {
             last_message_time: -100.0,
             truck_accessed: Default::default(),
             urgent_pending: Default::default(),
+            preempt_pending: false,
             other_mission_event_count: Default::default(),
             evidence_hinted_not_logged_via_walkie: None,
             priority_bar: 0.0,
+            hint_frequency: HintFrequency::default(),
+            last_played: None,
         }
     }
 }
@@ -55,6 +63,14 @@ impl WalkiePlay {
             .copied()
             .unwrap_or_default();
 
+        // Hints disabled by the player never play; urgent events are exempt.
+        if event
+            .effective_time_to_play(0, self.hint_frequency)
+            .is_none()
+        {
+            return false;
+        }
+
         // Calculate effective priority based on previous mission play count
         let effective_priority = event.effective_priority(saved_count);
 
@@ -63,10 +79,18 @@ impl WalkiePlay {
             return false;
         }
         self.urgent_pending = false;
+        // Urgent events cut off whatever non-urgent message is playing.
+        let preempts = event.priority().is_urgent()
+            && self
+                .event
+                .as_ref()
+                .is_some_and(|in_event| !in_event.priority().is_urgent());
         let mut count = 0;
         if let Some(event_stats) = self.played_events.get(&event) {
             count = event_stats.count + event_stats.other_count;
-            let next_time_to_play = event.time_to_play(count);
+            let next_time_to_play = event
+                .effective_time_to_play(count, self.hint_frequency)
+                .unwrap_or(f64::INFINITY);
             if time - event_stats.last_played < next_time_to_play {
                 // Wait for the next time to play
                 return false;
@@ -76,10 +100,11 @@ impl WalkiePlay {
         let repeat_behaviour = event.repeat_behaviour();
         let timing_mult = repeat_behaviour.timing_multiplier();
 
-        if time - self.last_message_time
-            < (20.0 + count as f64 * 30.0 + saved_count as f64 * 10.0)
-                * min_delay_mult
-                * timing_mult
+        if !preempts
+            && time - self.last_message_time
+                < (20.0 + count as f64 * 30.0 + saved_count as f64 * 10.0)
+                    * min_delay_mult
+                    * timing_mult
         {
             // Wait between messages
             return false;
@@ -90,11 +115,9 @@ impl WalkiePlay {
         }
 
         count += 1;
-        let mut rng = random_seed::rng();
-        let max_dice_value = saved_count * saved_count.clamp(0, 4);
+        let (dice, max_dice_value) = WalkieRepeatbehaviour::roll_dice(saved_count);
         let dice_threshold = repeat_behaviour.dice_threshold();
-        let dice = rng.random_range(0..=max_dice_value);
-        if dice > dice_threshold {
+        if should_suppress(&repeat_behaviour, dice) {
             // Skip playing this event, played too many times.
             info!(
                 "WalkiePlay: skipped: {:?}  play dice: {}/{} (threshold: {})",
@@ -106,7 +129,9 @@ impl WalkiePlay {
 
             return true;
         }
-        if let Some(in_event) = &self.event {
+        if let Some(in_event) = &self.event
+            && !preempts
+        {
             // Calculate effective priority for the current in-progress event for comparison
             let in_event_saved_count = self
                 .other_mission_event_count
@@ -123,6 +148,10 @@ impl WalkiePlay {
             return false;
         }
 
+        if preempts {
+            warn!("WalkiePlay: {:?} preempts {:?}", event, self.event);
+            self.preempt_pending = true;
+        }
         warn!(
             "WalkiePlay: {:?} - play dice: {}/{} (threshold: {})",
             event, dice, max_dice_value, dice_threshold
@@ -142,6 +171,23 @@ impl WalkiePlay {
         true
     }
 
+    /// Queues the last played message again, skipping its cooldown.
+    ///
+    /// Play counts are left untouched so replays don't affect priority downgrades.
+    /// Returns false if nothing has played yet or a message is already in progress.
+    pub fn replay_last(&mut self) -> bool {
+        if self.event.is_some() {
+            return false;
+        }
+        let Some((event, voice_line)) = self.last_played.clone() else {
+            return false;
+        };
+        self.event = Some(event);
+        self.state = None;
+        self.current_voice_line = Some(voice_line);
+        true
+    }
+
     /// Marks the event as played, even tough it wasn't. This is a the condition is already met and this makes no sense to trigger later.
     pub fn mark(&mut self, event: WalkieEvent, time: f64) {
         self.played_events.entry(event).or_default().last_played = time;
@@ -150,8 +196,10 @@ impl WalkiePlay {
     /// Reset all the state of the walkie play, so it will play again on a new mission.
     pub fn reset(&mut self) {
         let omec = self.other_mission_event_count.clone();
+        let hint_frequency = self.hint_frequency;
         let new_self = Self::default();
         *self = new_self;
+        self.hint_frequency = hint_frequency;
         // Ensure current_voice_line is also reset, though Default::default() handles it.
         self.current_voice_line = None;
         // Keep the other mission event count, so it can be used in the next mission.
@@ -186,3 +234,54 @@ pub enum WalkieSoundState {
     Talking,
     Outro,
 }
+
+impl WalkieSoundState {
+    /// Volume of this part of the transmission before user settings are applied.
+    /// Voice lines are recorded much louder than the radio clicks.
+    pub fn base_volume(&self) -> f32 {
+        match self {
+            WalkieSoundState::Talking => 0.2,
+            WalkieSoundState::Intro | WalkieSoundState::Outro => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_last_keeps_play_counts() {
+        let mut walkie_play = WalkiePlay::default();
+        assert!(!walkie_play.replay_last());
+
+        let line = VoiceLineData {
+            ogg_path: "sounds/radio-on-zzt.ogg".to_string(),
+            subtitle_text: "Check the van.".to_string(),
+            tags: vec![],
+            length_seconds: 2,
+        };
+        walkie_play.played_events.insert(
+            WalkieEvent::GearInVan,
+            WalkieEventStats {
+                count: 1,
+                other_count: 0,
+                last_played: 10.0,
+            },
+        );
+        walkie_play.last_played = Some((WalkieEvent::GearInVan, line.clone()));
+
+        assert!(walkie_play.replay_last());
+        assert_eq!(walkie_play.event, Some(WalkieEvent::GearInVan));
+        assert_eq!(
+            walkie_play
+                .current_voice_line
+                .as_ref()
+                .map(|l| &l.subtitle_text),
+            Some(&line.subtitle_text)
+        );
+        assert_eq!(walkie_play.played_events[&WalkieEvent::GearInVan].count, 1);
+        // Can't queue a replay while a message is in progress.
+        assert!(!walkie_play.replay_last());
+    }
+}